@@ -0,0 +1,285 @@
+-- ============================================================================
+-- Migration 004: Full-text search index
+-- ============================================================================
+--
+-- One FTS5 table indexes the free-text fields of every entity that has them.
+-- entity_type / entity_id / date are stored but not tokenized; title and body
+-- are searchable. Triggers keep the index in sync with every write path —
+-- Tauri commands, CLI, HTTP API, import and test data generation — so no
+-- command has to remember to update it.
+--
+-- Not part of export/import: the index is derived data and is rebuilt by the
+-- triggers as rows are re-inserted.
+--
+-- entity_id is UNINDEXED, so rows are keyed by a deterministic rowid instead:
+-- source id * 16 + a code per entity type. Trigger deletes are then rowid
+-- point lookups rather than scans of the whole index. Codes:
+--   0 journal, 1 study_session, 2 application, 3 status_change,
+--   4 urge_entry, 5 relapse_entry, 6 weekly_review, 7 periodic_review (014).
+-- ============================================================================
+
+CREATE VIRTUAL TABLE search_index USING fts5(
+  entity_type UNINDEXED,
+  entity_id UNINDEXED,
+  date UNINDEXED,
+  title,
+  body,
+  tokenize = 'porter unicode61'
+);
+
+-- ----------------------------------------------------------------------------
+-- 1. journal
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 0, 'journal', id, date,
+    'Journal ' || journal.date,
+    journal.highlight || char(10) ||
+      journal.gratitude || char(10) ||
+      journal.reflection || char(10) ||
+      journal.tomorrow_goal
+  FROM journal;
+
+CREATE TRIGGER search_journal_ai AFTER INSERT ON journal BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 0, 'journal', NEW.id, NEW.date,
+    'Journal ' || NEW.date,
+    NEW.highlight || char(10) ||
+      NEW.gratitude || char(10) ||
+      NEW.reflection || char(10) ||
+      NEW.tomorrow_goal
+  );
+END;
+
+CREATE TRIGGER search_journal_au AFTER UPDATE ON journal BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 0;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 0, 'journal', NEW.id, NEW.date,
+    'Journal ' || NEW.date,
+    NEW.highlight || char(10) ||
+      NEW.gratitude || char(10) ||
+      NEW.reflection || char(10) ||
+      NEW.tomorrow_goal
+  );
+END;
+
+CREATE TRIGGER search_journal_ad AFTER DELETE ON journal BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 0;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 2. study_session
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 1, 'study_session', id, date,
+    study_session.subject || CASE WHEN study_session.topic != '' THEN ' — ' || study_session.topic ELSE '' END,
+    study_session.notes
+  FROM study_session;
+
+CREATE TRIGGER search_study_session_ai AFTER INSERT ON study_session BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 1, 'study_session', NEW.id, NEW.date,
+    NEW.subject || CASE WHEN NEW.topic != '' THEN ' — ' || NEW.topic ELSE '' END,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_study_session_au AFTER UPDATE ON study_session BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 1;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 1, 'study_session', NEW.id, NEW.date,
+    NEW.subject || CASE WHEN NEW.topic != '' THEN ' — ' || NEW.topic ELSE '' END,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_study_session_ad AFTER DELETE ON study_session BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 1;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 3. application
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 2, 'application', id, date_applied,
+    application.company || ' — ' || application.role,
+    application.notes
+  FROM application;
+
+CREATE TRIGGER search_application_ai AFTER INSERT ON application BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 2, 'application', NEW.id, NEW.date_applied,
+    NEW.company || ' — ' || NEW.role,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_application_au AFTER UPDATE ON application BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 2;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 2, 'application', NEW.id, NEW.date_applied,
+    NEW.company || ' — ' || NEW.role,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_application_ad AFTER DELETE ON application BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 2;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 4. status_change
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 3, 'status_change', id, date,
+    COALESCE((SELECT company FROM application WHERE id = status_change.application_id), '') || ' — ' || status_change.status,
+    status_change.notes
+  FROM status_change;
+
+CREATE TRIGGER search_status_change_ai AFTER INSERT ON status_change BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 3, 'status_change', NEW.id, NEW.date,
+    COALESCE((SELECT company FROM application WHERE id = NEW.application_id), '') || ' — ' || NEW.status,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_status_change_au AFTER UPDATE ON status_change BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 3;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 3, 'status_change', NEW.id, NEW.date,
+    COALESCE((SELECT company FROM application WHERE id = NEW.application_id), '') || ' — ' || NEW.status,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_status_change_ad AFTER DELETE ON status_change BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 3;
+END;
+
+-- Status change titles carry the company name, so a rename rebuilds them.
+CREATE TRIGGER search_status_change_company_au AFTER UPDATE OF company ON application BEGIN
+  DELETE FROM search_index
+    WHERE rowid IN (SELECT id * 16 + 3 FROM status_change WHERE application_id = NEW.id);
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+    SELECT
+      id * 16 + 3, 'status_change', id, date,
+      NEW.company || ' — ' || status_change.status,
+      status_change.notes
+    FROM status_change WHERE application_id = NEW.id;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 5. urge_entry
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 4, 'urge_entry', id, date,
+    'Urge — ' || urge_entry.technique,
+    urge_entry.trigger || char(10) ||
+      urge_entry.notes
+  FROM urge_entry;
+
+CREATE TRIGGER search_urge_entry_ai AFTER INSERT ON urge_entry BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 4, 'urge_entry', NEW.id, NEW.date,
+    'Urge — ' || NEW.technique,
+    NEW.trigger || char(10) ||
+      NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_urge_entry_au AFTER UPDATE ON urge_entry BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 4;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 4, 'urge_entry', NEW.id, NEW.date,
+    'Urge — ' || NEW.technique,
+    NEW.trigger || char(10) ||
+      NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_urge_entry_ad AFTER DELETE ON urge_entry BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 4;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 6. relapse_entry
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 5, 'relapse_entry', id, date,
+    'Relapse — ' || relapse_entry.trigger,
+    relapse_entry.notes
+  FROM relapse_entry;
+
+CREATE TRIGGER search_relapse_entry_ai AFTER INSERT ON relapse_entry BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 5, 'relapse_entry', NEW.id, NEW.date,
+    'Relapse — ' || NEW.trigger,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_relapse_entry_au AFTER UPDATE ON relapse_entry BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 5;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 5, 'relapse_entry', NEW.id, NEW.date,
+    'Relapse — ' || NEW.trigger,
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER search_relapse_entry_ad AFTER DELETE ON relapse_entry BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 5;
+END;
+
+-- ----------------------------------------------------------------------------
+-- 7. weekly_review
+-- ----------------------------------------------------------------------------
+
+INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body)
+  SELECT
+    id * 16 + 6, 'weekly_review', id, week_start,
+    'Week ' || weekly_review.week_number || ' review',
+    weekly_review.biggest_win || char(10) ||
+      weekly_review.biggest_challenge || char(10) ||
+      weekly_review.next_week_goal || char(10) ||
+      weekly_review.reflection
+  FROM weekly_review;
+
+CREATE TRIGGER search_weekly_review_ai AFTER INSERT ON weekly_review BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 6, 'weekly_review', NEW.id, NEW.week_start,
+    'Week ' || NEW.week_number || ' review',
+    NEW.biggest_win || char(10) ||
+      NEW.biggest_challenge || char(10) ||
+      NEW.next_week_goal || char(10) ||
+      NEW.reflection
+  );
+END;
+
+CREATE TRIGGER search_weekly_review_au AFTER UPDATE ON weekly_review BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 6;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 6, 'weekly_review', NEW.id, NEW.week_start,
+    'Week ' || NEW.week_number || ' review',
+    NEW.biggest_win || char(10) ||
+      NEW.biggest_challenge || char(10) ||
+      NEW.next_week_goal || char(10) ||
+      NEW.reflection
+  );
+END;
+
+CREATE TRIGGER search_weekly_review_ad AFTER DELETE ON weekly_review BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 6;
+END;
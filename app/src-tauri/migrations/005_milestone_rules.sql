@@ -0,0 +1,58 @@
+-- ============================================================================
+-- Migration 005: Declarative milestone rules
+-- ============================================================================
+--
+-- Milestone check logic moves from a hardcoded match on ids into the table:
+-- each row carries `metric comparator target` plus an optional window and
+-- habit (engine/milestone_rules.rs). `threshold` stays as the human-readable
+-- description. A NULL metric means the milestone has no rule and is never
+-- awarded automatically.
+--
+-- Metric names are validated in Rust rather than by CHECK so new metrics do
+-- not need a table rebuild.
+--
+-- is_custom = 1 marks user-created milestones; only those may be edited or
+-- deleted.
+-- ============================================================================
+
+ALTER TABLE milestone ADD COLUMN metric TEXT;
+ALTER TABLE milestone ADD COLUMN comparator TEXT
+  CHECK(comparator IS NULL OR comparator IN ('>=', '>', '==', '<=', '<'));
+ALTER TABLE milestone ADD COLUMN target REAL;
+ALTER TABLE milestone ADD COLUMN window_days INTEGER
+  CHECK(window_days IS NULL OR window_days > 0);
+ALTER TABLE milestone ADD COLUMN habit_name TEXT;
+ALTER TABLE milestone ADD COLUMN is_custom INTEGER NOT NULL DEFAULT 0
+  CHECK(is_custom IN (0, 1));
+
+-- ----------------------------------------------------------------------------
+-- Rules for the seeded milestones (previously is_threshold_met)
+-- ----------------------------------------------------------------------------
+
+-- Tracking
+UPDATE milestone SET metric = 'days_tracked', comparator = '>=', target = 1 WHERE id = 'first_steps';
+UPDATE milestone SET metric = 'days_tracked', comparator = '>=', target = 7 WHERE id = 'one_week_in';
+
+-- Score. avg_80 follows its description ("30-day avg >= 80%"); final scores
+-- are fractions, so the target is 0.8. trending_up has no rule yet.
+UPDATE milestone SET metric = 'streak', comparator = '>=', target = 5 WHERE id = 'streak_5';
+UPDATE milestone SET metric = 'streak', comparator = '>=', target = 7 WHERE id = 'streak_7';
+UPDATE milestone SET metric = 'streak', comparator = '>=', target = 30 WHERE id = 'streak_30';
+UPDATE milestone SET metric = 'avg_score', comparator = '>=', target = 0.8, window_days = 30 WHERE id = 'avg_80';
+
+-- Clean
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 1 WHERE id = 'clean_1';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 7 WHERE id = 'clean_7';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 14 WHERE id = 'clean_14';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 30 WHERE id = 'clean_30';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 60 WHERE id = 'clean_60';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 90 WHERE id = 'clean_90';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 180 WHERE id = 'clean_180';
+UPDATE milestone SET metric = 'clean_days', comparator = '>=', target = 365 WHERE id = 'clean_365';
+
+-- Study
+UPDATE milestone SET metric = 'study_hours', comparator = '>', target = 0 WHERE id = 'first_session';
+UPDATE milestone SET metric = 'study_hours', comparator = '>=', target = 50 WHERE id = 'study_50h';
+UPDATE milestone SET metric = 'study_hours', comparator = '>=', target = 100 WHERE id = 'study_100h';
+UPDATE milestone SET metric = 'study_hours', comparator = '>=', target = 500 WHERE id = 'study_500h';
+UPDATE milestone SET metric = 'high_focus_sessions', comparator = '>=', target = 10 WHERE id = 'focus_master';
@@ -0,0 +1,15 @@
+-- ============================================================================
+-- Migration 006: Rule for the trending_up milestone
+-- ============================================================================
+--
+-- "30d avg > previous 30d avg for 3 consecutive months" becomes the
+-- improving_months metric: the rolling 30-day average of final_score at the
+-- end of each calendar month is compared with the previous month's
+-- (engine/trend.rs). Only completed months count.
+--
+-- The guard leaves the row alone if it was already given a rule by hand.
+-- ============================================================================
+
+UPDATE milestone
+SET metric = 'improving_months', comparator = '>=', target = 3
+WHERE id = 'trending_up' AND metric IS NULL;
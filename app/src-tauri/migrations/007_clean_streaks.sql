@@ -0,0 +1,33 @@
+-- ============================================================================
+-- Migration 007: Configurable clean streaks
+-- ============================================================================
+--
+-- A clean streak names a set of vices (habit_config rows with pool = 'vice',
+-- stored as a JSON array of habit names) and optionally relapse_entry dates.
+-- A logged day is clean for a streak when none of its vices were done and,
+-- if use_relapse_entries = 1, no relapse was recorded that day.
+--
+-- The default streak replaces the hardcoded `porn = 0 AND masturbate = 0`
+-- rule: it drives MilestoneContext.consecutive_clean_days and every clean_N
+-- milestone. Exactly one row is the default (partial unique index).
+--
+-- milestone.clean_streak names the streak a clean_days rule measures; NULL
+-- means the default streak.
+-- ============================================================================
+
+CREATE TABLE clean_streak (
+  id                  INTEGER PRIMARY KEY,
+  name                TEXT NOT NULL UNIQUE COLLATE NOCASE,
+  vices               TEXT NOT NULL DEFAULT '[]',
+  use_relapse_entries INTEGER NOT NULL DEFAULT 0 CHECK(use_relapse_entries IN (0, 1)),
+  is_default          INTEGER NOT NULL DEFAULT 0 CHECK(is_default IN (0, 1)),
+  created_at          TEXT NOT NULL,
+  last_modified       TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_clean_streak_default ON clean_streak(is_default) WHERE is_default = 1;
+
+INSERT INTO clean_streak (name, vices, use_relapse_entries, is_default, created_at, last_modified)
+VALUES ('Recovery', '["porn","masturbate"]', 0, 1, '2026-01-20T00:00:00Z', '2026-01-20T00:00:00Z');
+
+ALTER TABLE milestone ADD COLUMN clean_streak TEXT;
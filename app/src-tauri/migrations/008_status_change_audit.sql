@@ -0,0 +1,27 @@
+-- ============================================================================
+-- Migration 008: Status change audit log
+-- ============================================================================
+--
+-- status_change rows can now be corrected (update_status_change) or removed
+-- (delete_status_change). Each correction appends a row here with the values
+-- before and after, so the pipeline history stays reconstructible.
+--
+-- status_change_id has no FK: the audited row may no longer exist. The
+-- new_* columns are NULL for deletions.
+-- ============================================================================
+
+CREATE TABLE status_change_audit (
+  id                INTEGER PRIMARY KEY,
+  status_change_id  INTEGER NOT NULL,
+  application_id    INTEGER NOT NULL REFERENCES application(id) ON DELETE RESTRICT,
+  action            TEXT NOT NULL CHECK(action IN ('update', 'delete')),
+  old_status        TEXT NOT NULL,
+  old_date          TEXT NOT NULL,
+  old_notes         TEXT NOT NULL,
+  new_status        TEXT,
+  new_date          TEXT,
+  new_notes         TEXT,
+  created_at        TEXT NOT NULL
+);
+
+CREATE INDEX idx_status_change_audit_app ON status_change_audit(application_id);
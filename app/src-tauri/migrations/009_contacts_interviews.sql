@@ -0,0 +1,45 @@
+-- ============================================================================
+-- Migration 009: Application contacts and interviews
+-- ============================================================================
+--
+-- An application carries a single contact_name/contact_email. These child
+-- tables hold any number of people (recruiter, hiring manager, referrer, ...)
+-- and interview events per application. Both are returned with the
+-- application by get_application.
+--
+-- interview.time is 'HH:MM' or '' when unknown; interviewers is free text.
+-- ============================================================================
+
+CREATE TABLE contact (
+  id              INTEGER PRIMARY KEY,
+  application_id  INTEGER NOT NULL REFERENCES application(id) ON DELETE RESTRICT,
+  name            TEXT NOT NULL,
+  role            TEXT NOT NULL CHECK(role IN (
+    'recruiter', 'hiring_manager', 'referrer', 'interviewer', 'other'
+  )),
+  email           TEXT NOT NULL DEFAULT '',
+  phone           TEXT NOT NULL DEFAULT '',
+  notes           TEXT NOT NULL DEFAULT '',
+  created_at      TEXT NOT NULL,
+  last_modified   TEXT NOT NULL
+);
+
+CREATE INDEX idx_contact_app ON contact(application_id);
+
+CREATE TABLE interview (
+  id              INTEGER PRIMARY KEY,
+  application_id  INTEGER NOT NULL REFERENCES application(id) ON DELETE RESTRICT,
+  date            TEXT NOT NULL,
+  time            TEXT NOT NULL DEFAULT '',
+  round           TEXT NOT NULL,
+  interviewers    TEXT NOT NULL DEFAULT '',
+  prep_notes      TEXT NOT NULL DEFAULT '',
+  outcome         TEXT NOT NULL DEFAULT 'pending' CHECK(outcome IN (
+    'pending', 'passed', 'failed', 'cancelled'
+  )),
+  notes           TEXT NOT NULL DEFAULT '',
+  created_at      TEXT NOT NULL,
+  last_modified   TEXT NOT NULL
+);
+
+CREATE INDEX idx_interview_app ON interview(application_id);
@@ -0,0 +1,104 @@
+-- ============================================================================
+-- Migration 010: Recovery amendments
+-- ============================================================================
+--
+-- relapse_entry and urge_entry lock 24 hours after creation (ADR-006). A
+-- correction after that is appended here instead of rewriting the row: one
+-- row per changed field, with the value it replaced and a reason. Rows are
+-- never updated or deleted.
+--
+-- entry_id has no FK because it points into either table, named by entity.
+-- Values are stored as text; integer fields are cast back in the views.
+--
+-- relapse_entry_effective and urge_entry_effective overlay the latest
+-- amendment of each field on the original row. They have the same columns
+-- as their tables, so a query can read either one.
+-- ============================================================================
+
+CREATE TABLE recovery_amendment (
+  id          INTEGER PRIMARY KEY,
+  entity      TEXT NOT NULL CHECK(entity IN ('relapse_entry', 'urge_entry')),
+  entry_id    INTEGER NOT NULL,
+  field       TEXT NOT NULL,
+  old_value   TEXT NOT NULL,
+  new_value   TEXT NOT NULL,
+  reason      TEXT NOT NULL,
+  created_at  TEXT NOT NULL
+);
+
+CREATE INDEX idx_recovery_amendment_entry ON recovery_amendment(entity, entry_id);
+
+CREATE VIEW relapse_entry_effective AS
+SELECT
+  r.id,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'date'
+     ORDER BY a.id DESC LIMIT 1), r.date) AS date,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'time'
+     ORDER BY a.id DESC LIMIT 1), r.time) AS time,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'duration'
+     ORDER BY a.id DESC LIMIT 1), r.duration) AS duration,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'trigger'
+     ORDER BY a.id DESC LIMIT 1), r.trigger) AS trigger,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'location'
+     ORDER BY a.id DESC LIMIT 1), r.location) AS location,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'device'
+     ORDER BY a.id DESC LIMIT 1), r.device) AS device,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'activity_before'
+     ORDER BY a.id DESC LIMIT 1), r.activity_before) AS activity_before,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'emotional_state'
+     ORDER BY a.id DESC LIMIT 1), r.emotional_state) AS emotional_state,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'resistance_technique'
+     ORDER BY a.id DESC LIMIT 1), r.resistance_technique) AS resistance_technique,
+  CAST(COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'urge_intensity'
+     ORDER BY a.id DESC LIMIT 1), r.urge_intensity) AS INTEGER) AS urge_intensity,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'relapse_entry' AND a.entry_id = r.id AND a.field = 'notes'
+     ORDER BY a.id DESC LIMIT 1), r.notes) AS notes,
+  r.urge_entry_id,
+  r.created_at,
+  r.last_modified
+FROM relapse_entry r;
+
+CREATE VIEW urge_entry_effective AS
+SELECT
+  r.id,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'date'
+     ORDER BY a.id DESC LIMIT 1), r.date) AS date,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'time'
+     ORDER BY a.id DESC LIMIT 1), r.time) AS time,
+  CAST(COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'intensity'
+     ORDER BY a.id DESC LIMIT 1), r.intensity) AS INTEGER) AS intensity,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'technique'
+     ORDER BY a.id DESC LIMIT 1), r.technique) AS technique,
+  CAST(COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'effectiveness'
+     ORDER BY a.id DESC LIMIT 1), r.effectiveness) AS INTEGER) AS effectiveness,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'duration'
+     ORDER BY a.id DESC LIMIT 1), r.duration) AS duration,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'did_pass'
+     ORDER BY a.id DESC LIMIT 1), r.did_pass) AS did_pass,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'trigger'
+     ORDER BY a.id DESC LIMIT 1), r.trigger) AS trigger,
+  COALESCE((SELECT a.new_value FROM recovery_amendment a
+     WHERE a.entity = 'urge_entry' AND a.entry_id = r.id AND a.field = 'notes'
+     ORDER BY a.id DESC LIMIT 1), r.notes) AS notes,
+  r.created_at,
+  r.last_modified
+FROM urge_entry r;
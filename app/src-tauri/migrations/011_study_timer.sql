@@ -0,0 +1,23 @@
+-- ============================================================================
+-- Migration 011: Study timer
+-- ============================================================================
+--
+-- A study session in progress, started from the app instead of typed in
+-- afterwards. It lives here rather than in study_session so that analytics
+-- never see a half-finished session. stop_study_timer turns it into a
+-- study_session row and deletes it, so there is at most one row.
+--
+-- Times are local 'YYYY-MM-DDTHH:MM:SS'. paused_at is set while paused;
+-- paused_seconds sums the pauses already resumed.
+-- ============================================================================
+
+CREATE TABLE study_timer (
+  id              INTEGER PRIMARY KEY,
+  subject         TEXT NOT NULL,
+  study_type      TEXT NOT NULL,
+  location        TEXT NOT NULL,
+  started_at      TEXT NOT NULL,
+  paused_at       TEXT,
+  paused_seconds  INTEGER NOT NULL DEFAULT 0 CHECK(paused_seconds >= 0),
+  pause_count     INTEGER NOT NULL DEFAULT 0 CHECK(pause_count >= 0)
+);
@@ -0,0 +1,25 @@
+-- ============================================================================
+-- Migration 012: Study goals
+-- ============================================================================
+--
+-- Targets for study hours. A goal covers one subject, or every subject when
+-- subject is NULL. period 'week' sets hours for each week from start_date
+-- to end_date (open-ended when end_date is NULL); period 'term' sets hours
+-- for the whole start_date..end_date range, so end_date is required.
+--
+-- Progress is computed from study_session.duration_minutes; nothing about
+-- it is stored.
+-- ============================================================================
+
+CREATE TABLE study_goal (
+  id             INTEGER PRIMARY KEY,
+  subject        TEXT,
+  period         TEXT NOT NULL CHECK(period IN ('week', 'term')),
+  target_hours   REAL NOT NULL CHECK(target_hours > 0),
+  start_date     TEXT NOT NULL,
+  end_date       TEXT,
+  created_at     TEXT NOT NULL,
+  last_modified  TEXT NOT NULL,
+  CHECK(end_date IS NULL OR end_date >= start_date),
+  CHECK(period = 'week' OR end_date IS NOT NULL)
+);
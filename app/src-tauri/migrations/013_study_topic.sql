@@ -0,0 +1,40 @@
+-- ============================================================================
+-- Migration 013: Study topics
+-- ============================================================================
+--
+-- study_session.topic was free text. Each distinct (subject, topic) pair now
+-- becomes a study_topic row, and sessions point at it through topic_id. The
+-- topic text stays on the session as entered.
+--
+-- Topic names are unique per subject, ignoring case. The review schedule is
+-- not stored: it is replayed from the topic's sessions (see
+-- engine::spaced_repetition).
+-- ============================================================================
+
+CREATE TABLE study_topic (
+  id          INTEGER PRIMARY KEY,
+  subject     TEXT NOT NULL,
+  name        TEXT NOT NULL COLLATE NOCASE,
+  created_at  TEXT NOT NULL,
+  UNIQUE(subject, name)
+);
+
+ALTER TABLE study_session ADD COLUMN topic_id INTEGER
+  REFERENCES study_topic(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_study_session_topic ON study_session(topic_id);
+
+-- Backfill topics from existing sessions, named as first logged.
+INSERT INTO study_topic (subject, name, created_at)
+SELECT subject, TRIM(topic), MIN(logged_at)
+FROM study_session
+WHERE TRIM(topic) != ''
+GROUP BY subject, TRIM(topic) COLLATE NOCASE
+ORDER BY MIN(logged_at);
+
+UPDATE study_session
+SET topic_id = (
+  SELECT t.id FROM study_topic t
+  WHERE t.subject = study_session.subject AND t.name = TRIM(study_session.topic)
+)
+WHERE TRIM(topic) != '';
@@ -0,0 +1,87 @@
+-- ============================================================================
+-- Migration 014: Monthly, quarterly and yearly reviews
+-- ============================================================================
+--
+-- The same frozen-at-save stats as weekly_review (ADR-002 SD3), for a
+-- calendar month, quarter or year. The roll-up columns (weeks_reviewed,
+-- best/worst week, avg_weekly_score) and week_snapshot come from the saved
+-- weekly_review rows whose week starts in the period, not from daily_log.
+--
+-- Each period has its own reflection prompts; reflections is a JSON object
+-- of prompt key -> answer.
+-- ============================================================================
+
+CREATE TABLE periodic_review (
+  id                  INTEGER PRIMARY KEY,
+  period              TEXT NOT NULL CHECK(period IN ('month', 'quarter', 'year')),
+  period_start        TEXT NOT NULL,
+  period_end          TEXT NOT NULL,
+
+  -- Auto-computed stats (frozen at save time — ADR-002 SD3)
+  avg_score           REAL,
+  days_tracked        INTEGER,
+  best_day_score      REAL,
+  worst_day_score     REAL,
+  habits_completed    INTEGER,
+  study_hours         REAL,
+  applications_sent   INTEGER,
+  relapses            INTEGER,
+  urges_resisted      INTEGER,
+  streak_at_end       INTEGER,
+
+  -- Roll-up of the saved weekly reviews (frozen at save time)
+  weeks_reviewed      INTEGER,
+  best_week_start     TEXT,
+  best_week_score     REAL,
+  worst_week_start    TEXT,
+  worst_week_score    REAL,
+  avg_weekly_score    REAL,
+
+  -- Manual reflection
+  reflections         TEXT NOT NULL DEFAULT '{}',
+
+  -- Snapshot data
+  snapshot_date       TEXT,
+  score_snapshot      TEXT,
+  week_snapshot       TEXT,
+
+  logged_at           TEXT NOT NULL,
+  last_modified       TEXT NOT NULL,
+  UNIQUE(period, period_start),
+  CHECK(period_end >= period_start)
+);
+
+-- ----------------------------------------------------------------------------
+-- Full-text search (see migration 004)
+-- ----------------------------------------------------------------------------
+
+CREATE TRIGGER search_periodic_review_ai AFTER INSERT ON periodic_review BEGIN
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 7, 'periodic_review', NEW.id, NEW.period_start,
+    CASE NEW.period
+      WHEN 'month' THEN substr(NEW.period_start, 1, 7)
+      WHEN 'quarter' THEN substr(NEW.period_start, 1, 4) || ' Q' ||
+        ((CAST(substr(NEW.period_start, 6, 2) AS INTEGER) + 2) / 3)
+      ELSE substr(NEW.period_start, 1, 4)
+    END || ' review',
+    (SELECT COALESCE(group_concat(value, char(10)), '') FROM json_each(NEW.reflections))
+  );
+END;
+
+CREATE TRIGGER search_periodic_review_au AFTER UPDATE ON periodic_review BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 7;
+  INSERT INTO search_index (rowid, entity_type, entity_id, date, title, body) VALUES (
+    NEW.id * 16 + 7, 'periodic_review', NEW.id, NEW.period_start,
+    CASE NEW.period
+      WHEN 'month' THEN substr(NEW.period_start, 1, 7)
+      WHEN 'quarter' THEN substr(NEW.period_start, 1, 4) || ' Q' ||
+        ((CAST(substr(NEW.period_start, 6, 2) AS INTEGER) + 2) / 3)
+      ELSE substr(NEW.period_start, 1, 4)
+    END || ' review',
+    (SELECT COALESCE(group_concat(value, char(10)), '') FROM json_each(NEW.reflections))
+  );
+END;
+
+CREATE TRIGGER search_periodic_review_ad AFTER DELETE ON periodic_review BEGIN
+  DELETE FROM search_index WHERE rowid = OLD.id * 16 + 7;
+END;
@@ -0,0 +1,731 @@
+//! Headless command-line front end (`ltu-cli`).
+//!
+//! Opens the same `ltu.db` as the desktop app (or a fixture passed with
+//! `--db`), runs pending migrations, and dispatches each subcommand to the
+//! `*_impl` function behind the matching Tauri command — validation,
+//! transactions, scoring and the edit cascade are therefore identical to the
+//! UI. Results are printed to stdout as pretty JSON so they can be piped into
+//! other tools; errors go to stderr.
+//!
+//! Unlike the app, the CLI does not take a rolling backup on every launch
+//! (ADR-001 SD3) — scripted use would rotate real backups out within minutes.
+//! Use `ltu-cli backup` explicitly before bulk edits.
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::commands::daily_log::{
+    daily_log_to_input, get_daily_logs_impl, query_daily_log_by_date, rescore_all_impl,
+    save_daily_log_with_milestones, DailyLogInput,
+};
+use crate::commands::data::{export_data_impl, get_db_stats_impl, import_data_impl};
+use crate::commands::journal::{query_journal_by_date, save_journal_impl, JournalInput};
+use crate::commands::milestone::{recompute_milestones_impl, Milestone};
+use crate::commands::search::{search_impl, SearchFilters};
+use crate::commands::study::{
+    delete_study_session_impl, get_study_sessions_impl, get_study_sessions_range_impl,
+    save_study_session_with_milestones, update_study_session_impl, StudySessionInput,
+};
+use crate::commands::CommandError;
+use crate::db;
+use crate::server;
+
+const USAGE: &str = "\
+Usage: ltu-cli [--db <path>] <command> [args...]
+
+Commands:
+  log <date> [field=value ...]       Show a daily log, or create/update it with the given fields
+  log <start> <end>                  List daily logs in a date range
+  journal <date> [field=value ...]   Show a journal entry, or create/update it
+  study list <date> [<end>]          List study sessions for a date or date range
+  study add field=value ...          Add a study session
+  study update <id> field=value ...  Update fields of a study session
+  study delete <id>                  Delete a study session
+  export [file]                      Write a JSON export to a file (stdout if omitted)
+  import <file>                      Replace ALL data with a JSON export
+  backup [dest]                      Rolling backup into backups/, or a copy at dest
+  search <words...>                  Full-text search across journal, study, applications, recovery and reviews
+  stats                              Row counts per table and database file size
+  rescore                            Recompute every daily score with the current config
+  recompute-milestones               Re-date milestones from history and award any missed
+  serve [--port N] [--rotate-token]  Local HTTP/JSON API on 127.0.0.1 (token in api_token beside the DB)
+
+Dates are YYYY-MM-DD. Field names match the JSON field names of the
+corresponding input type (e.g. gym=1, meal_quality=Good, mood=4).";
+
+/// Exit code for a command that ran but failed.
+const EXIT_ERROR: i32 = 1;
+/// Exit code for malformed invocations (unknown command, missing arguments).
+const EXIT_USAGE: i32 = 2;
+
+/// Fields a new study session must be given explicitly. `date` defaults to
+/// today; `duration_minutes` to the time between start and end; `topic`,
+/// `resources` and `notes` to empty.
+const REQUIRED_STUDY_FIELDS: &[&str] = &[
+    "subject",
+    "study_type",
+    "start_time",
+    "end_time",
+    "focus_score",
+    "location",
+];
+
+// ---------------------------------------------------------------------------
+// Entry Point
+// ---------------------------------------------------------------------------
+
+/// Parse `args` (without the program name), run the command, and return the
+/// process exit code.
+pub fn run(args: &[String]) -> i32 {
+    let (db_path, rest) = match split_db_flag(args) {
+        Ok(parsed) => parsed,
+        Err(msg) => return usage_error(&msg),
+    };
+
+    if rest.is_empty() || rest[0] == "help" || rest[0] == "--help" || rest[0] == "-h" {
+        println!("{}", USAGE);
+        return if rest.is_empty() { EXIT_USAGE } else { 0 };
+    }
+
+    let mut conn = match db::open_db(&db_path) {
+        Ok(conn) => conn,
+        Err(e) => {
+            eprintln!("Failed to open database '{}': {}", db_path.display(), e);
+            return EXIT_ERROR;
+        }
+    };
+    if let Err(e) = db::migrations::run_migrations(&mut conn) {
+        eprintln!("Failed to run migrations: {}", e);
+        return EXIT_ERROR;
+    }
+
+    match dispatch(&conn, &db_path, &rest) {
+        Ok(Value::Null) => 0,
+        Ok(output) => match serde_json::to_string_pretty(&output) {
+            Ok(text) => {
+                print_stdout(&text);
+                0
+            }
+            Err(e) => {
+                eprintln!("JSON error: {}", e);
+                EXIT_ERROR
+            }
+        },
+        Err(CliError::Usage(msg)) => usage_error(&msg),
+        Err(CliError::Command(e)) => {
+            eprintln!("Error: {}", e);
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Write to stdout, ignoring a closed pipe (`ltu-cli export | head`).
+fn print_stdout(text: &str) {
+    use std::io::Write;
+    let _ = writeln!(std::io::stdout().lock(), "{}", text);
+}
+
+fn usage_error(msg: &str) -> i32 {
+    eprintln!("{}\n\n{}", msg, USAGE);
+    EXIT_USAGE
+}
+
+/// Extracts `--db <path>` (anywhere before the command) and returns the
+/// database path plus the remaining arguments.
+fn split_db_flag(args: &[String]) -> Result<(PathBuf, Vec<String>), String> {
+    let mut db_path: Option<PathBuf> = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--db" && rest.is_empty() {
+            let path = iter
+                .next()
+                .ok_or_else(|| "--db requires a path".to_string())?;
+            db_path = Some(PathBuf::from(path));
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    let db_path = match db_path {
+        Some(path) => path,
+        None => {
+            std::fs::create_dir_all(db::get_db_dir())
+                .map_err(|e| format!("Failed to create database directory: {}", e))?;
+            db::get_db_path()
+        }
+    };
+
+    Ok((db_path, rest))
+}
+
+// ---------------------------------------------------------------------------
+// Dispatch
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum CliError {
+    /// Bad invocation — printed together with the usage text.
+    Usage(String),
+    /// The underlying command failed.
+    Command(CommandError),
+}
+
+impl<E: Into<CommandError>> From<E> for CliError {
+    fn from(e: E) -> Self {
+        CliError::Command(e.into())
+    }
+}
+
+type CliResult<T> = Result<T, CliError>;
+
+fn usage<T>(msg: impl Into<String>) -> CliResult<T> {
+    Err(CliError::Usage(msg.into()))
+}
+
+/// Run one subcommand against an open connection. Returns the JSON to print,
+/// or `Value::Null` when there is nothing to print.
+fn dispatch(conn: &Connection, db_path: &Path, args: &[String]) -> CliResult<Value> {
+    let (command, rest) = args
+        .split_first()
+        .ok_or_else(|| CliError::Usage("Missing command".to_string()))?;
+
+    match command.as_str() {
+        "log" => cmd_log(conn, rest),
+        "journal" => cmd_journal(conn, rest),
+        "study" => cmd_study(conn, rest),
+        "export" => cmd_export(conn, rest),
+        "import" => cmd_import(conn, rest),
+        "backup" => cmd_backup(conn, db_path, rest),
+        "search" => cmd_search(conn, rest),
+        "stats" => to_json(&get_db_stats_impl(conn, db_path)?),
+        "rescore" => to_json(&rescore_all_impl(conn)?),
+        "recompute-milestones" => cmd_recompute_milestones(conn),
+        "serve" => cmd_serve(conn, db_path, rest),
+        other => usage(format!("Unknown command '{}'", other)),
+    }
+}
+
+fn cmd_log(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    let date = match args.first() {
+        Some(d) => parse_date_arg(d)?,
+        None => return usage("log requires a date"),
+    };
+
+    // `log <start> <end>` — range listing
+    if let Some(second) = args.get(1).filter(|a| !a.contains('=')) {
+        let end = parse_date_arg(second)?;
+        return to_json(&get_daily_logs_impl(conn, &date, &end)?);
+    }
+
+    let assignments = &args[1..];
+    let existing = query_daily_log_by_date(conn, &date)?;
+    if assignments.is_empty() {
+        return to_json(&existing);
+    }
+
+    let base = match existing {
+        Some(log) => daily_log_to_input(&log),
+        None => empty_daily_log_input(&date),
+    };
+    let entry: DailyLogInput = apply_assignments(&base, assignments, &[])?;
+    let (log, achieved) = save_daily_log_with_milestones(conn, entry)?;
+    report_milestones(&achieved);
+    to_json(&log)
+}
+
+fn cmd_journal(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    let date = match args.first() {
+        Some(d) => parse_date_arg(d)?,
+        None => return usage("journal requires a date"),
+    };
+
+    let assignments = &args[1..];
+    let existing = query_journal_by_date(conn, &date)?;
+    if assignments.is_empty() {
+        return to_json(&existing);
+    }
+
+    // A new entry has no sensible default for mood/energy, so both must be given.
+    let (base, required): (JournalInput, &[&str]) = match existing {
+        Some(j) => (
+            JournalInput {
+                date: j.date,
+                mood: j.mood,
+                energy: j.energy,
+                highlight: j.highlight,
+                gratitude: j.gratitude,
+                reflection: j.reflection,
+                tomorrow_goal: j.tomorrow_goal,
+            },
+            &[],
+        ),
+        None => (
+            JournalInput {
+                date: date.clone(),
+                mood: 0,
+                energy: 0,
+                highlight: String::new(),
+                gratitude: String::new(),
+                reflection: String::new(),
+                tomorrow_goal: String::new(),
+            },
+            &["mood", "energy"],
+        ),
+    };
+    let entry: JournalInput = apply_assignments(&base, assignments, required)?;
+    to_json(&save_journal_impl(conn, entry)?)
+}
+
+fn cmd_study(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    let (sub, rest) = match args.split_first() {
+        Some(split) => split,
+        None => return usage("study requires a subcommand: list, add, update, delete"),
+    };
+
+    match sub.as_str() {
+        "list" => match rest {
+            [date] => to_json(&get_study_sessions_impl(conn, &parse_date_arg(date)?)?),
+            [start, end] => to_json(&get_study_sessions_range_impl(
+                conn,
+                &parse_date_arg(start)?,
+                &parse_date_arg(end)?,
+            )?),
+            _ => usage("study list requires a date or a start and end date"),
+        },
+        "add" => {
+            let base = StudySessionInput {
+                date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                subject: String::new(),
+                study_type: String::new(),
+                start_time: String::new(),
+                end_time: String::new(),
+                duration_minutes: 0,
+                focus_score: 0,
+                location: String::new(),
+                topic: String::new(),
+                resources: String::new(),
+                notes: String::new(),
+            };
+            let session: StudySessionInput =
+                apply_assignments(&base, rest, REQUIRED_STUDY_FIELDS)?;
+            let (saved, achieved) = save_study_session_with_milestones(conn, session)?;
+            report_milestones(&achieved);
+            to_json(&saved)
+        }
+        "update" => {
+            let (id, assignments) = match rest.split_first() {
+                Some((id, assignments)) => (parse_id_arg(id)?, assignments),
+                None => return usage("study update requires an id"),
+            };
+            let existing = conn
+                .query_row(
+                    "SELECT date, subject, study_type, start_time, end_time, \
+                     duration_minutes, focus_score, location, topic, resources, notes \
+                     FROM study_session WHERE id = ?1",
+                    [id],
+                    |row| {
+                        Ok(StudySessionInput {
+                            date: row.get("date")?,
+                            subject: row.get("subject")?,
+                            study_type: row.get("study_type")?,
+                            start_time: row.get("start_time")?,
+                            end_time: row.get("end_time")?,
+                            duration_minutes: row.get("duration_minutes")?,
+                            focus_score: row.get("focus_score")?,
+                            location: row.get("location")?,
+                            topic: row.get("topic")?,
+                            resources: row.get("resources")?,
+                            notes: row.get("notes")?,
+                        })
+                    },
+                )
+                .map_err(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => CommandError::from(format!(
+                        "Study session with id {} not found",
+                        id
+                    )),
+                    other => CommandError::from(other),
+                })?;
+            let session: StudySessionInput = apply_assignments(&existing, assignments, &[])?;
+            to_json(&update_study_session_impl(conn, id, session)?)
+        }
+        "delete" => match rest {
+            [id] => {
+                delete_study_session_impl(conn, parse_id_arg(id)?)?;
+                Ok(Value::Null)
+            }
+            _ => usage("study delete requires an id"),
+        },
+        other => usage(format!("Unknown study subcommand '{}'", other)),
+    }
+}
+
+fn cmd_export(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    let json = export_data_impl(conn)?;
+    match args {
+        [] => {
+            print_stdout(&json);
+            Ok(Value::Null)
+        }
+        [file] => {
+            std::fs::write(file, &json)
+                .map_err(|e| format!("Failed to write file '{}': {}", file, e))?;
+            Ok(serde_json::json!({ "exported_to": file }))
+        }
+        _ => usage("export takes at most one file argument"),
+    }
+}
+
+fn cmd_import(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    let file = match args {
+        [file] => file,
+        _ => return usage("import requires exactly one file argument"),
+    };
+    let json = std::fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read file '{}': {}", file, e))?;
+    let achieved = import_data_impl(conn, &json)?;
+    Ok(serde_json::json!({ "imported_from": file, "milestones_achieved": achieved }))
+}
+
+fn cmd_backup(conn: &Connection, db_path: &Path, args: &[String]) -> CliResult<Value> {
+    // Fold the WAL into the main file so a plain file copy is complete.
+    conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
+
+    match args {
+        [] => {
+            let path = db::backup::run_backup(db_path)?
+                .ok_or_else(|| CommandError::from("No database file to back up"))?;
+            Ok(serde_json::json!({ "backup": path.to_string_lossy() }))
+        }
+        [dest] => {
+            std::fs::copy(db_path, dest).map_err(|e| {
+                format!("Failed to backup database to '{}': {}", dest, e)
+            })?;
+            Ok(serde_json::json!({ "backup": dest }))
+        }
+        _ => usage("backup takes at most one destination argument"),
+    }
+}
+
+fn cmd_search(conn: &Connection, args: &[String]) -> CliResult<Value> {
+    if args.is_empty() {
+        return usage("search requires a query");
+    }
+    to_json(&search_impl(conn, &args.join(" "), SearchFilters::default())?)
+}
+
+fn cmd_recompute_milestones(conn: &Connection) -> CliResult<Value> {
+    let result = recompute_milestones_impl(conn)?;
+    report_milestones(&result.newly_achieved);
+    to_json(&result)
+}
+
+fn cmd_serve(conn: &Connection, db_path: &Path, args: &[String]) -> CliResult<Value> {
+    let mut port = server::DEFAULT_PORT;
+    let mut rotate = false;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--port" => match iter.next().map(|p| p.parse::<u16>()) {
+                Some(Ok(p)) => port = p,
+                _ => return usage("--port requires a port number"),
+            },
+            "--rotate-token" => rotate = true,
+            other => return usage(format!("Unknown serve option '{}'", other)),
+        }
+    }
+
+    let token_path = server::token_path(db_path);
+    let token = server::load_or_create_token(&token_path, rotate)?;
+    eprintln!("API token: {}", token_path.display());
+
+    server::serve(conn, port, &token)?;
+    Ok(Value::Null)
+}
+
+// ---------------------------------------------------------------------------
+// Argument Helpers
+// ---------------------------------------------------------------------------
+
+/// Announce newly achieved milestones on stderr, keeping stdout pure JSON.
+fn report_milestones(achieved: &[Milestone]) {
+    for milestone in achieved {
+        eprintln!("Milestone unlocked: {} {}", milestone.emoji, milestone.name);
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> CliResult<Value> {
+    Ok(serde_json::to_value(value)?)
+}
+
+fn parse_date_arg(arg: &str) -> CliResult<String> {
+    match chrono::NaiveDate::parse_from_str(arg, "%Y-%m-%d") {
+        Ok(_) => Ok(arg.to_string()),
+        Err(_) => usage(format!("Invalid date '{}': expected YYYY-MM-DD", arg)),
+    }
+}
+
+fn parse_id_arg(arg: &str) -> CliResult<i64> {
+    match arg.parse::<i64>() {
+        Ok(id) => Ok(id),
+        Err(_) => usage(format!("Invalid id '{}': expected an integer", arg)),
+    }
+}
+
+fn empty_daily_log_input(date: &str) -> DailyLogInput {
+    DailyLogInput {
+        date: date.to_string(),
+        schoolwork: 0,
+        personal_project: 0,
+        classes: 0,
+        job_search: 0,
+        gym: 0,
+        sleep_7_9h: 0,
+        wake_8am: 0,
+        supplements: 0,
+        meal_quality: "None".to_string(),
+        stretching: 0,
+        meditate: 0,
+        read: 0,
+        social: "None".to_string(),
+        porn: 0,
+        masturbate: 0,
+        weed: 0,
+        skip_class: 0,
+        binged_content: 0,
+        gaming_1h: 0,
+        past_12am: 0,
+        late_wake: 0,
+        phone_use: 0,
+    }
+}
+
+/// Overlay `field=value` assignments onto `base` via its JSON representation.
+///
+/// The type of each field in `base` decides how the value is read: string
+/// fields take the raw text, everything else is parsed as JSON (so `gym=1`,
+/// `follow_up_date=null`). Unknown field names are rejected, as is a missing
+/// entry from `required`.
+fn apply_assignments<T: Serialize + DeserializeOwned>(
+    base: &T,
+    assignments: &[String],
+    required: &[&str],
+) -> CliResult<T> {
+    let mut obj = match serde_json::to_value(base)? {
+        Value::Object(obj) => obj,
+        _ => return Err(CommandError::from("Input type is not a JSON object").into()),
+    };
+
+    let mut assigned: Vec<&str> = Vec::with_capacity(assignments.len());
+    for assignment in assignments {
+        let (field, raw) = match assignment.split_once('=') {
+            Some(pair) => pair,
+            None => return usage(format!("Expected field=value, got '{}'", assignment)),
+        };
+        let value = match obj.get(field) {
+            None => return usage(format!("Unknown field '{}'", field)),
+            Some(Value::String(_)) => Value::String(raw.to_string()),
+            Some(_) => match serde_json::from_str::<Value>(raw) {
+                Ok(v) => v,
+                Err(_) => return usage(format!("Invalid value for '{}': {}", field, raw)),
+            },
+        };
+        obj.insert(field.to_string(), value);
+        assigned.push(field);
+    }
+
+    if let Some(missing) = required.iter().find(|f| !assigned.contains(f)) {
+        return usage(format!("Missing required field '{}'", missing));
+    }
+
+    serde_json::from_value(Value::Object(obj))
+        .map_err(|e| CliError::Usage(format!("Invalid field value: {}", e)))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn run_on(conn: &Connection, list: &[&str]) -> CliResult<Value> {
+        dispatch(conn, Path::new("unused.db"), &args(list))
+    }
+
+    #[test]
+    fn test_split_db_flag_extracts_path() {
+        let (path, rest) = split_db_flag(&args(&["--db", "/tmp/x.db", "stats"])).unwrap();
+        assert_eq!(path, PathBuf::from("/tmp/x.db"));
+        assert_eq!(rest, args(&["stats"]));
+    }
+
+    #[test]
+    fn test_split_db_flag_missing_path() {
+        assert!(split_db_flag(&args(&["--db"])).is_err());
+    }
+
+    #[test]
+    fn test_log_create_and_show() {
+        let conn = setup_test_db();
+        let saved = run_on(&conn, &["log", "2026-03-01", "gym=1", "meal_quality=Good"]).unwrap();
+        assert_eq!(saved["gym"], 1);
+        assert_eq!(saved["meal_quality"], "Good");
+        assert!(saved["final_score"].as_f64().unwrap() > 0.0);
+
+        let shown = run_on(&conn, &["log", "2026-03-01"]).unwrap();
+        assert_eq!(shown["id"], saved["id"]);
+    }
+
+    #[test]
+    fn test_log_update_keeps_other_fields() {
+        let conn = setup_test_db();
+        run_on(&conn, &["log", "2026-03-01", "gym=1"]).unwrap();
+        let updated = run_on(&conn, &["log", "2026-03-01", "read=1"]).unwrap();
+        assert_eq!(updated["gym"], 1);
+        assert_eq!(updated["read"], 1);
+    }
+
+    #[test]
+    fn test_log_missing_date_shows_null() {
+        let conn = setup_test_db();
+        assert_eq!(run_on(&conn, &["log", "2026-03-01"]).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn test_log_range() {
+        let conn = setup_test_db();
+        run_on(&conn, &["log", "2026-03-01", "gym=1"]).unwrap();
+        run_on(&conn, &["log", "2026-03-02", "gym=1"]).unwrap();
+        let listed = run_on(&conn, &["log", "2026-03-01", "2026-03-31"]).unwrap();
+        assert_eq!(listed.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_log_rejects_unknown_field_and_bad_date() {
+        let conn = setup_test_db();
+        assert!(matches!(
+            run_on(&conn, &["log", "2026-03-01", "bogus=1"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            run_on(&conn, &["log", "03/01/2026"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_journal_requires_mood_and_energy_for_new_entry() {
+        let conn = setup_test_db();
+        assert!(matches!(
+            run_on(&conn, &["journal", "2026-03-01", "highlight=Hi"]),
+            Err(CliError::Usage(_))
+        ));
+
+        let saved =
+            run_on(&conn, &["journal", "2026-03-01", "mood=4", "energy=3", "highlight=42"]).unwrap();
+        assert_eq!(saved["mood"], 4);
+        assert_eq!(saved["highlight"], "42", "string fields keep raw text");
+
+        let updated = run_on(&conn, &["journal", "2026-03-01", "mood=5"]).unwrap();
+        assert_eq!(updated["mood"], 5);
+        assert_eq!(updated["energy"], 3);
+    }
+
+    #[test]
+    fn test_study_add_update_list_delete() {
+        let conn = setup_test_db();
+        let added = run_on(
+            &conn,
+            &[
+                "study", "add", "date=2026-03-01", "subject=Math", "study_type=Self-Study",
+                "start_time=09:00", "end_time=10:00", "duration_minutes=60", "focus_score=4",
+                "location=Library",
+            ],
+        )
+        .unwrap();
+        let id = added["id"].as_i64().unwrap().to_string();
+
+        let updated = run_on(&conn, &["study", "update", &id, "focus_score=5"]).unwrap();
+        assert_eq!(updated["focus_score"], 5);
+        assert_eq!(updated["subject"], "Math");
+
+        let listed = run_on(&conn, &["study", "list", "2026-03-01"]).unwrap();
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+
+        assert_eq!(run_on(&conn, &["study", "delete", &id]).unwrap(), Value::Null);
+        let listed = run_on(&conn, &["study", "list", "2026-03-01", "2026-03-31"]).unwrap();
+        assert!(listed.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_study_add_derives_duration_from_times() {
+        let conn = setup_test_db();
+        let added = run_on(
+            &conn,
+            &[
+                "study", "add", "date=2026-03-01", "subject=Math", "study_type=Self-Study",
+                "start_time=09:00", "end_time=10:15", "focus_score=4", "location=Library",
+            ],
+        )
+        .unwrap();
+        assert_eq!(added["duration_minutes"], 75);
+    }
+
+    #[test]
+    fn test_study_add_requires_fields() {
+        let conn = setup_test_db();
+        assert!(matches!(
+            run_on(&conn, &["study", "add", "subject=Math"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_rescore_reports_counts() {
+        let conn = setup_test_db();
+        run_on(&conn, &["log", "2026-03-01", "gym=1"]).unwrap();
+        run_on(&conn, &["log", "2026-03-02", "gym=1"]).unwrap();
+        let summary = run_on(&conn, &["rescore"]).unwrap();
+        assert_eq!(summary["days_rescored"], 2);
+        assert_eq!(summary["days_changed"], 0, "config unchanged → nothing to rewrite");
+    }
+
+    #[test]
+    fn test_recompute_milestones_reports_result() {
+        let conn = setup_test_db();
+        run_on(&conn, &["log", "2025-03-01", "gym=1"]).unwrap();
+        let result = run_on(&conn, &["recompute-milestones"]).unwrap();
+        // The save already awarded first_steps (dated today); replay re-dates it
+        assert!(result["newly_achieved"].as_array().unwrap().is_empty());
+        let redated = result["redated"].as_array().unwrap();
+        let first_steps = redated.iter().find(|m| m["id"] == "first_steps").unwrap();
+        assert_eq!(first_steps["achieved_date"], "2025-03-01");
+    }
+
+    #[test]
+    fn test_unknown_command_is_usage_error() {
+        let conn = setup_test_db();
+        assert!(matches!(run_on(&conn, &["frobnicate"]), Err(CliError::Usage(_))));
+    }
+}
@@ -3,7 +3,24 @@ use serde::{Deserialize, Serialize};
 
 use crate::AppState;
 
-use super::daily_log::{row_to_daily_log, DailyLog, DAILY_LOG_COLUMNS};
+use crate::engine::correlation::{correlate, lagged_pairs, CorrelationStats};
+use crate::engine::funnel::{
+    application_funnel, pipeline_rank, ApplicationFunnel, ApplicationHistory,
+};
+use crate::engine::recovery_patterns::{
+    recovery_patterns, urge_linkage, CategoryOptions, LinkedUrge, RecoveryPatterns,
+    RelapseObservation, UrgeLinkage, UrgeObservation,
+};
+use crate::engine::risk::{
+    assess_risk, DaySignal, MoodSignal, RiskAssessment, RiskInputs, UrgeSignal, RISK_LOOKBACK_DAYS,
+    SCORE_BASELINE_DAYS, SCORE_RECENT_DAYS,
+};
+use crate::engine::study_patterns::{study_patterns, LoggedDay, StudyObservation, StudyPatterns};
+use crate::engine::trend::{monthly_trend, MonthlyTrendPoint};
+
+use super::config::{query_config, VALID_CORRELATION_WINDOWS};
+use super::daily_log::{resolve_dropdown_value, row_to_daily_log, DailyLog, DAILY_LOG_COLUMNS};
+use super::recovery::RecoveryView;
 use super::{CommandError, CommandResult};
 
 // ---------------------------------------------------------------------------
@@ -83,6 +100,80 @@ pub struct WeeklyRecovery {
     pub urges_resisted: i64,
 }
 
+/// One habit/vice against one outcome at one lag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitCorrelation {
+    pub habit_name: String,
+    pub display_name: String,
+    pub pool: String,
+    /// `"final_score"`, `"mood"` or `"energy"`.
+    pub target: String,
+    /// 0 = same day, 1 = habit today vs. outcome tomorrow.
+    pub lag_days: i64,
+    #[serde(flatten)]
+    pub stats: CorrelationStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationReport {
+    /// Window actually used (0 = all time).
+    pub window_days: i64,
+    pub start: String,
+    pub end: String,
+    pub days_logged: i64,
+    pub correlations: Vec<HabitCorrelation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoodTrendPoint {
+    pub date: String,
+    pub mood: i64,
+    pub energy: i64,
+    pub mood_avg_7d: Option<f64>,
+    pub energy_avg_7d: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoodDayOfWeekAvg {
+    pub day: i32,
+    pub avg_mood: f64,
+    pub avg_energy: f64,
+    pub count: i64,
+}
+
+/// Average mood/energy on journaled days a habit or vice was done vs. not.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitMoodImpact {
+    pub habit_name: String,
+    pub display_name: String,
+    pub pool: String,
+    pub days_done: i64,
+    pub days_not_done: i64,
+    pub avg_mood_done: Option<f64>,
+    pub avg_mood_not_done: Option<f64>,
+    pub avg_energy_done: Option<f64>,
+    pub avg_energy_not_done: Option<f64>,
+}
+
+/// Average mood/energy `days_after` a relapse (0 = the relapse day itself).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoodLag {
+    pub days_after: i64,
+    pub avg_mood: Option<f64>,
+    pub avg_energy: Option<f64>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoodAnalytics {
+    pub avg_mood: Option<f64>,
+    pub avg_energy: Option<f64>,
+    pub trend: Vec<MoodTrendPoint>,
+    pub by_day_of_week: Vec<MoodDayOfWeekAvg>,
+    pub habit_impact: Vec<HabitMoodImpact>,
+    pub after_relapse: Vec<MoodLag>,
+}
+
 // ---------------------------------------------------------------------------
 // Column Validation (SQL injection prevention)
 // ---------------------------------------------------------------------------
@@ -113,12 +204,12 @@ const VALID_DAILY_LOG_COLUMNS: &[&str] = &[
     "phone_use",
 ];
 
-fn is_valid_daily_log_column(name: &str) -> bool {
+pub(crate) fn is_valid_daily_log_column(name: &str) -> bool {
     VALID_DAILY_LOG_COLUMNS.contains(&name)
 }
 
 /// Quote column name for SQL. Handles the reserved word "read".
-fn quote_column_name(name: &str) -> String {
+pub(crate) fn quote_column_name(name: &str) -> String {
     if name == "read" {
         "\"read\"".to_string()
     } else {
@@ -405,19 +496,10 @@ pub(crate) fn get_application_pipeline_impl(conn: &Connection) -> CommandResult<
     let mut stmt = conn.prepare(
         "SELECT current_status, COUNT(*) AS count FROM application \
          WHERE archived = 0 \
-         GROUP BY current_status \
-         ORDER BY CASE current_status \
-           WHEN 'Applied' THEN 1 \
-           WHEN 'Phone Screen' THEN 2 \
-           WHEN 'Interview' THEN 3 \
-           WHEN 'Offer' THEN 4 \
-           WHEN 'Rejected' THEN 5 \
-           WHEN 'Withdrawn' THEN 6 \
-           WHEN 'No Response' THEN 7 \
-           ELSE 8 END",
+         GROUP BY current_status",
     )?;
 
-    let stages: Vec<PipelineStage> = stmt
+    let mut stages: Vec<PipelineStage> = stmt
         .query_map([], |row| {
             Ok(PipelineStage {
                 status: row.get("current_status")?,
@@ -426,19 +508,28 @@ pub(crate) fn get_application_pipeline_impl(conn: &Connection) -> CommandResult<
         })?
         .collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)?;
+    stages.sort_by_key(|s| pipeline_rank(&s.status));
 
     Ok(PipelineSummary { stages })
 }
 
+/// Relapse and urge counts for `[start, end]`, in total and by week, read
+/// from the original or amended entries.
 pub(crate) fn get_recovery_frequency_impl(
     conn: &Connection,
     start: &str,
     end: &str,
+    view: RecoveryView,
 ) -> CommandResult<RecoveryFrequency> {
+    let (relapses, urges) = (view.relapse_table(), view.urge_table());
+
     // Totals
     let relapse_count: i64 = conn
         .query_row(
-            "SELECT COUNT(*) FROM relapse_entry WHERE date >= ?1 AND date <= ?2",
+            &format!(
+                "SELECT COUNT(*) FROM {} WHERE date >= ?1 AND date <= ?2",
+                relapses
+            ),
             params![start, end],
             |row| row.get(0),
         )
@@ -446,7 +537,10 @@ pub(crate) fn get_recovery_frequency_impl(
 
     let urge_count: i64 = conn
         .query_row(
-            "SELECT COUNT(*) FROM urge_entry WHERE date >= ?1 AND date <= ?2",
+            &format!(
+                "SELECT COUNT(*) FROM {} WHERE date >= ?1 AND date <= ?2",
+                urges
+            ),
             params![start, end],
             |row| row.get(0),
         )
@@ -454,32 +548,37 @@ pub(crate) fn get_recovery_frequency_impl(
 
     let urges_resisted: i64 = conn
         .query_row(
-            "SELECT COUNT(*) FROM urge_entry \
-             WHERE date >= ?1 AND date <= ?2 AND did_pass LIKE 'Yes%'",
+            &format!(
+                "SELECT COUNT(*) FROM {} \
+                 WHERE date >= ?1 AND date <= ?2 AND did_pass LIKE 'Yes%'",
+                urges
+            ),
             params![start, end],
             |row| row.get(0),
         )
         .map_err(CommandError::from)?;
 
     // Weekly breakdown — relapses
-    let mut relapse_stmt = conn.prepare(
+    let mut relapse_stmt = conn.prepare(&format!(
         "SELECT date(date, 'weekday 0', '-6 days') AS week_start, COUNT(*) AS count \
-         FROM relapse_entry WHERE date >= ?1 AND date <= ?2 \
+         FROM {} WHERE date >= ?1 AND date <= ?2 \
          GROUP BY week_start ORDER BY week_start",
-    )?;
+        relapses
+    ))?;
     let relapse_weeks: Vec<(String, i64)> = relapse_stmt
         .query_map(params![start, end], |row| Ok((row.get("week_start")?, row.get("count")?)))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)?;
 
     // Weekly breakdown — urges (total + resisted)
-    let mut urge_stmt = conn.prepare(
+    let mut urge_stmt = conn.prepare(&format!(
         "SELECT date(date, 'weekday 0', '-6 days') AS week_start, \
                 COUNT(*) AS urges, \
                 SUM(CASE WHEN did_pass LIKE 'Yes%' THEN 1 ELSE 0 END) AS resisted \
-         FROM urge_entry WHERE date >= ?1 AND date <= ?2 \
+         FROM {} WHERE date >= ?1 AND date <= ?2 \
          GROUP BY week_start ORDER BY week_start",
-    )?;
+        urges
+    ))?;
     let urge_weeks: Vec<(String, i64, i64)> = urge_stmt
         .query_map(params![start, end], |row| {
             Ok((row.get("week_start")?, row.get("urges")?, row.get("resisted")?))
@@ -527,201 +626,1058 @@ pub(crate) fn get_recovery_frequency_impl(
 }
 
 // ---------------------------------------------------------------------------
-// Tauri Commands
+// Correlation Engine
 // ---------------------------------------------------------------------------
 
-#[tauri::command]
-pub fn get_score_trend(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<Vec<ScoreTrendPoint>> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_score_trend_impl(&db, &start, &end)
-}
+/// Outcomes every habit is correlated against, in output order.
+const CORRELATION_TARGETS: [&str; 3] = ["final_score", "mood", "energy"];
 
-#[tauri::command]
-pub fn get_habit_completion_rates(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<Vec<HabitCompletionRate>> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_habit_completion_rates_impl(&db, &start, &end)
-}
+/// Lags computed for every habit/target pair.
+const CORRELATION_LAGS: [usize; 2] = [0, 1];
 
-#[tauri::command]
-pub fn get_vice_frequency(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<Vec<ViceFrequency>> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_vice_frequency_impl(&db, &start, &end)
-}
+/// Pearson/Spearman correlations of every active habit and vice against
+/// `final_score`, journal mood and energy, same-day and one day later.
+///
+/// `window` overrides `app_config.correlation_window_days` and must be one of
+/// the same allowed values; 0 means all time. The window ends at `as_of`.
+/// Series are indexed by calendar day so lag 1 always means the next
+/// calendar day — a gap in logging drops the pair instead of shifting it.
+pub(crate) fn get_correlations_impl(
+    conn: &Connection,
+    window: Option<i64>,
+    as_of: &str,
+) -> CommandResult<CorrelationReport> {
+    let window_days = match window {
+        Some(w) => w,
+        None => query_config(conn)?.correlation_window_days,
+    };
+    if !VALID_CORRELATION_WINDOWS.contains(&window_days) {
+        return Err(CommandError::from(
+            "correlation window must be one of: 0, 30, 60, 90, 180, 365",
+        ));
+    }
 
-#[tauri::command]
-pub fn get_day_of_week_averages(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<Vec<DayOfWeekAvg>> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_day_of_week_averages_impl(&db, &start, &end)
-}
+    let end_date = chrono::NaiveDate::parse_from_str(as_of, "%Y-%m-%d")
+        .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", as_of, e)))?;
+    let start_date = if window_days == 0 {
+        let earliest: Option<String> = conn
+            .query_row("SELECT MIN(date) FROM daily_log", [], |row| row.get(0))
+            .map_err(CommandError::from)?;
+        match earliest.and_then(|d| chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()) {
+            Some(d) if d < end_date => d,
+            _ => end_date,
+        }
+    } else {
+        end_date - chrono::Duration::days(window_days - 1)
+    };
+    let start = start_date.format("%Y-%m-%d").to_string();
+    let end = end_date.format("%Y-%m-%d").to_string();
 
-#[tauri::command]
-pub fn get_correlation_data(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<Vec<DailyLog>> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_correlation_data_impl(&db, &start, &end)
-}
+    // 1. Active, non-retired habits and vices with a known column
+    let mut stmt = conn.prepare(
+        "SELECT name, display_name, pool, input_type, options_json, column_name \
+         FROM habit_config WHERE is_active = 1 AND retired_at IS NULL \
+         ORDER BY pool, sort_order ASC",
+    )?;
+    let mut habits: Vec<(String, String, String, String, Option<String>, String)> = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get("name")?,
+                row.get("display_name")?,
+                row.get("pool")?,
+                row.get("input_type")?,
+                row.get("options_json")?,
+                row.get("column_name")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+    habits.retain(|h| is_valid_daily_log_column(&h.5));
+
+    // 2. Day-indexed series over the window
+    let day_count = (end_date - start_date).num_days() as usize + 1;
+    let day_index = |date: &str| -> Option<usize> {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .ok()
+            .map(|d| (d - start_date).num_days() as usize)
+            .filter(|&i| i < day_count)
+    };
+
+    let mut habit_series: Vec<Vec<Option<f64>>> = vec![vec![None; day_count]; habits.len()];
+    let mut target_series: Vec<Vec<Option<f64>>> = vec![vec![None; day_count]; 3];
+    let mut days_logged = 0i64;
+
+    let columns: Vec<String> = habits.iter().map(|h| quote_column_name(&h.5)).collect();
+    let sql = format!(
+        "SELECT date, final_score{}{} FROM daily_log WHERE date >= ?1 AND date <= ?2",
+        if columns.is_empty() { "" } else { ", " },
+        columns.join(", ")
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params![start, end])?;
+    while let Some(row) = rows.next()? {
+        let date: String = row.get(0)?;
+        let idx = match day_index(&date) {
+            Some(i) => i,
+            None => continue,
+        };
+        days_logged += 1;
+        target_series[0][idx] = row.get::<_, Option<f64>>(1)?;
+
+        for (h, (_, _, _, input_type, options_json, _)) in habits.iter().enumerate() {
+            habit_series[h][idx] = if input_type == "dropdown" {
+                row.get::<_, Option<String>>(h + 2)?
+                    .map(|key| resolve_dropdown_value(&key, options_json))
+            } else {
+                row.get::<_, Option<f64>>(h + 2)?
+            };
+        }
+    }
 
-#[tauri::command]
-pub fn get_study_summary(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<StudySummary> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_study_summary_impl(&db, &start, &end)
-}
+    let mut stmt = conn.prepare(
+        "SELECT date, mood, energy FROM journal WHERE date >= ?1 AND date <= ?2",
+    )?;
+    let journal: Vec<(String, f64, f64)> = stmt
+        .query_map(params![start, end], |row| {
+            Ok((row.get("date")?, row.get("mood")?, row.get("energy")?))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+    for (date, mood, energy) in journal {
+        if let Some(idx) = day_index(&date) {
+            target_series[1][idx] = Some(mood);
+            target_series[2][idx] = Some(energy);
+        }
+    }
 
-#[tauri::command]
-pub fn get_application_pipeline(
-    state: tauri::State<'_, AppState>,
-) -> CommandResult<PipelineSummary> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_application_pipeline_impl(&db)
-}
+    // 3. Every habit × target × lag
+    let mut correlations = Vec::with_capacity(habits.len() * 6);
+    for (t, target) in CORRELATION_TARGETS.iter().enumerate() {
+        for &lag in &CORRELATION_LAGS {
+            let mut group: Vec<HabitCorrelation> = habits
+                .iter()
+                .zip(&habit_series)
+                .map(|((name, display_name, pool, _, _, _), series)| {
+                    let (xs, ys) = lagged_pairs(series, &target_series[t], lag);
+                    HabitCorrelation {
+                        habit_name: name.clone(),
+                        display_name: display_name.clone(),
+                        pool: pool.clone(),
+                        target: target.to_string(),
+                        lag_days: lag as i64,
+                        stats: correlate(&xs, &ys),
+                    }
+                })
+                .collect();
+
+            // Strongest first, uncomputable last (same ordering as the TS engine)
+            group.sort_by(|a, b| {
+                let abs = |c: &HabitCorrelation| c.stats.pearson_r.map_or(-1.0, f64::abs);
+                abs(b).total_cmp(&abs(a))
+            });
+            correlations.extend(group);
+        }
+    }
 
-#[tauri::command]
-pub fn get_recovery_frequency(
-    state: tauri::State<'_, AppState>,
-    start: String,
-    end: String,
-) -> CommandResult<RecoveryFrequency> {
-    let db = state
-        .db
-        .lock()
-        .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_recovery_frequency_impl(&db, &start, &end)
+    Ok(CorrelationReport {
+        window_days,
+        start,
+        end,
+        days_logged,
+        correlations,
+    })
 }
 
 // ---------------------------------------------------------------------------
-// Tests
+// Mood Analytics
 // ---------------------------------------------------------------------------
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::db::migrations::run_migrations;
-
-    fn setup_test_db() -> Connection {
-        let mut conn = Connection::open_in_memory().unwrap();
-        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
-        run_migrations(&mut conn).expect("Migrations should succeed");
-        conn
-    }
+/// How many days after a relapse `get_mood_analytics` follows mood/energy.
+const RELAPSE_MOOD_LAG_DAYS: i64 = 3;
 
-    /// Insert a minimal daily_log row for testing.
-    fn insert_daily_log(conn: &Connection, date: &str, final_score: f64) {
-        let now = chrono::Utc::now().to_rfc3339();
-        conn.execute(
-            "INSERT INTO daily_log (\
-             date, schoolwork, personal_project, classes, job_search, \
-             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
-             meditate, \"read\", social, \
-             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
-             past_12am, late_wake, phone_use, \
-             positive_score, vice_penalty, base_score, streak, final_score, \
-             logged_at, last_modified\
-             ) VALUES (\
-             ?1, 1, 1, 0, 0, 1, 1, 0, 0, 'Good', 0, 1, 0, 'None', \
-             0, 0, 0, 0, 0, 0, 0, 0, 0, \
-             50.0, 0.0, 50.0, 1, ?2, ?3, ?4)",
-            params![date, final_score, &now, &now],
-        )
-        .unwrap();
-    }
+/// Mood/energy trends, weekday averages, habit done-vs-not comparison and
+/// post-relapse lag, all from `journal` joined with `daily_log` by date.
+pub(crate) fn get_mood_analytics_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+    view: RecoveryView,
+) -> CommandResult<MoodAnalytics> {
+    // 1. Trend with 7-entry moving averages (same convention as get_score_trend)
+    let mut stmt = conn.prepare(
+        "SELECT date, mood, energy FROM journal \
+         WHERE date >= ?1 AND date <= ?2 \
+         ORDER BY date ASC",
+    )?;
+    let raw: Vec<(String, i64, i64)> = stmt
+        .query_map(params![start, end], |row| {
+            Ok((row.get("date")?, row.get("mood")?, row.get("energy")?))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
 
-    /// Insert a daily_log with custom habit values.
-    fn insert_daily_log_with_habits(
-        conn: &Connection,
-        date: &str,
-        final_score: f64,
-        gym: i64,
-        porn: i64,
-    ) {
-        let now = chrono::Utc::now().to_rfc3339();
-        conn.execute(
-            "INSERT INTO daily_log (\
-             date, schoolwork, personal_project, classes, job_search, \
-             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
-             meditate, \"read\", social, \
-             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
-             past_12am, late_wake, phone_use, \
-             positive_score, vice_penalty, base_score, streak, final_score, \
-             logged_at, last_modified\
-             ) VALUES (\
-             ?1, 1, 0, 0, 0, ?3, 0, 0, 0, 'None', 0, 0, 0, 'None', \
-             ?4, 0, 0, 0, 0, 0, 0, 0, 0, \
-             50.0, 0.0, 50.0, 1, ?2, ?5, ?6)",
-            params![date, final_score, gym, porn, &now, &now],
-        )
-        .unwrap();
+    let mut trend = Vec::with_capacity(raw.len());
+    for (i, (date, mood, energy)) in raw.iter().enumerate() {
+        let (mood_avg_7d, energy_avg_7d) = if i >= 6 {
+            let window = &raw[i - 6..=i];
+            let mood_sum: i64 = window.iter().map(|(_, m, _)| m).sum();
+            let energy_sum: i64 = window.iter().map(|(_, _, e)| e).sum();
+            (Some(mood_sum as f64 / 7.0), Some(energy_sum as f64 / 7.0))
+        } else {
+            (None, None)
+        };
+        trend.push(MoodTrendPoint {
+            date: date.clone(),
+            mood: *mood,
+            energy: *energy,
+            mood_avg_7d,
+            energy_avg_7d,
+        });
     }
 
-    fn insert_study_session(
-        conn: &Connection,
-        date: &str,
-        subject: &str,
-        duration_min: i64,
-        focus: i64,
-    ) {
-        let now = chrono::Utc::now().to_rfc3339();
-        conn.execute(
-            "INSERT INTO study_session (\
-             date, subject, study_type, start_time, end_time, \
-             duration_minutes, focus_score, location, topic, resources, notes, \
-             logged_at, last_modified\
-             ) VALUES (?1, ?2, 'Self-Study', '09:00', '11:00', \
-             ?3, ?4, 'Library', '', '', '', ?5, ?6)",
-            params![date, subject, duration_min, focus, &now, &now],
+    let (avg_mood, avg_energy) = if raw.is_empty() {
+        (None, None)
+    } else {
+        let n = raw.len() as f64;
+        (
+            Some(raw.iter().map(|(_, m, _)| *m as f64).sum::<f64>() / n),
+            Some(raw.iter().map(|(_, _, e)| *e as f64).sum::<f64>() / n),
         )
-        .unwrap();
-    }
+    };
 
-    fn insert_application(conn: &Connection, date: &str, status: &str) {
-        let now = chrono::Utc::now().to_rfc3339();
-        conn.execute(
-            "INSERT INTO application (\
-             date_applied, company, role, source, current_status, \
-             url, notes, salary, contact_name, contact_email, \
+    // 2. Day of week (0 = Monday, matching get_day_of_week_averages)
+    let mut stmt = conn.prepare(
+        "SELECT \
+           CASE CAST(strftime('%w', date) AS INTEGER) \
+             WHEN 0 THEN 6 \
+             WHEN 1 THEN 0 \
+             WHEN 2 THEN 1 \
+             WHEN 3 THEN 2 \
+             WHEN 4 THEN 3 \
+             WHEN 5 THEN 4 \
+             WHEN 6 THEN 5 \
+           END AS day_idx, \
+           AVG(mood) AS avg_mood, \
+           AVG(energy) AS avg_energy, \
+           COUNT(*) AS count \
+         FROM journal \
+         WHERE date >= ?1 AND date <= ?2 \
+         GROUP BY day_idx \
+         ORDER BY day_idx ASC",
+    )?;
+    let by_day_of_week = stmt
+        .query_map(params![start, end], |row| {
+            Ok(MoodDayOfWeekAvg {
+                day: row.get("day_idx")?,
+                avg_mood: row.get("avg_mood")?,
+                avg_energy: row.get("avg_energy")?,
+                count: row.get("count")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    // 3. Habit/vice done vs. not done, over days with both a journal and a log
+    let mut stmt = conn.prepare(
+        "SELECT name, display_name, pool, column_name, input_type \
+         FROM habit_config WHERE is_active = 1 AND retired_at IS NULL \
+         ORDER BY pool, sort_order ASC",
+    )?;
+    let habits: Vec<(String, String, String, String, String)> = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get("name")?,
+                row.get("display_name")?,
+                row.get("pool")?,
+                row.get("column_name")?,
+                row.get("input_type")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut habit_impact = Vec::with_capacity(habits.len());
+    for (name, display_name, pool, column_name, input_type) in &habits {
+        if !is_valid_daily_log_column(column_name) {
+            continue;
+        }
+
+        let quoted_col = format!("d.{}", quote_column_name(column_name));
+        let done_expr = if input_type == "dropdown" {
+            format!("({} != 'None' AND {} != '')", quoted_col, quoted_col)
+        } else {
+            format!("({} > 0)", quoted_col)
+        };
+        let sql = format!(
+            "SELECT {done} AS done, COUNT(*) AS count, \
+               AVG(j.mood) AS avg_mood, AVG(j.energy) AS avg_energy \
+             FROM journal j JOIN daily_log d ON d.date = j.date \
+             WHERE j.date >= ?1 AND j.date <= ?2 \
+             GROUP BY done",
+            done = done_expr
+        );
+
+        let mut impact = HabitMoodImpact {
+            habit_name: name.clone(),
+            display_name: display_name.clone(),
+            pool: pool.clone(),
+            days_done: 0,
+            days_not_done: 0,
+            avg_mood_done: None,
+            avg_mood_not_done: None,
+            avg_energy_done: None,
+            avg_energy_not_done: None,
+        };
+        let mut stmt = conn.prepare(&sql)?;
+        let groups = stmt
+            .query_map(params![start, end], |row| {
+                Ok((
+                    row.get::<_, bool>("done")?,
+                    row.get::<_, i64>("count")?,
+                    row.get::<_, f64>("avg_mood")?,
+                    row.get::<_, f64>("avg_energy")?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CommandError::from)?;
+        for (done, count, mood, energy) in groups {
+            if done {
+                impact.days_done = count;
+                impact.avg_mood_done = Some(mood);
+                impact.avg_energy_done = Some(energy);
+            } else {
+                impact.days_not_done = count;
+                impact.avg_mood_not_done = Some(mood);
+                impact.avg_energy_not_done = Some(energy);
+            }
+        }
+        habit_impact.push(impact);
+    }
+
+    // 4. Mood on the relapse day and the days after it. Several relapses on
+    //    one date count once; a journal day following two relapses counts once
+    //    per lag it falls on.
+    let mut after_relapse = Vec::with_capacity(RELAPSE_MOOD_LAG_DAYS as usize + 1);
+    for days_after in 0..=RELAPSE_MOOD_LAG_DAYS {
+        let offset = format!("+{} days", days_after);
+        let (avg_mood, avg_energy, count): (Option<f64>, Option<f64>, i64) = conn
+            .query_row(
+                &format!(
+                    "SELECT AVG(j.mood), AVG(j.energy), COUNT(*) FROM journal j \
+                     WHERE j.date IN ( \
+                       SELECT DISTINCT date(r.date, ?3) FROM {} r \
+                       WHERE r.date >= ?1 AND r.date <= ?2 \
+                     )",
+                    view.relapse_table()
+                ),
+                params![start, end, offset],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .map_err(CommandError::from)?;
+        after_relapse.push(MoodLag {
+            days_after,
+            avg_mood,
+            avg_energy,
+            count,
+        });
+    }
+
+    Ok(MoodAnalytics {
+        avg_mood,
+        avg_energy,
+        trend,
+        by_day_of_week,
+        habit_impact,
+        after_relapse,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Monthly Trend
+// ---------------------------------------------------------------------------
+
+/// Rolling 30-day score averages at each month end in `[start, end]`, each
+/// compared with the month before.
+///
+/// The series is computed from the first logged score so `previous_avg` and
+/// `improving_months` are correct for the first month returned. A month that
+/// has not ended yet (or ends after `end`) is measured up to the earlier of
+/// `end` and today and flagged `complete: false`.
+pub(crate) fn get_monthly_trend_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> CommandResult<Vec<MonthlyTrendPoint>> {
+    let parse = |d: &str| {
+        chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", d, e)))
+    };
+    let start_date = parse(start)?;
+    let end_date = parse(end)?;
+    let as_of = end_date.min(chrono::Local::now().date_naive());
+
+    let mut stmt = conn.prepare(
+        "SELECT date, final_score FROM daily_log \
+         WHERE date <= ?1 AND final_score IS NOT NULL \
+         ORDER BY date ASC",
+    )?;
+    let scores: Vec<(String, f64)> = stmt
+        .query_map(params![as_of.format("%Y-%m-%d").to_string()], |row| {
+            Ok((row.get("date")?, row.get("final_score")?))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let first_month = start_date.format("%Y-%m").to_string();
+    Ok(monthly_trend(&scores, as_of)
+        .into_iter()
+        .filter(|p| p.month >= first_month)
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// Application Funnel
+// ---------------------------------------------------------------------------
+
+/// Funnel, velocity, per-source response and per-role outcome analytics for
+/// applications with `date_applied` in `[start, end]`.
+///
+/// Built from the full `status_change` history rather than `current_status`,
+/// so an application later rejected still counts as having reached interview.
+/// Archived applications are included: archiving hides an application from
+/// the list but does not erase its history.
+pub(crate) fn get_application_funnel_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> CommandResult<ApplicationFunnel> {
+    let mut stmt = conn.prepare(
+        "SELECT id, source, role, date_applied, current_status FROM application \
+         WHERE date_applied >= ?1 AND date_applied <= ?2 \
+         ORDER BY date_applied ASC, id ASC",
+    )?;
+    let apps: Vec<(i64, ApplicationHistory)> = stmt
+        .query_map(params![start, end], |row| {
+            Ok((
+                row.get("id")?,
+                ApplicationHistory {
+                    source: row.get("source")?,
+                    role: row.get("role")?,
+                    date_applied: row.get("date_applied")?,
+                    current_status: row.get("current_status")?,
+                    changes: Vec::new(),
+                },
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT status, date FROM status_change WHERE application_id = ?1 \
+         ORDER BY date ASC, id ASC",
+    )?;
+    let mut histories = Vec::with_capacity(apps.len());
+    for (id, mut app) in apps {
+        app.changes = stmt
+            .query_map([id], |row| Ok((row.get("status")?, row.get("date")?)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CommandError::from)?;
+        histories.push(app);
+    }
+
+    Ok(application_funnel(&histories))
+}
+
+// ---------------------------------------------------------------------------
+// Recovery Patterns
+// ---------------------------------------------------------------------------
+
+/// String list under `key` in `app_config.dropdown_options`; empty when the
+/// key is missing or the JSON is malformed.
+fn dropdown_list(options: &serde_json::Value, key: &str) -> Vec<String> {
+    options
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|i| i.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Trigger, time-of-day, device and emotional-state distributions of the
+/// relapses in `[start, end]`, the pass rate of each urge technique, and the
+/// hours and weekdays with elevated relapse risk.
+pub(crate) fn get_recovery_patterns_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+    view: RecoveryView,
+) -> CommandResult<RecoveryPatterns> {
+    let parse = |d: &str| {
+        chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", d, e)))
+    };
+    let (start_date, end_date) = (parse(start)?, parse(end)?);
+    if start_date > end_date {
+        return Err(CommandError::from(format!(
+            "Start date {} is after end date {}",
+            start, end
+        )));
+    }
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT date, time, trigger, device, emotional_state FROM {} \
+         WHERE date >= ?1 AND date <= ?2 ORDER BY date ASC, time ASC",
+        view.relapse_table()
+    ))?;
+    let relapses: Vec<RelapseObservation> = stmt
+        .query_map(params![start, end], |row| {
+            Ok(RelapseObservation {
+                date: row.get("date")?,
+                time: row.get("time")?,
+                trigger: row.get("trigger")?,
+                device: row.get("device")?,
+                emotional_state: row.get("emotional_state")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT date, time, technique, intensity, effectiveness, \
+                did_pass LIKE 'Yes%' AS passed \
+         FROM {} WHERE date >= ?1 AND date <= ?2 ORDER BY date ASC, time ASC",
+        view.urge_table()
+    ))?;
+    let urges: Vec<UrgeObservation> = stmt
+        .query_map(params![start, end], |row| {
+            Ok(UrgeObservation {
+                date: row.get("date")?,
+                time: row.get("time")?,
+                technique: row.get("technique")?,
+                intensity: row.get("intensity")?,
+                effectiveness: row.get("effectiveness")?,
+                passed: row.get("passed")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let config = query_config(conn)?;
+    let dropdowns: serde_json::Value =
+        serde_json::from_str(&config.dropdown_options).unwrap_or_default();
+    let options = CategoryOptions {
+        triggers: dropdown_list(&dropdowns, "relapse_trigger_options"),
+        devices: dropdown_list(&dropdowns, "relapse_device_options"),
+        emotional_states: dropdown_list(&dropdowns, "relapse_emotional_state_options"),
+    };
+
+    Ok(recovery_patterns(start_date, end_date, &relapses, &urges, &options))
+}
+
+// ---------------------------------------------------------------------------
+// Urge Linkage
+// ---------------------------------------------------------------------------
+
+/// For urges dated `[start, end]`: how many a relapse links to (overall and
+/// by intensity band) and the median minutes from urge to relapse. Also
+/// counts the relapses in the range with no linked urge.
+pub(crate) fn get_urge_linkage_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+    view: RecoveryView,
+) -> CommandResult<UrgeLinkage> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT u.date, u.time, u.intensity, r.date AS relapse_date, r.time AS relapse_time \
+         FROM {} u LEFT JOIN {} r ON r.urge_entry_id = u.id \
+         WHERE u.date >= ?1 AND u.date <= ?2 ORDER BY u.date ASC, u.time ASC",
+        view.urge_table(),
+        view.relapse_table()
+    ))?;
+    let urges: Vec<LinkedUrge> = stmt
+        .query_map(params![start, end], |row| {
+            let relapse_date: Option<String> = row.get("relapse_date")?;
+            let relapse_time: Option<String> = row.get("relapse_time")?;
+            Ok(LinkedUrge {
+                date: row.get("date")?,
+                time: row.get("time")?,
+                intensity: row.get("intensity")?,
+                relapse: relapse_date.zip(relapse_time),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let unlinked_relapses: i64 = conn.query_row(
+        &format!(
+            "SELECT COUNT(*) FROM {} \
+             WHERE date >= ?1 AND date <= ?2 AND urge_entry_id IS NULL",
+            view.relapse_table()
+        ),
+        params![start, end],
+        |row| row.get(0),
+    )?;
+
+    Ok(urge_linkage(start, end, &urges, unlinked_relapses))
+}
+
+// ---------------------------------------------------------------------------
+// Risk Assessment
+// ---------------------------------------------------------------------------
+
+/// Early-warning relapse risk for `date`, from the daily logs, journal
+/// entries and urges of the days before it and the relapse history before
+/// it. See `engine::risk` for the factors and weights.
+pub(crate) fn get_risk_assessment_impl(
+    conn: &Connection,
+    date: &str,
+    view: RecoveryView,
+) -> CommandResult<RiskAssessment> {
+    let assessed = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", date, e)))?;
+    let fmt = |d: chrono::NaiveDate| d.format("%Y-%m-%d").to_string();
+    let window_start = fmt(assessed - chrono::Duration::days(RISK_LOOKBACK_DAYS));
+    let score_start =
+        fmt(assessed - chrono::Duration::days(SCORE_RECENT_DAYS + SCORE_BASELINE_DAYS));
+
+    let mut stmt = conn.prepare(
+        "SELECT date, final_score, past_12am, phone_use FROM daily_log \
+         WHERE date >= ?1 AND date < ?2 ORDER BY date ASC",
+    )?;
+    let days: Vec<DaySignal> = stmt
+        .query_map(params![score_start, date], |row| {
+            Ok(DaySignal {
+                date: row.get("date")?,
+                final_score: row.get("final_score")?,
+                past_12am: row.get::<_, i64>("past_12am")? != 0,
+                phone_use: row.get("phone_use")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT date, mood, energy FROM journal \
+         WHERE date >= ?1 AND date < ?2 ORDER BY date ASC",
+    )?;
+    let moods: Vec<MoodSignal> = stmt
+        .query_map(params![window_start, date], |row| {
+            Ok(MoodSignal {
+                date: row.get("date")?,
+                mood: row.get("mood")?,
+                energy: row.get("energy")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT date, intensity FROM {} \
+         WHERE date >= ?1 AND date < ?2 ORDER BY date ASC",
+        view.urge_table()
+    ))?;
+    let urges: Vec<UrgeSignal> = stmt
+        .query_map(params![window_start, date], |row| {
+            Ok(UrgeSignal {
+                date: row.get("date")?,
+                intensity: row.get("intensity")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT date FROM {} WHERE date < ?1 ORDER BY date ASC",
+        view.relapse_table()
+    ))?;
+    let relapse_dates: Vec<String> = stmt
+        .query_map(params![date], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let config = query_config(conn)?;
+    let inputs = RiskInputs {
+        days,
+        moods,
+        urges,
+        relapse_dates,
+        phone_ceiling_minutes: config.phone_t3_min,
+    };
+    Ok(assess_risk(assessed, &inputs))
+}
+
+// ---------------------------------------------------------------------------
+// Study Analytics
+// ---------------------------------------------------------------------------
+
+/// Focus and hours by time of day, location, study type and session length,
+/// weekly hours per subject with their trend, and how study days line up
+/// with the `schoolwork` habit and `final_score`, for `[start, end]`.
+pub(crate) fn get_study_analytics_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> CommandResult<StudyPatterns> {
+    let parse = |d: &str| {
+        chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", d, e)))
+    };
+    let (start_date, end_date) = (parse(start)?, parse(end)?);
+    if start_date > end_date {
+        return Err(CommandError::from(format!(
+            "Start date {} is after end date {}",
+            start, end
+        )));
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT date, start_time, subject, study_type, location, duration_minutes, focus_score \
+         FROM study_session WHERE date >= ?1 AND date <= ?2 \
+         ORDER BY date ASC, start_time ASC",
+    )?;
+    let sessions: Vec<StudyObservation> = stmt
+        .query_map(params![start, end], |row| {
+            Ok(StudyObservation {
+                date: row.get("date")?,
+                start_time: row.get("start_time")?,
+                subject: row.get("subject")?,
+                study_type: row.get("study_type")?,
+                location: row.get("location")?,
+                duration_minutes: row.get("duration_minutes")?,
+                focus_score: row.get("focus_score")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT date, schoolwork > 0 AS schoolwork, final_score FROM daily_log \
+         WHERE date >= ?1 AND date <= ?2 ORDER BY date ASC",
+    )?;
+    let days: Vec<LoggedDay> = stmt
+        .query_map(params![start, end], |row| {
+            Ok(LoggedDay {
+                date: row.get("date")?,
+                schoolwork: row.get("schoolwork")?,
+                final_score: row.get("final_score")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    Ok(study_patterns(start_date, end_date, &sessions, &days))
+}
+
+// ---------------------------------------------------------------------------
+// Tauri Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn get_score_trend(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<ScoreTrendPoint>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_score_trend_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_habit_completion_rates(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<HabitCompletionRate>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_habit_completion_rates_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_vice_frequency(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<ViceFrequency>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_vice_frequency_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_day_of_week_averages(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<DayOfWeekAvg>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_day_of_week_averages_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_correlation_data(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<DailyLog>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_correlation_data_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_study_summary(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<StudySummary> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_summary_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_application_pipeline(
+    state: tauri::State<'_, AppState>,
+) -> CommandResult<PipelineSummary> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_application_pipeline_impl(&db)
+}
+
+#[tauri::command]
+pub fn get_application_funnel(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<ApplicationFunnel> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_application_funnel_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_recovery_frequency(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+    view: Option<RecoveryView>,
+) -> CommandResult<RecoveryFrequency> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_recovery_frequency_impl(&db, &start, &end, view.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_recovery_patterns(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+    view: Option<RecoveryView>,
+) -> CommandResult<RecoveryPatterns> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_recovery_patterns_impl(&db, &start, &end, view.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_urge_linkage(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+    view: Option<RecoveryView>,
+) -> CommandResult<UrgeLinkage> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_urge_linkage_impl(&db, &start, &end, view.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_risk_assessment(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    view: Option<RecoveryView>,
+) -> CommandResult<RiskAssessment> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_risk_assessment_impl(&db, &date, view.unwrap_or_default())
+}
+
+/// Correlation report ending today. `window` defaults to
+/// `app_config.correlation_window_days`.
+#[tauri::command]
+pub fn get_correlations(
+    state: tauri::State<'_, AppState>,
+    window: Option<i64>,
+) -> CommandResult<CorrelationReport> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    get_correlations_impl(&db, window, &today)
+}
+
+#[tauri::command]
+pub fn get_mood_analytics(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+    view: Option<RecoveryView>,
+) -> CommandResult<MoodAnalytics> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_mood_analytics_impl(&db, &start, &end, view.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_study_analytics(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<StudyPatterns> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_analytics_impl(&db, &start, &end)
+}
+
+#[tauri::command]
+pub fn get_monthly_trend(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> CommandResult<Vec<MonthlyTrendPoint>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_monthly_trend_impl(&db, &start, &end)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    /// Insert a minimal daily_log row for testing.
+    fn insert_daily_log(conn: &Connection, date: &str, final_score: f64) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO daily_log (\
+             date, schoolwork, personal_project, classes, job_search, \
+             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
+             meditate, \"read\", social, \
+             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
+             past_12am, late_wake, phone_use, \
+             positive_score, vice_penalty, base_score, streak, final_score, \
+             logged_at, last_modified\
+             ) VALUES (\
+             ?1, 1, 1, 0, 0, 1, 1, 0, 0, 'Good', 0, 1, 0, 'None', \
+             0, 0, 0, 0, 0, 0, 0, 0, 0, \
+             50.0, 0.0, 50.0, 1, ?2, ?3, ?4)",
+            params![date, final_score, &now, &now],
+        )
+        .unwrap();
+    }
+
+    /// Insert a daily_log with custom habit values.
+    fn insert_daily_log_with_habits(
+        conn: &Connection,
+        date: &str,
+        final_score: f64,
+        gym: i64,
+        porn: i64,
+    ) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO daily_log (\
+             date, schoolwork, personal_project, classes, job_search, \
+             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
+             meditate, \"read\", social, \
+             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
+             past_12am, late_wake, phone_use, \
+             positive_score, vice_penalty, base_score, streak, final_score, \
+             logged_at, last_modified\
+             ) VALUES (\
+             ?1, 1, 0, 0, 0, ?3, 0, 0, 0, 'None', 0, 0, 0, 'None', \
+             ?4, 0, 0, 0, 0, 0, 0, 0, 0, \
+             50.0, 0.0, 50.0, 1, ?2, ?5, ?6)",
+            params![date, final_score, gym, porn, &now, &now],
+        )
+        .unwrap();
+    }
+
+    fn insert_study_session(
+        conn: &Connection,
+        date: &str,
+        subject: &str,
+        duration_min: i64,
+        focus: i64,
+    ) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO study_session (\
+             date, subject, study_type, start_time, end_time, \
+             duration_minutes, focus_score, location, topic, resources, notes, \
+             logged_at, last_modified\
+             ) VALUES (?1, ?2, 'Self-Study', '09:00', '11:00', \
+             ?3, ?4, 'Library', '', '', '', ?5, ?6)",
+            params![date, subject, duration_min, focus, &now, &now],
+        )
+        .unwrap();
+    }
+
+    fn insert_application(conn: &Connection, date: &str, status: &str) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO application (\
+             date_applied, company, role, source, current_status, \
+             url, notes, salary, contact_name, contact_email, \
              login_username, login_password, archived, logged_at, last_modified\
              ) VALUES (?1, 'TestCo', 'Dev', 'LinkedIn', ?2, \
              '', '', '', '', '', '', '', 0, ?3, ?4)",
@@ -888,6 +1844,50 @@ mod tests {
         assert!((result.hours_by_subject[1].hours - 1.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_get_study_analytics() {
+        let conn = setup_test_db();
+
+        insert_study_session(&conn, "2026-02-02", "Math", 120, 4);
+        insert_study_session(&conn, "2026-02-10", "Math", 60, 2);
+        insert_study_session(&conn, "2026-02-11", "Physics", 30, 5);
+        insert_daily_log(&conn, "2026-02-02", 80.0);
+        insert_daily_log(&conn, "2026-02-03", 60.0);
+        conn.execute("UPDATE daily_log SET schoolwork = 0 WHERE date = '2026-02-03'", [])
+            .unwrap();
+
+        let result = get_study_analytics_impl(&conn, "2026-02-01", "2026-02-14").unwrap();
+        assert_eq!(result.session_count, 3);
+        assert_eq!(result.locations[0].value, "Library");
+        assert_eq!(result.median_session_minutes, Some(60.0));
+
+        // Sunday the 1st falls in the week of Monday, January 26, which only
+        // that day of the range covers
+        let math = &result.subject_trends[0];
+        assert_eq!(math.subject, "Math");
+        let weeks: Vec<(&str, i64)> = math
+            .weeks
+            .iter()
+            .map(|w| (w.week_start.as_str(), w.days_in_range))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![("2026-01-26", 1), ("2026-02-02", 7), ("2026-02-09", 6)]
+        );
+        // Only one whole week, so no trend
+        assert_eq!(math.trend_hours_per_week, None);
+
+        let days = &result.study_days;
+        assert_eq!(days.logged_days, 2);
+        assert_eq!(days.study_days, 1);
+        assert_eq!(days.study_and_schoolwork_days, 1);
+        assert_eq!(days.avg_score_study_days, Some(80.0));
+        assert_eq!(days.avg_score_other_days, Some(60.0));
+
+        assert!(get_study_analytics_impl(&conn, "2026-02-14", "2026-02-01").is_err());
+        assert!(get_study_analytics_impl(&conn, "Feb 1", "2026-02-14").is_err());
+    }
+
     // -----------------------------------------------------------------------
     // G. Application Pipeline
     // -----------------------------------------------------------------------
@@ -896,24 +1896,141 @@ mod tests {
     fn test_get_application_pipeline_groups_by_status() {
         let conn = setup_test_db();
 
-        insert_application(&conn, "2026-02-01", "Applied");
-        insert_application(&conn, "2026-02-02", "Applied");
-        insert_application(&conn, "2026-02-03", "Interview");
+        insert_application(&conn, "2026-02-01", "applied");
+        insert_application(&conn, "2026-02-02", "applied");
+        insert_application(&conn, "2026-02-03", "interview");
 
         let result = get_application_pipeline_impl(&conn).unwrap();
         assert_eq!(result.stages.len(), 2);
-        assert_eq!(result.stages[0].status, "Applied");
+        assert_eq!(result.stages[0].status, "applied");
         assert_eq!(result.stages[0].count, 2);
-        assert_eq!(result.stages[1].status, "Interview");
+        assert_eq!(result.stages[1].status, "interview");
         assert_eq!(result.stages[1].count, 1);
     }
 
+    #[test]
+    fn test_get_application_pipeline_orders_snake_case_statuses() {
+        let conn = setup_test_db();
+        for status in [
+            "no_response", "withdrawn", "rejected", "offer",
+            "interview", "technical_screen", "phone_screen", "applied",
+        ] {
+            insert_application(&conn, "2026-02-01", status);
+        }
+
+        let result = get_application_pipeline_impl(&conn).unwrap();
+        let order: Vec<&str> = result.stages.iter().map(|s| s.status.as_str()).collect();
+        assert_eq!(
+            order,
+            vec![
+                "applied", "phone_screen", "technical_screen", "interview",
+                "offer", "rejected", "withdrawn", "no_response",
+            ]
+        );
+    }
+
+    /// Application with its full status history; the first entry is the
+    /// application itself (`applied` on `date_applied`).
+    fn insert_application_history(
+        conn: &Connection,
+        source: &str,
+        role: &str,
+        history: &[(&str, &str)],
+    ) -> i64 {
+        let now = chrono::Utc::now().to_rfc3339();
+        let (_, applied) = history[0];
+        let (current, _) = history[history.len() - 1];
+        conn.execute(
+            "INSERT INTO application (\
+             date_applied, company, role, source, current_status, \
+             url, notes, salary, contact_name, contact_email, \
+             login_username, login_password, archived, logged_at, last_modified\
+             ) VALUES (?1, 'TestCo', ?2, ?3, ?4, '', '', '', '', '', '', '', 0, ?5, ?5)",
+            params![applied, role, source, current, &now],
+        )
+        .unwrap();
+        let id = conn.last_insert_rowid();
+        for (status, date) in history {
+            conn.execute(
+                "INSERT INTO status_change (application_id, status, date, notes, created_at) \
+                 VALUES (?1, ?2, ?3, '', ?4)",
+                params![id, status, date, &now],
+            )
+            .unwrap();
+        }
+        id
+    }
+
+    #[test]
+    fn test_get_application_funnel_from_status_history() {
+        let conn = setup_test_db();
+        insert_application_history(
+            &conn,
+            "LinkedIn",
+            "Dev",
+            &[
+                ("applied", "2026-02-01"),
+                ("phone_screen", "2026-02-04"),
+                ("interview", "2026-02-10"),
+                ("rejected", "2026-02-12"),
+            ],
+        );
+        insert_application_history(
+            &conn,
+            "LinkedIn",
+            "Dev",
+            &[("applied", "2026-02-02"), ("no_response", "2026-03-05")],
+        );
+        let archived = insert_application_history(
+            &conn,
+            "Referral",
+            "QA",
+            &[
+                ("applied", "2026-02-03"),
+                ("phone_screen", "2026-02-05"),
+                ("offer", "2026-02-20"),
+            ],
+        );
+        conn.execute("UPDATE application SET archived = 1 WHERE id = ?1", [archived])
+            .unwrap();
+        // Outside the range
+        insert_application_history(&conn, "LinkedIn", "Dev", &[("applied", "2026-01-15")]);
+
+        let f = get_application_funnel_impl(&conn, "2026-02-01", "2026-02-28").unwrap();
+        assert_eq!(f.total, 3);
+        assert_eq!(f.responses, 2);
+        // Rejected after interview still reached interview
+        let interview = f.stages.iter().find(|s| s.status == "interview").unwrap();
+        assert_eq!(interview.reached, 2);
+        let phone = f.stages.iter().find(|s| s.status == "phone_screen").unwrap();
+        assert!((phone.conversion_rate.unwrap() - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(phone.median_days_from_previous, Some(2.5));
+
+        assert_eq!(f.by_source[0].source, "LinkedIn");
+        assert_eq!(f.by_source[0].responses, 1);
+        assert_eq!(f.by_source[0].median_days_to_response, Some(3.0));
+        assert_eq!(f.by_source[1].offers, 1);
+
+        let dev = f.by_role.iter().find(|r| r.role == "Dev").unwrap();
+        assert_eq!((dev.applications, dev.rejected, dev.no_response), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_get_application_funnel_empty_range() {
+        let conn = setup_test_db();
+        let f = get_application_funnel_impl(&conn, "2026-02-01", "2026-02-28").unwrap();
+        assert_eq!(f.total, 0);
+        assert_eq!(f.stages[0].reached, 0);
+    }
+
     // -----------------------------------------------------------------------
     // H. Recovery Frequency
     // -----------------------------------------------------------------------
 
     #[test]
     fn test_get_recovery_frequency_totals() {
+        use crate::commands::recovery::add_recovery_amendment_impl;
+
         let conn = setup_test_db();
         let now = chrono::Utc::now().to_rfc3339();
 
@@ -950,11 +2067,510 @@ mod tests {
         )
         .unwrap();
 
-        let result =
-            get_recovery_frequency_impl(&conn, "2026-02-01", "2026-02-28").unwrap();
+        let result = get_recovery_frequency_impl(
+            &conn,
+            "2026-02-01",
+            "2026-02-28",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert_eq!(result.relapse_count, 1);
         assert_eq!(result.urge_count, 2);
         assert_eq!(result.urges_resisted, 1);
         assert!(!result.weekly_data.is_empty());
+
+        // Amend the locked relapse into March and one urge to "resisted"
+        let locked = (chrono::Utc::now() - chrono::Duration::hours(25)).to_rfc3339();
+        conn.execute("UPDATE relapse_entry SET created_at = ?1", params![&locked])
+            .unwrap();
+        conn.execute("UPDATE urge_entry SET created_at = ?1", params![&locked])
+            .unwrap();
+        let (relapse_id, urge_id): (i64, i64) = conn
+            .query_row(
+                "SELECT (SELECT id FROM relapse_entry), \
+                        (SELECT id FROM urge_entry WHERE date = '2026-02-17')",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        add_recovery_amendment_impl(&conn, "relapse_entry", relapse_id, "date", "2026-03-01", "x")
+            .unwrap();
+        add_recovery_amendment_impl(&conn, "urge_entry", urge_id, "did_pass", "Yes", "x")
+            .unwrap();
+
+        let feb = |view| get_recovery_frequency_impl(&conn, "2026-02-01", "2026-02-28", view);
+        let original = feb(RecoveryView::Original).unwrap();
+        assert_eq!((original.relapse_count, original.urges_resisted), (1, 1));
+        let amended = feb(RecoveryView::Amended).unwrap();
+        assert_eq!((amended.relapse_count, amended.urges_resisted), (0, 2));
+    }
+
+    #[test]
+    fn test_get_recovery_patterns() {
+        let conn = setup_test_db();
+        let now = chrono::Utc::now().to_rfc3339();
+        for (date, time, trigger) in [
+            ("2026-02-16", "23:00", "Stress"),
+            ("2026-02-23", "23:30", "Stress"),
+            ("2026-02-18", "14:00", "Boredom"),
+        ] {
+            conn.execute(
+                "INSERT INTO relapse_entry (\
+                 date, time, duration, trigger, location, device, \
+                 activity_before, emotional_state, resistance_technique, \
+                 urge_intensity, notes, created_at, last_modified\
+                 ) VALUES (?1, ?2, '< 5 min', ?3, 'Bedroom', \
+                 'Phone', 'Scrolling', 'Stressed', 'None', 5, '', ?4, ?4)",
+                params![date, time, trigger, &now],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "INSERT INTO urge_entry (\
+             date, time, intensity, technique, effectiveness, \
+             duration, did_pass, trigger, notes, created_at, last_modified\
+             ) VALUES ('2026-02-17', '22:00', 6, 'Cold Water', 4, \
+             '5-15 min', 'Yes - mostly', '', '', ?1, ?1)",
+            params![&now],
+        )
+        .unwrap();
+
+        let p = get_recovery_patterns_impl(
+            &conn,
+            "2026-02-01",
+            "2026-02-28",
+            RecoveryView::Original,
+        )
+        .unwrap();
+        assert_eq!((p.relapse_count, p.urge_count), (3, 1));
+        assert_eq!(p.triggers[0].value, "Stress");
+        // Seeded trigger options are listed even when unused
+        assert!(p.triggers.iter().any(|t| t.value == "Loneliness" && t.relapses == 0));
+        assert_eq!(p.elevated_hours, vec![23]);
+        assert_eq!(p.elevated_weekdays, vec!["Monday".to_string()]);
+        assert_eq!(p.techniques[0].technique, "Cold Water");
+        assert!((p.techniques[0].pass_rate - 1.0).abs() < 1e-9);
+
+        let original = RecoveryView::Original;
+        assert!(get_recovery_patterns_impl(&conn, "2026-02-28", "2026-02-01", original).is_err());
+        assert!(get_recovery_patterns_impl(&conn, "Feb 1", "2026-02-28", original).is_err());
+    }
+
+    #[test]
+    fn test_get_urge_linkage() {
+        use crate::commands::recovery::{
+            link_relapse_urge_impl, save_relapse_entry_impl, save_urge_entry_impl,
+            RelapseEntryInput, UrgeEntryInput,
+        };
+
+        let conn = setup_test_db();
+        let urge = |time: &str, intensity: i64| UrgeEntryInput {
+            date: "2026-02-16".to_string(),
+            time: time.to_string(),
+            intensity,
+            technique: "Cold Water".to_string(),
+            effectiveness: 2,
+            duration: "1-5 min".to_string(),
+            did_pass: "Partially".to_string(),
+            trigger: String::new(),
+            notes: String::new(),
+        };
+        let relapse = |time: &str| RelapseEntryInput {
+            date: "2026-02-16".to_string(),
+            time: time.to_string(),
+            duration: "< 5 min".to_string(),
+            trigger: "Stress".to_string(),
+            location: "Bedroom".to_string(),
+            device: "Phone".to_string(),
+            activity_before: "Scrolling".to_string(),
+            emotional_state: "Stressed".to_string(),
+            resistance_technique: "None".to_string(),
+            urge_intensity: 9,
+            notes: String::new(),
+            urge_entry_id: None,
+        };
+
+        let strong = save_urge_entry_impl(&conn, urge("22:00", 9)).unwrap();
+        save_urge_entry_impl(&conn, urge("10:00", 2)).unwrap();
+        let r = save_relapse_entry_impl(&conn, relapse("22:45")).unwrap();
+        link_relapse_urge_impl(&conn, r.id, Some(strong.id)).unwrap();
+        save_relapse_entry_impl(&conn, relapse("23:30")).unwrap();
+
+        let l =
+            get_urge_linkage_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        assert_eq!((l.urges, l.linked_urges, l.unlinked_relapses), (2, 1, 1));
+        assert_eq!(l.median_minutes_to_relapse, Some(45.0));
+        let severe = l.bands.iter().find(|b| b.band == "severe").unwrap();
+        assert_eq!((severe.urges, severe.relapses), (1, 1));
+        let low = l.bands.iter().find(|b| b.band == "low").unwrap();
+        assert_eq!((low.urges, low.conversion_rate), (1, 0.0));
+    }
+
+    // -----------------------------------------------------------------------
+    // I. Correlations
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_get_correlations_window_from_config_and_validation() {
+        let conn = setup_test_db();
+        let report = get_correlations_impl(&conn, None, "2026-03-31").unwrap();
+        assert_eq!(report.window_days, 90, "seed config default");
+        assert_eq!(report.start, "2026-01-01");
+        assert_eq!(report.end, "2026-03-31");
+        assert_eq!(report.days_logged, 0);
+
+        assert!(get_correlations_impl(&conn, Some(45), "2026-03-31").is_err());
+    }
+
+    #[test]
+    fn test_get_correlations_same_day_and_lagged() {
+        let conn = setup_test_db();
+        // Gym on even days drives the score up the same day only
+        for day in 1..=14 {
+            let gym = (day % 2 == 0) as i64;
+            let score = if gym == 1 { 80.0 } else { 30.0 } + day as f64 * 0.1;
+            insert_daily_log_with_habits(&conn, &format!("2026-03-{:02}", day), score, gym, 0);
+        }
+
+        let report = get_correlations_impl(&conn, Some(30), "2026-03-14").unwrap();
+        assert_eq!(report.days_logged, 14);
+
+        let find = |target: &str, lag: i64| {
+            report
+                .correlations
+                .iter()
+                .find(|c| c.habit_name == "gym" && c.target == target && c.lag_days == lag)
+                .unwrap()
+        };
+
+        let same_day = find("final_score", 0);
+        assert_eq!(same_day.stats.n, 14);
+        assert!(same_day.stats.pearson_r.unwrap() > 0.99);
+        assert!(same_day.stats.p_value.unwrap() < 0.001);
+
+        // Next day's score is the opposite parity → strong negative
+        let lagged = find("final_score", 1);
+        assert_eq!(lagged.stats.n, 13);
+        assert!(lagged.stats.pearson_r.unwrap() < -0.99);
+
+        // No journal entries → mood has nothing to pair with
+        let mood = find("mood", 0);
+        assert_eq!(mood.stats.flag.as_deref(), Some("insufficient_data"));
+
+        // Never-done habit has zero variance
+        let stretching = report
+            .correlations
+            .iter()
+            .find(|c| c.habit_name == "stretching" && c.target == "final_score" && c.lag_days == 0)
+            .unwrap();
+        assert_eq!(stretching.stats.flag.as_deref(), Some("zero_variance"));
+    }
+
+    // -----------------------------------------------------------------------
+    // J. Mood Analytics
+    // -----------------------------------------------------------------------
+
+    fn insert_journal(conn: &Connection, date: &str, mood: i64, energy: i64) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO journal (date, mood, energy, logged_at, last_modified) \
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![date, mood, energy, &now, &now],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_get_mood_analytics_empty() {
+        let conn = setup_test_db();
+        let result =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        assert!(result.trend.is_empty());
+        assert_eq!(result.avg_mood, None);
+        assert_eq!(result.after_relapse.len(), 4);
+        assert!(result.after_relapse.iter().all(|l| l.count == 0));
+    }
+
+    #[test]
+    fn test_get_mood_analytics_trend_and_weekday() {
+        let conn = setup_test_db();
+        // 2026-02-02 is a Monday
+        for day in 2..=8 {
+            insert_journal(&conn, &format!("2026-02-{:02}", day), (day - 2) % 5 + 1, 3);
+        }
+        let result =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+
+        assert_eq!(result.trend.len(), 7);
+        assert!(result.trend[5].mood_avg_7d.is_none());
+        // Moods 1,2,3,4,5,1,2 → 18 / 7
+        assert!((result.trend[6].mood_avg_7d.unwrap() - 18.0 / 7.0).abs() < 1e-9);
+        assert!((result.trend[6].energy_avg_7d.unwrap() - 3.0).abs() < 1e-9);
+        assert_eq!(result.by_day_of_week.len(), 7);
+        assert_eq!(result.by_day_of_week[0].day, 0);
+        assert!((result.by_day_of_week[0].avg_mood - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_mood_analytics_habit_done_vs_not() {
+        let conn = setup_test_db();
+        insert_daily_log_with_habits(&conn, "2026-02-10", 80.0, 1, 0);
+        insert_daily_log_with_habits(&conn, "2026-02-11", 80.0, 1, 0);
+        insert_daily_log_with_habits(&conn, "2026-02-12", 20.0, 0, 1);
+        insert_journal(&conn, "2026-02-10", 5, 4);
+        insert_journal(&conn, "2026-02-11", 4, 4);
+        insert_journal(&conn, "2026-02-12", 2, 1);
+
+        let result =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        let gym = result.habit_impact.iter().find(|h| h.habit_name == "gym").unwrap();
+        assert_eq!(gym.days_done, 2);
+        assert_eq!(gym.days_not_done, 1);
+        assert!((gym.avg_mood_done.unwrap() - 4.5).abs() < 1e-9);
+        assert!((gym.avg_mood_not_done.unwrap() - 2.0).abs() < 1e-9);
+
+        let porn = result.habit_impact.iter().find(|h| h.habit_name == "porn").unwrap();
+        assert_eq!(porn.pool, "vice");
+        assert_eq!(porn.days_done, 1);
+        assert!((porn.avg_energy_done.unwrap() - 1.0).abs() < 1e-9);
+
+        // A retired habit drops out even if its row is still flagged active.
+        conn.execute(
+            "UPDATE habit_config SET retired_at = '2026-03-01T00:00:00Z' WHERE name = 'porn'",
+            [],
+        )
+        .unwrap();
+        let result =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        assert!(result.habit_impact.iter().all(|h| h.habit_name != "porn"));
+        assert!(result.habit_impact.iter().any(|h| h.habit_name == "gym"));
+    }
+
+    #[test]
+    fn test_get_mood_analytics_after_relapse() {
+        use crate::commands::recovery::add_recovery_amendment_impl;
+
+        let conn = setup_test_db();
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO relapse_entry (\
+             date, time, duration, trigger, location, device, \
+             activity_before, emotional_state, resistance_technique, \
+             urge_intensity, notes, created_at, last_modified\
+             ) VALUES ('2026-02-16', '23:00', '< 5 min', 'Boredom', 'Bedroom', \
+             'Phone', 'Scrolling', 'Stressed', 'None', 5, '', ?1, ?2)",
+            params![&now, &now],
+        )
+        .unwrap();
+        insert_journal(&conn, "2026-02-16", 3, 3);
+        insert_journal(&conn, "2026-02-17", 1, 2);
+        insert_journal(&conn, "2026-02-19", 4, 4);
+
+        let result =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        let lag = |d: i64| result.after_relapse.iter().find(|l| l.days_after == d).unwrap();
+        assert_eq!(lag(0).avg_mood, Some(3.0));
+        assert_eq!(lag(1).avg_mood, Some(1.0));
+        assert_eq!(lag(2).count, 0);
+        assert_eq!(lag(3).avg_energy, Some(4.0));
+
+        // Amending the locked relapse a day later shifts the lags in the
+        // amended view only.
+        let locked = (chrono::Utc::now() - chrono::Duration::hours(25)).to_rfc3339();
+        conn.execute("UPDATE relapse_entry SET created_at = ?1", params![&locked])
+            .unwrap();
+        let relapse_id: i64 = conn
+            .query_row("SELECT id FROM relapse_entry", [], |row| row.get(0))
+            .unwrap();
+        add_recovery_amendment_impl(&conn, "relapse_entry", relapse_id, "date", "2026-02-17", "x")
+            .unwrap();
+        let original =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Original)
+                .unwrap();
+        assert_eq!(original.after_relapse[0].avg_mood, Some(3.0));
+        let amended =
+            get_mood_analytics_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Amended)
+                .unwrap();
+        assert_eq!(amended.after_relapse[0].avg_mood, Some(1.0));
+        assert_eq!(amended.after_relapse[2].avg_mood, Some(4.0));
+    }
+
+    #[test]
+    fn test_get_monthly_trend_series() {
+        let conn = setup_test_db();
+        for (month, days, score) in [(1, 31, 0.5), (2, 28, 0.6), (3, 31, 0.7), (4, 30, 0.65)] {
+            for d in 1..=days {
+                insert_daily_log(&conn, &format!("2025-{:02}-{:02}", month, d), score);
+            }
+        }
+
+        let points = get_monthly_trend_impl(&conn, "2025-02-10", "2025-04-30").unwrap();
+        let months: Vec<&str> = points.iter().map(|p| p.month.as_str()).collect();
+        assert_eq!(months, vec!["2025-02", "2025-03", "2025-04"]);
+        // February still compares against January even though it's outside the range
+        assert!(points[0].previous_avg.is_some());
+        assert_eq!(points[0].improving_months, 1);
+        assert_eq!(points[1].improving_months, 2);
+        assert_eq!(points[2].improving_months, 0);
+        assert!((points[2].rolling_avg.unwrap() - 0.65).abs() < 1e-9);
+        assert!(points.iter().all(|p| p.complete));
+    }
+
+    #[test]
+    fn test_get_monthly_trend_partial_month_and_bad_date() {
+        let conn = setup_test_db();
+        for d in 1..=20 {
+            insert_daily_log(&conn, &format!("2025-06-{:02}", d), 0.6);
+        }
+        let points = get_monthly_trend_impl(&conn, "2025-06-01", "2025-06-20").unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(!points[0].complete);
+        assert_eq!(points[0].period_end, "2025-06-20");
+        assert_eq!(points[0].scored_days, 20);
+
+        assert!(get_monthly_trend_impl(&conn, "June", "2025-06-20").is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // K. Risk Assessment
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_get_risk_assessment_reads_only_prior_days() {
+        use crate::commands::recovery::add_recovery_amendment_impl;
+
+        let conn = setup_test_db();
+        assert!(get_risk_assessment_impl(&conn, "2026-13-01", RecoveryView::Original).is_err());
+
+        // A bad day logged on the assessed date itself doesn't count.
+        insert_daily_log(&conn, "2026-03-10", 0.1);
+        conn.execute(
+            "UPDATE daily_log SET past_12am = 1, phone_use = 600 WHERE date = '2026-03-10'",
+            [],
+        )
+        .unwrap();
+        let a = get_risk_assessment_impl(&conn, "2026-03-10", RecoveryView::Original).unwrap();
+        assert_eq!(a.window_end, "2026-03-09");
+        let late = a.factors.iter().find(|f| f.key == "late_nights").unwrap();
+        assert_eq!(late.value, None);
+
+        let a = get_risk_assessment_impl(&conn, "2026-03-11", RecoveryView::Original).unwrap();
+        let late = a.factors.iter().find(|f| f.key == "late_nights").unwrap();
+        assert_eq!(late.value, Some(1.0));
+        let phone = a.factors.iter().find(|f| f.key == "phone_use").unwrap();
+        assert_eq!(phone.value, Some(1.0), "600 minutes is past the 301 ceiling");
+
+        // Urges are read through the selected view.
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO urge_entry (\
+             date, time, intensity, technique, effectiveness, \
+             duration, did_pass, trigger, notes, created_at, last_modified\
+             ) VALUES ('2026-03-09', '22:00', 4, 'Meditation', 3, \
+             '1-5 min', 'Yes', '', '', ?1, ?2)",
+            params![&now, &now],
+        )
+        .unwrap();
+        let locked = (chrono::Utc::now() - chrono::Duration::hours(25)).to_rfc3339();
+        conn.execute("UPDATE urge_entry SET created_at = ?1", params![&locked])
+            .unwrap();
+        let urge_id: i64 = conn
+            .query_row("SELECT id FROM urge_entry", [], |row| row.get(0))
+            .unwrap();
+        add_recovery_amendment_impl(&conn, "urge_entry", urge_id, "intensity", "9", "x").unwrap();
+        let urge_value = |view| {
+            let a = get_risk_assessment_impl(&conn, "2026-03-11", view).unwrap();
+            a.factors.iter().find(|f| f.key == "urge_intensity").unwrap().value
+        };
+        assert_eq!(urge_value(RecoveryView::Original), Some(0.4));
+        assert_eq!(urge_value(RecoveryView::Amended), Some(0.9));
+    }
+
+    /// Replays stored history day by day: the three days before each relapse
+    /// are bad (low score, late nights, heavy phone use, low mood, strong
+    /// urges), the rest are good. Relapse days should score clearly higher.
+    #[test]
+    fn test_get_risk_assessment_backtest_against_relapses() {
+        let conn = setup_test_db();
+        let now = chrono::Utc::now().to_rfc3339();
+        let first = chrono::NaiveDate::from_ymd_opt(2025, 12, 1).unwrap();
+        const CYCLE: i64 = 11;
+        const DAYS: i64 = 88;
+        let is_bad = |i: i64| (CYCLE - 4..CYCLE - 1).contains(&(i % CYCLE));
+
+        for i in 0..DAYS {
+            let date = (first + chrono::Duration::days(i)).format("%Y-%m-%d").to_string();
+            let bad = is_bad(i);
+            insert_daily_log(&conn, &date, if bad { 0.3 } else { 0.8 });
+            conn.execute(
+                "UPDATE daily_log SET past_12am = ?1, phone_use = ?2 WHERE date = ?3",
+                params![bad as i64, if bad { 300 } else { 30 }, &date],
+            )
+            .unwrap();
+            if bad {
+                insert_journal(&conn, &date, 1, 2);
+                conn.execute(
+                    "INSERT INTO urge_entry (\
+                     date, time, intensity, technique, effectiveness, \
+                     duration, did_pass, trigger, notes, created_at, last_modified\
+                     ) VALUES (?1, '22:00', 8, 'Cold Shower', 2, \
+                     '5-15 min', 'Partially', '', '', ?2, ?3)",
+                    params![&date, &now, &now],
+                )
+                .unwrap();
+            } else {
+                insert_journal(&conn, &date, 4, 4);
+            }
+            if i % CYCLE == CYCLE - 1 {
+                conn.execute(
+                    "INSERT INTO relapse_entry (\
+                     date, time, duration, trigger, location, device, \
+                     activity_before, emotional_state, resistance_technique, \
+                     urge_intensity, notes, created_at, last_modified\
+                     ) VALUES (?1, '23:00', '< 5 min', 'Stress', 'Bedroom', \
+                     'Phone', 'Scrolling', 'Stressed', 'None', 8, '', ?2, ?3)",
+                    params![&date, &now, &now],
+                )
+                .unwrap();
+            }
+        }
+
+        let (mut relapse_scores, mut other_scores) = (Vec::new(), Vec::new());
+        let mut relapse_flagged = 0;
+        let mut quiet_flagged = 0;
+        // Skip the first cycle, which has no score baseline yet.
+        for i in CYCLE..DAYS {
+            let date = (first + chrono::Duration::days(i)).format("%Y-%m-%d").to_string();
+            let a = get_risk_assessment_impl(&conn, &date, RecoveryView::Original).unwrap();
+            let score = a.score.unwrap();
+            let flagged = a.level.as_deref() != Some("low");
+            if i % CYCLE == CYCLE - 1 {
+                relapse_scores.push(score);
+                relapse_flagged += flagged as usize;
+            } else {
+                other_scores.push(score);
+                // No bad day in the lookback window.
+                if !(i - RISK_LOOKBACK_DAYS..i).any(is_bad) {
+                    quiet_flagged += flagged as usize;
+                }
+            }
+        }
+
+        let avg = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        assert_eq!(relapse_scores.len(), 7);
+        assert_eq!(relapse_flagged, relapse_scores.len(), "every relapse day is flagged");
+        assert_eq!(quiet_flagged, 0, "quiet stretches are never flagged");
+        assert!(
+            avg(&relapse_scores) > avg(&other_scores) + 0.25,
+            "relapse days {:.2} vs other days {:.2}",
+            avg(&relapse_scores),
+            avg(&other_scores)
+        );
     }
 }
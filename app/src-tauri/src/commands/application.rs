@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::AppState;
 
+use crate::engine::funnel::{stage_index, PIPELINE_ORDER};
+
+use super::milestone::{emit_milestones_achieved, evaluate_milestones_impl, Milestone};
 use super::validation::{validate_optional_text_length, validate_text_length};
 use super::{CommandError, CommandResult};
 
@@ -92,6 +95,134 @@ pub struct StatusChangeInput {
     pub notes: Option<String>,
 }
 
+/// One correction to the status history: the values before, and after for
+/// updates (`new_*` are `None` for deletions).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChangeAudit {
+    pub id: i64,
+    pub status_change_id: i64,
+    pub application_id: i64,
+    /// `"update"` or `"delete"`.
+    pub action: String,
+    pub old_status: String,
+    pub old_date: String,
+    pub old_notes: String,
+    pub new_status: Option<String>,
+    pub new_date: Option<String>,
+    pub new_notes: Option<String>,
+    pub created_at: String,
+}
+
+/// An application that needs a follow-up, with the date it is due.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowUp {
+    pub application_id: i64,
+    pub company: String,
+    pub role: String,
+    pub current_status: String,
+    /// Date of the latest status change (`date_applied` if there is none).
+    pub last_status_date: String,
+    /// User-set `application.follow_up_date`, if any.
+    pub follow_up_date: Option<String>,
+    /// Derived from the status and `last_status_date`.
+    pub suggested_date: Option<String>,
+    /// `follow_up_date` when set, otherwise `suggested_date`.
+    pub due_date: String,
+    /// `due_date − as_of`; negative when overdue.
+    pub days_until_due: i64,
+}
+
+/// Result of `get_follow_ups`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowUps {
+    pub as_of: String,
+    /// Due before `as_of`, most overdue first.
+    pub overdue: Vec<FollowUp>,
+    /// Due from `as_of` through `as_of + UPCOMING_FOLLOW_UP_DAYS`, soonest first.
+    pub upcoming: Vec<FollowUp>,
+}
+
+/// An application with its contacts and interviews, returned by
+/// `get_application`. Serializes as the `Application` fields plus
+/// `contacts` and `interviews`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationDetail {
+    #[serde(flatten)]
+    pub application: Application,
+    pub contacts: Vec<Contact>,
+    pub interviews: Vec<Interview>,
+}
+
+/// Full contact row returned to the frontend.
+/// Field names and types must match the TypeScript `Contact` interface exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: i64,
+    pub application_id: i64,
+    pub name: String,
+    /// One of `CONTACT_ROLES`.
+    pub role: String,
+    pub email: String,
+    pub phone: String,
+    pub notes: String,
+    pub created_at: String,
+    pub last_modified: String,
+}
+
+/// Input for saving/updating a contact. The application is fixed on save.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactInput {
+    pub name: String,
+    pub role: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Full interview row returned to the frontend.
+/// Field names and types must match the TypeScript `Interview` interface exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interview {
+    pub id: i64,
+    pub application_id: i64,
+    pub date: String,
+    /// `HH:MM`, or empty when the time is not known.
+    pub time: String,
+    /// Free text, e.g. "Phone screen" or "Onsite 2".
+    pub round: String,
+    pub interviewers: String,
+    pub prep_notes: String,
+    /// One of `INTERVIEW_OUTCOMES`.
+    pub outcome: String,
+    pub notes: String,
+    pub created_at: String,
+    pub last_modified: String,
+}
+
+/// Input for saving/updating an interview. The application is fixed on save.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterviewInput {
+    pub date: String,
+    #[serde(default)]
+    pub time: String,
+    pub round: String,
+    #[serde(default)]
+    pub interviewers: String,
+    #[serde(default)]
+    pub prep_notes: String,
+    #[serde(default = "default_interview_outcome")]
+    pub outcome: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+fn default_interview_outcome() -> String {
+    "pending".to_string()
+}
+
 // ---------------------------------------------------------------------------
 // Constants & Helpers
 // ---------------------------------------------------------------------------
@@ -104,6 +235,25 @@ const APPLICATION_COLUMNS: &str = "\
 const STATUS_CHANGE_COLUMNS: &str = "\
     id, application_id, status, date, notes, created_at";
 
+const CONTACT_COLUMNS: &str = "\
+    id, application_id, name, role, email, phone, notes, created_at, last_modified";
+
+const INTERVIEW_COLUMNS: &str = "\
+    id, application_id, date, time, round, interviewers, prep_notes, \
+    outcome, notes, created_at, last_modified";
+
+pub(crate) const CONTACT_ROLES: [&str; 5] =
+    ["recruiter", "hiring_manager", "referrer", "interviewer", "other"];
+
+pub(crate) const INTERVIEW_OUTCOMES: [&str; 4] = ["pending", "passed", "failed", "cancelled"];
+
+/// How far ahead `get_follow_ups` looks for upcoming follow-ups.
+pub(crate) const UPCOMING_FOLLOW_UP_DAYS: i64 = 7;
+
+/// Default silence, in days, before `mark_stale_applications` gives up on an
+/// application.
+pub(crate) const DEFAULT_NO_RESPONSE_DAYS: i64 = 30;
+
 fn row_to_application(row: &rusqlite::Row) -> rusqlite::Result<Application> {
     Ok(Application {
         id: row.get("id")?,
@@ -137,7 +287,7 @@ fn row_to_status_change(row: &rusqlite::Row) -> rusqlite::Result<StatusChange> {
     })
 }
 
-fn query_application_by_id(
+pub(crate) fn query_application_by_id(
     conn: &Connection,
     id: i64,
 ) -> CommandResult<Option<Application>> {
@@ -150,6 +300,228 @@ fn query_application_by_id(
         .map_err(CommandError::from)
 }
 
+/// Days after the last status change before a follow-up is suggested, by
+/// current status. Terminal statuses (rejected, withdrawn, no_response) get
+/// no suggestion.
+fn suggested_follow_up_days(status: &str) -> Option<i64> {
+    match status {
+        "applied" => Some(7),
+        "phone_screen" => Some(5),
+        "interview" | "technical_screen" => Some(3),
+        "offer" => Some(2),
+        _ => None,
+    }
+}
+
+fn parse_iso_date(date: &str) -> CommandResult<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", date, e)))
+}
+
+fn query_status_change_by_id(conn: &Connection, id: i64) -> CommandResult<StatusChange> {
+    let sql = format!(
+        "SELECT {} FROM status_change WHERE id = ?1",
+        STATUS_CHANGE_COLUMNS
+    );
+    conn.query_row(&sql, [id], row_to_status_change)
+        .optional()
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved status change"))
+}
+
+/// Append a status_change row and keep `application.current_status` in step
+/// (D5 sync invariant). Callers own the transaction and the validation.
+fn insert_status_change(
+    conn: &Connection,
+    app_id: i64,
+    status: &str,
+    date: &str,
+    notes: &str,
+) -> CommandResult<i64> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO status_change (\
+         application_id, status, date, notes, created_at\
+         ) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![app_id, status, date, notes, &now],
+    )?;
+    let id = conn.last_insert_rowid();
+    conn.execute(
+        "UPDATE application SET current_status = ?2, last_modified = ?3 WHERE id = ?1",
+        params![app_id, status, &now],
+    )?;
+    Ok(id)
+}
+
+// ---------------------------------------------------------------------------
+// Status Transitions
+// ---------------------------------------------------------------------------
+
+/// Statuses that end an application. Leaving them needs `reopen_application`.
+const CLOSED_STATUSES: [&str; 2] = ["rejected", "withdrawn"];
+
+/// Statuses reachable from `from` through `add_status_change`.
+///
+/// Pipeline stages only move forward (a stage may be skipped). Any open
+/// application can be rejected or withdrawn; an offer can no longer go
+/// unanswered. A late reply moves a `no_response` application back into the
+/// pipeline.
+fn allowed_transitions(from: &str) -> &'static [&'static str] {
+    match from {
+        "applied" => &[
+            "phone_screen", "technical_screen", "interview", "offer",
+            "rejected", "withdrawn", "no_response",
+        ],
+        "phone_screen" => &[
+            "technical_screen", "interview", "offer", "rejected", "withdrawn", "no_response",
+        ],
+        "technical_screen" => &["interview", "offer", "rejected", "withdrawn", "no_response"],
+        "interview" => &["offer", "rejected", "withdrawn", "no_response"],
+        "offer" => &["rejected", "withdrawn"],
+        "no_response" => &[
+            "phone_screen", "technical_screen", "interview", "offer", "rejected", "withdrawn",
+        ],
+        _ => &[],
+    }
+}
+
+fn validate_transition(from: &str, to: &str) -> CommandResult<()> {
+    if !PIPELINE_ORDER.contains(&to) {
+        return Err(CommandError::from(format!("Unknown status '{}'", to)));
+    }
+    if from == to {
+        return Err(CommandError::from(format!("Application is already '{}'", to)));
+    }
+    if CLOSED_STATUSES.contains(&from) {
+        return Err(CommandError::from(format!(
+            "Application is '{}'; reopen it before changing its status",
+            from
+        )));
+    }
+    if !allowed_transitions(from).contains(&to) {
+        return Err(CommandError::from(format!(
+            "Cannot change status from '{}' to '{}'",
+            from, to
+        )));
+    }
+    Ok(())
+}
+
+/// What a new status change is validated against.
+struct StatusState {
+    current_status: String,
+    date_applied: String,
+    /// Latest `status_change.date`, if any.
+    last_date: Option<String>,
+}
+
+fn query_status_state(conn: &Connection, app_id: i64) -> CommandResult<StatusState> {
+    conn.query_row(
+        "SELECT current_status, date_applied, \
+         (SELECT MAX(date) FROM status_change WHERE application_id = ?1) AS last_date \
+         FROM application WHERE id = ?1",
+        [app_id],
+        |row| {
+            Ok(StatusState {
+                current_status: row.get("current_status")?,
+                date_applied: row.get("date_applied")?,
+                last_date: row.get("last_date")?,
+            })
+        },
+    )
+    .optional()?
+    .ok_or_else(|| CommandError::from(format!("Application with id {} not found", app_id)))
+}
+
+/// A status change can't predate the application or the previous change.
+fn validate_status_date(state: &StatusState, date: &str) -> CommandResult<()> {
+    let changed = parse_iso_date(date)?;
+    if changed < parse_iso_date(&state.date_applied)? {
+        return Err(CommandError::from(format!(
+            "Status date {} is before the application date {}",
+            date, state.date_applied
+        )));
+    }
+    if let Some(last) = &state.last_date {
+        if changed < parse_iso_date(last)? {
+            return Err(CommandError::from(format!(
+                "Status date {} is before the previous status change on {}",
+                date, last
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check one link of an edited history: `next` must follow `prev` in the
+/// transition graph (a closed status may be followed by a reopen) and must
+/// not be dated before it.
+fn validate_history_link(prev: &StatusChange, next: &StatusChange) -> CommandResult<()> {
+    if parse_iso_date(&next.date)? < parse_iso_date(&prev.date)? {
+        return Err(CommandError::from(format!(
+            "Status date {} would be before the preceding '{}' change on {}",
+            next.date, prev.status, prev.date
+        )));
+    }
+    if prev.status == next.status {
+        return Err(CommandError::from(format!(
+            "History would repeat '{}' twice in a row",
+            next.status
+        )));
+    }
+    if !CLOSED_STATUSES.contains(&prev.status.as_str())
+        && !allowed_transitions(&prev.status).contains(&next.status.as_str())
+    {
+        return Err(CommandError::from(format!(
+            "History would change status from '{}' to '{}'",
+            prev.status, next.status
+        )));
+    }
+    Ok(())
+}
+
+/// D5 sync invariant after an edit: `current_status` is the status of the
+/// latest remaining change.
+fn resync_current_status(conn: &Connection, app_id: i64) -> CommandResult<()> {
+    let latest: String = conn.query_row(
+        "SELECT status FROM status_change WHERE application_id = ?1 \
+         ORDER BY created_at DESC, id DESC LIMIT 1",
+        [app_id],
+        |row| row.get(0),
+    )?;
+    conn.execute(
+        "UPDATE application SET current_status = ?2, last_modified = ?3 WHERE id = ?1",
+        params![app_id, latest, chrono::Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+fn insert_status_change_audit(
+    conn: &Connection,
+    old: &StatusChange,
+    new: Option<&StatusChange>,
+) -> CommandResult<()> {
+    conn.execute(
+        "INSERT INTO status_change_audit (\
+         status_change_id, application_id, action, old_status, old_date, old_notes, \
+         new_status, new_date, new_notes, created_at\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            old.id,
+            old.application_id,
+            if new.is_some() { "update" } else { "delete" },
+            old.status,
+            old.date,
+            old.notes,
+            new.map(|n| &n.status),
+            new.map(|n| &n.date),
+            new.map(|n| &n.notes),
+            chrono::Utc::now().to_rfc3339(),
+        ],
+    )?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Commands
 // ---------------------------------------------------------------------------
@@ -163,7 +535,7 @@ pub fn get_applications(
     get_applications_impl(&db, filters)
 }
 
-fn get_applications_impl(
+pub(crate) fn get_applications_impl(
     conn: &Connection,
     filters: AppFilters,
 ) -> CommandResult<Vec<Application>> {
@@ -223,18 +595,36 @@ fn get_applications_impl(
 pub fn get_application(
     state: tauri::State<'_, AppState>,
     id: i64,
-) -> CommandResult<Option<Application>> {
+) -> CommandResult<Option<ApplicationDetail>> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-    query_application_by_id(&db, id)
+    get_application_impl(&db, id)
+}
+
+pub(crate) fn get_application_impl(
+    conn: &Connection,
+    id: i64,
+) -> CommandResult<Option<ApplicationDetail>> {
+    let Some(application) = query_application_by_id(conn, id)? else {
+        return Ok(None);
+    };
+    Ok(Some(ApplicationDetail {
+        application,
+        contacts: get_contacts_impl(conn, id)?,
+        interviews: get_interviews_impl(conn, id)?,
+    }))
 }
 
+/// Newly achieved milestones are emitted as `milestones-achieved`.
 #[tauri::command]
 pub fn save_application(
+    handle: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
     app: ApplicationInput,
 ) -> CommandResult<Application> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-    save_application_impl(&db, app)
+    let (saved, achieved) = save_application_with_milestones(&db, app)?;
+    emit_milestones_achieved(&handle, &achieved);
+    Ok(saved)
 }
 
 fn validate_application_input(app: &ApplicationInput) -> CommandResult<()> {
@@ -251,13 +641,23 @@ fn validate_application_input(app: &ApplicationInput) -> CommandResult<()> {
     Ok(())
 }
 
-fn save_application_impl(
+pub(crate) fn save_application_impl(
     conn: &Connection,
     app: ApplicationInput,
 ) -> CommandResult<Application> {
+    save_application_with_milestones(conn, app).map(|(saved, _)| saved)
+}
+
+/// `save_application_impl`, also returning the milestones the save newly
+/// achieved. The milestone check runs inside the insert transaction.
+pub(crate) fn save_application_with_milestones(
+    conn: &Connection,
+    app: ApplicationInput,
+) -> CommandResult<(Application, Vec<Milestone>)> {
     validate_application_input(&app)?;
 
     let id: i64;
+    let achieved;
     {
         let tx = conn
             .unchecked_transaction()
@@ -301,11 +701,13 @@ fn save_application_impl(
             params![id, "applied", app.date_applied, "", &now],
         )?;
 
+        achieved = evaluate_milestones_impl(&tx)?;
         tx.commit()?;
     }
 
-    query_application_by_id(conn, id)?
-        .ok_or_else(|| CommandError::from("Failed to read back saved application"))
+    let saved = query_application_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved application"))?;
+    Ok((saved, achieved))
 }
 
 #[tauri::command]
@@ -318,7 +720,7 @@ pub fn update_application(
     update_application_impl(&db, id, app)
 }
 
-fn update_application_impl(
+pub(crate) fn update_application_impl(
     conn: &Connection,
     id: i64,
     app: ApplicationInput,
@@ -327,7 +729,11 @@ fn update_application_impl(
 
     let now = chrono::Utc::now().to_rfc3339();
 
-    let rows_affected = conn.execute(
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let rows_affected = tx.execute(
         "UPDATE application SET \
          date_applied = ?2, company = ?3, role = ?4, source = ?5, \
          url = ?6, notes = ?7, follow_up_date = ?8, salary = ?9, \
@@ -359,11 +765,48 @@ fn update_application_impl(
             id
         )));
     }
+    sync_first_status_change(&tx, id, &app.date_applied)?;
+    tx.commit()?;
 
     query_application_by_id(conn, id)?
         .ok_or_else(|| CommandError::from("Failed to read back updated application"))
 }
 
+/// Keep the first status change dated on `date_applied`. The new date must
+/// not pass the change after it; the move is audited like a correction.
+fn sync_first_status_change(
+    conn: &Connection,
+    app_id: i64,
+    date_applied: &str,
+) -> CommandResult<()> {
+    let mut history = get_status_history_impl(conn, app_id)?.into_iter();
+    let Some(first) = history.next() else {
+        return Ok(());
+    };
+    if first.date == date_applied {
+        return Ok(());
+    }
+    if let Some(next) = history.next() {
+        if parse_iso_date(&next.date)? < parse_iso_date(date_applied)? {
+            return Err(CommandError::from(format!(
+                "Date applied {} is after the following '{}' change on {}",
+                date_applied, next.status, next.date
+            )));
+        }
+    }
+
+    let updated = StatusChange {
+        date: date_applied.to_string(),
+        ..first.clone()
+    };
+    conn.execute(
+        "UPDATE status_change SET date = ?2 WHERE id = ?1",
+        params![first.id, updated.date],
+    )?;
+    insert_status_change_audit(conn, &first, Some(&updated))?;
+    Ok(())
+}
+
 #[tauri::command]
 pub fn archive_application(
     state: tauri::State<'_, AppState>,
@@ -373,7 +816,7 @@ pub fn archive_application(
     archive_application_impl(&db, id)
 }
 
-fn archive_application_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+pub(crate) fn archive_application_impl(conn: &Connection, id: i64) -> CommandResult<()> {
     let now = chrono::Utc::now().to_rfc3339();
 
     let rows_affected = conn.execute(
@@ -381,109 +824,795 @@ fn archive_application_impl(conn: &Connection, id: i64) -> CommandResult<()> {
         params![id, &now],
     )?;
 
-    if rows_affected == 0 {
-        return Err(CommandError::from(format!(
-            "Application with id {} not found",
-            id
-        )));
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!(
+            "Application with id {} not found",
+            id
+        )));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn add_status_change(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+    change: StatusChangeInput,
+) -> CommandResult<StatusChange> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    add_status_change_impl(&db, app_id, change)
+}
+
+pub(crate) fn add_status_change_impl(
+    conn: &Connection,
+    app_id: i64,
+    change: StatusChangeInput,
+) -> CommandResult<StatusChange> {
+    validate_optional_text_length("Status change notes", &change.notes, 1000)?;
+
+    let sc_id: i64;
+    {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+        let state = query_status_state(&tx, app_id)?;
+        validate_transition(&state.current_status, &change.status)?;
+        validate_status_date(&state, &change.changed_date)?;
+
+        let notes = change.notes.unwrap_or_default();
+        sc_id = insert_status_change(&tx, app_id, &change.status, &change.changed_date, &notes)?;
+        tx.commit()?;
+    }
+
+    query_status_change_by_id(conn, sc_id)
+}
+
+#[tauri::command]
+pub fn reopen_application(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+    date: String,
+    notes: Option<String>,
+) -> CommandResult<StatusChange> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    reopen_application_impl(&db, id, &date, notes)
+}
+
+/// Reopen a rejected or withdrawn application. The application returns to
+/// the last pipeline stage it reached before closing (`applied` if none),
+/// recorded as a new status_change so the history stays append-only.
+pub(crate) fn reopen_application_impl(
+    conn: &Connection,
+    app_id: i64,
+    date: &str,
+    notes: Option<String>,
+) -> CommandResult<StatusChange> {
+    validate_optional_text_length("Status change notes", &notes, 1000)?;
+
+    let sc_id: i64;
+    {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+        let state = query_status_state(&tx, app_id)?;
+        if !CLOSED_STATUSES.contains(&state.current_status.as_str()) {
+            return Err(CommandError::from(format!(
+                "Only rejected or withdrawn applications can be reopened (current status is '{}')",
+                state.current_status
+            )));
+        }
+        validate_status_date(&state, date)?;
+
+        let mut stmt = tx.prepare(
+            "SELECT status FROM status_change WHERE application_id = ?1 \
+             ORDER BY created_at DESC, id DESC",
+        )?;
+        let history = stmt
+            .query_map([app_id], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CommandError::from)?;
+        drop(stmt);
+        let status = history
+            .into_iter()
+            .find(|s| stage_index(s).is_some())
+            .unwrap_or_else(|| "applied".to_string());
+
+        let notes = match notes.filter(|n| !n.trim().is_empty()) {
+            Some(n) => format!("Reopened: {}", n),
+            None => "Reopened".to_string(),
+        };
+        sc_id = insert_status_change(&tx, app_id, &status, date, &notes)?;
+        tx.commit()?;
+    }
+
+    query_status_change_by_id(conn, sc_id)
+}
+
+#[tauri::command]
+pub fn get_status_history(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+) -> CommandResult<Vec<StatusChange>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_status_history_impl(&db, app_id)
+}
+
+pub(crate) fn get_status_history_impl(
+    conn: &Connection,
+    app_id: i64,
+) -> CommandResult<Vec<StatusChange>> {
+    let sql = format!(
+        "SELECT {} FROM status_change WHERE application_id = ?1 \
+         ORDER BY created_at ASC, id ASC",
+        STATUS_CHANGE_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(params![app_id], row_to_status_change)?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+// ---------------------------------------------------------------------------
+// Status History Corrections
+// ---------------------------------------------------------------------------
+
+/// The status change with `id` and its neighbours in its application's
+/// history (ordered by `created_at`, `id`).
+fn query_status_change_neighbours(
+    conn: &Connection,
+    id: i64,
+) -> CommandResult<(Option<StatusChange>, StatusChange, Option<StatusChange>)> {
+    let app_id: i64 = conn
+        .query_row(
+            "SELECT application_id FROM status_change WHERE id = ?1",
+            [id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or_else(|| CommandError::from(format!("Status change with id {} not found", id)))?;
+    let mut history = get_status_history_impl(conn, app_id)?;
+    let index = history
+        .iter()
+        .position(|sc| sc.id == id)
+        .ok_or_else(|| CommandError::from(format!("Status change with id {} not found", id)))?;
+    let next = (index + 1 < history.len()).then(|| history.remove(index + 1));
+    let current = history.remove(index);
+    let prev = index.checked_sub(1).map(|i| history.remove(i));
+    Ok((prev, current, next))
+}
+
+#[tauri::command]
+pub fn update_status_change(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+    change: StatusChangeInput,
+) -> CommandResult<StatusChange> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_status_change_impl(&db, id, change)
+}
+
+/// Correct the status, date or notes of a recorded change. The edited row
+/// must still fit between its neighbours; `current_status` is resynced and
+/// the old and new values are written to `status_change_audit`. Only the
+/// notes of the first change can be edited; its date follows
+/// `date_applied` through `update_application`.
+pub(crate) fn update_status_change_impl(
+    conn: &Connection,
+    id: i64,
+    change: StatusChangeInput,
+) -> CommandResult<StatusChange> {
+    validate_optional_text_length("Status change notes", &change.notes, 1000)?;
+    if !PIPELINE_ORDER.contains(&change.status.as_str()) {
+        return Err(CommandError::from(format!("Unknown status '{}'", change.status)));
+    }
+
+    {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+        let (prev, old, next) = query_status_change_neighbours(&tx, id)?;
+        let updated = StatusChange {
+            status: change.status,
+            date: change.changed_date,
+            notes: change.notes.unwrap_or_default(),
+            ..old.clone()
+        };
+        if updated.status == old.status && updated.date == old.date && updated.notes == old.notes {
+            return Ok(old);
+        }
+        if prev.is_none() && (updated.status != old.status || updated.date != old.date) {
+            return Err(CommandError::from(
+                "Cannot change the status or date of the first status change; \
+                 edit the application's date applied instead",
+            ));
+        }
+
+        let state = query_status_state(&tx, old.application_id)?;
+        if parse_iso_date(&updated.date)? < parse_iso_date(&state.date_applied)? {
+            return Err(CommandError::from(format!(
+                "Status date {} is before the application date {}",
+                updated.date, state.date_applied
+            )));
+        }
+        if let Some(prev) = &prev {
+            validate_history_link(prev, &updated)?;
+        }
+        if let Some(next) = &next {
+            validate_history_link(&updated, next)?;
+        }
+
+        tx.execute(
+            "UPDATE status_change SET status = ?2, date = ?3, notes = ?4 WHERE id = ?1",
+            params![id, updated.status, updated.date, updated.notes],
+        )?;
+        insert_status_change_audit(&tx, &old, Some(&updated))?;
+        resync_current_status(&tx, old.application_id)?;
+        tx.commit()?;
+    }
+
+    query_status_change_by_id(conn, id)
+}
+
+#[tauri::command]
+pub fn delete_status_change(state: tauri::State<'_, AppState>, id: i64) -> CommandResult<()> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_status_change_impl(&db, id)
+}
+
+/// Remove a mistaken status change. The application must keep at least one
+/// change and the remaining history must still be a valid sequence;
+/// `current_status` is resynced and the removed values are audited.
+pub(crate) fn delete_status_change_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let (prev, old, next) = query_status_change_neighbours(&tx, id)?;
+    match (&prev, &next) {
+        (None, None) => {
+            return Err(CommandError::from(
+                "Cannot delete the only status change of an application",
+            ));
+        }
+        (None, Some(_)) => {
+            return Err(CommandError::from(
+                "Cannot delete the first status change while later changes exist",
+            ));
+        }
+        (Some(prev), Some(next)) => validate_history_link(prev, next)?,
+        (Some(_), None) => {}
+    }
+
+    tx.execute("DELETE FROM status_change WHERE id = ?1", [id])?;
+    insert_status_change_audit(&tx, &old, None)?;
+    resync_current_status(&tx, old.application_id)?;
+    tx.commit()?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_status_change_audit(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+) -> CommandResult<Vec<StatusChangeAudit>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_status_change_audit_impl(&db, app_id)
+}
+
+/// Corrections made to an application's status history, oldest first.
+pub(crate) fn get_status_change_audit_impl(
+    conn: &Connection,
+    app_id: i64,
+) -> CommandResult<Vec<StatusChangeAudit>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, status_change_id, application_id, action, old_status, old_date, \
+         old_notes, new_status, new_date, new_notes, created_at \
+         FROM status_change_audit WHERE application_id = ?1 ORDER BY id ASC",
+    )?;
+    let rows = stmt.query_map([app_id], |row| {
+        Ok(StatusChangeAudit {
+            id: row.get("id")?,
+            status_change_id: row.get("status_change_id")?,
+            application_id: row.get("application_id")?,
+            action: row.get("action")?,
+            old_status: row.get("old_status")?,
+            old_date: row.get("old_date")?,
+            old_notes: row.get("old_notes")?,
+            new_status: row.get("new_status")?,
+            new_date: row.get("new_date")?,
+            new_notes: row.get("new_notes")?,
+            created_at: row.get("created_at")?,
+        })
+    })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+// ---------------------------------------------------------------------------
+// Contacts & Interviews
+// ---------------------------------------------------------------------------
+
+fn row_to_contact(row: &rusqlite::Row) -> rusqlite::Result<Contact> {
+    Ok(Contact {
+        id: row.get("id")?,
+        application_id: row.get("application_id")?,
+        name: row.get("name")?,
+        role: row.get("role")?,
+        email: row.get("email")?,
+        phone: row.get("phone")?,
+        notes: row.get("notes")?,
+        created_at: row.get("created_at")?,
+        last_modified: row.get("last_modified")?,
+    })
+}
+
+fn row_to_interview(row: &rusqlite::Row) -> rusqlite::Result<Interview> {
+    Ok(Interview {
+        id: row.get("id")?,
+        application_id: row.get("application_id")?,
+        date: row.get("date")?,
+        time: row.get("time")?,
+        round: row.get("round")?,
+        interviewers: row.get("interviewers")?,
+        prep_notes: row.get("prep_notes")?,
+        outcome: row.get("outcome")?,
+        notes: row.get("notes")?,
+        created_at: row.get("created_at")?,
+        last_modified: row.get("last_modified")?,
+    })
+}
+
+fn query_contact_by_id(conn: &Connection, id: i64) -> CommandResult<Option<Contact>> {
+    let sql = format!("SELECT {} FROM contact WHERE id = ?1", CONTACT_COLUMNS);
+    conn.query_row(&sql, [id], row_to_contact)
+        .optional()
+        .map_err(CommandError::from)
+}
+
+fn query_interview_by_id(conn: &Connection, id: i64) -> CommandResult<Option<Interview>> {
+    let sql = format!("SELECT {} FROM interview WHERE id = ?1", INTERVIEW_COLUMNS);
+    conn.query_row(&sql, [id], row_to_interview)
+        .optional()
+        .map_err(CommandError::from)
+}
+
+fn ensure_application_exists(conn: &Connection, app_id: i64) -> CommandResult<()> {
+    if query_application_by_id(conn, app_id)?.is_none() {
+        return Err(CommandError::from(format!(
+            "Application with id {} not found",
+            app_id
+        )));
+    }
+    Ok(())
+}
+
+fn validate_contact_input(contact: &ContactInput) -> CommandResult<()> {
+    if contact.name.trim().is_empty() {
+        return Err(CommandError::from("Contact name cannot be empty"));
+    }
+    if !CONTACT_ROLES.contains(&contact.role.as_str()) {
+        return Err(CommandError::from(format!(
+            "Invalid contact role '{}'. Expected one of: {}",
+            contact.role,
+            CONTACT_ROLES.join(", ")
+        )));
+    }
+    validate_text_length("Contact name", &contact.name, 200)?;
+    validate_text_length("Contact email", &contact.email, 200)?;
+    validate_text_length("Contact phone", &contact.phone, 100)?;
+    validate_text_length("Contact notes", &contact.notes, 1000)?;
+    Ok(())
+}
+
+fn validate_interview_input(interview: &InterviewInput) -> CommandResult<()> {
+    parse_iso_date(&interview.date)?;
+    if !interview.time.is_empty()
+        && chrono::NaiveTime::parse_from_str(&interview.time, "%H:%M").is_err()
+    {
+        return Err(CommandError::from(format!(
+            "Invalid interview time '{}'. Expected HH:MM",
+            interview.time
+        )));
+    }
+    if interview.round.trim().is_empty() {
+        return Err(CommandError::from("Interview round cannot be empty"));
+    }
+    if !INTERVIEW_OUTCOMES.contains(&interview.outcome.as_str()) {
+        return Err(CommandError::from(format!(
+            "Invalid interview outcome '{}'. Expected one of: {}",
+            interview.outcome,
+            INTERVIEW_OUTCOMES.join(", ")
+        )));
+    }
+    validate_text_length("Interview round", &interview.round, 200)?;
+    validate_text_length("Interviewers", &interview.interviewers, 1000)?;
+    validate_text_length("Prep notes", &interview.prep_notes, 5000)?;
+    validate_text_length("Interview notes", &interview.notes, 5000)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_contacts(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+) -> CommandResult<Vec<Contact>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_contacts_impl(&db, app_id)
+}
+
+pub(crate) fn get_contacts_impl(conn: &Connection, app_id: i64) -> CommandResult<Vec<Contact>> {
+    let sql = format!(
+        "SELECT {} FROM contact WHERE application_id = ?1 ORDER BY id ASC",
+        CONTACT_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([app_id], row_to_contact)?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn save_contact(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+    contact: ContactInput,
+) -> CommandResult<Contact> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_contact_impl(&db, app_id, contact)
+}
+
+pub(crate) fn save_contact_impl(
+    conn: &Connection,
+    app_id: i64,
+    contact: ContactInput,
+) -> CommandResult<Contact> {
+    validate_contact_input(&contact)?;
+    ensure_application_exists(conn, app_id)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO contact (\
+         application_id, name, role, email, phone, notes, created_at, last_modified\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            app_id,
+            contact.name,
+            contact.role,
+            contact.email,
+            contact.phone,
+            contact.notes,
+            &now,
+            &now,
+        ],
+    )?;
+
+    query_contact_by_id(conn, conn.last_insert_rowid())?
+        .ok_or_else(|| CommandError::from("Failed to read back saved contact"))
+}
+
+#[tauri::command]
+pub fn update_contact(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+    contact: ContactInput,
+) -> CommandResult<Contact> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_contact_impl(&db, id, contact)
+}
+
+pub(crate) fn update_contact_impl(
+    conn: &Connection,
+    id: i64,
+    contact: ContactInput,
+) -> CommandResult<Contact> {
+    validate_contact_input(&contact)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let rows_affected = conn.execute(
+        "UPDATE contact SET \
+         name = ?2, role = ?3, email = ?4, phone = ?5, notes = ?6, last_modified = ?7 \
+         WHERE id = ?1",
+        params![
+            id,
+            contact.name,
+            contact.role,
+            contact.email,
+            contact.phone,
+            contact.notes,
+            &now,
+        ],
+    )?;
+
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!("Contact with id {} not found", id)));
+    }
+
+    query_contact_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back updated contact"))
+}
+
+#[tauri::command]
+pub fn delete_contact(state: tauri::State<'_, AppState>, id: i64) -> CommandResult<()> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_contact_impl(&db, id)
+}
+
+pub(crate) fn delete_contact_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let rows_affected = conn.execute("DELETE FROM contact WHERE id = ?1", [id])?;
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!("Contact with id {} not found", id)));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_interviews(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+) -> CommandResult<Vec<Interview>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_interviews_impl(&db, app_id)
+}
+
+/// Interviews of an application in schedule order. Untimed interviews sort
+/// first within their day.
+pub(crate) fn get_interviews_impl(
+    conn: &Connection,
+    app_id: i64,
+) -> CommandResult<Vec<Interview>> {
+    let sql = format!(
+        "SELECT {} FROM interview WHERE application_id = ?1 \
+         ORDER BY date ASC, time ASC, id ASC",
+        INTERVIEW_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([app_id], row_to_interview)?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn save_interview(
+    state: tauri::State<'_, AppState>,
+    app_id: i64,
+    interview: InterviewInput,
+) -> CommandResult<Interview> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_interview_impl(&db, app_id, interview)
+}
+
+pub(crate) fn save_interview_impl(
+    conn: &Connection,
+    app_id: i64,
+    interview: InterviewInput,
+) -> CommandResult<Interview> {
+    validate_interview_input(&interview)?;
+    ensure_application_exists(conn, app_id)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO interview (\
+         application_id, date, time, round, interviewers, prep_notes, \
+         outcome, notes, created_at, last_modified\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            app_id,
+            interview.date,
+            interview.time,
+            interview.round,
+            interview.interviewers,
+            interview.prep_notes,
+            interview.outcome,
+            interview.notes,
+            &now,
+            &now,
+        ],
+    )?;
+
+    query_interview_by_id(conn, conn.last_insert_rowid())?
+        .ok_or_else(|| CommandError::from("Failed to read back saved interview"))
+}
+
+#[tauri::command]
+pub fn update_interview(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+    interview: InterviewInput,
+) -> CommandResult<Interview> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_interview_impl(&db, id, interview)
+}
+
+pub(crate) fn update_interview_impl(
+    conn: &Connection,
+    id: i64,
+    interview: InterviewInput,
+) -> CommandResult<Interview> {
+    validate_interview_input(&interview)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let rows_affected = conn.execute(
+        "UPDATE interview SET \
+         date = ?2, time = ?3, round = ?4, interviewers = ?5, prep_notes = ?6, \
+         outcome = ?7, notes = ?8, last_modified = ?9 \
+         WHERE id = ?1",
+        params![
+            id,
+            interview.date,
+            interview.time,
+            interview.round,
+            interview.interviewers,
+            interview.prep_notes,
+            interview.outcome,
+            interview.notes,
+            &now,
+        ],
+    )?;
+
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!("Interview with id {} not found", id)));
+    }
+
+    query_interview_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back updated interview"))
+}
+
+#[tauri::command]
+pub fn delete_interview(state: tauri::State<'_, AppState>, id: i64) -> CommandResult<()> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_interview_impl(&db, id)
+}
+
+pub(crate) fn delete_interview_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let rows_affected = conn.execute("DELETE FROM interview WHERE id = ?1", [id])?;
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!("Interview with id {} not found", id)));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Follow-ups
+// ---------------------------------------------------------------------------
+
+/// Active (non-archived) applications with the date of their latest status
+/// change.
+fn query_active_with_last_status_date(
+    conn: &Connection,
+) -> CommandResult<Vec<(Application, String)>> {
+    let sql = format!(
+        "SELECT {}, \
+         COALESCE((SELECT MAX(sc.date) FROM status_change sc \
+                   WHERE sc.application_id = application.id), date_applied) AS last_status_date \
+         FROM application WHERE archived = 0 ORDER BY id ASC",
+        APPLICATION_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row_to_application(row)?, row.get("last_status_date")?))
+    })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn get_follow_ups(
+    state: tauri::State<'_, AppState>,
+    as_of: String,
+) -> CommandResult<FollowUps> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_follow_ups_impl(&db, &as_of)
+}
+
+/// Overdue and upcoming follow-ups as of `as_of`.
+///
+/// An explicit `follow_up_date` always wins. Otherwise a date is suggested
+/// from the current status and the age of the last status change; archived
+/// applications and terminal statuses are skipped.
+pub(crate) fn get_follow_ups_impl(conn: &Connection, as_of: &str) -> CommandResult<FollowUps> {
+    let today = parse_iso_date(as_of)?;
+    let horizon = today + chrono::Duration::days(UPCOMING_FOLLOW_UP_DAYS);
+
+    let mut overdue = Vec::new();
+    let mut upcoming = Vec::new();
+    for (app, last_status_date) in query_active_with_last_status_date(conn)? {
+        let suggested = match suggested_follow_up_days(&app.current_status) {
+            Some(days) => Some(parse_iso_date(&last_status_date)? + chrono::Duration::days(days)),
+            None => None,
+        };
+        let due = match &app.follow_up_date {
+            Some(d) if !d.is_empty() => parse_iso_date(d)?,
+            _ => match suggested {
+                Some(d) => d,
+                None => continue,
+            },
+        };
+        if due > horizon {
+            continue;
+        }
+
+        let follow_up = FollowUp {
+            application_id: app.id,
+            company: app.company,
+            role: app.role,
+            current_status: app.current_status,
+            last_status_date,
+            follow_up_date: app.follow_up_date.filter(|d| !d.is_empty()),
+            suggested_date: suggested.map(|d| d.format("%Y-%m-%d").to_string()),
+            due_date: due.format("%Y-%m-%d").to_string(),
+            days_until_due: (due - today).num_days(),
+        };
+        if due < today {
+            overdue.push(follow_up);
+        } else {
+            upcoming.push(follow_up);
+        }
     }
+    overdue.sort_by(|a, b| (&a.due_date, a.application_id).cmp(&(&b.due_date, b.application_id)));
+    upcoming.sort_by(|a, b| (&a.due_date, a.application_id).cmp(&(&b.due_date, b.application_id)));
 
-    Ok(())
+    Ok(FollowUps {
+        as_of: as_of.to_string(),
+        overdue,
+        upcoming,
+    })
 }
 
 #[tauri::command]
-pub fn add_status_change(
+pub fn mark_stale_applications(
     state: tauri::State<'_, AppState>,
-    app_id: i64,
-    change: StatusChangeInput,
-) -> CommandResult<StatusChange> {
+    as_of: String,
+    days: Option<i64>,
+) -> CommandResult<Vec<Application>> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-    add_status_change_impl(&db, app_id, change)
+    mark_stale_applications_impl(&db, &as_of, days)
 }
 
-fn add_status_change_impl(
+/// Move every active application still at `applied` whose last status change
+/// is at least `days` (default `DEFAULT_NO_RESPONSE_DAYS`) before `as_of` to
+/// `no_response`, recording a status_change dated `as_of`. Returns the
+/// updated applications.
+pub(crate) fn mark_stale_applications_impl(
     conn: &Connection,
-    app_id: i64,
-    change: StatusChangeInput,
-) -> CommandResult<StatusChange> {
-    validate_optional_text_length("Status change notes", &change.notes, 1000)?;
+    as_of: &str,
+    days: Option<i64>,
+) -> CommandResult<Vec<Application>> {
+    let today = parse_iso_date(as_of)?;
+    let days = days.unwrap_or(DEFAULT_NO_RESPONSE_DAYS);
+    if !(1..=365).contains(&days) {
+        return Err(CommandError::from("days must be between 1 and 365"));
+    }
 
-    let sc_id: i64;
+    let mut stale_ids = Vec::new();
     {
         let tx = conn
             .unchecked_transaction()
             .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+        let notes = format!("No response after {} days", days);
 
-        let now = chrono::Utc::now().to_rfc3339();
-        let notes = change.notes.unwrap_or_default();
-
-        // Verify the application exists
-        let app_exists: bool = tx
-            .query_row(
-                "SELECT COUNT(*) FROM application WHERE id = ?1",
-                [app_id],
-                |row| row.get::<_, i64>(0),
-            )
-            .map(|c| c > 0)?;
-
-        if !app_exists {
-            return Err(CommandError::from(format!(
-                "Application with id {} not found",
-                app_id
-            )));
+        for (app, last_status_date) in query_active_with_last_status_date(&tx)? {
+            if app.current_status != "applied"
+                || (today - parse_iso_date(&last_status_date)?).num_days() < days
+            {
+                continue;
+            }
+            insert_status_change(&tx, app.id, "no_response", as_of, &notes)?;
+            stale_ids.push(app.id);
         }
-
-        // Insert status_change row
-        tx.execute(
-            "INSERT INTO status_change (\
-             application_id, status, date, notes, created_at\
-             ) VALUES (?1, ?2, ?3, ?4, ?5)",
-            params![app_id, change.status, change.changed_date, notes, &now],
-        )?;
-
-        sc_id = tx.last_insert_rowid();
-
-        // D5 sync invariant: update application.current_status
-        tx.execute(
-            "UPDATE application SET current_status = ?2, last_modified = ?3 WHERE id = ?1",
-            params![app_id, change.status, &now],
-        )?;
-
         tx.commit()?;
     }
 
-    let sql = format!(
-        "SELECT {} FROM status_change WHERE id = ?1",
-        STATUS_CHANGE_COLUMNS
-    );
-    conn.query_row(&sql, [sc_id], row_to_status_change)
-        .optional()
-        .map_err(CommandError::from)?
-        .ok_or_else(|| CommandError::from("Failed to read back saved status change"))
-}
-
-#[tauri::command]
-pub fn get_status_history(
-    state: tauri::State<'_, AppState>,
-    app_id: i64,
-) -> CommandResult<Vec<StatusChange>> {
-    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_status_history_impl(&db, app_id)
-}
-
-fn get_status_history_impl(
-    conn: &Connection,
-    app_id: i64,
-) -> CommandResult<Vec<StatusChange>> {
-    let sql = format!(
-        "SELECT {} FROM status_change WHERE application_id = ?1 \
-         ORDER BY created_at ASC, id ASC",
-        STATUS_CHANGE_COLUMNS
-    );
-    let mut stmt = conn.prepare(&sql)?;
-    let rows = stmt.query_map(params![app_id], row_to_status_change)?;
-    rows.collect::<Result<Vec<_>, _>>()
-        .map_err(CommandError::from)
+    stale_ids
+        .into_iter()
+        .map(|id| {
+            query_application_by_id(conn, id)?
+                .ok_or_else(|| CommandError::from("Failed to read back updated application"))
+        })
+        .collect()
 }
 
 // ---------------------------------------------------------------------------
@@ -831,8 +1960,331 @@ mod tests {
         );
         assert!(
             result.is_err(),
-            "invalid status should be rejected by CHECK constraint"
+            "invalid status should be rejected"
+        );
+    }
+
+    // -------------------------------------------------------------------
+    // Status Transition Tests
+    // -------------------------------------------------------------------
+
+    fn change_status(
+        conn: &Connection,
+        app_id: i64,
+        status: &str,
+        date: &str,
+    ) -> CommandResult<StatusChange> {
+        add_status_change_impl(
+            conn,
+            app_id,
+            StatusChangeInput {
+                status: status.to_string(),
+                changed_date: date.to_string(),
+                notes: None,
+            },
+        )
+    }
+
+    fn err_of<T: std::fmt::Debug>(result: CommandResult<T>) -> String {
+        format!("{}", result.unwrap_err())
+    }
+
+    #[test]
+    fn test_transition_graph() {
+        // Forward moves, skips and closing are allowed
+        assert!(validate_transition("applied", "interview").is_ok());
+        assert!(validate_transition("phone_screen", "technical_screen").is_ok());
+        assert!(validate_transition("interview", "rejected").is_ok());
+        assert!(validate_transition("offer", "withdrawn").is_ok());
+        assert!(validate_transition("no_response", "phone_screen").is_ok());
+        // Backwards, repeats, unknown and closed are not
+        assert!(validate_transition("interview", "phone_screen").is_err());
+        assert!(validate_transition("offer", "no_response").is_err());
+        assert!(validate_transition("interview", "interview").is_err());
+        assert!(validate_transition("applied", "ghosted").is_err());
+        assert!(validate_transition("rejected", "offer").is_err());
+        assert!(validate_transition("withdrawn", "applied").is_err());
+        // Nothing leads back to applied
+        for from in PIPELINE_ORDER {
+            assert!(!allowed_transitions(from).contains(&"applied"), "{}", from);
+        }
+    }
+
+    #[test]
+    fn test_add_status_change_rejects_invalid_transition() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "rejected", "2026-02-20").unwrap();
+
+        let err = err_of(change_status(&conn, a.id, "offer", "2026-02-21"));
+        assert!(err.contains("reopen"), "{}", err);
+
+        let b = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, b.id, "interview", "2026-02-20").unwrap();
+        let err = err_of(change_status(&conn, b.id, "phone_screen", "2026-02-21"));
+        assert!(err.contains("from 'interview' to 'phone_screen'"), "{}", err);
+        let err = err_of(change_status(&conn, b.id, "interview", "2026-02-21"));
+        assert!(err.contains("already 'interview'"), "{}", err);
+
+        // Nothing was written
+        assert_eq!(get_status_history_impl(&conn, b.id).unwrap().len(), 2);
+        let app = query_application_by_id(&conn, b.id).unwrap().unwrap();
+        assert_eq!(app.current_status, "interview");
+    }
+
+    #[test]
+    fn test_add_status_change_rejects_backdated_changes() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let err = err_of(change_status(&conn, a.id, "phone_screen", "2026-02-17"));
+        assert!(err.contains("before the application date"), "{}", err);
+
+        change_status(&conn, a.id, "phone_screen", "2026-02-22").unwrap();
+        let err = err_of(change_status(&conn, a.id, "interview", "2026-02-21"));
+        assert!(err.contains("before the previous status change"), "{}", err);
+        // Same day as the previous change is fine
+        change_status(&conn, a.id, "interview", "2026-02-22").unwrap();
+
+        assert!(change_status(&conn, a.id, "offer", "22/02/2026").is_err());
+    }
+
+    #[test]
+    fn test_reopen_application_returns_to_last_stage() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "interview", "2026-02-20").unwrap();
+        change_status(&conn, a.id, "withdrawn", "2026-02-21").unwrap();
+
+        let sc =
+            reopen_application_impl(&conn, a.id, "2026-02-25", Some("Changed my mind".into()))
+                .unwrap();
+        assert_eq!(sc.status, "interview");
+        assert_eq!(sc.date, "2026-02-25");
+        assert_eq!(sc.notes, "Reopened: Changed my mind");
+        let app = query_application_by_id(&conn, a.id).unwrap().unwrap();
+        assert_eq!(app.current_status, "interview");
+
+        // Normal transitions work again
+        change_status(&conn, a.id, "offer", "2026-02-26").unwrap();
+    }
+
+    #[test]
+    fn test_reopen_application_without_stage_goes_to_applied() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "rejected", "2026-02-20").unwrap();
+
+        let sc = reopen_application_impl(&conn, a.id, "2026-02-20", None).unwrap();
+        assert_eq!(sc.status, "applied");
+        assert_eq!(sc.notes, "Reopened");
+    }
+
+    #[test]
+    fn test_reopen_application_rules() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let err = err_of(reopen_application_impl(&conn, a.id, "2026-02-20", None));
+        assert!(err.contains("Only rejected or withdrawn"), "{}", err);
+
+        change_status(&conn, a.id, "rejected", "2026-02-20").unwrap();
+        let err = err_of(reopen_application_impl(&conn, a.id, "2026-02-19", None));
+        assert!(err.contains("before the previous status change"), "{}", err);
+
+        assert!(reopen_application_impl(&conn, 99999, "2026-02-20", None).is_err());
+    }
+
+    // -------------------------------------------------------------------
+    // Status History Correction Tests
+    // -------------------------------------------------------------------
+
+    fn edit(status: &str, date: &str, notes: Option<&str>) -> StatusChangeInput {
+        StatusChangeInput {
+            status: status.to_string(),
+            changed_date: date.to_string(),
+            notes: notes.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_update_status_change_resyncs_and_audits() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "phone_screen", "2026-02-20").unwrap();
+        let wrong = change_status(&conn, a.id, "rejected", "2026-02-22").unwrap();
+
+        let fixed = update_status_change_impl(
+            &conn,
+            wrong.id,
+            edit("interview", "2026-02-23", Some("Onsite")),
+        )
+        .unwrap();
+        assert_eq!(fixed.id, wrong.id);
+        assert_eq!(fixed.status, "interview");
+        assert_eq!(fixed.date, "2026-02-23");
+        assert_eq!(fixed.notes, "Onsite");
+        let app = query_application_by_id(&conn, a.id).unwrap().unwrap();
+        assert_eq!(app.current_status, "interview");
+
+        let audit = get_status_change_audit_impl(&conn, a.id).unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, "update");
+        assert_eq!(audit[0].status_change_id, wrong.id);
+        assert_eq!(audit[0].old_status, "rejected");
+        assert_eq!(audit[0].old_date, "2026-02-22");
+        assert_eq!(audit[0].new_status.as_deref(), Some("interview"));
+        assert_eq!(audit[0].new_notes.as_deref(), Some("Onsite"));
+
+        // Unchanged values write no audit entry
+        update_status_change_impl(
+            &conn,
+            wrong.id,
+            edit("interview", "2026-02-23", Some("Onsite")),
+        )
+        .unwrap();
+        assert_eq!(get_status_change_audit_impl(&conn, a.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_update_status_change_must_fit_neighbours() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        let phone = change_status(&conn, a.id, "phone_screen", "2026-02-20").unwrap();
+        change_status(&conn, a.id, "interview", "2026-02-25").unwrap();
+
+        // After the next change
+        let err = err_of(update_status_change_impl(
+            &conn,
+            phone.id,
+            edit("phone_screen", "2026-02-26", None),
+        ));
+        assert!(err.contains("before the preceding 'phone_screen'"), "{}", err);
+        // Before the application date
+        let err = err_of(update_status_change_impl(
+            &conn,
+            phone.id,
+            edit("phone_screen", "2026-02-01", None),
+        ));
+        assert!(err.contains("before the application date"), "{}", err);
+        // Would repeat the next status
+        let err = err_of(update_status_change_impl(
+            &conn,
+            phone.id,
+            edit("interview", "2026-02-20", None),
+        ));
+        assert!(err.contains("repeat 'interview'"), "{}", err);
+        // Would move backwards from the next status
+        let err =
+            err_of(update_status_change_impl(&conn, phone.id, edit("offer", "2026-02-20", None)));
+        assert!(err.contains("from 'offer' to 'interview'"), "{}", err);
+
+        assert!(
+            update_status_change_impl(&conn, phone.id, edit("nope", "2026-02-20", None)).is_err()
         );
+        assert!(
+            update_status_change_impl(&conn, 99999, edit("offer", "2026-02-20", None)).is_err()
+        );
+        assert!(get_status_change_audit_impl(&conn, a.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_update_status_change_keeps_first_change_in_sync() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "phone_screen", "2026-02-25").unwrap();
+        let first = get_status_history_impl(&conn, a.id).unwrap().remove(0);
+        assert_eq!(first.date, a.date_applied);
+
+        // Moving it later would leave date_applied behind
+        let err = err_of(update_status_change_impl(
+            &conn,
+            first.id,
+            edit("applied", "2026-02-20", None),
+        ));
+        assert!(err.contains("first status change"), "{}", err);
+        let err = err_of(update_status_change_impl(
+            &conn,
+            first.id,
+            edit("no_response", &a.date_applied, None),
+        ));
+        assert!(err.contains("first status change"), "{}", err);
+
+        // Notes can still be corrected
+        let fixed = update_status_change_impl(
+            &conn,
+            first.id,
+            edit("applied", &a.date_applied, Some("Referral")),
+        )
+        .unwrap();
+        assert_eq!(fixed.notes, "Referral");
+        assert_eq!(get_status_change_audit_impl(&conn, a.id).unwrap().len(), 1);
+
+        // Editing date_applied moves the first change with it, up to the next one
+        let mut input = make_default_app_input();
+        input.date_applied = "2026-02-26".to_string();
+        let err = err_of(update_application_impl(&conn, a.id, input.clone()));
+        assert!(err.contains("after the following 'phone_screen'"), "{}", err);
+        let app = query_application_by_id(&conn, a.id).unwrap().unwrap();
+        assert_eq!(app.date_applied, first.date, "rejected edit is rolled back");
+
+        input.date_applied = "2026-02-24".to_string();
+        update_application_impl(&conn, a.id, input).unwrap();
+        let history = get_status_history_impl(&conn, a.id).unwrap();
+        assert_eq!(history[0].date, "2026-02-24");
+        let audit = get_status_change_audit_impl(&conn, a.id).unwrap();
+        assert_eq!(audit.len(), 2);
+        assert_eq!(audit[1].old_date, first.date);
+        assert_eq!(audit[1].new_date.as_deref(), Some("2026-02-24"));
+    }
+
+    #[test]
+    fn test_delete_status_change_resyncs_and_audits() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        change_status(&conn, a.id, "phone_screen", "2026-02-20").unwrap();
+        let misclick = change_status(&conn, a.id, "offer", "2026-02-20").unwrap();
+
+        delete_status_change_impl(&conn, misclick.id).unwrap();
+        let app = query_application_by_id(&conn, a.id).unwrap().unwrap();
+        assert_eq!(app.current_status, "phone_screen");
+        assert_eq!(get_status_history_impl(&conn, a.id).unwrap().len(), 2);
+
+        let audit = get_status_change_audit_impl(&conn, a.id).unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, "delete");
+        assert_eq!(audit[0].old_status, "offer");
+        assert_eq!(audit[0].new_status, None);
+    }
+
+    #[test]
+    fn test_delete_status_change_rules() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        let history = get_status_history_impl(&conn, a.id).unwrap();
+
+        let err = err_of(delete_status_change_impl(&conn, history[0].id));
+        assert!(err.contains("only status change"), "{}", err);
+
+        // The first row stays while later changes exist
+        change_status(&conn, a.id, "phone_screen", "2026-02-19").unwrap();
+        let err = err_of(delete_status_change_impl(&conn, history[0].id));
+        assert!(err.contains("first status change"), "{}", err);
+        let phone = get_status_history_impl(&conn, a.id).unwrap().remove(1);
+        delete_status_change_impl(&conn, phone.id).unwrap();
+
+        // Removing the middle row must leave a valid sequence
+        change_status(&conn, a.id, "rejected", "2026-02-20").unwrap();
+        let reopened = reopen_application_impl(&conn, a.id, "2026-02-21", None).unwrap();
+        let history = get_status_history_impl(&conn, a.id).unwrap();
+        let err = err_of(delete_status_change_impl(&conn, history[1].id));
+        assert!(err.contains("repeat 'applied'"), "{}", err);
+        // The reopen itself can go, leaving the application rejected
+        delete_status_change_impl(&conn, reopened.id).unwrap();
+        let app = query_application_by_id(&conn, a.id).unwrap().unwrap();
+        assert_eq!(app.current_status, "rejected");
+
+        assert!(delete_status_change_impl(&conn, 99999).is_err());
     }
 
     // -------------------------------------------------------------------
@@ -1048,6 +2500,266 @@ mod tests {
         assert_eq!(results[2].date_applied, "2026-02-10");
     }
 
+    // -------------------------------------------------------------------
+    // Follow-up Tests
+    // -------------------------------------------------------------------
+
+    fn save_app_on(conn: &Connection, date_applied: &str, company: &str) -> Application {
+        let mut input = make_default_app_input();
+        input.date_applied = date_applied.to_string();
+        input.company = company.to_string();
+        save_application_impl(conn, input).unwrap()
+    }
+
+    #[test]
+    fn test_follow_up_suggested_from_last_status_change() {
+        let conn = setup_test_db();
+        let a = save_app_on(&conn, "2026-02-18", "Acme");
+
+        // applied → 7 days later
+        let f = get_follow_ups_impl(&conn, "2026-02-24").unwrap();
+        assert!(f.overdue.is_empty());
+        assert_eq!(f.upcoming.len(), 1);
+        assert_eq!(f.upcoming[0].suggested_date.as_deref(), Some("2026-02-25"));
+        assert_eq!(f.upcoming[0].due_date, "2026-02-25");
+        assert_eq!(f.upcoming[0].days_until_due, 1);
+
+        let f = get_follow_ups_impl(&conn, "2026-02-27").unwrap();
+        assert_eq!(f.overdue.len(), 1);
+        assert_eq!(f.overdue[0].days_until_due, -2);
+
+        // interview → 3 days after the interview
+        change_status(&conn, a.id, "interview", "2026-02-26").unwrap();
+        let f = get_follow_ups_impl(&conn, "2026-02-27").unwrap();
+        assert!(f.overdue.is_empty());
+        assert_eq!(f.upcoming[0].last_status_date, "2026-02-26");
+        assert_eq!(f.upcoming[0].due_date, "2026-03-01");
+    }
+
+    #[test]
+    fn test_follow_up_explicit_date_wins_and_horizon() {
+        let conn = setup_test_db();
+        let mut input = make_default_app_input();
+        input.follow_up_date = Some("2026-03-10".to_string());
+        save_application_impl(&conn, input).unwrap();
+
+        // Suggested 2026-02-25 is ignored; 2026-03-10 is beyond the horizon
+        let f = get_follow_ups_impl(&conn, "2026-02-27").unwrap();
+        assert!(f.overdue.is_empty());
+        assert!(f.upcoming.is_empty());
+
+        let f = get_follow_ups_impl(&conn, "2026-03-05").unwrap();
+        assert_eq!(f.upcoming.len(), 1);
+        assert_eq!(f.upcoming[0].follow_up_date.as_deref(), Some("2026-03-10"));
+        assert_eq!(f.upcoming[0].suggested_date.as_deref(), Some("2026-02-25"));
+        assert_eq!(f.upcoming[0].due_date, "2026-03-10");
+    }
+
+    #[test]
+    fn test_follow_ups_skip_terminal_and_archived() {
+        let conn = setup_test_db();
+        let rejected = save_app_on(&conn, "2026-02-01", "Rejected Co");
+        change_status(&conn, rejected.id, "rejected", "2026-02-05").unwrap();
+        let archived = save_app_on(&conn, "2026-02-01", "Archived Co");
+        archive_application_impl(&conn, archived.id).unwrap();
+        let open = save_app_on(&conn, "2026-02-01", "Open Co");
+        let older = save_app_on(&conn, "2026-01-20", "Older Co");
+
+        let f = get_follow_ups_impl(&conn, "2026-02-20").unwrap();
+        let ids: Vec<i64> = f.overdue.iter().map(|x| x.application_id).collect();
+        // Most overdue first
+        assert_eq!(ids, vec![older.id, open.id]);
+    }
+
+    #[test]
+    fn test_get_follow_ups_rejects_bad_date() {
+        let conn = setup_test_db();
+        assert!(get_follow_ups_impl(&conn, "02/20/2026").is_err());
+    }
+
+    #[test]
+    fn test_mark_stale_applications() {
+        let conn = setup_test_db();
+        let stale = save_app_on(&conn, "2026-01-01", "Stale Co");
+        let recent = save_app_on(&conn, "2026-02-10", "Recent Co");
+        let screening = save_app_on(&conn, "2026-01-01", "Screen Co");
+        change_status(&conn, screening.id, "phone_screen", "2026-01-05").unwrap();
+
+        let updated = mark_stale_applications_impl(&conn, "2026-02-15", None).unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].id, stale.id);
+        assert_eq!(updated[0].current_status, "no_response");
+
+        let history = get_status_history_impl(&conn, stale.id).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].status, "no_response");
+        assert_eq!(history[1].date, "2026-02-15");
+        assert_eq!(history[1].notes, "No response after 30 days");
+
+        let untouched = query_application_by_id(&conn, recent.id).unwrap().unwrap();
+        assert_eq!(untouched.current_status, "applied");
+        let untouched = query_application_by_id(&conn, screening.id).unwrap().unwrap();
+        assert_eq!(untouched.current_status, "phone_screen");
+
+        // Running again changes nothing
+        assert!(mark_stale_applications_impl(&conn, "2026-02-15", None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_mark_stale_applications_custom_days() {
+        let conn = setup_test_db();
+        let a = save_app_on(&conn, "2026-02-10", "Acme");
+
+        assert!(mark_stale_applications_impl(&conn, "2026-02-15", Some(6)).unwrap().is_empty());
+        let updated = mark_stale_applications_impl(&conn, "2026-02-15", Some(5)).unwrap();
+        assert_eq!(updated[0].id, a.id);
+
+        assert!(mark_stale_applications_impl(&conn, "2026-02-15", Some(0)).is_err());
+        assert!(mark_stale_applications_impl(&conn, "not-a-date", None).is_err());
+    }
+
+    // -------------------------------------------------------------------
+    // Contacts & Interviews
+    // -------------------------------------------------------------------
+
+    fn make_contact(name: &str, role: &str) -> ContactInput {
+        ContactInput {
+            name: name.to_string(),
+            role: role.to_string(),
+            email: String::new(),
+            phone: String::new(),
+            notes: String::new(),
+        }
+    }
+
+    fn make_interview(date: &str, time: &str, round: &str) -> InterviewInput {
+        InterviewInput {
+            date: date.to_string(),
+            time: time.to_string(),
+            round: round.to_string(),
+            interviewers: String::new(),
+            prep_notes: String::new(),
+            outcome: "pending".to_string(),
+            notes: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_contact_crud() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let mut input = make_contact("Dana Lee", "recruiter");
+        input.email = "dana@acme.com".to_string();
+        let saved = save_contact_impl(&conn, a.id, input).unwrap();
+        assert_eq!(saved.application_id, a.id);
+        assert_eq!(saved.email, "dana@acme.com");
+
+        let mut edit = make_contact("Dana Lee", "hiring_manager");
+        edit.notes = "Moved teams".to_string();
+        let updated = update_contact_impl(&conn, saved.id, edit).unwrap();
+        assert_eq!(updated.role, "hiring_manager");
+        assert_eq!(updated.notes, "Moved teams");
+        assert_eq!(updated.created_at, saved.created_at);
+
+        save_contact_impl(&conn, a.id, make_contact("Sam", "referrer")).unwrap();
+        assert_eq!(get_contacts_impl(&conn, a.id).unwrap().len(), 2);
+
+        delete_contact_impl(&conn, saved.id).unwrap();
+        let remaining = get_contacts_impl(&conn, a.id).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].name, "Sam");
+        assert!(delete_contact_impl(&conn, saved.id).is_err());
+        assert!(update_contact_impl(&conn, 999, make_contact("X", "other")).is_err());
+    }
+
+    #[test]
+    fn test_contact_validation() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let err = err_of(save_contact_impl(&conn, a.id, make_contact("  ", "recruiter")));
+        assert!(err.contains("cannot be empty"), "{}", err);
+        let err = err_of(save_contact_impl(&conn, a.id, make_contact("Dana", "boss")));
+        assert!(err.contains("Invalid contact role"), "{}", err);
+        let err = err_of(save_contact_impl(&conn, 999, make_contact("Dana", "recruiter")));
+        assert!(err.contains("not found"), "{}", err);
+    }
+
+    #[test]
+    fn test_interview_crud_and_ordering() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let onsite_input = make_interview("2026-02-25", "14:00", "Onsite");
+        let onsite = save_interview_impl(&conn, a.id, onsite_input).unwrap();
+        save_interview_impl(&conn, a.id, make_interview("2026-02-20", "", "Phone screen")).unwrap();
+        save_interview_impl(&conn, a.id, make_interview("2026-02-25", "09:30", "Coding")).unwrap();
+
+        let rounds: Vec<String> = get_interviews_impl(&conn, a.id)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.round)
+            .collect();
+        assert_eq!(rounds, ["Phone screen", "Coding", "Onsite"]);
+
+        let mut edit = make_interview("2026-02-25", "14:00", "Onsite");
+        edit.outcome = "passed".to_string();
+        edit.interviewers = "Ana, Raj".to_string();
+        let updated = update_interview_impl(&conn, onsite.id, edit).unwrap();
+        assert_eq!(updated.outcome, "passed");
+        assert_eq!(updated.interviewers, "Ana, Raj");
+
+        delete_interview_impl(&conn, onsite.id).unwrap();
+        assert_eq!(get_interviews_impl(&conn, a.id).unwrap().len(), 2);
+        assert!(delete_interview_impl(&conn, onsite.id).is_err());
+    }
+
+    #[test]
+    fn test_interview_validation() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+
+        let err = err_of(save_interview_impl(&conn, a.id, make_interview("02/20/2026", "", "R1")));
+        assert!(err.contains("Invalid date"), "{}", err);
+        let late = make_interview("2026-02-20", "25:00", "R1");
+        let err = err_of(save_interview_impl(&conn, a.id, late));
+        assert!(err.contains("Invalid interview time"), "{}", err);
+        let err = err_of(save_interview_impl(&conn, a.id, make_interview("2026-02-20", "", " ")));
+        assert!(err.contains("cannot be empty"), "{}", err);
+
+        let mut bad = make_interview("2026-02-20", "", "R1");
+        bad.outcome = "ghosted".to_string();
+        let err = err_of(save_interview_impl(&conn, a.id, bad));
+        assert!(err.contains("Invalid interview outcome"), "{}", err);
+
+        // Outcome defaults to pending when omitted
+        let input: InterviewInput =
+            serde_json::from_str(r#"{"date":"2026-02-20","round":"R1"}"#).unwrap();
+        assert_eq!(save_interview_impl(&conn, a.id, input).unwrap().outcome, "pending");
+    }
+
+    #[test]
+    fn test_get_application_includes_contacts_and_interviews() {
+        let conn = setup_test_db();
+        let a = save_application_impl(&conn, make_default_app_input()).unwrap();
+        save_contact_impl(&conn, a.id, make_contact("Dana", "recruiter")).unwrap();
+        save_interview_impl(&conn, a.id, make_interview("2026-02-20", "10:00", "R1")).unwrap();
+
+        let detail = get_application_impl(&conn, a.id).unwrap().unwrap();
+        assert_eq!(detail.application.id, a.id);
+        assert_eq!(detail.contacts.len(), 1);
+        assert_eq!(detail.interviews.len(), 1);
+
+        // Application fields stay at the top level of the JSON
+        let json = serde_json::to_value(&detail).unwrap();
+        assert_eq!(json["company"], a.company.as_str());
+        assert_eq!(json["contacts"][0]["name"], "Dana");
+        assert_eq!(json["interviews"][0]["round"], "R1");
+
+        assert!(get_application_impl(&conn, 999).unwrap().is_none());
+    }
+
     // -------------------------------------------------------------------
     // FK Constraint Test
     // -------------------------------------------------------------------
@@ -0,0 +1,519 @@
+use std::collections::HashSet;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+use super::analytics::{is_valid_daily_log_column, quote_column_name};
+use super::daily_log::resolve_dropdown_value;
+use super::validation::validate_text_length;
+use super::{CommandError, CommandResult};
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// A named definition of a "clean" day. Field names match the TypeScript
+/// `CleanStreak` interface.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanStreak {
+    pub id: i64,
+    pub name: String,
+    /// `habit_config.name` of each vice that breaks the streak.
+    pub vices: Vec<String>,
+    /// A day with a `relapse_entry` also breaks the streak.
+    pub use_relapse_entries: bool,
+    /// The default streak drives `consecutive_clean_days` and the seeded
+    /// clean_N milestones.
+    pub is_default: bool,
+    pub created_at: String,
+    pub last_modified: String,
+    /// Computed: consecutive clean days ending at the most recent daily log.
+    pub current_days: i64,
+}
+
+/// Input for save_clean_streak. `id = None` creates a new streak.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanStreakInput {
+    pub id: Option<i64>,
+    pub name: String,
+    pub vices: Vec<String>,
+    #[serde(default)]
+    pub use_relapse_entries: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Column Constants & Row Mapper
+// ---------------------------------------------------------------------------
+
+const CLEAN_STREAK_COLUMNS: &str = "\
+    id, name, vices, use_relapse_entries, is_default, created_at, last_modified";
+
+fn row_to_clean_streak(row: &rusqlite::Row) -> rusqlite::Result<CleanStreak> {
+    let vices_json: String = row.get("vices")?;
+    Ok(CleanStreak {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        vices: serde_json::from_str(&vices_json).unwrap_or_default(),
+        use_relapse_entries: row.get("use_relapse_entries")?,
+        is_default: row.get("is_default")?,
+        created_at: row.get("created_at")?,
+        last_modified: row.get("last_modified")?,
+        current_days: 0,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Query Helpers
+// ---------------------------------------------------------------------------
+
+fn with_current_days(conn: &Connection, mut streak: CleanStreak) -> CommandResult<CleanStreak> {
+    streak.current_days = current_clean_days(conn, &streak)?;
+    Ok(streak)
+}
+
+pub(crate) fn query_clean_streaks(conn: &Connection) -> CommandResult<Vec<CleanStreak>> {
+    let sql = format!(
+        "SELECT {} FROM clean_streak ORDER BY is_default DESC, name ASC",
+        CLEAN_STREAK_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt
+        .query_map([], row_to_clean_streak)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+    rows.into_iter().map(|s| with_current_days(conn, s)).collect()
+}
+
+fn query_clean_streak_by_id(conn: &Connection, id: i64) -> CommandResult<Option<CleanStreak>> {
+    let sql = format!("SELECT {} FROM clean_streak WHERE id = ?1", CLEAN_STREAK_COLUMNS);
+    conn.query_row(&sql, params![id], row_to_clean_streak)
+        .optional()
+        .map_err(CommandError::from)
+}
+
+/// Look up a streak by name (case-insensitive). `current_days` is not filled.
+pub(crate) fn query_clean_streak_by_name(
+    conn: &Connection,
+    name: &str,
+) -> CommandResult<Option<CleanStreak>> {
+    let sql = format!("SELECT {} FROM clean_streak WHERE name = ?1", CLEAN_STREAK_COLUMNS);
+    conn.query_row(&sql, params![name], row_to_clean_streak)
+        .optional()
+        .map_err(CommandError::from)
+}
+
+/// The default streak. `current_days` is not filled.
+pub(crate) fn query_default_clean_streak(conn: &Connection) -> CommandResult<CleanStreak> {
+    let sql = format!("SELECT {} FROM clean_streak WHERE is_default = 1", CLEAN_STREAK_COLUMNS);
+    conn.query_row(&sql, [], row_to_clean_streak)
+        .optional()?
+        .ok_or_else(|| CommandError::from("No default clean streak configured"))
+}
+
+// ---------------------------------------------------------------------------
+// Clean-Day Evaluation
+// ---------------------------------------------------------------------------
+
+/// Dates on which a habit was done (value > 0; dropdowns resolved through
+/// their options). Unknown habits yield no dates.
+pub(crate) fn load_habit_days(conn: &Connection, habit: &str) -> CommandResult<Vec<String>> {
+    let config: Option<(String, String, Option<String>)> = conn
+        .query_row(
+            "SELECT column_name, input_type, options_json FROM habit_config WHERE name = ?1",
+            params![habit],
+            |row| Ok((row.get("column_name")?, row.get("input_type")?, row.get("options_json")?)),
+        )
+        .optional()?;
+    let (column_name, input_type, options_json) = match config {
+        Some(c) if is_valid_daily_log_column(&c.0) => c,
+        _ => return Ok(Vec::new()),
+    };
+
+    let sql = format!(
+        "SELECT date, {} FROM daily_log ORDER BY date ASC",
+        quote_column_name(&column_name)
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query([])?;
+    let mut dates = Vec::new();
+    while let Some(row) = rows.next()? {
+        let value = if input_type == "dropdown" {
+            row.get::<_, Option<String>>(1)?
+                .map(|key| resolve_dropdown_value(&key, &options_json))
+        } else {
+            row.get::<_, Option<f64>>(1)?
+        };
+        if value.unwrap_or(0.0) > 0.0 {
+            dates.push(row.get(0)?);
+        }
+    }
+    Ok(dates)
+}
+
+/// Logged dates that are clean under `streak`: no listed vice was done and,
+/// if enabled, no relapse was recorded that day.
+pub(crate) fn clean_dates(
+    conn: &Connection,
+    streak: &CleanStreak,
+) -> CommandResult<HashSet<String>> {
+    let mut stmt = conn.prepare("SELECT date FROM daily_log")?;
+    let mut dates: HashSet<String> = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()
+        .map_err(CommandError::from)?;
+
+    for vice in &streak.vices {
+        for date in load_habit_days(conn, vice)? {
+            dates.remove(&date);
+        }
+    }
+    if streak.use_relapse_entries {
+        let mut stmt = conn.prepare("SELECT DISTINCT date FROM relapse_entry")?;
+        let relapses = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CommandError::from)?;
+        for date in relapses {
+            dates.remove(&date);
+        }
+    }
+    Ok(dates)
+}
+
+/// Count consecutive clean days walking backwards from the most recent
+/// daily_log. Breaks on a day that isn't clean or on a gap in dates.
+pub(crate) fn current_clean_days(conn: &Connection, streak: &CleanStreak) -> CommandResult<i64> {
+    let clean = clean_dates(conn, streak)?;
+    let mut stmt = conn.prepare("SELECT date FROM daily_log ORDER BY date DESC")?;
+    let logged: Vec<String> = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut count: i64 = 0;
+    let mut expected: Option<chrono::NaiveDate> = None;
+    for date in &logged {
+        let parsed = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", date, e)))?;
+        if expected.is_some_and(|e| e != parsed) || !clean.contains(date) {
+            break;
+        }
+        count += 1;
+        expected = parsed.pred_opt();
+    }
+    Ok(count)
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_clean_streak_input(conn: &Connection, input: &CleanStreakInput) -> CommandResult<()> {
+    if input.name.trim().is_empty() {
+        return Err(CommandError::from("Clean streak name cannot be empty"));
+    }
+    validate_text_length("Clean streak name", &input.name, 50)?;
+
+    if input.vices.is_empty() && !input.use_relapse_entries {
+        return Err(CommandError::from(
+            "A clean streak needs at least one vice or use_relapse_entries",
+        ));
+    }
+    let mut seen = HashSet::new();
+    for vice in &input.vices {
+        if !seen.insert(vice.as_str()) {
+            return Err(CommandError::from(format!("Vice '{}' is listed twice", vice)));
+        }
+        let pool: Option<String> = conn
+            .query_row(
+                "SELECT pool FROM habit_config WHERE name = ?1",
+                params![vice],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match pool.as_deref() {
+            Some("vice") => {}
+            Some(_) => {
+                return Err(CommandError::from(format!("Habit '{}' is not a vice", vice)));
+            }
+            None => return Err(CommandError::from(format!("Unknown vice '{}'", vice))),
+        }
+    }
+
+    let duplicate: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM clean_streak WHERE name = ?1 AND id != ?2",
+        params![input.name.trim(), input.id.unwrap_or(-1)],
+        |row| row.get(0),
+    )?;
+    if duplicate {
+        return Err(CommandError::from(format!(
+            "A clean streak named '{}' already exists",
+            input.name.trim()
+        )));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+/// Create or update a clean streak. Renaming also updates the milestones
+/// that measure it.
+pub(crate) fn save_clean_streak_impl(
+    conn: &Connection,
+    input: CleanStreakInput,
+) -> CommandResult<CleanStreak> {
+    validate_clean_streak_input(conn, &input)?;
+
+    let name = input.name.trim();
+    let vices = serde_json::to_string(&input.vices)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+    let id = match input.id {
+        Some(id) => {
+            let existing = query_clean_streak_by_id(&tx, id)?
+                .ok_or_else(|| {
+                    CommandError::from(format!("Clean streak with id {} not found", id))
+                })?;
+            tx.execute(
+                "UPDATE clean_streak SET name = ?2, vices = ?3, use_relapse_entries = ?4, \
+                 last_modified = ?5 WHERE id = ?1",
+                params![id, name, vices, input.use_relapse_entries, now],
+            )?;
+            tx.execute(
+                "UPDATE milestone SET clean_streak = ?2 WHERE clean_streak = ?1 COLLATE NOCASE",
+                params![existing.name, name],
+            )?;
+            id
+        }
+        None => {
+            tx.execute(
+                "INSERT INTO clean_streak (name, vices, use_relapse_entries, is_default, \
+                 created_at, last_modified) VALUES (?1, ?2, ?3, 0, ?4, ?5)",
+                params![name, vices, input.use_relapse_entries, now, now],
+            )?;
+            tx.last_insert_rowid()
+        }
+    };
+    tx.commit()?;
+
+    let saved = query_clean_streak_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved clean streak"))?;
+    with_current_days(conn, saved)
+}
+
+/// Delete a clean streak definition. The default streak and streaks that
+/// milestones still measure cannot be deleted.
+pub(crate) fn delete_clean_streak_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let streak = query_clean_streak_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from(format!("Clean streak with id {} not found", id)))?;
+    if streak.is_default {
+        return Err(CommandError::from("The default clean streak cannot be deleted"));
+    }
+    let used_by: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM milestone WHERE clean_streak = ?1 COLLATE NOCASE",
+        params![streak.name],
+        |row| row.get(0),
+    )?;
+    if used_by > 0 {
+        return Err(CommandError::from(format!(
+            "Clean streak '{}' is used by {} milestone(s)",
+            streak.name, used_by
+        )));
+    }
+    conn.execute("DELETE FROM clean_streak WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tauri Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn get_clean_streaks(state: tauri::State<'_, AppState>) -> CommandResult<Vec<CleanStreak>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    query_clean_streaks(&db)
+}
+
+#[tauri::command]
+pub fn save_clean_streak(
+    state: tauri::State<'_, AppState>,
+    streak: CleanStreakInput,
+) -> CommandResult<CleanStreak> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_clean_streak_impl(&db, streak)
+}
+
+#[tauri::command]
+pub fn delete_clean_streak(state: tauri::State<'_, AppState>, id: i64) -> CommandResult<()> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_clean_streak_impl(&db, id)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    /// Insert a daily_log row with the given vice values.
+    fn insert_day(conn: &Connection, date: &str, porn: i64, weed: i64, gaming: i64) {
+        let now = "2026-01-20T00:00:00Z";
+        conn.execute(
+            "INSERT INTO daily_log (\
+             date, schoolwork, personal_project, classes, job_search, \
+             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
+             meditate, \"read\", social, \
+             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
+             past_12am, late_wake, phone_use, \
+             positive_score, vice_penalty, base_score, streak, final_score, \
+             logged_at, last_modified\
+             ) VALUES (\
+             ?1, 0, 0, 0, 0, 0, 0, 0, 0, 'None', 0, 0, 0, 'None', \
+             ?2, 0, ?3, 0, 0, ?4, 0, 0, 0, \
+             0.0, 0.0, 0.0, 0, 0.5, ?5, ?6)",
+            params![date, porn, weed, gaming, now, now],
+        )
+        .unwrap();
+    }
+
+    fn insert_relapse(conn: &Connection, date: &str) {
+        let now = "2026-01-20T00:00:00Z";
+        conn.execute(
+            "INSERT INTO relapse_entry (\
+             date, time, duration, trigger, location, device, \
+             activity_before, emotional_state, resistance_technique, \
+             urge_intensity, notes, created_at, last_modified\
+             ) VALUES (?1, '23:00', '< 5 min', 'Boredom', 'Bedroom', \
+             'Phone', 'Scrolling', 'Stressed', 'None', 5, '', ?2, ?3)",
+            params![date, now, now],
+        )
+        .unwrap();
+    }
+
+    fn input(name: &str, vices: &[&str]) -> CleanStreakInput {
+        CleanStreakInput {
+            id: None,
+            name: name.to_string(),
+            vices: vices.iter().map(|v| v.to_string()).collect(),
+            use_relapse_entries: false,
+        }
+    }
+
+    #[test]
+    fn test_seeded_default_streak() {
+        let conn = setup_test_db();
+        let streaks = query_clean_streaks(&conn).unwrap();
+        assert_eq!(streaks.len(), 1);
+        assert!(streaks[0].is_default);
+        assert_eq!(streaks[0].vices, vec!["porn", "masturbate"]);
+        assert_eq!(streaks[0].current_days, 0);
+    }
+
+    #[test]
+    fn test_named_streaks_count_their_own_vices() {
+        let conn = setup_test_db();
+        insert_day(&conn, "2026-03-01", 0, 1, 0);
+        insert_day(&conn, "2026-03-02", 0, 0, 1);
+        insert_day(&conn, "2026-03-03", 0, 0, 0);
+
+        let weed = save_clean_streak_impl(&conn, input("Weed", &["weed"])).unwrap();
+        let gaming = save_clean_streak_impl(&conn, input("Gaming", &["gaming_1h"])).unwrap();
+        assert_eq!(weed.current_days, 2);
+        assert_eq!(gaming.current_days, 1);
+        let default = query_default_clean_streak(&conn).unwrap();
+        assert_eq!(current_clean_days(&conn, &default).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_relapse_entries_break_streak() {
+        let conn = setup_test_db();
+        insert_day(&conn, "2026-03-01", 0, 0, 0);
+        insert_day(&conn, "2026-03-02", 0, 0, 0);
+        insert_day(&conn, "2026-03-03", 0, 0, 0);
+        insert_relapse(&conn, "2026-03-02");
+
+        let mut relapse_only = input("Relapses", &[]);
+        relapse_only.use_relapse_entries = true;
+        let streak = save_clean_streak_impl(&conn, relapse_only).unwrap();
+        assert_eq!(streak.current_days, 1);
+        assert!(!clean_dates(&conn, &streak).unwrap().contains("2026-03-02"));
+    }
+
+    #[test]
+    fn test_gap_breaks_streak() {
+        let conn = setup_test_db();
+        insert_day(&conn, "2026-03-01", 0, 0, 0);
+        insert_day(&conn, "2026-03-03", 0, 0, 0);
+        let default = query_default_clean_streak(&conn).unwrap();
+        assert_eq!(current_clean_days(&conn, &default).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_validation() {
+        let conn = setup_test_db();
+        assert!(save_clean_streak_impl(&conn, input("", &["weed"])).is_err());
+        assert!(save_clean_streak_impl(&conn, input("Nothing", &[])).is_err());
+        assert!(save_clean_streak_impl(&conn, input("Gym", &["gym"])).is_err(), "good habit");
+        assert!(save_clean_streak_impl(&conn, input("Vape", &["vape"])).is_err(), "unknown");
+        assert!(save_clean_streak_impl(&conn, input("Twice", &["weed", "weed"])).is_err());
+        assert!(
+            save_clean_streak_impl(&conn, input("recovery", &["weed"])).is_err(),
+            "duplicate name"
+        );
+    }
+
+    #[test]
+    fn test_rename_updates_milestones_and_delete_rules() {
+        let conn = setup_test_db();
+        let weed = save_clean_streak_impl(&conn, input("Weed", &["weed"])).unwrap();
+        conn.execute(
+            "UPDATE milestone SET clean_streak = 'Weed' WHERE id = 'clean_90'",
+            [],
+        )
+        .unwrap();
+
+        let mut rename = input("Cannabis", &["weed"]);
+        rename.id = Some(weed.id);
+        save_clean_streak_impl(&conn, rename).unwrap();
+        let referenced: String = conn
+            .query_row("SELECT clean_streak FROM milestone WHERE id = 'clean_90'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(referenced, "Cannabis");
+
+        // Still referenced
+        assert!(delete_clean_streak_impl(&conn, weed.id).is_err());
+        conn.execute("UPDATE milestone SET clean_streak = NULL", []).unwrap();
+        delete_clean_streak_impl(&conn, weed.id).unwrap();
+
+        let default = query_default_clean_streak(&conn).unwrap();
+        assert!(delete_clean_streak_impl(&conn, default.id).is_err());
+    }
+}
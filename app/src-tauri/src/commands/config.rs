@@ -112,7 +112,7 @@ const HABIT_CONFIG_COLUMNS: &str = "\
 /// remaining values (30, 60, 90, 180, 365) map to common analytics horizons.
 /// Restricting to this set prevents nonsensical windows and keeps the UI
 /// dropdown in sync with the backend.
-const VALID_CORRELATION_WINDOWS: [i64; 6] = [0, 30, 60, 90, 180, 365];
+pub(crate) const VALID_CORRELATION_WINDOWS: [i64; 6] = [0, 30, 60, 90, 180, 365];
 
 // ---------------------------------------------------------------------------
 // Row Mappers
@@ -166,7 +166,7 @@ fn row_to_habit_config(row: &rusqlite::Row) -> rusqlite::Result<HabitConfig> {
 // Query Helpers
 // ---------------------------------------------------------------------------
 
-fn query_config(conn: &Connection) -> CommandResult<AppConfig> {
+pub(crate) fn query_config(conn: &Connection) -> CommandResult<AppConfig> {
     let sql = format!(
         "SELECT {} FROM app_config WHERE id = 'default'",
         APP_CONFIG_COLUMNS
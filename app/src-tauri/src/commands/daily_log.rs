@@ -8,6 +8,7 @@ use crate::engine::scoring::{
 };
 use crate::AppState;
 
+use super::milestone::{emit_milestones_achieved, evaluate_milestones_impl, Milestone};
 use super::validation::validate_text_length;
 use super::{CommandError, CommandResult};
 
@@ -216,7 +217,7 @@ fn get_column_value_string(entry: &DailyLogInput, column_name: &str) -> String {
 /// Resolves a dropdown text key to its numeric value using the options_json mapping.
 ///
 /// Example: options_json = `{"Poor":0,"Good":2,"Great":3}`, text_key = "Great" → 3.0
-fn resolve_dropdown_value(text_key: &str, options_json: &Option<String>) -> f64 {
+pub(crate) fn resolve_dropdown_value(text_key: &str, options_json: &Option<String>) -> f64 {
     let json_str = match options_json {
         Some(s) => s,
         None => return 0.0,
@@ -453,7 +454,7 @@ fn determine_previous_streak(conn: &Connection, date: &str) -> CommandResult<i32
 // ---------------------------------------------------------------------------
 
 /// Queries a single daily_log row by date.
-fn query_daily_log_by_date(conn: &Connection, date: &str) -> CommandResult<Option<DailyLog>> {
+pub(crate) fn query_daily_log_by_date(conn: &Connection, date: &str) -> CommandResult<Option<DailyLog>> {
     let sql = format!(
         "SELECT {} FROM daily_log WHERE date = ?1",
         DAILY_LOG_COLUMNS
@@ -486,11 +487,19 @@ pub fn get_daily_logs(
     end: String,
 ) -> CommandResult<Vec<DailyLog>> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_daily_logs_impl(&db, &start, &end)
+}
+
+pub(crate) fn get_daily_logs_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> CommandResult<Vec<DailyLog>> {
     let sql = format!(
         "SELECT {} FROM daily_log WHERE date >= ?1 AND date <= ?2 ORDER BY date ASC",
         DAILY_LOG_COLUMNS
     );
-    let mut stmt = db.prepare(&sql)?;
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params![start, end], row_to_daily_log)?;
     rows.collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)
@@ -503,10 +512,14 @@ pub fn get_streak_at_date(
     date: String,
 ) -> CommandResult<i32> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-    let streak: i32 = db
+    get_streak_at_date_impl(&db, &date)
+}
+
+pub(crate) fn get_streak_at_date_impl(conn: &Connection, date: &str) -> CommandResult<i32> {
+    let streak: i32 = conn
         .query_row(
             "SELECT COALESCE(streak, 0) FROM daily_log WHERE date = ?1",
-            [&date],
+            [date],
             |row| row.get(0),
         )
         .optional()?
@@ -525,20 +538,40 @@ pub fn get_streak_at_date(
 /// 4. Compute scores via the Rust scoring engine
 /// 5. INSERT or UPDATE the daily_log row with habit values + computed scores
 /// 6. Run cascade if subsequent scored days exist
-/// 7. Commit and return the saved row
+/// 7. Award any milestones the new state meets
+/// 8. Commit and return the saved row
+///
+/// Newly achieved milestones are emitted as `milestones-achieved`.
 #[tauri::command]
 pub fn save_daily_log(
+    app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
     entry: DailyLogInput,
 ) -> CommandResult<DailyLog> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    let (log, achieved) = save_daily_log_with_milestones(&db, entry)?;
+    emit_milestones_achieved(&app, &achieved);
+    Ok(log)
+}
 
+pub(crate) fn save_daily_log_impl(
+    conn: &Connection,
+    entry: DailyLogInput,
+) -> CommandResult<DailyLog> {
+    save_daily_log_with_milestones(conn, entry).map(|(log, _)| log)
+}
+
+/// `save_daily_log_impl`, also returning the milestones the save newly achieved.
+pub(crate) fn save_daily_log_with_milestones(
+    conn: &Connection,
+    entry: DailyLogInput,
+) -> CommandResult<(DailyLog, Vec<Milestone>)> {
     validate_text_length("Meal quality", &entry.meal_quality, 200)?;
     validate_text_length("Social", &entry.social, 200)?;
 
-    // Run the entire save + cascade within a single transaction
-    {
-        let tx = db.unchecked_transaction().map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+    // Run the entire save + cascade + milestone check within a single transaction
+    let achieved = {
+        let tx = conn.unchecked_transaction().map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
 
         // Step 1: Check if row already exists (for logged_at preservation + cascade decision)
         let existing: Option<(i64, String)> = tx
@@ -692,13 +725,144 @@ pub fn save_daily_log(
             }
         }
 
-        // Step 8: Commit
+        // Step 8: Award milestones against the post-cascade state
+        let achieved = evaluate_milestones_impl(&tx)?;
+
+        // Step 9: Commit
         tx.commit()?;
+        achieved
+    };
+
+    // Step 10: Read back and return the saved row
+    let log = query_daily_log_by_date(conn, &entry.date)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved daily log"))?;
+    Ok((log, achieved))
+}
+
+// ---------------------------------------------------------------------------
+// Full Rescore
+// ---------------------------------------------------------------------------
+
+/// Result of a full rescore pass over every daily_log row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescoreSummary {
+    pub days_rescored: i64,
+    pub days_changed: i64,
+}
+
+/// Rebuilds the user-editable input from a stored daily_log row.
+pub(crate) fn daily_log_to_input(log: &DailyLog) -> DailyLogInput {
+    DailyLogInput {
+        date: log.date.clone(),
+        schoolwork: log.schoolwork,
+        personal_project: log.personal_project,
+        classes: log.classes,
+        job_search: log.job_search,
+        gym: log.gym,
+        sleep_7_9h: log.sleep_7_9h,
+        wake_8am: log.wake_8am,
+        supplements: log.supplements,
+        meal_quality: log.meal_quality.clone(),
+        stretching: log.stretching,
+        meditate: log.meditate,
+        read: log.read,
+        social: log.social.clone(),
+        porn: log.porn,
+        masturbate: log.masturbate,
+        weed: log.weed,
+        skip_class: log.skip_class,
+        binged_content: log.binged_content,
+        gaming_1h: log.gaming_1h,
+        past_12am: log.past_12am,
+        late_wake: log.late_wake,
+        phone_use: log.phone_use,
+    }
+}
+
+/// Recompute the scores of every daily_log row against the current config.
+///
+/// ADR-002 SD1 makes config changes prospective only, so this is never run
+/// implicitly — it exists for explicit, user-initiated rescoring (CLI
+/// `rescore`). Rows are walked in date order so each day's new streak feeds
+/// the next, which makes a separate cascade pass unnecessary. The streak
+/// conventions match `determine_previous_streak`: -1 for the first day, 0
+/// after a calendar gap.
+pub(crate) fn rescore_all_impl(conn: &Connection) -> CommandResult<RescoreSummary> {
+    let sql = format!("SELECT {} FROM daily_log ORDER BY date ASC", DAILY_LOG_COLUMNS);
+    let logs: Vec<DailyLog> = {
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map([], row_to_daily_log)?;
+        rows.collect::<Result<Vec<_>, _>>()?
+    };
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let habit_configs = load_active_habit_configs(&tx)?;
+    let scoring_config = load_scoring_config(&tx)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let mut days_changed: i64 = 0;
+    let mut previous: Option<(chrono::NaiveDate, i32)> = None;
+
+    for log in &logs {
+        let date = chrono::NaiveDate::parse_from_str(&log.date, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", log.date, e)))?;
+
+        let previous_streak = match previous {
+            None => -1,
+            Some((prev_date, prev_streak)) if date.pred_opt() == Some(prev_date) => prev_streak,
+            Some(_) => 0,
+        };
+
+        let input = daily_log_to_input(log);
+        let scores = compute_scores(&ScoringInput {
+            habit_values: build_habit_values(&input, &habit_configs),
+            vice_values: build_vice_values(&input, &habit_configs),
+            phone_minutes: input.phone_use as f64,
+            previous_streak,
+            config: scoring_config.clone(),
+        });
+
+        let unchanged = log.streak == Some(scores.streak)
+            && [
+                (log.positive_score, scores.positive_score),
+                (log.vice_penalty, scores.vice_penalty),
+                (log.base_score, scores.base_score),
+                (log.final_score, scores.final_score),
+            ]
+            .iter()
+            .all(|(stored, fresh)| stored.is_some_and(|v| (v - fresh).abs() < 1e-9));
+
+        if !unchanged {
+            tx.execute(
+                "UPDATE daily_log SET \
+                 positive_score = ?2, vice_penalty = ?3, base_score = ?4, \
+                 streak = ?5, final_score = ?6, last_modified = ?7 \
+                 WHERE date = ?1",
+                params![
+                    log.date,
+                    scores.positive_score,
+                    scores.vice_penalty,
+                    scores.base_score,
+                    scores.streak,
+                    scores.final_score,
+                    &now,
+                ],
+            )?;
+            days_changed += 1;
+        }
+
+        previous = Some((date, scores.streak));
     }
 
-    // Step 9: Read back and return the saved row
-    query_daily_log_by_date(&db, &entry.date)?
-        .ok_or_else(|| CommandError::from("Failed to read back saved daily log"))
+    tx.commit()?;
+
+    Ok(RescoreSummary {
+        days_rescored: logs.len() as i64,
+        days_changed,
+    })
 }
 
 // ===========================================================================
@@ -1207,113 +1371,7 @@ mod tests {
 
     /// Helper: runs save_daily_log against a raw connection (no Tauri State).
     fn save_daily_log_direct(conn: &Connection, entry: DailyLogInput) -> CommandResult<DailyLog> {
-        {
-            let tx = conn.unchecked_transaction().map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
-
-            let existing: Option<(i64, String)> = tx
-                .query_row(
-                    "SELECT id, logged_at FROM daily_log WHERE date = ?1",
-                    [&entry.date],
-                    |row| Ok((row.get("id")?, row.get("logged_at")?)),
-                )
-                .optional()?;
-
-            let now = chrono::Utc::now().to_rfc3339();
-            let logged_at = match &existing {
-                Some((_, original)) => original.clone(),
-                None => now.clone(),
-            };
-
-            let habit_configs = load_active_habit_configs(&tx)?;
-            let scoring_config = load_scoring_config(&tx)?;
-
-            let habit_values = build_habit_values(&entry, &habit_configs);
-            let vice_values = build_vice_values(&entry, &habit_configs);
-            let previous_streak = determine_previous_streak(&tx, &entry.date)?;
-
-            let scoring_input = ScoringInput {
-                habit_values,
-                vice_values,
-                phone_minutes: entry.phone_use as f64,
-                previous_streak,
-                config: scoring_config.clone(),
-            };
-
-            let scores = compute_scores(&scoring_input);
-
-            if existing.is_some() {
-                tx.execute(
-                    "UPDATE daily_log SET \
-                     schoolwork = ?2, personal_project = ?3, classes = ?4, job_search = ?5, \
-                     gym = ?6, sleep_7_9h = ?7, wake_8am = ?8, supplements = ?9, \
-                     meal_quality = ?10, stretching = ?11, \
-                     meditate = ?12, \"read\" = ?13, social = ?14, \
-                     porn = ?15, masturbate = ?16, weed = ?17, skip_class = ?18, \
-                     binged_content = ?19, gaming_1h = ?20, past_12am = ?21, \
-                     late_wake = ?22, phone_use = ?23, \
-                     positive_score = ?24, vice_penalty = ?25, base_score = ?26, \
-                     streak = ?27, final_score = ?28, \
-                     last_modified = ?29 \
-                     WHERE date = ?1",
-                    params![
-                        entry.date, entry.schoolwork, entry.personal_project,
-                        entry.classes, entry.job_search, entry.gym, entry.sleep_7_9h,
-                        entry.wake_8am, entry.supplements, entry.meal_quality, entry.stretching,
-                        entry.meditate, entry.read, entry.social,
-                        entry.porn, entry.masturbate, entry.weed, entry.skip_class,
-                        entry.binged_content, entry.gaming_1h, entry.past_12am,
-                        entry.late_wake, entry.phone_use,
-                        scores.positive_score, scores.vice_penalty, scores.base_score,
-                        scores.streak, scores.final_score, &now,
-                    ],
-                )?;
-            } else {
-                tx.execute(
-                    "INSERT INTO daily_log (\
-                     date, schoolwork, personal_project, classes, job_search, \
-                     gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
-                     meditate, \"read\", social, \
-                     porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
-                     past_12am, late_wake, phone_use, \
-                     positive_score, vice_penalty, base_score, streak, final_score, \
-                     logged_at, last_modified\
-                     ) VALUES (\
-                     ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, \
-                     ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, \
-                     ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30\
-                     )",
-                    params![
-                        entry.date, entry.schoolwork, entry.personal_project,
-                        entry.classes, entry.job_search, entry.gym, entry.sleep_7_9h,
-                        entry.wake_8am, entry.supplements, entry.meal_quality, entry.stretching,
-                        entry.meditate, entry.read, entry.social,
-                        entry.porn, entry.masturbate, entry.weed, entry.skip_class,
-                        entry.binged_content, entry.gaming_1h, entry.past_12am,
-                        entry.late_wake, entry.phone_use,
-                        scores.positive_score, scores.vice_penalty, scores.base_score,
-                        scores.streak, scores.final_score, logged_at, &now,
-                    ],
-                )?;
-            }
-
-            let subsequent_days = load_subsequent_days(&tx, &entry.date)?;
-            if !subsequent_days.is_empty() {
-                let cascade_updates =
-                    compute_cascade(&entry.date, &scores, &subsequent_days, &scoring_config);
-                for update in cascade_updates.iter().skip(1) {
-                    tx.execute(
-                        "UPDATE daily_log SET streak = ?2, final_score = ?3, last_modified = ?4 \
-                         WHERE date = ?1",
-                        params![update.date, update.streak, update.final_score, &now],
-                    )?;
-                }
-            }
-
-            tx.commit()?;
-        }
-
-        query_daily_log_by_date(conn, &entry.date)?
-            .ok_or_else(|| CommandError::from("Failed to read back saved daily log"))
+        save_daily_log_impl(conn, entry)
     }
 
     #[test]
@@ -1516,6 +1574,51 @@ mod tests {
         assert_eq!(result.logged_at, from_db.logged_at);
     }
 
+    #[test]
+    fn test_rescore_all_applies_new_config() {
+        let conn = setup_test_db();
+        let mut input = make_default_input("2026-02-01");
+        input.gym = 1;
+        let before = save_daily_log_direct(&conn, input).unwrap();
+        save_daily_log_direct(&conn, make_default_input("2026-02-02")).unwrap();
+
+        // Unchanged config: every row is visited, none rewritten
+        let summary = rescore_all_impl(&conn).unwrap();
+        assert_eq!(summary.days_rescored, 2);
+        assert_eq!(summary.days_changed, 0);
+
+        // Lower target: the partial day now scores higher, the empty day stays 0
+        conn.execute("UPDATE app_config SET target_fraction = 0.5 WHERE id = 'default'", [])
+            .unwrap();
+        let summary = rescore_all_impl(&conn).unwrap();
+        assert_eq!(summary.days_rescored, 2);
+        assert_eq!(summary.days_changed, 1);
+
+        let after = query_daily_log_by_date(&conn, "2026-02-01").unwrap().unwrap();
+        assert!(after.positive_score.unwrap() > before.positive_score.unwrap());
+        assert_eq!(after.logged_at, before.logged_at);
+    }
+
+    #[test]
+    fn test_save_awards_milestones_in_transaction() {
+        let conn = setup_test_db();
+        let (_, achieved) =
+            save_daily_log_with_milestones(&conn, make_default_input("2026-02-01")).unwrap();
+        let ids: Vec<&str> = achieved.iter().map(|m| m.id.as_str()).collect();
+        assert!(ids.contains(&"first_steps"));
+        assert!(ids.contains(&"clean_1"));
+
+        // Re-saving the same day earns nothing new
+        let (_, again) =
+            save_daily_log_with_milestones(&conn, make_default_input("2026-02-01")).unwrap();
+        assert!(again.is_empty());
+
+        let achieved_in_db: i64 = conn
+            .query_row("SELECT COUNT(*) FROM milestone WHERE achieved = 1", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(achieved_in_db, achieved.len() as i64);
+    }
+
     // -----------------------------------------------------------------------
     // Scoring Config Loader Tests
     // -----------------------------------------------------------------------
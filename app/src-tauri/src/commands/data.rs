@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -5,6 +7,8 @@ use serde_json::Value;
 use crate::db;
 use crate::AppState;
 
+use super::milestone::{emit_milestones_achieved, replay_milestones, Milestone};
+use super::study_topic::link_session_topics;
 use super::{CommandError, CommandResult};
 
 // ---------------------------------------------------------------------------
@@ -32,28 +36,46 @@ pub struct TableCount {
 const ALL_TABLES: &[&str] = &[
     "app_config",
     "habit_config",
+    "clean_streak",
     "daily_log",
     "journal",
+    "study_topic",
     "study_session",
+    "study_timer",
+    "study_goal",
     "application",
     "status_change",
+    "status_change_audit",
+    "contact",
+    "interview",
     "urge_entry",
     "relapse_entry",
+    "recovery_amendment",
     "weekly_review",
+    "periodic_review",
     "milestone",
 ];
 
 /// DELETE order: child tables first to respect FK constraints.
 const DELETE_ORDER: &[&str] = &[
+    "interview",
+    "contact",
+    "status_change_audit",
     "status_change",
+    "recovery_amendment",
     "relapse_entry",
     "urge_entry",
     "weekly_review",
+    "periodic_review",
     "milestone",
+    "study_goal",
+    "study_timer",
     "study_session",
+    "study_topic",
     "journal",
     "daily_log",
     "application",
+    "clean_streak",
     "habit_config",
     "app_config",
 ];
@@ -62,14 +84,23 @@ const DELETE_ORDER: &[&str] = &[
 const INSERT_ORDER: &[&str] = &[
     "app_config",
     "habit_config",
+    "clean_streak",
     "daily_log",
     "journal",
+    "study_topic",
     "study_session",
+    "study_timer",
+    "study_goal",
     "application",
     "status_change",
+    "status_change_audit",
+    "contact",
+    "interview",
     "urge_entry",
     "relapse_entry",
+    "recovery_amendment",
     "weekly_review",
+    "periodic_review",
     "milestone",
 ];
 
@@ -118,6 +149,44 @@ fn export_table(conn: &Connection, table: &str) -> CommandResult<Vec<Value>> {
 
 /// Import rows into a table from a JSON array.
 /// Each JSON object's keys are used as column names.
+/// Milestone rule columns keyed by milestone id.
+type MilestoneRuleRow = (String, String, String, f64, Option<i64>, Option<String>);
+
+fn snapshot_milestone_rules(conn: &Connection) -> CommandResult<Vec<MilestoneRuleRow>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, metric, comparator, target, window_days, habit_name \
+         FROM milestone WHERE metric IS NOT NULL",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+    Ok(rows)
+}
+
+/// Exports taken before milestone rules existed (migration 005) import their
+/// milestones without one. Give such rows back the rule the database had.
+fn restore_missing_milestone_rules(conn: &Connection, rules: &[MilestoneRuleRow]) -> CommandResult<()> {
+    for (id, metric, comparator, target, window_days, habit_name) in rules {
+        conn.execute(
+            "UPDATE milestone SET metric = ?2, comparator = ?3, target = ?4, \
+             window_days = ?5, habit_name = ?6 \
+             WHERE id = ?1 AND metric IS NULL",
+            rusqlite::params![id, metric, comparator, target, window_days, habit_name],
+        )?;
+    }
+    Ok(())
+}
+
 fn import_table(conn: &Connection, table: &str, rows: &[Value]) -> CommandResult<()> {
     if rows.is_empty() {
         return Ok(());
@@ -197,7 +266,7 @@ fn json_to_sqlite(v: &Value) -> rusqlite::types::Value {
 // Export Implementation
 // ---------------------------------------------------------------------------
 
-fn export_data_impl(conn: &Connection) -> CommandResult<String> {
+pub(crate) fn export_data_impl(conn: &Connection) -> CommandResult<String> {
     // 1. Collect row counts
     let mut row_counts = serde_json::Map::new();
     for table in ALL_TABLES {
@@ -216,7 +285,7 @@ fn export_data_impl(conn: &Connection) -> CommandResult<String> {
         "export_timestamp": chrono::Utc::now().to_rfc3339(),
         "schema_version": 1,
         "row_counts": Value::Object(row_counts),
-        "description": "Life Tracker Ultimate data export. Tables: app_config (scoring parameters and settings), habit_config (habit/vice definitions with points and categories), daily_log (daily habit entries with computed scores), journal (daily mood/energy/reflection entries), study_session (academic study tracking), application (job applications), status_change (application pipeline history), urge_entry (urge resistance tracking), relapse_entry (relapse incidents), weekly_review (weekly reflection snapshots), milestone (achievement definitions and unlock state)."
+        "description": "Life Tracker Ultimate data export. Tables: app_config (scoring parameters and settings), habit_config (habit/vice definitions with points and categories), clean_streak (named sets of vices defining clean days), daily_log (daily habit entries with computed scores), journal (daily mood/energy/reflection entries), study_topic (topics studied per subject, for review scheduling), study_session (academic study tracking), study_timer (the in-progress study timer, if any), study_goal (study hour targets per week or term), application (job applications), status_change (application pipeline history), status_change_audit (corrections made to status history), contact (people linked to an application), interview (interview events per application), urge_entry (urge resistance tracking), relapse_entry (relapse incidents), recovery_amendment (corrections to locked relapse and urge entries), weekly_review (weekly reflection snapshots), periodic_review (monthly, quarterly and yearly reflection snapshots), milestone (achievement definitions and unlock state)."
     });
 
     // 3. Build export object with all tables
@@ -244,7 +313,10 @@ fn export_data_impl(conn: &Connection) -> CommandResult<String> {
 // Import Implementation
 // ---------------------------------------------------------------------------
 
-fn import_data_impl(conn: &Connection, json: &str) -> CommandResult<()> {
+/// Replace all data with an export. Returns the milestones newly achieved by
+/// the imported history. Milestones are dated by replaying that history, so
+/// earned ones carry the day they were first met rather than the import date.
+pub(crate) fn import_data_impl(conn: &Connection, json: &str) -> CommandResult<Vec<Milestone>> {
     // 1. Parse JSON
     let data: Value = serde_json::from_str(json)?;
     let obj = data
@@ -267,14 +339,20 @@ fn import_data_impl(conn: &Connection, json: &str) -> CommandResult<()> {
         )));
     }
 
-    // 3. Single transaction: DELETE ALL + INSERT
-    {
+    // 3. Single transaction: DELETE ALL + INSERT + milestone replay
+    let achieved = {
         let tx = conn
             .unchecked_transaction()
             .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
 
-        // DELETE in FK-safe order
+        let prior_rules = snapshot_milestone_rules(&tx)?;
+
+        // DELETE in FK-safe order. Exports from before clean streaks existed
+        // keep the current definitions rather than leaving none.
         for table in DELETE_ORDER {
+            if *table == "clean_streak" && !obj.contains_key(*table) {
+                continue;
+            }
             tx.execute(&format!("DELETE FROM {}", table), [])?;
         }
 
@@ -292,20 +370,27 @@ fn import_data_impl(conn: &Connection, json: &str) -> CommandResult<()> {
             }
         }
 
+        restore_missing_milestone_rules(&tx, &prior_rules)?;
+        // Exports from before study topics carry only the topic text.
+        if !obj.contains_key("study_topic") {
+            link_session_topics(&tx)?;
+        }
+
+        let achieved = replay_milestones(&tx)?.newly_achieved;
         tx.commit()?;
-    }
+        achieved
+    };
 
-    Ok(())
+    Ok(achieved)
 }
 
 // ---------------------------------------------------------------------------
 // DB Stats Implementation
 // ---------------------------------------------------------------------------
 
-fn get_db_stats_impl(conn: &Connection) -> CommandResult<DbStats> {
+pub(crate) fn get_db_stats_impl(conn: &Connection, db_path: &Path) -> CommandResult<DbStats> {
     // File size
-    let db_path = db::get_db_path();
-    let file_size_bytes = std::fs::metadata(&db_path)
+    let file_size_bytes = std::fs::metadata(db_path)
         .map(|m| m.len() as i64)
         .unwrap_or(0);
 
@@ -344,8 +429,10 @@ pub fn export_data(state: tauri::State<'_, AppState>) -> CommandResult<String> {
     export_data_impl(&db)
 }
 
+/// Newly achieved milestones are emitted as `milestones-achieved`.
 #[tauri::command]
 pub fn import_data(
+    app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
     json: String,
 ) -> CommandResult<()> {
@@ -353,7 +440,9 @@ pub fn import_data(
         .db
         .lock()
         .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    import_data_impl(&db, &json)
+    let achieved = import_data_impl(&db, &json)?;
+    emit_milestones_achieved(&app, &achieved);
+    Ok(())
 }
 
 #[tauri::command]
@@ -362,7 +451,7 @@ pub fn get_db_stats(state: tauri::State<'_, AppState>) -> CommandResult<DbStats>
         .db
         .lock()
         .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_db_stats_impl(&db)
+    get_db_stats_impl(&db, &db::get_db_path())
 }
 
 #[tauri::command]
@@ -565,6 +654,146 @@ mod tests {
         assert_eq!(before, after);
     }
 
+    #[test]
+    fn test_import_awards_milestones_for_imported_history() {
+        let conn = setup_test_db();
+        let mut data: Value = serde_json::from_str(&export_data_impl(&conn).unwrap()).unwrap();
+        data["study_session"] = serde_json::json!([{
+            "id": 1, "date": "2026-01-05", "subject": "Math", "study_type": "Self-Study",
+            "start_time": "09:00", "end_time": "10:00", "duration_minutes": 60,
+            "focus_score": 4, "location": "Home", "topic": "", "resources": "", "notes": "",
+            "logged_at": "2026-01-05T10:00:00Z", "last_modified": "2026-01-05T10:00:00Z"
+        }]);
+
+        let achieved = import_data_impl(&conn, &data.to_string()).unwrap();
+        let ids: Vec<&str> = achieved.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["first_session"]);
+
+        let (flag, date): (bool, Option<String>) = conn
+            .query_row(
+                "SELECT achieved, achieved_date FROM milestone WHERE id = 'first_session'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert!(flag);
+        // Dated by the imported session, not the day of the import
+        assert_eq!(date.as_deref(), Some("2026-01-05"));
+    }
+
+    #[test]
+    fn test_import_restores_rules_missing_from_old_export() {
+        let conn = setup_test_db();
+        let mut data: Value = serde_json::from_str(&export_data_impl(&conn).unwrap()).unwrap();
+        for row in data["milestone"].as_array_mut().unwrap() {
+            let obj = row.as_object_mut().unwrap();
+            for col in ["metric", "comparator", "target", "window_days", "habit_name", "is_custom"] {
+                obj.remove(col);
+            }
+        }
+
+        import_data_impl(&conn, &data.to_string()).unwrap();
+
+        let (metric, target): (String, f64) = conn
+            .query_row(
+                "SELECT metric, target FROM milestone WHERE id = 'study_50h'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(metric, "study_hours");
+        assert!((target - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_import_links_topics_missing_from_old_export() {
+        let conn = setup_test_db();
+        let mut data: Value = serde_json::from_str(&export_data_impl(&conn).unwrap()).unwrap();
+        data.as_object_mut().unwrap().remove("study_topic");
+        data["study_session"] = serde_json::json!([{
+            "id": 1, "date": "2026-01-05", "subject": "Networking", "study_type": "Review",
+            "start_time": "09:00", "end_time": "10:00", "duration_minutes": 60,
+            "focus_score": 4, "location": "Home", "topic": "Subnetting", "resources": "",
+            "notes": "", "logged_at": "2026-01-05T10:00:00Z",
+            "last_modified": "2026-01-05T10:00:00Z"
+        }]);
+
+        import_data_impl(&conn, &data.to_string()).unwrap();
+
+        let (topic_id, name): (i64, String) = conn
+            .query_row(
+                "SELECT s.topic_id, t.name FROM study_session s \
+                 JOIN study_topic t ON t.id = s.topic_id",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert!(topic_id > 0);
+        assert_eq!(name, "Subnetting");
+    }
+
+    #[test]
+    fn test_import_clean_streaks() {
+        let conn = setup_test_db();
+        conn.execute(
+            "INSERT INTO clean_streak (name, vices, created_at, last_modified) \
+             VALUES ('Weed', '[\"weed\"]', '2026-01-20T00:00:00Z', '2026-01-20T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+        let export = export_data_impl(&conn).unwrap();
+        let count = |conn: &Connection| -> i64 {
+            conn.query_row("SELECT COUNT(*) FROM clean_streak", [], |row| row.get(0))
+                .unwrap()
+        };
+
+        // Round trip
+        import_data_impl(&conn, &export).unwrap();
+        assert_eq!(count(&conn), 2);
+
+        // An export from before clean streaks keeps the current definitions
+        let mut old: Value = serde_json::from_str(&export).unwrap();
+        old.as_object_mut().unwrap().remove("clean_streak");
+        conn.execute("DELETE FROM clean_streak WHERE name = 'Weed'", []).unwrap();
+        import_data_impl(&conn, &old.to_string()).unwrap();
+        assert_eq!(count(&conn), 1);
+    }
+
+    #[test]
+    fn test_import_contacts_and_interviews() {
+        use crate::commands::application::{
+            get_application_impl, save_application_impl, save_contact_impl,
+            save_interview_impl, ApplicationInput, ContactInput, InterviewInput,
+        };
+
+        let conn = setup_test_db();
+        let app: ApplicationInput = serde_json::from_value(serde_json::json!({
+            "date_applied": "2026-02-10", "company": "Acme", "role": "Dev",
+            "source": "LinkedIn", "url": "", "notes": "", "follow_up_date": null,
+            "salary": "", "contact_name": "", "contact_email": "",
+            "login_username": "", "login_password": ""
+        }))
+        .unwrap();
+        let a = save_application_impl(&conn, app).unwrap();
+        let contact: ContactInput =
+            serde_json::from_str(r#"{"name":"Dana","role":"recruiter"}"#).unwrap();
+        save_contact_impl(&conn, a.id, contact).unwrap();
+        let interview: InterviewInput =
+            serde_json::from_str(r#"{"date":"2026-02-20","round":"Phone screen"}"#).unwrap();
+        save_interview_impl(&conn, a.id, interview).unwrap();
+
+        let export = export_data_impl(&conn).unwrap();
+        let data: Value = serde_json::from_str(&export).unwrap();
+        assert_eq!(data["_meta"]["row_counts"]["contact"].as_i64().unwrap(), 1);
+        assert_eq!(data["_meta"]["row_counts"]["interview"].as_i64().unwrap(), 1);
+
+        // Re-importing replaces the children before their application
+        import_data_impl(&conn, &export).unwrap();
+        let detail = get_application_impl(&conn, a.id).unwrap().unwrap();
+        assert_eq!(detail.contacts[0].name, "Dana");
+        assert_eq!(detail.interviews[0].round, "Phone screen");
+    }
+
     // -----------------------------------------------------------------------
     // C. DB Stats tests
     // -----------------------------------------------------------------------
@@ -573,7 +802,7 @@ mod tests {
     fn test_get_db_stats_table_counts() {
         let conn = setup_test_db();
         // For in-memory DB, file_size won't work but table counts should
-        let stats = get_db_stats_impl(&conn).unwrap();
+        let stats = get_db_stats_impl(&conn, &db::get_db_path()).unwrap();
 
         assert_eq!(stats.table_counts.len(), ALL_TABLES.len());
 
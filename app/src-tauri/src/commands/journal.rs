@@ -62,7 +62,7 @@ fn row_to_journal(row: &rusqlite::Row) -> rusqlite::Result<Journal> {
     })
 }
 
-fn query_journal_by_date(conn: &Connection, date: &str) -> CommandResult<Option<Journal>> {
+pub(crate) fn query_journal_by_date(conn: &Connection, date: &str) -> CommandResult<Option<Journal>> {
     let sql = format!(
         "SELECT {} FROM journal WHERE date = ?1",
         JOURNAL_COLUMNS
@@ -91,14 +91,17 @@ pub fn save_journal(
     entry: JournalInput,
 ) -> CommandResult<Journal> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_journal_impl(&db, entry)
+}
 
+pub(crate) fn save_journal_impl(conn: &Connection, entry: JournalInput) -> CommandResult<Journal> {
     validate_text_length("Highlight", &entry.highlight, 8000)?;
     validate_text_length("Gratitude", &entry.gratitude, 8000)?;
     validate_text_length("Reflection", &entry.reflection, 8000)?;
     validate_text_length("Tomorrow goal", &entry.tomorrow_goal, 8000)?;
 
     {
-        let tx = db
+        let tx = conn
             .unchecked_transaction()
             .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
 
@@ -157,7 +160,7 @@ pub fn save_journal(
         tx.commit()?;
     }
 
-    query_journal_by_date(&db, &entry.date)?
+    query_journal_by_date(conn, &entry.date)?
         .ok_or_else(|| CommandError::from("Failed to read back saved journal entry"))
 }
 
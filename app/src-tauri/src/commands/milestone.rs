@@ -1,8 +1,18 @@
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+use tauri::Emitter;
 
+use crate::engine::milestone_rules::{
+    achievement_date, describe_rule, first_met_date, validate_rule, Comparator, DayRecord, Metric, MilestoneHistory,
+    MilestoneRule, StudyRecord, HIGH_FOCUS_THRESHOLD,
+};
 use crate::AppState;
 
+use super::clean_streak::{
+    clean_dates, current_clean_days, load_habit_days, query_clean_streak_by_name,
+    query_default_clean_streak,
+};
+use super::validation::validate_text_length;
 use super::{CommandError, CommandResult};
 
 // ---------------------------------------------------------------------------
@@ -21,6 +31,22 @@ pub struct Milestone {
     pub achieved: bool,
     pub achieved_date: Option<String>,
     pub created_at: String,
+    /// Award rule. `None` for milestones that are never awarded automatically.
+    pub rule: Option<MilestoneRule>,
+    /// User-created (editable, deletable) rather than seeded.
+    pub is_custom: bool,
+}
+
+/// Input for create_milestone / update_milestone.
+/// An empty `threshold` is replaced by a description generated from the rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneInput {
+    pub name: String,
+    pub emoji: String,
+    pub category: String,
+    #[serde(default)]
+    pub threshold: String,
+    pub rule: MilestoneRule,
 }
 
 /// Context passed from frontend with current stats for checking milestones.
@@ -37,14 +63,52 @@ pub struct MilestoneContext {
     pub high_focus_sessions: i64,
 }
 
+/// Result of replaying history with `recompute_milestones`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneRecompute {
+    /// Milestones the replay found met that were not yet achieved.
+    pub newly_achieved: Vec<Milestone>,
+    /// Already-achieved milestones whose `achieved_date` was corrected.
+    pub redated: Vec<Milestone>,
+}
+
+/// Event emitted with the `Vec<Milestone>` newly achieved by a save or import.
+pub const MILESTONES_ACHIEVED_EVENT: &str = "milestones-achieved";
+
 // ---------------------------------------------------------------------------
 // Column Constants & Row Mapper
 // ---------------------------------------------------------------------------
 
 const MILESTONE_COLUMNS: &str = "\
-    id, name, emoji, category, threshold, achieved, achieved_date, created_at";
+    id, name, emoji, category, threshold, achieved, achieved_date, created_at, \
+    metric, comparator, target, window_days, habit_name, is_custom, clean_streak";
+
+const VALID_CATEGORIES: &[&str] = &["score", "clean", "study", "tracking"];
+
+/// Prefix of generated ids, keeping custom milestones apart from seeded ones.
+const CUSTOM_ID_PREFIX: &str = "custom_";
 
 fn row_to_milestone(row: &rusqlite::Row) -> rusqlite::Result<Milestone> {
+    let metric: Option<String> = row.get("metric")?;
+    let comparator: Option<String> = row.get("comparator")?;
+    let target: Option<f64> = row.get("target")?;
+    // A row whose metric this build doesn't know has no usable rule.
+    let rule = match (
+        metric.as_deref().and_then(Metric::parse),
+        comparator.as_deref().and_then(Comparator::parse),
+        target,
+    ) {
+        (Some(metric), Some(comparator), Some(target)) => Some(MilestoneRule {
+            metric,
+            comparator,
+            target,
+            window_days: row.get("window_days")?,
+            habit: row.get("habit_name")?,
+            clean_streak: row.get("clean_streak")?,
+        }),
+        _ => None,
+    };
+
     Ok(Milestone {
         id: row.get("id")?,
         name: row.get("name")?,
@@ -54,6 +118,8 @@ fn row_to_milestone(row: &rusqlite::Row) -> rusqlite::Result<Milestone> {
         achieved: row.get("achieved")?,
         achieved_date: row.get("achieved_date")?,
         created_at: row.get("created_at")?,
+        rule,
+        is_custom: row.get("is_custom")?,
     })
 }
 
@@ -61,7 +127,7 @@ fn row_to_milestone(row: &rusqlite::Row) -> rusqlite::Result<Milestone> {
 // Query Helpers
 // ---------------------------------------------------------------------------
 
-fn query_all_milestones(conn: &Connection) -> CommandResult<Vec<Milestone>> {
+pub(crate) fn query_all_milestones(conn: &Connection) -> CommandResult<Vec<Milestone>> {
     let sql = format!(
         "SELECT {} FROM milestone ORDER BY category, id",
         MILESTONE_COLUMNS
@@ -74,57 +140,37 @@ fn query_all_milestones(conn: &Connection) -> CommandResult<Vec<Milestone>> {
     Ok(rows)
 }
 
-/// Determine whether a milestone's threshold is met given the current context.
-/// Returns true if the milestone should be marked as achieved.
-///
-/// Matching is based on milestone ID:
-/// - tracking: first_steps, one_week_in → total_days_tracked
-/// - score: streak_N → current_streak, avg_80 → avg_score_7d
-/// - clean: clean_N → consecutive_clean_days
-/// - study: first_session → total_study_hours > 0, study_Nh → total_study_hours, focus_master → high_focus_sessions
-fn is_threshold_met(milestone_id: &str, ctx: &MilestoneContext) -> bool {
-    match milestone_id {
-        // Tracking milestones
-        "first_steps" => ctx.total_days_tracked >= 1, // first day logged
-        "one_week_in" => ctx.total_days_tracked >= 7, // one full week of tracking
-
-        // Score milestones
-        "streak_5" => ctx.current_streak >= 5,   // first multi-day achievement
-        "streak_7" => ctx.current_streak >= 7,   // full week streak
-        "streak_30" => ctx.current_streak >= 30, // monthly streak achievement
-        "avg_80" => ctx.avg_score_7d >= 80.0,    // 80% of max over trailing 7 days
-        "trending_up" => {
-            // Requires multi-month trend analysis beyond MilestoneContext scope.
-            // Deferred to future implementation.
-            false
-        }
-
-        // Clean streak milestones — parse number from id
-        id if id.starts_with("clean_") => {
-            if let Ok(days) = id.trim_start_matches("clean_").parse::<i64>() {
-                ctx.consecutive_clean_days >= days
-            } else {
-                false
-            }
-        }
-
-        // Study milestones
-        "first_session" => ctx.total_study_hours > 0.0,
-        "study_50h" => ctx.total_study_hours >= 50.0,
-        "study_100h" => ctx.total_study_hours >= 100.0,
-        "study_500h" => ctx.total_study_hours >= 500.0,
-        "focus_master" => ctx.high_focus_sessions >= 10,
+pub(crate) fn query_milestone_by_id(conn: &Connection, id: &str) -> CommandResult<Option<Milestone>> {
+    let sql = format!("SELECT {} FROM milestone WHERE id = ?1", MILESTONE_COLUMNS);
+    conn.query_row(&sql, params![id], row_to_milestone)
+        .optional()
+        .map_err(CommandError::from)
+}
 
-        // Unknown milestone ID — don't achieve
-        _ => false,
+/// Value of a rule's metric taken straight from a `MilestoneContext`, for
+/// the all-time metrics the context carries. `None` means the rule needs the
+/// full history (windows, averages, habit counts, monthly trends).
+fn context_value(rule: &MilestoneRule, ctx: &MilestoneContext) -> Option<f64> {
+    if rule.window_days.is_some() || rule.clean_streak.is_some() {
+        return None;
+    }
+    match rule.metric {
+        Metric::DaysTracked => Some(ctx.total_days_tracked as f64),
+        Metric::Streak => Some(ctx.current_streak as f64),
+        Metric::CleanDays => Some(ctx.consecutive_clean_days as f64),
+        Metric::StudyHours => Some(ctx.total_study_hours),
+        Metric::HighFocusSessions => Some(ctx.high_focus_sessions as f64),
+        Metric::Applications => Some(ctx.total_applications as f64),
+        Metric::HighestScore => Some(ctx.highest_score),
+        Metric::AvgScore | Metric::HabitCount | Metric::ImprovingMonths => None,
     }
 }
 
-fn check_milestones_impl(
+pub(crate) fn check_milestones_impl(
     conn: &Connection,
     context: &MilestoneContext,
 ) -> CommandResult<Vec<Milestone>> {
-    // 1. Query all unachieved milestones
+    // 1. Query all unachieved milestones that have a rule
     let sql = format!(
         "SELECT {} FROM milestone WHERE achieved = 0",
         MILESTONE_COLUMNS
@@ -134,30 +180,39 @@ fn check_milestones_impl(
         .query_map([], row_to_milestone)?
         .collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)?;
+    let rules: Vec<(&Milestone, &MilestoneRule)> = unachieved
+        .iter()
+        .filter_map(|m| m.rule.as_ref().map(|r| (m, r)))
+        .collect();
+
+    // 2. Load history only if some rule can't be answered from the context
+    let needs_history = rules.iter().any(|(_, r)| context_value(r, context).is_none());
+    let history = if needs_history {
+        let rules: Vec<&MilestoneRule> = rules.iter().map(|(_, r)| *r).collect();
+        load_milestone_history(conn, &rules)?
+    } else {
+        MilestoneHistory::default()
+    };
+    let as_of = chrono::Local::now().date_naive();
 
     let mut newly_achieved: Vec<Milestone> = Vec::new();
 
-    // 2. Check each milestone
-    for milestone in &unachieved {
-        if is_threshold_met(&milestone.id, context) {
-            // 3. One-way flip: achieved 0 → 1
+    // 3. Check each rule
+    for (milestone, rule) in rules {
+        let achieved_on = match context_value(rule, context) {
+            Some(value) => rule.comparator.holds(value, rule.target).then_some(as_of),
+            None => achievement_date(rule, &history, as_of),
+        };
+        if let Some(date) = achieved_on {
+            // 4. One-way flip: achieved 0 → 1
             conn.execute(
-                "UPDATE milestone SET achieved = 1, achieved_date = date('now') \
-                 WHERE id = ?1",
-                params![milestone.id],
+                "UPDATE milestone SET achieved = 1, achieved_date = ?2 WHERE id = ?1",
+                params![milestone.id, date.format("%Y-%m-%d").to_string()],
             )?;
 
             // Read back the updated milestone
-            let updated: Milestone = conn
-                .query_row(
-                    &format!(
-                        "SELECT {} FROM milestone WHERE id = ?1",
-                        MILESTONE_COLUMNS
-                    ),
-                    params![milestone.id],
-                    row_to_milestone,
-                )
-                .map_err(CommandError::from)?;
+            let updated = query_milestone_by_id(conn, &milestone.id)?
+                .ok_or_else(|| CommandError::from("Failed to read back milestone"))?;
 
             newly_achieved.push(updated);
         }
@@ -167,52 +222,85 @@ fn check_milestones_impl(
 }
 
 // ---------------------------------------------------------------------------
-// Milestone Context Builder
+// Rule History Loader
 // ---------------------------------------------------------------------------
 
-/// Count consecutive clean days walking backwards from the most recent daily_log.
-/// A clean day: porn = 0 AND masturbate = 0.
-/// Streak breaks on: a day with porn > 0 OR masturbate > 0, OR a gap in dates.
-fn compute_consecutive_clean_days(conn: &Connection) -> CommandResult<i64> {
+/// Load everything `rules` measure, sorted by date. `DayRecord::clean`
+/// follows the default clean streak; named streaks and habits are loaded only
+/// when a rule references them.
+pub(crate) fn load_milestone_history(
+    conn: &Connection,
+    rules: &[&MilestoneRule],
+) -> CommandResult<MilestoneHistory> {
+    let default_clean = clean_dates(conn, &query_default_clean_streak(conn)?)?;
     let mut stmt = conn.prepare(
-        "SELECT date, porn, masturbate FROM daily_log ORDER BY date DESC",
+        "SELECT date, final_score, streak FROM daily_log ORDER BY date ASC",
     )?;
-    let rows: Vec<(String, i64, i64)> = stmt
-        .query_map([], |row| Ok((row.get("date")?, row.get("porn")?, row.get("masturbate")?)))?
+    let days = stmt
+        .query_map([], |row| {
+            let date: String = row.get("date")?;
+            Ok(DayRecord {
+                clean: default_clean.contains(&date),
+                date,
+                final_score: row.get("final_score")?,
+                streak: row.get("streak")?,
+            })
+        })?
         .collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)?;
 
-    if rows.is_empty() {
-        return Ok(0);
-    }
+    let mut stmt = conn.prepare(
+        "SELECT date, duration_minutes, focus_score FROM study_session ORDER BY date ASC",
+    )?;
+    let study = stmt
+        .query_map([], |row| {
+            Ok(StudyRecord {
+                date: row.get("date")?,
+                minutes: row.get("duration_minutes")?,
+                focus: row.get("focus_score")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
 
-    let mut count: i64 = 0;
-    let mut expected_date: Option<String> = None;
+    let mut stmt = conn.prepare("SELECT date_applied FROM application ORDER BY date_applied ASC")?;
+    let applications = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(CommandError::from)?;
 
-    for (date, porn, masturbate) in &rows {
-        // Check date continuity
-        if let Some(ref exp) = expected_date {
-            if date != exp {
-                break; // Gap in dates breaks the streak
+    let mut history = MilestoneHistory {
+        days,
+        study,
+        applications,
+        ..Default::default()
+    };
+    for rule in rules {
+        if let Some(habit) = rule.habit.as_deref() {
+            if !history.habit_days.contains_key(habit) {
+                history.habit_days.insert(habit.to_string(), load_habit_days(conn, habit)?);
             }
         }
-        // Check if clean
-        if *porn > 0 || *masturbate > 0 {
-            break;
+        if let Some(name) = rule.clean_streak.as_deref() {
+            if !history.clean_streak_days.contains_key(name) {
+                // A streak deleted since the rule was written measures nothing
+                let dates = match query_clean_streak_by_name(conn, name)? {
+                    Some(streak) => clean_dates(conn, &streak)?,
+                    None => Default::default(),
+                };
+                history.clean_streak_days.insert(name.to_string(), dates);
+            }
         }
-        count += 1;
-        // Compute previous day using SQLite date arithmetic
-        let prev: String = conn
-            .query_row("SELECT date(?1, '-1 day')", params![date], |row| row.get(0))
-            .map_err(CommandError::from)?;
-        expected_date = Some(prev);
     }
-
-    Ok(count)
+    Ok(history)
 }
 
+// ---------------------------------------------------------------------------
+// Milestone Context Builder
+// ---------------------------------------------------------------------------
+
 /// Compute all 8 MilestoneContext fields from the database in one call.
-fn get_milestone_context_impl(conn: &Connection) -> CommandResult<MilestoneContext> {
+pub(crate) fn get_milestone_context_impl(conn: &Connection) -> CommandResult<MilestoneContext> {
     // 1. current_streak: from most recent scored daily_log
     let current_streak: i64 = conn
         .query_row(
@@ -241,7 +329,7 @@ fn get_milestone_context_impl(conn: &Connection) -> CommandResult<MilestoneConte
         conn.query_row("SELECT COUNT(*) FROM application", [], |row| row.get(0))?;
 
     // 5. consecutive_clean_days
-    let consecutive_clean_days = compute_consecutive_clean_days(conn)?;
+    let consecutive_clean_days = current_clean_days(conn, &query_default_clean_streak(conn)?)?;
 
     // 6. highest_score
     let highest_score: f64 = conn.query_row(
@@ -263,8 +351,8 @@ fn get_milestone_context_impl(conn: &Connection) -> CommandResult<MilestoneConte
 
     // 8. high_focus_sessions: study sessions with focus_score >= 4
     let high_focus_sessions: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM study_session WHERE focus_score >= 4",
-        [],
+        "SELECT COUNT(*) FROM study_session WHERE focus_score >= ?1",
+        params![HIGH_FOCUS_THRESHOLD],
         |row| row.get(0),
     )?;
 
@@ -280,6 +368,247 @@ fn get_milestone_context_impl(conn: &Connection) -> CommandResult<MilestoneConte
     })
 }
 
+// ---------------------------------------------------------------------------
+// Custom Milestones
+// ---------------------------------------------------------------------------
+
+fn validate_milestone_input(conn: &Connection, input: &MilestoneInput) -> CommandResult<()> {
+    if input.name.trim().is_empty() {
+        return Err(CommandError::from("Milestone name cannot be empty"));
+    }
+    if input.emoji.trim().is_empty() {
+        return Err(CommandError::from("Milestone emoji cannot be empty"));
+    }
+    validate_text_length("Name", &input.name, 100)?;
+    validate_text_length("Emoji", &input.emoji, 32)?;
+    validate_text_length("Threshold", &input.threshold, 200)?;
+    if !VALID_CATEGORIES.contains(&input.category.as_str()) {
+        return Err(CommandError::from(format!(
+            "Invalid category '{}'. Must be one of: {}",
+            input.category,
+            VALID_CATEGORIES.join(", ")
+        )));
+    }
+
+    validate_rule(&input.rule).map_err(CommandError::from)?;
+    if let Some(ref habit) = input.rule.habit {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM habit_config WHERE name = ?1)",
+            params![habit],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(CommandError::from(format!("Unknown habit '{}'", habit)));
+        }
+    }
+    if let Some(ref streak) = input.rule.clean_streak {
+        if query_clean_streak_by_name(conn, streak)?.is_none() {
+            return Err(CommandError::from(format!("Unknown clean streak '{}'", streak)));
+        }
+    }
+    Ok(())
+}
+
+/// `custom_<slug of name>`, suffixed `_2`, `_3`, … if already taken.
+fn generate_milestone_id(conn: &Connection, name: &str) -> CommandResult<String> {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    let base = format!(
+        "{}{}",
+        CUSTOM_ID_PREFIX,
+        if slug.is_empty() { "milestone" } else { &slug }
+    );
+
+    let mut candidate = base.clone();
+    let mut suffix = 2;
+    while query_milestone_by_id(conn, &candidate)?.is_some() {
+        candidate = format!("{}_{}", base, suffix);
+        suffix += 1;
+    }
+    Ok(candidate)
+}
+
+fn threshold_text(input: &MilestoneInput) -> String {
+    if input.threshold.trim().is_empty() {
+        describe_rule(&input.rule)
+    } else {
+        input.threshold.trim().to_string()
+    }
+}
+
+/// Fetch a milestone that the user may modify. Seeded milestones are
+/// read-only.
+fn query_custom_milestone(conn: &Connection, id: &str) -> CommandResult<Milestone> {
+    let milestone = query_milestone_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from(format!("Milestone '{}' not found", id)))?;
+    if !milestone.is_custom {
+        return Err(CommandError::from(format!(
+            "Built-in milestone '{}' cannot be modified",
+            id
+        )));
+    }
+    Ok(milestone)
+}
+
+pub(crate) fn create_milestone_impl(
+    conn: &Connection,
+    input: MilestoneInput,
+) -> CommandResult<Milestone> {
+    validate_milestone_input(conn, &input)?;
+
+    let id = generate_milestone_id(conn, &input.name)?;
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO milestone (\
+         id, name, emoji, category, threshold, achieved, achieved_date, created_at, \
+         metric, comparator, target, window_days, habit_name, is_custom, clean_streak\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7, ?8, ?9, ?10, ?11, 1, ?12)",
+        params![
+            id,
+            input.name.trim(),
+            input.emoji.trim(),
+            input.category,
+            threshold_text(&input),
+            &now,
+            input.rule.metric.as_str(),
+            input.rule.comparator.as_str(),
+            input.rule.target,
+            input.rule.window_days,
+            input.rule.habit,
+            input.rule.clean_streak,
+        ],
+    )?;
+
+    query_milestone_by_id(conn, &id)?
+        .ok_or_else(|| CommandError::from("Failed to read back created milestone"))
+}
+
+/// Editing the rule of an achieved milestone does not revoke it — the
+/// one-way flip holds for custom milestones too.
+pub(crate) fn update_milestone_impl(
+    conn: &Connection,
+    id: &str,
+    input: MilestoneInput,
+) -> CommandResult<Milestone> {
+    query_custom_milestone(conn, id)?;
+    validate_milestone_input(conn, &input)?;
+
+    conn.execute(
+        "UPDATE milestone SET \
+         name = ?2, emoji = ?3, category = ?4, threshold = ?5, \
+         metric = ?6, comparator = ?7, target = ?8, window_days = ?9, habit_name = ?10, \
+         clean_streak = ?11 \
+         WHERE id = ?1",
+        params![
+            id,
+            input.name.trim(),
+            input.emoji.trim(),
+            input.category,
+            threshold_text(&input),
+            input.rule.metric.as_str(),
+            input.rule.comparator.as_str(),
+            input.rule.target,
+            input.rule.window_days,
+            input.rule.habit,
+            input.rule.clean_streak,
+        ],
+    )?;
+
+    query_milestone_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back updated milestone"))
+}
+
+/// Hard delete of a custom milestone definition (DATA_MODEL D8 exception).
+pub(crate) fn delete_milestone_impl(conn: &Connection, id: &str) -> CommandResult<()> {
+    query_custom_milestone(conn, id)?;
+    conn.execute("DELETE FROM milestone WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Automatic Evaluation
+// ---------------------------------------------------------------------------
+
+/// Build the context from the database and award every milestone it meets.
+///
+/// Called inside the save transactions of `save_daily_log`,
+/// `save_study_session` and `save_application`, and after `import_data`, so
+/// milestones are earned on every write path (UI, CLI, HTTP API) without the
+/// frontend having to supply a `MilestoneContext`.
+pub(crate) fn evaluate_milestones_impl(conn: &Connection) -> CommandResult<Vec<Milestone>> {
+    let context = get_milestone_context_impl(conn)?;
+    check_milestones_impl(conn, &context)
+}
+
+/// Replay the full history and give every rule-backed milestone the first
+/// date its rule was met.
+///
+/// Unachieved milestones that were met at some point are awarded with that
+/// date; achieved ones are re-dated when it differs. One-way flip: an
+/// achieved milestone the replay never finds met (data since edited or
+/// deleted) keeps its flag and date. Runs in the caller's transaction.
+pub(crate) fn replay_milestones(conn: &Connection) -> CommandResult<MilestoneRecompute> {
+    let milestones = query_all_milestones(conn)?;
+    let rules: Vec<&MilestoneRule> = milestones.iter().filter_map(|m| m.rule.as_ref()).collect();
+    let history = load_milestone_history(conn, &rules)?;
+    let as_of = chrono::Local::now().date_naive();
+
+    let mut result = MilestoneRecompute {
+        newly_achieved: Vec::new(),
+        redated: Vec::new(),
+    };
+    for milestone in &milestones {
+        let Some(rule) = milestone.rule.as_ref() else {
+            continue;
+        };
+        let Some(date) = first_met_date(rule, &history, as_of) else {
+            continue;
+        };
+        let date = date.format("%Y-%m-%d").to_string();
+        if milestone.achieved && milestone.achieved_date.as_deref() == Some(date.as_str()) {
+            continue;
+        }
+
+        conn.execute(
+            "UPDATE milestone SET achieved = 1, achieved_date = ?2 WHERE id = ?1",
+            params![milestone.id, date],
+        )?;
+        let updated = query_milestone_by_id(conn, &milestone.id)?
+            .ok_or_else(|| CommandError::from("Failed to read back milestone"))?;
+        if milestone.achieved {
+            result.redated.push(updated);
+        } else {
+            result.newly_achieved.push(updated);
+        }
+    }
+    Ok(result)
+}
+
+/// `replay_milestones` in its own transaction.
+pub(crate) fn recompute_milestones_impl(conn: &Connection) -> CommandResult<MilestoneRecompute> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+    let result = replay_milestones(&tx)?;
+    tx.commit()?;
+    Ok(result)
+}
+
+/// Notify the frontend of newly achieved milestones. A failed emit is logged
+/// and otherwise ignored — the achievement is already committed.
+pub(crate) fn emit_milestones_achieved(app: &tauri::AppHandle, achieved: &[Milestone]) {
+    if achieved.is_empty() {
+        return;
+    }
+    if let Err(e) = app.emit(MILESTONES_ACHIEVED_EVENT, achieved) {
+        eprintln!("Milestone event warning (non-fatal): {}", e);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tauri Commands
 // ---------------------------------------------------------------------------
@@ -318,6 +647,55 @@ pub fn check_milestones(
     check_milestones_impl(&db, &context)
 }
 
+#[tauri::command]
+pub fn create_milestone(
+    state: tauri::State<'_, AppState>,
+    milestone: MilestoneInput,
+) -> CommandResult<Milestone> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    create_milestone_impl(&db, milestone)
+}
+
+#[tauri::command]
+pub fn update_milestone(
+    state: tauri::State<'_, AppState>,
+    id: String,
+    milestone: MilestoneInput,
+) -> CommandResult<Milestone> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_milestone_impl(&db, &id, milestone)
+}
+
+/// Newly achieved milestones are emitted as `milestones-achieved`.
+#[tauri::command]
+pub fn recompute_milestones(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> CommandResult<MilestoneRecompute> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    let result = recompute_milestones_impl(&db)?;
+    emit_milestones_achieved(&app, &result.newly_achieved);
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn delete_milestone(state: tauri::State<'_, AppState>, id: String) -> CommandResult<()> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_milestone_impl(&db, &id)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -720,4 +1098,328 @@ mod tests {
         let ctx = get_milestone_context_impl(&conn).unwrap();
         assert_eq!(ctx.high_focus_sessions, 2);
     }
+
+    // -----------------------------------------------------------------------
+    // D. evaluate_milestones tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_evaluate_milestones_empty_db() {
+        let conn = setup_test_db();
+        assert!(evaluate_milestones_impl(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_milestones_uses_db_context() {
+        let conn = setup_test_db();
+        for i in 1..=7 {
+            insert_daily_log(&conn, &format!("2026-01-{:02}", i), i, 80.0, 0, 0);
+        }
+
+        let result = evaluate_milestones_impl(&conn).unwrap();
+        let ids: Vec<&str> = result.iter().map(|m| m.id.as_str()).collect();
+        assert!(ids.contains(&"first_steps"));
+        assert!(ids.contains(&"one_week_in"));
+        assert!(ids.contains(&"streak_7"));
+        assert!(ids.contains(&"clean_7"));
+        assert!(!ids.contains(&"streak_30"));
+
+        // Second pass: everything already flipped
+        assert!(evaluate_milestones_impl(&conn).unwrap().is_empty());
+    }
+
+    // -----------------------------------------------------------------------
+    // E. Rules & custom milestones
+    // -----------------------------------------------------------------------
+
+    fn make_gym_input(name: &str) -> MilestoneInput {
+        MilestoneInput {
+            name: name.to_string(),
+            emoji: "🏋️".to_string(),
+            category: "tracking".to_string(),
+            threshold: String::new(),
+            rule: MilestoneRule {
+                metric: Metric::HabitCount,
+                comparator: Comparator::Gte,
+                target: 3.0,
+                window_days: None,
+                habit: Some("gym".to_string()),
+                clean_streak: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_seed_milestones_have_rules() {
+        let conn = setup_test_db();
+        let milestones = query_all_milestones(&conn).unwrap();
+        for m in &milestones {
+            assert!(!m.is_custom);
+            assert!(m.rule.is_some(), "rule presence for {}", m.id);
+        }
+        let clean_30 = query_milestone_by_id(&conn, "clean_30").unwrap().unwrap();
+        let rule = clean_30.rule.unwrap();
+        assert_eq!(rule.metric, Metric::CleanDays);
+        assert_eq!(rule.comparator, Comparator::Gte);
+        assert!((rule.target - 30.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_avg_80_awarded_from_history() {
+        let conn = setup_test_db();
+        for i in 1..=29 {
+            insert_daily_log(&conn, &format!("2026-01-{:02}", i), 0, 0.85, 1, 0);
+        }
+        let ids = |v: Vec<Milestone>| v.into_iter().map(|m| m.id).collect::<Vec<_>>();
+        // 29 scored days: the 30-day average is not defined yet
+        assert!(!ids(evaluate_milestones_impl(&conn).unwrap()).contains(&"avg_80".to_string()));
+
+        insert_daily_log(&conn, "2026-01-30", 0, 0.85, 1, 0);
+        assert!(ids(evaluate_milestones_impl(&conn).unwrap()).contains(&"avg_80".to_string()));
+    }
+
+    #[test]
+    fn test_trending_up_backfills_achievement_date() {
+        let conn = setup_test_db();
+        let trending = query_milestone_by_id(&conn, "trending_up").unwrap().unwrap();
+        assert_eq!(trending.rule.unwrap().metric, Metric::ImprovingMonths);
+
+        // Jan..Apr 2025 each better than the month before, then a slump
+        let monthly = [(1, 31, 0.5), (2, 28, 0.55), (3, 31, 0.6), (4, 30, 0.65), (5, 31, 0.3)];
+        for (month, days, score) in monthly {
+            for d in 1..=days {
+                insert_daily_log(&conn, &format!("2025-{:02}-{:02}", month, d), 0, score, 1, 0);
+            }
+        }
+
+        let achieved = evaluate_milestones_impl(&conn).unwrap();
+        let trending = achieved.iter().find(|m| m.id == "trending_up").expect("trending_up awarded");
+        // Dated at the end of the third improving month, not today
+        assert_eq!(trending.achieved_date.as_deref(), Some("2025-04-30"));
+    }
+
+    #[test]
+    fn test_trending_up_needs_three_improving_months() {
+        let conn = setup_test_db();
+        let monthly = [(1, 31, 0.5), (2, 28, 0.55), (3, 31, 0.6), (4, 30, 0.6)];
+        for (month, days, score) in monthly {
+            for d in 1..=days {
+                insert_daily_log(&conn, &format!("2025-{:02}-{:02}", month, d), 0, score, 1, 0);
+            }
+        }
+        let achieved = evaluate_milestones_impl(&conn).unwrap();
+        assert!(!achieved.iter().any(|m| m.id == "trending_up"));
+    }
+
+    #[test]
+    fn test_create_milestone_generates_id_and_threshold() {
+        let conn = setup_test_db();
+        let created = create_milestone_impl(&conn, make_gym_input("Gym Rat!")).unwrap();
+        assert_eq!(created.id, "custom_gym_rat");
+        assert!(created.is_custom);
+        assert!(!created.achieved);
+        assert_eq!(created.threshold, "gym days >= 3");
+        assert_eq!(created.rule.as_ref().unwrap().habit.as_deref(), Some("gym"));
+
+        let second = create_milestone_impl(&conn, make_gym_input("Gym rat")).unwrap();
+        assert_eq!(second.id, "custom_gym_rat_2");
+        assert_eq!(query_all_milestones(&conn).unwrap().len(), 22);
+    }
+
+    #[test]
+    fn test_create_milestone_validation() {
+        let conn = setup_test_db();
+
+        let mut input = make_gym_input("Yoga");
+        input.rule.habit = Some("yoga".to_string());
+        assert!(create_milestone_impl(&conn, input).is_err(), "unknown habit");
+
+        let mut input = make_gym_input("Bad category");
+        input.category = "fitness".to_string();
+        assert!(create_milestone_impl(&conn, input).is_err());
+
+        let mut input = make_gym_input("No window");
+        input.rule = MilestoneRule {
+            metric: Metric::AvgScore,
+            comparator: Comparator::Gte,
+            target: 0.7,
+            window_days: None,
+            habit: None,
+            clean_streak: None,
+        };
+        assert!(create_milestone_impl(&conn, input).is_err());
+
+        assert!(create_milestone_impl(&conn, make_gym_input("  ")).is_err());
+    }
+
+    #[test]
+    fn test_custom_habit_rule_awarded_by_check() {
+        let conn = setup_test_db();
+        let created = create_milestone_impl(&conn, make_gym_input("Gym Rat")).unwrap();
+        for i in 1..=3 {
+            insert_daily_log(&conn, &format!("2026-01-{:02}", i), 0, 0.5, 1, 0);
+        }
+        conn.execute("UPDATE daily_log SET gym = 1 WHERE date <= '2026-01-02'", [])
+            .unwrap();
+        assert!(evaluate_milestones_impl(&conn)
+            .unwrap()
+            .iter()
+            .all(|m| m.id != created.id));
+
+        conn.execute("UPDATE daily_log SET gym = 1", []).unwrap();
+        let result = check_milestones_impl(&conn, &make_empty_context()).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, created.id);
+    }
+
+    #[test]
+    fn test_update_and_delete_only_custom() {
+        let conn = setup_test_db();
+        let created = create_milestone_impl(&conn, make_gym_input("Gym Rat")).unwrap();
+
+        let mut input = make_gym_input("Gym Legend");
+        input.rule.target = 100.0;
+        input.threshold = "100 gym days".to_string();
+        let updated = update_milestone_impl(&conn, &created.id, input).unwrap();
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.name, "Gym Legend");
+        assert_eq!(updated.threshold, "100 gym days");
+        assert!((updated.rule.unwrap().target - 100.0).abs() < f64::EPSILON);
+
+        assert!(update_milestone_impl(&conn, "streak_5", make_gym_input("x")).is_err());
+        assert!(delete_milestone_impl(&conn, "streak_5").is_err());
+        assert!(delete_milestone_impl(&conn, "custom_missing").is_err());
+
+        delete_milestone_impl(&conn, &created.id).unwrap();
+        assert!(query_milestone_by_id(&conn, &created.id).unwrap().is_none());
+    }
+
+    // -----------------------------------------------------------------------
+    // F. recompute_milestones tests
+    // -----------------------------------------------------------------------
+
+    fn achieved_date(conn: &Connection, id: &str) -> Option<String> {
+        query_milestone_by_id(conn, id).unwrap().unwrap().achieved_date
+    }
+
+    #[test]
+    fn test_recompute_awards_with_first_met_dates() {
+        let conn = setup_test_db();
+        for i in 1..=8 {
+            insert_daily_log(&conn, &format!("2025-03-{:02}", i), i, 0.6, 0, 0);
+        }
+        insert_study_session(&conn, "2025-03-04", 60, 3);
+        insert_application(&conn, "2025-03-06");
+
+        let result = recompute_milestones_impl(&conn).unwrap();
+        assert!(result.redated.is_empty());
+        let ids: Vec<&str> = result.newly_achieved.iter().map(|m| m.id.as_str()).collect();
+        for id in ["first_steps", "one_week_in", "streak_5", "clean_7", "first_session"] {
+            assert!(ids.contains(&id), "{} awarded", id);
+        }
+        assert_eq!(achieved_date(&conn, "first_steps").as_deref(), Some("2025-03-01"));
+        assert_eq!(achieved_date(&conn, "one_week_in").as_deref(), Some("2025-03-07"));
+        assert_eq!(achieved_date(&conn, "streak_5").as_deref(), Some("2025-03-05"));
+        assert_eq!(achieved_date(&conn, "clean_7").as_deref(), Some("2025-03-07"));
+        assert_eq!(achieved_date(&conn, "first_session").as_deref(), Some("2025-03-04"));
+        assert_eq!(achieved_date(&conn, "streak_30"), None);
+
+        // Second run changes nothing
+        let again = recompute_milestones_impl(&conn).unwrap();
+        assert!(again.newly_achieved.is_empty());
+        assert!(again.redated.is_empty());
+    }
+
+    #[test]
+    fn test_recompute_redates_milestones_checked_late() {
+        let conn = setup_test_db();
+        for i in 1..=5 {
+            insert_daily_log(&conn, &format!("2025-03-{:02}", i), i, 0.6, 1, 0);
+        }
+        // The incremental check dates everything today
+        evaluate_milestones_impl(&conn).unwrap();
+        let today = chrono::Local::now().date_naive().format("%Y-%m-%d").to_string();
+        assert_eq!(achieved_date(&conn, "streak_5"), Some(today));
+
+        let result = recompute_milestones_impl(&conn).unwrap();
+        assert!(result.newly_achieved.is_empty());
+        assert!(result.redated.iter().any(|m| m.id == "streak_5"));
+        assert_eq!(achieved_date(&conn, "streak_5").as_deref(), Some("2025-03-05"));
+        assert_eq!(achieved_date(&conn, "first_steps").as_deref(), Some("2025-03-01"));
+    }
+
+    #[test]
+    fn test_recompute_keeps_one_way_flip() {
+        let conn = setup_test_db();
+        conn.execute(
+            "UPDATE milestone SET achieved = 1, achieved_date = '2025-01-15' WHERE id = 'study_50h'",
+            [],
+        )
+        .unwrap();
+        insert_study_session(&conn, "2025-03-04", 60, 3);
+
+        let result = recompute_milestones_impl(&conn).unwrap();
+        assert!(!result.redated.iter().any(|m| m.id == "study_50h"));
+        let study_50h = query_milestone_by_id(&conn, "study_50h").unwrap().unwrap();
+        assert!(study_50h.achieved, "never revoked");
+        assert_eq!(study_50h.achieved_date.as_deref(), Some("2025-01-15"));
+    }
+
+    // -----------------------------------------------------------------------
+    // G. Clean streaks
+    // -----------------------------------------------------------------------
+
+    use crate::commands::clean_streak::{save_clean_streak_impl, CleanStreakInput};
+
+    #[test]
+    fn test_default_streak_definition_drives_context() {
+        let conn = setup_test_db();
+        insert_daily_log(&conn, "2026-01-01", 1, 0.8, 0, 0);
+        insert_daily_log(&conn, "2026-01-02", 2, 0.8, 0, 1);
+        insert_daily_log(&conn, "2026-01-03", 3, 0.8, 0, 0);
+        assert_eq!(get_milestone_context_impl(&conn).unwrap().consecutive_clean_days, 1);
+
+        // Narrow the default streak to porn only: masturbate no longer breaks it
+        let default = query_default_clean_streak(&conn).unwrap();
+        let input = CleanStreakInput {
+            id: Some(default.id),
+            name: default.name,
+            vices: vec!["porn".to_string()],
+            use_relapse_entries: default.use_relapse_entries,
+        };
+        save_clean_streak_impl(&conn, input).unwrap();
+        assert_eq!(get_milestone_context_impl(&conn).unwrap().consecutive_clean_days, 3);
+    }
+
+    #[test]
+    fn test_named_streak_milestone() {
+        let conn = setup_test_db();
+        let weed = CleanStreakInput {
+            id: None,
+            name: "Weed".to_string(),
+            vices: vec!["weed".to_string()],
+            use_relapse_entries: false,
+        };
+        save_clean_streak_impl(&conn, weed).unwrap();
+
+        let mut input = make_gym_input("Three Days Off Weed");
+        input.category = "clean".to_string();
+        input.rule.metric = Metric::CleanDays;
+        input.rule.habit = None;
+        input.rule.clean_streak = Some("Weed".to_string());
+        let created = create_milestone_impl(&conn, input.clone()).unwrap();
+        assert_eq!(created.threshold, "Weed clean days >= 3");
+
+        input.rule.clean_streak = Some("Gaming".to_string());
+        assert!(create_milestone_impl(&conn, input).is_err(), "unknown streak");
+
+        // Porn every day: the default streak never starts, the weed streak does
+        for d in 1..=3 {
+            insert_daily_log(&conn, &format!("2026-01-{:02}", d), d, 0.8, 1, 0);
+        }
+        let ids: Vec<String> =
+            evaluate_milestones_impl(&conn).unwrap().into_iter().map(|m| m.id).collect();
+        assert!(ids.contains(&created.id));
+        assert!(!ids.contains(&"clean_1".to_string()));
+    }
 }
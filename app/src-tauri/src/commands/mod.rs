@@ -1,14 +1,19 @@
 pub mod analytics;
 pub mod application;
+pub mod clean_streak;
 pub mod config;
 pub mod daily_log;
 pub mod data;
 pub mod file_io;
 pub mod journal;
 pub mod milestone;
+pub mod periodic_review;
 pub mod recovery;
 pub mod review;
+pub mod search;
 pub mod study;
+pub mod study_goal;
+pub mod study_topic;
 pub mod testdata;
 pub mod validation;
 
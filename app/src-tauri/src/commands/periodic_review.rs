@@ -0,0 +1,806 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Months, NaiveDate};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+use super::review::{
+    build_score_snapshot, compute_habits_completed, compute_period_stats_impl, PeriodStats,
+};
+use super::validation::validate_text_length;
+use super::{CommandError, CommandResult};
+
+// ---------------------------------------------------------------------------
+// Periods & Prompts
+// ---------------------------------------------------------------------------
+
+/// A calendar period longer than a week. Weeks keep their own
+/// `weekly_review` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewPeriod {
+    Month,
+    Quarter,
+    Year,
+}
+
+/// `(key, question)` reflection prompts. Keys are stored in
+/// `periodic_review.reflections`, so they must not change once used.
+const MONTH_PROMPTS: &[(&str, &str)] = &[
+    ("biggest_win", "What was the biggest win of the month?"),
+    (
+        "biggest_challenge",
+        "What was the hardest part of the month?",
+    ),
+    (
+        "habit_focus",
+        "Which habit needs the most attention next month?",
+    ),
+    ("next_month_goal", "What is your main goal for next month?"),
+    ("reflection", "Anything else about this month?"),
+];
+
+const QUARTER_PROMPTS: &[(&str, &str)] = &[
+    (
+        "progress",
+        "How did this quarter move you towards your bigger goals?",
+    ),
+    (
+        "patterns",
+        "What patterns showed up across the quarter's weeks?",
+    ),
+    ("stop_doing", "What will you stop doing next quarter?"),
+    (
+        "next_quarter_goals",
+        "What are your goals for next quarter?",
+    ),
+    ("reflection", "Anything else about this quarter?"),
+];
+
+const YEAR_PROMPTS: &[(&str, &str)] = &[
+    ("proudest", "What are you proudest of this year?"),
+    ("lessons", "What were the biggest lessons of the year?"),
+    ("changed", "How are you different from a year ago?"),
+    ("next_year_theme", "What is your theme for next year?"),
+    ("reflection", "Anything else about this year?"),
+];
+
+impl ReviewPeriod {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ReviewPeriod::Month => "month",
+            ReviewPeriod::Quarter => "quarter",
+            ReviewPeriod::Year => "year",
+        }
+    }
+
+    fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "month" => Some(ReviewPeriod::Month),
+            "quarter" => Some(ReviewPeriod::Quarter),
+            "year" => Some(ReviewPeriod::Year),
+            _ => None,
+        }
+    }
+
+    fn months(self) -> u32 {
+        match self {
+            ReviewPeriod::Month => 1,
+            ReviewPeriod::Quarter => 3,
+            ReviewPeriod::Year => 12,
+        }
+    }
+
+    pub(crate) fn prompts(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ReviewPeriod::Month => MONTH_PROMPTS,
+            ReviewPeriod::Quarter => QUARTER_PROMPTS,
+            ReviewPeriod::Year => YEAR_PROMPTS,
+        }
+    }
+
+    /// First and last day of the period starting on `period_start`, which
+    /// must be the 1st of a month, of January/April/July/October, or of
+    /// January respectively.
+    pub(crate) fn bounds(self, period_start: &str) -> CommandResult<(NaiveDate, NaiveDate)> {
+        let start = NaiveDate::parse_from_str(period_start, "%Y-%m-%d").map_err(|_| {
+            CommandError::from(format!(
+                "Invalid period start '{}'. Expected YYYY-MM-DD",
+                period_start
+            ))
+        })?;
+        let aligned = start.day() == 1 && (start.month() - 1) % self.months() == 0;
+        if !aligned {
+            return Err(CommandError::from(format!(
+                "A {} review must start on the first day of a {} (got {})",
+                self.as_str(),
+                self.as_str(),
+                period_start
+            )));
+        }
+        let end = start
+            .checked_add_months(Months::new(self.months()))
+            .and_then(|next| next.pred_opt())
+            .ok_or_else(|| CommandError::from("Period end is out of range"))?;
+        Ok((start, end))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// One reflection prompt of a period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewPrompt {
+    pub key: String,
+    pub question: String,
+}
+
+/// A saved weekly review inside the period, as captured in `week_snapshot`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewedWeek {
+    pub week_start: String,
+    /// The weekly review's frozen `avg_score`.
+    pub avg_score: Option<f64>,
+}
+
+/// Roll-up of the saved weekly reviews whose week starts in the period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekRollup {
+    pub weeks_reviewed: i64,
+    pub best_week_start: Option<String>,
+    pub best_week_score: Option<f64>,
+    pub worst_week_start: Option<String>,
+    pub worst_week_score: Option<f64>,
+    /// Mean of the weekly `avg_score`s, not of the days.
+    pub avg_weekly_score: Option<f64>,
+    pub weeks: Vec<ReviewedWeek>,
+}
+
+/// Live stats for a period (not stored). Returned by `compute_periodic_stats`
+/// for the review UI preview.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodicStats {
+    pub period: ReviewPeriod,
+    pub period_start: String,
+    pub period_end: String,
+    #[serde(flatten)]
+    pub stats: PeriodStats,
+    #[serde(flatten)]
+    pub rollup: WeekRollup,
+}
+
+/// Full periodic_review row returned to the frontend.
+/// Field names and types must match the TypeScript `PeriodicReview` interface exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodicReview {
+    pub id: i64,
+    pub period: ReviewPeriod,
+    pub period_start: String,
+    pub period_end: String,
+
+    // Auto-computed stats, frozen at save
+    pub avg_score: Option<f64>,
+    pub days_tracked: Option<i64>,
+    pub best_day_score: Option<f64>,
+    pub worst_day_score: Option<f64>,
+    pub habits_completed: Option<i64>,
+    pub study_hours: Option<f64>,
+    pub applications_sent: Option<i64>,
+    pub relapses: Option<i64>,
+    pub urges_resisted: Option<i64>,
+    pub streak_at_end: Option<i64>,
+
+    // Weekly roll-up, frozen at save
+    pub weeks_reviewed: Option<i64>,
+    pub best_week_start: Option<String>,
+    pub best_week_score: Option<f64>,
+    pub worst_week_start: Option<String>,
+    pub worst_week_score: Option<f64>,
+    pub avg_weekly_score: Option<f64>,
+
+    /// Prompt key -> answer.
+    pub reflections: BTreeMap<String, String>,
+
+    // Snapshot data
+    pub snapshot_date: Option<String>,
+    /// JSON array of `final_score`, one per day of the period.
+    pub score_snapshot: Option<String>,
+    /// JSON array of `ReviewedWeek`.
+    pub week_snapshot: Option<String>,
+
+    // Timestamps
+    pub logged_at: String,
+    pub last_modified: String,
+}
+
+/// Input received from the frontend when saving a periodic review.
+/// Contains only user-editable fields — no id, stats, snapshot, or timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodicReviewInput {
+    pub period: ReviewPeriod,
+    pub period_start: String,
+    /// Answers keyed by prompt key. Prompts left out are saved as unanswered.
+    #[serde(default)]
+    pub reflections: BTreeMap<String, String>,
+}
+
+// ---------------------------------------------------------------------------
+// Column Constants & Row Mapper
+// ---------------------------------------------------------------------------
+
+const PERIODIC_REVIEW_COLUMNS: &str = "\
+    id, period, period_start, period_end, \
+    avg_score, days_tracked, best_day_score, worst_day_score, \
+    habits_completed, study_hours, applications_sent, \
+    relapses, urges_resisted, streak_at_end, \
+    weeks_reviewed, best_week_start, best_week_score, \
+    worst_week_start, worst_week_score, avg_weekly_score, \
+    reflections, snapshot_date, score_snapshot, week_snapshot, \
+    logged_at, last_modified";
+
+fn row_to_periodic_review(row: &rusqlite::Row) -> rusqlite::Result<PeriodicReview> {
+    let period: String = row.get("period")?;
+    let reflections: String = row.get("reflections")?;
+    Ok(PeriodicReview {
+        id: row.get("id")?,
+        // The CHECK constraint only admits the three periods
+        period: ReviewPeriod::from_db(&period).unwrap_or(ReviewPeriod::Month),
+        period_start: row.get("period_start")?,
+        period_end: row.get("period_end")?,
+        avg_score: row.get("avg_score")?,
+        days_tracked: row.get("days_tracked")?,
+        best_day_score: row.get("best_day_score")?,
+        worst_day_score: row.get("worst_day_score")?,
+        habits_completed: row.get("habits_completed")?,
+        study_hours: row.get("study_hours")?,
+        applications_sent: row.get("applications_sent")?,
+        relapses: row.get("relapses")?,
+        urges_resisted: row.get("urges_resisted")?,
+        streak_at_end: row.get("streak_at_end")?,
+        weeks_reviewed: row.get("weeks_reviewed")?,
+        best_week_start: row.get("best_week_start")?,
+        best_week_score: row.get("best_week_score")?,
+        worst_week_start: row.get("worst_week_start")?,
+        worst_week_score: row.get("worst_week_score")?,
+        avg_weekly_score: row.get("avg_weekly_score")?,
+        reflections: serde_json::from_str(&reflections).unwrap_or_default(),
+        snapshot_date: row.get("snapshot_date")?,
+        score_snapshot: row.get("score_snapshot")?,
+        week_snapshot: row.get("week_snapshot")?,
+        logged_at: row.get("logged_at")?,
+        last_modified: row.get("last_modified")?,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Query Helpers
+// ---------------------------------------------------------------------------
+
+pub(crate) fn query_periodic_review(
+    conn: &Connection,
+    period: ReviewPeriod,
+    period_start: &str,
+) -> CommandResult<Option<PeriodicReview>> {
+    let sql = format!(
+        "SELECT {} FROM periodic_review WHERE period = ?1 AND period_start = ?2",
+        PERIODIC_REVIEW_COLUMNS
+    );
+    conn.query_row(
+        &sql,
+        params![period.as_str(), period_start],
+        row_to_periodic_review,
+    )
+    .optional()
+    .map_err(CommandError::from)
+}
+
+/// Roll-up of the saved weekly reviews whose `week_start` is in
+/// `[start, end]`. Uses their frozen `avg_score`, so later edits to daily logs
+/// do not change it. Weeks without a score count as reviewed but are left
+/// out of best, worst and average; ties go to the earlier week.
+fn compute_week_rollup(conn: &Connection, start: &str, end: &str) -> CommandResult<WeekRollup> {
+    let mut stmt = conn.prepare(
+        "SELECT week_start, avg_score FROM weekly_review \
+         WHERE week_start >= ?1 AND week_start <= ?2 ORDER BY week_start ASC",
+    )?;
+    let weeks: Vec<ReviewedWeek> = stmt
+        .query_map(params![start, end], |row| {
+            Ok(ReviewedWeek {
+                week_start: row.get("week_start")?,
+                avg_score: row.get("avg_score")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let scored: Vec<(&str, f64)> = weeks
+        .iter()
+        .filter_map(|w| w.avg_score.map(|s| (w.week_start.as_str(), s)))
+        .collect();
+    let best = scored
+        .iter()
+        .fold(None, |best: Option<&(&str, f64)>, w| match best {
+            Some(b) if b.1 >= w.1 => Some(b),
+            _ => Some(w),
+        });
+    let worst = scored
+        .iter()
+        .fold(None, |worst: Option<&(&str, f64)>, w| match worst {
+            Some(b) if b.1 <= w.1 => Some(b),
+            _ => Some(w),
+        });
+    let avg_weekly_score = if scored.is_empty() {
+        None
+    } else {
+        Some(scored.iter().map(|(_, s)| s).sum::<f64>() / scored.len() as f64)
+    };
+
+    Ok(WeekRollup {
+        weeks_reviewed: weeks.len() as i64,
+        best_week_start: best.map(|w| w.0.to_string()),
+        best_week_score: best.map(|w| w.1),
+        worst_week_start: worst.map(|w| w.0.to_string()),
+        worst_week_score: worst.map(|w| w.1),
+        avg_weekly_score,
+        weeks,
+    })
+}
+
+/// Check that every reflection key is one of the period's prompts and that
+/// answers fit the same limit as weekly reflections.
+fn validate_reflections(
+    period: ReviewPeriod,
+    reflections: &BTreeMap<String, String>,
+) -> CommandResult<()> {
+    let prompts = period.prompts();
+    for (key, answer) in reflections {
+        if !prompts.iter().any(|(k, _)| k == key) {
+            return Err(CommandError::from(format!(
+                "Unknown {} review prompt '{}'",
+                period.as_str(),
+                key
+            )));
+        }
+        validate_text_length(key, answer, 8000)?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+pub(crate) fn get_review_prompts_impl(period: ReviewPeriod) -> Vec<ReviewPrompt> {
+    period
+        .prompts()
+        .iter()
+        .map(|(key, question)| ReviewPrompt {
+            key: key.to_string(),
+            question: question.to_string(),
+        })
+        .collect()
+}
+
+pub(crate) fn get_periodic_review_impl(
+    conn: &Connection,
+    period: ReviewPeriod,
+    period_start: &str,
+) -> CommandResult<Option<PeriodicReview>> {
+    period.bounds(period_start)?;
+    query_periodic_review(conn, period, period_start)
+}
+
+/// Live stats and weekly roll-up for the period (not saved).
+pub(crate) fn compute_periodic_stats_impl(
+    conn: &Connection,
+    period: ReviewPeriod,
+    period_start: &str,
+) -> CommandResult<PeriodicStats> {
+    let (start, end) = period.bounds(period_start)?;
+    let (start, end) = (
+        start.format("%Y-%m-%d").to_string(),
+        end.format("%Y-%m-%d").to_string(),
+    );
+    Ok(PeriodicStats {
+        period,
+        stats: compute_period_stats_impl(conn, &start, &end)?,
+        rollup: compute_week_rollup(conn, &start, &end)?,
+        period_start: start,
+        period_end: end,
+    })
+}
+
+/// Save the reflections and freeze the period's stats, daily score snapshot
+/// and weekly roll-up (ADR-002 SD3). Saving again refreshes the frozen values
+/// and keeps the original `logged_at`.
+pub(crate) fn save_periodic_review_impl(
+    conn: &Connection,
+    review: PeriodicReviewInput,
+) -> CommandResult<PeriodicReview> {
+    validate_reflections(review.period, &review.reflections)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let reflections = serde_json::to_string(&review.reflections)?;
+
+    {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+        // 1. Compute stats and roll-up
+        let live = compute_periodic_stats_impl(&tx, review.period, &review.period_start)?;
+        let (start, end) = (&live.period_start, &live.period_end);
+        let habits_completed = compute_habits_completed(&tx, start, end)?;
+
+        // 2. Build snapshots (ADR-002 SD3: frozen at save time)
+        let score_snapshot = build_score_snapshot(&tx, start, end)?;
+        let week_snapshot = serde_json::to_string(&live.rollup.weeks)?;
+
+        // 3. Check for existing row
+        let existing_logged_at: Option<String> = tx
+            .query_row(
+                "SELECT logged_at FROM periodic_review WHERE period = ?1 AND period_start = ?2",
+                params![review.period.as_str(), start],
+                |row| row.get("logged_at"),
+            )
+            .optional()?;
+
+        // 4. UPDATE preserving the original logged_at, or INSERT with logged_at = now
+        let sql = if existing_logged_at.is_some() {
+            "UPDATE periodic_review SET \
+             period_end = ?3, \
+             avg_score = ?4, days_tracked = ?5, \
+             best_day_score = ?6, worst_day_score = ?7, \
+             habits_completed = ?8, study_hours = ?9, \
+             applications_sent = ?10, relapses = ?11, \
+             urges_resisted = ?12, streak_at_end = ?13, \
+             weeks_reviewed = ?14, best_week_start = ?15, best_week_score = ?16, \
+             worst_week_start = ?17, worst_week_score = ?18, avg_weekly_score = ?19, \
+             reflections = ?20, snapshot_date = ?21, \
+             score_snapshot = ?22, week_snapshot = ?23, \
+             logged_at = ?24, last_modified = ?25 \
+             WHERE period = ?1 AND period_start = ?2"
+        } else {
+            "INSERT INTO periodic_review (\
+             period, period_start, period_end, \
+             avg_score, days_tracked, best_day_score, worst_day_score, \
+             habits_completed, study_hours, applications_sent, \
+             relapses, urges_resisted, streak_at_end, \
+             weeks_reviewed, best_week_start, best_week_score, \
+             worst_week_start, worst_week_score, avg_weekly_score, \
+             reflections, snapshot_date, score_snapshot, week_snapshot, \
+             logged_at, last_modified\
+             ) VALUES (\
+             ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, \
+             ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)"
+        };
+
+        let (stats, rollup) = (&live.stats, &live.rollup);
+        tx.execute(
+            sql,
+            params![
+                review.period.as_str(),
+                start,
+                end,
+                stats.avg_score,
+                stats.days_tracked,
+                stats.best_day_score,
+                stats.worst_day_score,
+                habits_completed,
+                stats.total_study_hours,
+                stats.applications_sent,
+                stats.relapses,
+                stats.urges_resisted,
+                stats.current_streak.map(|s| s as i64),
+                rollup.weeks_reviewed,
+                rollup.best_week_start,
+                rollup.best_week_score,
+                rollup.worst_week_start,
+                rollup.worst_week_score,
+                rollup.avg_weekly_score,
+                reflections,
+                &now,
+                score_snapshot,
+                week_snapshot,
+                existing_logged_at.as_deref().unwrap_or(&now),
+                &now,
+            ],
+        )?;
+
+        tx.commit()?;
+    }
+
+    // 4. Read back and return
+    query_periodic_review(conn, review.period, &review.period_start)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved periodic review"))
+}
+
+// ---------------------------------------------------------------------------
+// Tauri Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn get_review_prompts(period: ReviewPeriod) -> Vec<ReviewPrompt> {
+    get_review_prompts_impl(period)
+}
+
+#[tauri::command]
+pub fn get_periodic_review(
+    state: tauri::State<'_, AppState>,
+    period: ReviewPeriod,
+    period_start: String,
+) -> CommandResult<Option<PeriodicReview>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_periodic_review_impl(&db, period, &period_start)
+}
+
+#[tauri::command]
+pub fn compute_periodic_stats(
+    state: tauri::State<'_, AppState>,
+    period: ReviewPeriod,
+    period_start: String,
+) -> CommandResult<PeriodicStats> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    compute_periodic_stats_impl(&db, period, &period_start)
+}
+
+#[tauri::command]
+pub fn save_periodic_review(
+    state: tauri::State<'_, AppState>,
+    review: PeriodicReviewInput,
+) -> CommandResult<PeriodicReview> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_periodic_review_impl(&db, review)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::review::{save_weekly_review_impl, WeeklyReviewInput};
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    /// Insert a minimal daily_log row for testing.
+    fn insert_daily_log(conn: &Connection, date: &str, final_score: f64) {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO daily_log (\
+             date, schoolwork, personal_project, classes, job_search, \
+             gym, sleep_7_9h, wake_8am, supplements, meal_quality, stretching, \
+             meditate, \"read\", social, \
+             porn, masturbate, weed, skip_class, binged_content, gaming_1h, \
+             past_12am, late_wake, phone_use, \
+             positive_score, vice_penalty, base_score, streak, final_score, \
+             logged_at, last_modified\
+             ) VALUES (\
+             ?1, 1, 0, 0, 0, 1, 0, 0, 0, 'None', 0, 0, 0, 'None', \
+             0, 0, 0, 0, 0, 0, 0, 0, 0, \
+             50.0, 0.0, 50.0, 1, ?2, ?3, ?4)",
+            params![date, final_score, &now, &now],
+        )
+        .unwrap();
+    }
+
+    fn save_week(conn: &Connection, week_start: &str) {
+        save_weekly_review_impl(
+            conn,
+            WeeklyReviewInput {
+                week_start: week_start.to_string(),
+                week_end: String::new(),
+                week_number: 1,
+                biggest_win: String::new(),
+                biggest_challenge: String::new(),
+                next_week_goal: String::new(),
+                reflection: String::new(),
+            },
+        )
+        .unwrap();
+    }
+
+    fn month_input(reflections: &[(&str, &str)]) -> PeriodicReviewInput {
+        PeriodicReviewInput {
+            period: ReviewPeriod::Month,
+            period_start: "2026-03-01".to_string(),
+            reflections: reflections
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_period_bounds() {
+        let bounds = |period: ReviewPeriod, start: &str| {
+            period
+                .bounds(start)
+                .map(|(s, e)| (s.to_string(), e.to_string()))
+        };
+        assert_eq!(
+            bounds(ReviewPeriod::Month, "2028-02-01").unwrap().1,
+            "2028-02-29"
+        );
+        assert_eq!(
+            bounds(ReviewPeriod::Quarter, "2026-10-01").unwrap().1,
+            "2026-12-31"
+        );
+        assert_eq!(
+            bounds(ReviewPeriod::Year, "2026-01-01").unwrap().1,
+            "2026-12-31"
+        );
+
+        assert!(bounds(ReviewPeriod::Month, "2026-03-02").is_err());
+        assert!(bounds(ReviewPeriod::Quarter, "2026-02-01").is_err());
+        assert!(bounds(ReviewPeriod::Year, "2026-07-01").is_err());
+        assert!(bounds(ReviewPeriod::Month, "March").is_err());
+    }
+
+    #[test]
+    fn test_each_period_has_its_own_prompts() {
+        let keys = |p: ReviewPeriod| -> Vec<String> {
+            get_review_prompts_impl(p)
+                .into_iter()
+                .map(|p| p.key)
+                .collect()
+        };
+        assert!(keys(ReviewPeriod::Month).contains(&"next_month_goal".to_string()));
+        assert!(keys(ReviewPeriod::Quarter).contains(&"next_quarter_goals".to_string()));
+        assert!(keys(ReviewPeriod::Year).contains(&"next_year_theme".to_string()));
+        assert!(!keys(ReviewPeriod::Year).contains(&"next_month_goal".to_string()));
+    }
+
+    #[test]
+    fn test_compute_periodic_stats_uses_whole_period() {
+        let conn = setup_test_db();
+        insert_daily_log(&conn, "2026-02-28", 10.0);
+        insert_daily_log(&conn, "2026-03-01", 60.0);
+        insert_daily_log(&conn, "2026-03-31", 80.0);
+        insert_daily_log(&conn, "2026-04-01", 10.0);
+
+        let stats = compute_periodic_stats_impl(&conn, ReviewPeriod::Month, "2026-03-01").unwrap();
+        assert_eq!(stats.period_end, "2026-03-31");
+        assert_eq!(stats.stats.days_tracked, 2);
+        assert!((stats.stats.avg_score.unwrap() - 70.0).abs() < 0.01);
+        assert_eq!(stats.rollup.weeks_reviewed, 0);
+        assert_eq!(stats.rollup.best_week_start, None);
+
+        let quarter =
+            compute_periodic_stats_impl(&conn, ReviewPeriod::Quarter, "2026-01-01").unwrap();
+        assert_eq!(quarter.stats.days_tracked, 3);
+    }
+
+    #[test]
+    fn test_rollup_comes_from_frozen_weekly_reviews() {
+        let conn = setup_test_db();
+        insert_daily_log(&conn, "2026-03-02", 60.0); // week of Mar 2
+        insert_daily_log(&conn, "2026-03-09", 90.0); // week of Mar 9
+        insert_daily_log(&conn, "2026-03-16", 70.0); // week of Mar 16 (not reviewed)
+        save_week(&conn, "2026-02-23"); // starts in February: not in March
+        save_week(&conn, "2026-03-02");
+        save_week(&conn, "2026-03-09");
+
+        // Edits after the weekly reviews were saved don't move the roll-up
+        conn.execute(
+            "UPDATE daily_log SET final_score = 10.0 WHERE date = '2026-03-09'",
+            [],
+        )
+        .unwrap();
+
+        let saved = save_periodic_review_impl(&conn, month_input(&[])).unwrap();
+        assert_eq!(saved.weeks_reviewed, Some(2));
+        assert_eq!(saved.best_week_start.as_deref(), Some("2026-03-09"));
+        assert_eq!(saved.best_week_score, Some(90.0));
+        assert_eq!(saved.worst_week_start.as_deref(), Some("2026-03-02"));
+        assert!((saved.avg_weekly_score.unwrap() - 75.0).abs() < 0.01);
+
+        let weeks: Vec<ReviewedWeek> =
+            serde_json::from_str(saved.week_snapshot.as_ref().unwrap()).unwrap();
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].week_start, "2026-03-02");
+
+        // The daily stats are live at save time
+        assert_eq!(saved.days_tracked, Some(3));
+    }
+
+    #[test]
+    fn test_save_periodic_review_freezes_snapshot() {
+        let conn = setup_test_db();
+        insert_daily_log(&conn, "2026-03-01", 75.0);
+
+        let saved = save_periodic_review_impl(
+            &conn,
+            month_input(&[("biggest_win", "Shipped the project")]),
+        )
+        .unwrap();
+        let snapshot: Vec<serde_json::Value> =
+            serde_json::from_str(saved.score_snapshot.as_ref().unwrap()).unwrap();
+        assert_eq!(snapshot.len(), 31);
+        assert!((snapshot[0].as_f64().unwrap() - 75.0).abs() < 0.01);
+        assert!(snapshot[1].is_null());
+        assert_eq!(saved.reflections["biggest_win"], "Shipped the project");
+
+        conn.execute(
+            "UPDATE daily_log SET final_score = 99.0 WHERE date = '2026-03-01'",
+            [],
+        )
+        .unwrap();
+
+        let reloaded = get_periodic_review_impl(&conn, ReviewPeriod::Month, "2026-03-01")
+            .unwrap()
+            .unwrap();
+        assert_eq!(reloaded.score_snapshot, saved.score_snapshot);
+        assert_eq!(reloaded.avg_score, Some(75.0));
+    }
+
+    #[test]
+    fn test_save_periodic_review_updates_existing() {
+        let conn = setup_test_db();
+        let first = save_periodic_review_impl(&conn, month_input(&[("reflection", "v1")])).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let second =
+            save_periodic_review_impl(&conn, month_input(&[("reflection", "v2")])).unwrap();
+
+        assert_eq!(second.logged_at, first.logged_at);
+        assert_ne!(second.last_modified, first.last_modified);
+        assert_eq!(second.reflections["reflection"], "v2");
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM periodic_review", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_save_periodic_review_rejects_other_periods_prompts() {
+        let conn = setup_test_db();
+        let err = save_periodic_review_impl(&conn, month_input(&[("next_year_theme", "Focus")]))
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown month review prompt"));
+
+        let mut misaligned = month_input(&[]);
+        misaligned.period_start = "2026-03-15".to_string();
+        assert!(save_periodic_review_impl(&conn, misaligned).is_err());
+    }
+
+    #[test]
+    fn test_periodic_review_is_searchable() {
+        let conn = setup_test_db();
+        let mut input = month_input(&[("lessons", "Sleep matters")]);
+        input.period = ReviewPeriod::Year;
+        input.period_start = "2026-01-01".to_string();
+        save_periodic_review_impl(&conn, input).unwrap();
+
+        let (title, body): (String, String) = conn
+            .query_row(
+                "SELECT title, body FROM search_index WHERE entity_type = 'periodic_review'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(title, "2026 review");
+        assert_eq!(body, "Sleep matters");
+    }
+}
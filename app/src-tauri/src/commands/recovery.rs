@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::AppState;
 
+use crate::engine::recovery_patterns::{link_score, minutes_between, trigger_matches};
+
 use super::validation::validate_text_length;
 use super::{CommandError, CommandResult};
 
@@ -82,6 +84,72 @@ pub struct UrgeEntryInput {
     pub notes: String,
 }
 
+/// Which values reads of relapse/urge entries return.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryView {
+    /// The rows as logged (and edited within the correction window).
+    #[default]
+    Original,
+    /// The rows with the latest amendment of each field applied.
+    Amended,
+}
+
+impl RecoveryView {
+    /// Table or view to read relapse entries from.
+    pub(crate) fn relapse_table(self) -> &'static str {
+        match self {
+            RecoveryView::Original => "relapse_entry",
+            RecoveryView::Amended => "relapse_entry_effective",
+        }
+    }
+
+    /// Table or view to read urge entries from.
+    pub(crate) fn urge_table(self) -> &'static str {
+        match self {
+            RecoveryView::Original => "urge_entry",
+            RecoveryView::Amended => "urge_entry_effective",
+        }
+    }
+}
+
+/// One recovery_amendment row: a correction to one field of a locked entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryAmendment {
+    pub id: i64,
+    /// `relapse_entry` or `urge_entry`.
+    pub entity: String,
+    pub entry_id: i64,
+    pub field: String,
+    /// Effective value before this amendment.
+    pub old_value: String,
+    pub new_value: String,
+    pub reason: String,
+    pub created_at: String,
+}
+
+/// A relapse or urge entry as logged, its amendments (oldest first), and the
+/// entry with those amendments applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendedEntry<T> {
+    pub original: T,
+    pub amendments: Vec<RecoveryAmendment>,
+    pub effective: T,
+}
+
+/// A candidate urge for a relapse's `urge_entry_id`, from `suggest_urge_links`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrgeLinkSuggestion {
+    pub urge: UrgeEntry,
+    /// Minutes from the urge to the relapse.
+    pub minutes_before: i64,
+    pub trigger_match: bool,
+    /// `link_score` of the above; higher is a likelier link.
+    pub score: f64,
+    /// The relapse already links to this urge.
+    pub is_current: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Column Constants & Row Mappers
 // ---------------------------------------------------------------------------
@@ -95,6 +163,9 @@ const URGE_ENTRY_COLUMNS: &str = "\
     id, date, time, intensity, technique, effectiveness, \
     duration, did_pass, trigger, notes, created_at, last_modified";
 
+const RECOVERY_AMENDMENT_COLUMNS: &str = "\
+    id, entity, entry_id, field, old_value, new_value, reason, created_at";
+
 fn row_to_relapse_entry(row: &rusqlite::Row) -> rusqlite::Result<RelapseEntry> {
     Ok(RelapseEntry {
         id: row.get("id")?,
@@ -132,6 +203,19 @@ fn row_to_urge_entry(row: &rusqlite::Row) -> rusqlite::Result<UrgeEntry> {
     })
 }
 
+fn row_to_recovery_amendment(row: &rusqlite::Row) -> rusqlite::Result<RecoveryAmendment> {
+    Ok(RecoveryAmendment {
+        id: row.get("id")?,
+        entity: row.get("entity")?,
+        entry_id: row.get("entry_id")?,
+        field: row.get("field")?,
+        old_value: row.get("old_value")?,
+        new_value: row.get("new_value")?,
+        reason: row.get("reason")?,
+        created_at: row.get("created_at")?,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Query Helpers
 // ---------------------------------------------------------------------------
@@ -162,11 +246,11 @@ fn query_urge_entry_by_id(
         .map_err(CommandError::from)
 }
 
-/// Check whether an entry is within the 24-hour correction window.
+/// Whether an entry is still within the 24-hour correction window.
 ///
 /// Per ADR-006 SD5, the window is measured from `created_at` (ISO 8601 / RFC 3339),
-/// not from the `date` field. Returns `Ok(())` if editable, or `Err` if locked.
-fn check_correction_window(created_at: &str, entity_name: &str) -> CommandResult<()> {
+/// not from the `date` field.
+fn within_correction_window(created_at: &str) -> CommandResult<bool> {
     let created = chrono::DateTime::parse_from_rfc3339(created_at).map_err(|e| {
         CommandError::from(format!(
             "Failed to parse created_at timestamp '{}': {}",
@@ -176,8 +260,12 @@ fn check_correction_window(created_at: &str, entity_name: &str) -> CommandResult
 
     let now = chrono::Utc::now();
     let elapsed = now.signed_duration_since(created);
+    Ok(elapsed <= chrono::Duration::hours(24))
+}
 
-    if elapsed > chrono::Duration::hours(24) {
+/// Returns `Ok(())` if the entry is editable, or `Err` if locked.
+fn check_correction_window(created_at: &str, entity_name: &str) -> CommandResult<()> {
+    if !within_correction_window(created_at)? {
         return Err(CommandError::from(format!(
             "{} locked: the 24-hour correction window has expired",
             entity_name
@@ -196,23 +284,28 @@ pub fn get_relapse_entries(
     state: tauri::State<'_, AppState>,
     start: String,
     end: String,
+    view: Option<RecoveryView>,
 ) -> CommandResult<Vec<RelapseEntry>> {
     let db = state
         .db
         .lock()
         .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_relapse_entries_impl(&db, &start, &end)
+    get_relapse_entries_impl(&db, &start, &end, view.unwrap_or_default())
 }
 
-fn get_relapse_entries_impl(
+/// Relapse entries dated `[start, end]`, newest first. With
+/// `RecoveryView::Amended`, dates and values are the amended ones.
+pub(crate) fn get_relapse_entries_impl(
     conn: &Connection,
     start: &str,
     end: &str,
+    view: RecoveryView,
 ) -> CommandResult<Vec<RelapseEntry>> {
     let sql = format!(
-        "SELECT {} FROM relapse_entry WHERE date >= ?1 AND date <= ?2 \
+        "SELECT {} FROM {} WHERE date >= ?1 AND date <= ?2 \
          ORDER BY date DESC, time DESC",
-        RELAPSE_ENTRY_COLUMNS
+        RELAPSE_ENTRY_COLUMNS,
+        view.relapse_table()
     );
     let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params![start, end], row_to_relapse_entry)?;
@@ -250,7 +343,7 @@ fn validate_urge_input(entry: &UrgeEntryInput) -> CommandResult<()> {
     Ok(())
 }
 
-fn save_relapse_entry_impl(
+pub(crate) fn save_relapse_entry_impl(
     conn: &Connection,
     entry: RelapseEntryInput,
 ) -> CommandResult<RelapseEntry> {
@@ -300,7 +393,7 @@ pub fn update_relapse_entry(
     update_relapse_entry_impl(&db, id, entry)
 }
 
-fn update_relapse_entry_impl(
+pub(crate) fn update_relapse_entry_impl(
     conn: &Connection,
     id: i64,
     entry: RelapseEntryInput,
@@ -364,23 +457,28 @@ pub fn get_urge_entries(
     state: tauri::State<'_, AppState>,
     start: String,
     end: String,
+    view: Option<RecoveryView>,
 ) -> CommandResult<Vec<UrgeEntry>> {
     let db = state
         .db
         .lock()
         .map_err(|_| CommandError::from("DB lock poisoned"))?;
-    get_urge_entries_impl(&db, &start, &end)
+    get_urge_entries_impl(&db, &start, &end, view.unwrap_or_default())
 }
 
-fn get_urge_entries_impl(
+/// Urge entries dated `[start, end]`, newest first. With
+/// `RecoveryView::Amended`, dates and values are the amended ones.
+pub(crate) fn get_urge_entries_impl(
     conn: &Connection,
     start: &str,
     end: &str,
+    view: RecoveryView,
 ) -> CommandResult<Vec<UrgeEntry>> {
     let sql = format!(
-        "SELECT {} FROM urge_entry WHERE date >= ?1 AND date <= ?2 \
+        "SELECT {} FROM {} WHERE date >= ?1 AND date <= ?2 \
          ORDER BY date DESC, time DESC",
-        URGE_ENTRY_COLUMNS
+        URGE_ENTRY_COLUMNS,
+        view.urge_table()
     );
     let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params![start, end], row_to_urge_entry)?;
@@ -400,7 +498,7 @@ pub fn save_urge_entry(
     save_urge_entry_impl(&db, entry)
 }
 
-fn save_urge_entry_impl(
+pub(crate) fn save_urge_entry_impl(
     conn: &Connection,
     entry: UrgeEntryInput,
 ) -> CommandResult<UrgeEntry> {
@@ -446,7 +544,7 @@ pub fn update_urge_entry(
     update_urge_entry_impl(&db, id, entry)
 }
 
-fn update_urge_entry_impl(
+pub(crate) fn update_urge_entry_impl(
     conn: &Connection,
     id: i64,
     entry: UrgeEntryInput,
@@ -496,6 +594,393 @@ fn update_urge_entry_impl(
         .ok_or_else(|| CommandError::from("Failed to read back updated urge entry"))
 }
 
+// ---------------------------------------------------------------------------
+// Urge Links
+// ---------------------------------------------------------------------------
+
+/// Id of the relapse other than `relapse_id` that links to `urge_id`, if any.
+fn query_other_linked_relapse(
+    conn: &Connection,
+    urge_id: i64,
+    relapse_id: i64,
+) -> CommandResult<Option<i64>> {
+    conn.query_row(
+        "SELECT id FROM relapse_entry WHERE urge_entry_id = ?1 AND id != ?2",
+        params![urge_id, relapse_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn suggest_urge_links(
+    state: tauri::State<'_, AppState>,
+    relapse_id: i64,
+) -> CommandResult<Vec<UrgeLinkSuggestion>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    suggest_urge_links_impl(&db, relapse_id)
+}
+
+/// Same-day urges at or before the relapse, best candidate first. Ranked by
+/// time proximity plus a bonus when the urge's trigger matches. Urges that
+/// another relapse already links to are left out.
+pub(crate) fn suggest_urge_links_impl(
+    conn: &Connection,
+    relapse_id: i64,
+) -> CommandResult<Vec<UrgeLinkSuggestion>> {
+    let relapse = query_relapse_entry_by_id(conn, relapse_id)?.ok_or_else(|| {
+        CommandError::from(format!("Relapse entry with id {} not found", relapse_id))
+    })?;
+
+    let sql = format!(
+        "SELECT {} FROM urge_entry WHERE date = ?1 AND time <= ?2 \
+         AND id NOT IN (SELECT urge_entry_id FROM relapse_entry \
+                        WHERE urge_entry_id IS NOT NULL AND id != ?3)",
+        URGE_ENTRY_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let urges = stmt
+        .query_map(params![relapse.date, relapse.time, relapse_id], row_to_urge_entry)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)?;
+
+    let mut suggestions: Vec<UrgeLinkSuggestion> = urges
+        .into_iter()
+        .filter_map(|urge| {
+            let minutes_before =
+                minutes_between(&urge.date, &urge.time, &relapse.date, &relapse.time)?;
+            let trigger_match = trigger_matches(&urge.trigger, &relapse.trigger);
+            Some(UrgeLinkSuggestion {
+                minutes_before,
+                trigger_match,
+                score: link_score(minutes_before, trigger_match),
+                is_current: relapse.urge_entry_id == Some(urge.id),
+                urge,
+            })
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.minutes_before.cmp(&b.minutes_before))
+    });
+    Ok(suggestions)
+}
+
+#[tauri::command]
+pub fn link_relapse_urge(
+    state: tauri::State<'_, AppState>,
+    relapse_id: i64,
+    urge_id: Option<i64>,
+) -> CommandResult<RelapseEntry> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    link_relapse_urge_impl(&db, relapse_id, urge_id)
+}
+
+/// Set (or clear, with `None`) the urge a relapse followed. Changing the link
+/// edits the relapse, so it is subject to the relapse's 24-hour correction
+/// window (ADR-006). The urge must come no later than the relapse and must
+/// not already be linked to another relapse.
+pub(crate) fn link_relapse_urge_impl(
+    conn: &Connection,
+    relapse_id: i64,
+    urge_id: Option<i64>,
+) -> CommandResult<RelapseEntry> {
+    let relapse = query_relapse_entry_by_id(conn, relapse_id)?.ok_or_else(|| {
+        CommandError::from(format!("Relapse entry with id {} not found", relapse_id))
+    })?;
+    check_correction_window(&relapse.created_at, "Relapse entry")?;
+
+    if let Some(urge_id) = urge_id {
+        let urge = query_urge_entry_by_id(conn, urge_id)?.ok_or_else(|| {
+            CommandError::from(format!("Urge entry with id {} not found", urge_id))
+        })?;
+        let minutes = minutes_between(&urge.date, &urge.time, &relapse.date, &relapse.time)
+            .ok_or_else(|| CommandError::from("Invalid urge or relapse date/time"))?;
+        if minutes < 0 {
+            return Err(CommandError::from(
+                "Cannot link an urge that happened after the relapse",
+            ));
+        }
+        if let Some(other) = query_other_linked_relapse(conn, urge_id, relapse_id)? {
+            return Err(CommandError::from(format!(
+                "Urge entry {} is already linked to relapse entry {}",
+                urge_id, other
+            )));
+        }
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "UPDATE relapse_entry SET urge_entry_id = ?2, last_modified = ?3 WHERE id = ?1",
+        params![relapse_id, urge_id, &now],
+    )?;
+
+    query_relapse_entry_by_id(conn, relapse_id)?
+        .ok_or_else(|| CommandError::from("Failed to read back updated relapse entry"))
+}
+
+// ---------------------------------------------------------------------------
+// Amendments
+// ---------------------------------------------------------------------------
+
+/// Entities `add_recovery_amendment` accepts.
+pub(crate) const RECOVERY_AMENDMENT_ENTITIES: [&str; 2] = ["relapse_entry", "urge_entry"];
+
+/// Amendable relapse_entry fields. `urge_entry_id` has its own command
+/// (`link_relapse_urge`).
+pub(crate) const RELAPSE_AMENDABLE_FIELDS: [&str; 11] = [
+    "date",
+    "time",
+    "duration",
+    "trigger",
+    "location",
+    "device",
+    "activity_before",
+    "emotional_state",
+    "resistance_technique",
+    "urge_intensity",
+    "notes",
+];
+
+pub(crate) const URGE_AMENDABLE_FIELDS: [&str; 9] = [
+    "date",
+    "time",
+    "intensity",
+    "technique",
+    "effectiveness",
+    "duration",
+    "did_pass",
+    "trigger",
+    "notes",
+];
+
+/// Validate an amended value against the rules of its column. Returns the
+/// value as stored: dates and times zero-padded, numbers trimmed.
+fn normalize_amendment_value(field: &str, value: &str) -> CommandResult<String> {
+    let in_range = |min: i64, max: i64| -> CommandResult<String> {
+        match value.trim().parse::<i64>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n.to_string()),
+            _ => Err(CommandError::from(format!(
+                "{} must be a whole number from {} to {}",
+                field, min, max
+            ))),
+        }
+    };
+    match field {
+        "date" => chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .map_err(|_| {
+                CommandError::from(format!("Invalid date '{}': expected YYYY-MM-DD", value))
+            }),
+        "time" => chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M")
+            .map(|t| t.format("%H:%M").to_string())
+            .map_err(|_| CommandError::from(format!("Invalid time '{}': expected HH:MM", value))),
+        "urge_intensity" | "intensity" => in_range(1, 10),
+        "effectiveness" => in_range(1, 5),
+        "notes" => validate_text_length("Notes", value, 2000).map(|_| value.to_string()),
+        _ => validate_text_length(field, value, 500).map(|_| value.to_string()),
+    }
+}
+
+/// Amendments of one entry, oldest first.
+fn query_recovery_amendments(
+    conn: &Connection,
+    entity: &str,
+    entry_id: i64,
+) -> CommandResult<Vec<RecoveryAmendment>> {
+    let sql = format!(
+        "SELECT {} FROM recovery_amendment WHERE entity = ?1 AND entry_id = ?2 \
+         ORDER BY id ASC",
+        RECOVERY_AMENDMENT_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(params![entity, entry_id], row_to_recovery_amendment)?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn add_recovery_amendment(
+    state: tauri::State<'_, AppState>,
+    entity: String,
+    id: i64,
+    field: String,
+    new_value: String,
+    reason: String,
+) -> CommandResult<RecoveryAmendment> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    add_recovery_amendment_impl(&db, &entity, id, &field, &new_value, &reason)
+}
+
+/// Append a correction to one field of a locked relapse or urge entry. The
+/// entry itself is never changed (ADR-006); reads in `RecoveryView::Amended`
+/// pick the correction up. Entries still within their correction window
+/// must be edited directly instead.
+pub(crate) fn add_recovery_amendment_impl(
+    conn: &Connection,
+    entity: &str,
+    id: i64,
+    field: &str,
+    new_value: &str,
+    reason: &str,
+) -> CommandResult<RecoveryAmendment> {
+    let (fields, label): (&[&str], &str) = match entity {
+        "relapse_entry" => (&RELAPSE_AMENDABLE_FIELDS, "Relapse entry"),
+        "urge_entry" => (&URGE_AMENDABLE_FIELDS, "Urge entry"),
+        _ => {
+            return Err(CommandError::from(format!(
+                "Invalid entity '{}'. Must be one of: {}",
+                entity,
+                RECOVERY_AMENDMENT_ENTITIES.join(", ")
+            )))
+        }
+    };
+    if !fields.contains(&field) {
+        return Err(CommandError::from(format!(
+            "Field '{}' of {} cannot be amended. Must be one of: {}",
+            field,
+            entity,
+            fields.join(", ")
+        )));
+    }
+    let new_value = normalize_amendment_value(field, new_value)?;
+    if reason.trim().is_empty() {
+        return Err(CommandError::from("An amendment needs a reason"));
+    }
+    validate_text_length("Reason", reason, 500)?;
+
+    let created_at: String = conn
+        .query_row(
+            &format!("SELECT created_at FROM {} WHERE id = ?1", entity),
+            [id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or_else(|| CommandError::from(format!("{} with id {} not found", label, id)))?;
+    if within_correction_window(&created_at)? {
+        return Err(CommandError::from(format!(
+            "{} is still within its 24-hour correction window; edit it directly",
+            label
+        )));
+    }
+
+    // `field` is from the allow-list above, so it is safe to interpolate.
+    let effective_table = if entity == "relapse_entry" {
+        RecoveryView::Amended.relapse_table()
+    } else {
+        RecoveryView::Amended.urge_table()
+    };
+    let old_value: String = conn.query_row(
+        &format!(
+            "SELECT CAST({} AS TEXT) FROM {} WHERE id = ?1",
+            field, effective_table
+        ),
+        [id],
+        |row| row.get(0),
+    )?;
+    if old_value == new_value {
+        return Err(CommandError::from(format!(
+            "{} is already '{}'",
+            field, new_value
+        )));
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO recovery_amendment (\
+         entity, entry_id, field, old_value, new_value, reason, created_at\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![entity, id, field, old_value, new_value, reason.trim(), &now],
+    )?;
+
+    let amendment_id = conn.last_insert_rowid();
+    let sql = format!(
+        "SELECT {} FROM recovery_amendment WHERE id = ?1",
+        RECOVERY_AMENDMENT_COLUMNS
+    );
+    conn.query_row(&sql, [amendment_id], row_to_recovery_amendment)
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub fn get_amended_relapse_entry(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+) -> CommandResult<Option<AmendedEntry<RelapseEntry>>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_amended_relapse_entry_impl(&db, id)
+}
+
+/// A relapse entry as logged, its amendments, and its effective values.
+pub(crate) fn get_amended_relapse_entry_impl(
+    conn: &Connection,
+    id: i64,
+) -> CommandResult<Option<AmendedEntry<RelapseEntry>>> {
+    let original = match query_relapse_entry_by_id(conn, id)? {
+        Some(entry) => entry,
+        None => return Ok(None),
+    };
+    let sql = format!(
+        "SELECT {} FROM {} WHERE id = ?1",
+        RELAPSE_ENTRY_COLUMNS,
+        RecoveryView::Amended.relapse_table()
+    );
+    let effective = conn.query_row(&sql, [id], row_to_relapse_entry)?;
+    Ok(Some(AmendedEntry {
+        original,
+        amendments: query_recovery_amendments(conn, "relapse_entry", id)?,
+        effective,
+    }))
+}
+
+#[tauri::command]
+pub fn get_amended_urge_entry(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+) -> CommandResult<Option<AmendedEntry<UrgeEntry>>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_amended_urge_entry_impl(&db, id)
+}
+
+/// An urge entry as logged, its amendments, and its effective values.
+pub(crate) fn get_amended_urge_entry_impl(
+    conn: &Connection,
+    id: i64,
+) -> CommandResult<Option<AmendedEntry<UrgeEntry>>> {
+    let original = match query_urge_entry_by_id(conn, id)? {
+        Some(entry) => entry,
+        None => return Ok(None),
+    };
+    let sql = format!(
+        "SELECT {} FROM {} WHERE id = ?1",
+        URGE_ENTRY_COLUMNS,
+        RecoveryView::Amended.urge_table()
+    );
+    let effective = conn.query_row(&sql, [id], row_to_urge_entry)?;
+    Ok(Some(AmendedEntry {
+        original,
+        amendments: query_recovery_amendments(conn, "urge_entry", id)?,
+        effective,
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -711,8 +1196,13 @@ mod tests {
         save_relapse_entry_impl(&conn, input3).unwrap();
 
         // Query range that includes only Feb 15-18
-        let results =
-            get_relapse_entries_impl(&conn, "2026-02-15", "2026-02-20").unwrap();
+        let results = get_relapse_entries_impl(
+            &conn,
+            "2026-02-15",
+            "2026-02-20",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert_eq!(results.len(), 2);
     }
 
@@ -735,8 +1225,13 @@ mod tests {
         input3.time = "22:00".to_string();
         save_relapse_entry_impl(&conn, input3).unwrap();
 
-        let results =
-            get_relapse_entries_impl(&conn, "2026-02-01", "2026-02-28").unwrap();
+        let results = get_relapse_entries_impl(
+            &conn,
+            "2026-02-01",
+            "2026-02-28",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert_eq!(results.len(), 3);
         // date DESC, time DESC
         assert_eq!(results[0].date, "2026-02-18");
@@ -749,8 +1244,13 @@ mod tests {
     #[test]
     fn test_get_relapse_entries_empty_range() {
         let conn = setup_test_db();
-        let results =
-            get_relapse_entries_impl(&conn, "2026-03-01", "2026-03-31").unwrap();
+        let results = get_relapse_entries_impl(
+            &conn,
+            "2026-03-01",
+            "2026-03-31",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert!(results.is_empty());
     }
 
@@ -818,8 +1318,13 @@ mod tests {
         input3.date = "2026-02-25".to_string();
         save_urge_entry_impl(&conn, input3).unwrap();
 
-        let results =
-            get_urge_entries_impl(&conn, "2026-02-15", "2026-02-20").unwrap();
+        let results = get_urge_entries_impl(
+            &conn,
+            "2026-02-15",
+            "2026-02-20",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert_eq!(results.len(), 2);
     }
 
@@ -842,8 +1347,13 @@ mod tests {
         input3.time = "22:00".to_string();
         save_urge_entry_impl(&conn, input3).unwrap();
 
-        let results =
-            get_urge_entries_impl(&conn, "2026-02-01", "2026-02-28").unwrap();
+        let results = get_urge_entries_impl(
+            &conn,
+            "2026-02-01",
+            "2026-02-28",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert_eq!(results.len(), 3);
         assert_eq!(results[0].date, "2026-02-18");
         assert_eq!(results[0].time, "22:00");
@@ -855,8 +1365,13 @@ mod tests {
     #[test]
     fn test_get_urge_entries_empty_range() {
         let conn = setup_test_db();
-        let results =
-            get_urge_entries_impl(&conn, "2026-03-01", "2026-03-31").unwrap();
+        let results = get_urge_entries_impl(
+            &conn,
+            "2026-03-01",
+            "2026-03-31",
+            RecoveryView::Original,
+        )
+        .unwrap();
         assert!(results.is_empty());
     }
 
@@ -1061,4 +1576,180 @@ mod tests {
         let result = save_relapse_entry_impl(&conn, input);
         assert!(result.is_err());
     }
+
+    // -----------------------------------------------------------------------
+    // G. Urge Link Tests
+    // -----------------------------------------------------------------------
+
+    fn save_urge_at(conn: &Connection, time: &str, trigger: &str) -> UrgeEntry {
+        let mut input = make_urge_input();
+        input.time = time.to_string();
+        input.trigger = trigger.to_string();
+        save_urge_entry_impl(conn, input).unwrap()
+    }
+
+    #[test]
+    fn test_suggest_urge_links_ranking() {
+        let conn = setup_test_db();
+        // Relapse at 23:30 triggered by Boredom
+        let relapse = save_relapse_entry_impl(&conn, make_relapse_input()).unwrap();
+        let close = save_urge_at(&conn, "23:00", "tired");
+        let bored = save_urge_at(&conn, "20:00", "bored, then boredom");
+        let early = save_urge_at(&conn, "08:00", "");
+        save_urge_at(&conn, "23:45", "after the relapse");
+
+        let mut other_day = make_urge_input();
+        other_day.date = "2026-02-17".to_string();
+        save_urge_entry_impl(&conn, other_day).unwrap();
+
+        let suggestions = suggest_urge_links_impl(&conn, relapse.id).unwrap();
+        let ids: Vec<i64> = suggestions.iter().map(|s| s.urge.id).collect();
+        // Trigger match outweighs three and a half hours
+        assert_eq!(ids, vec![bored.id, close.id, early.id]);
+        assert!(suggestions[0].trigger_match);
+        assert_eq!(suggestions[1].minutes_before, 30);
+        assert!(!suggestions[1].is_current);
+
+        assert!(suggest_urge_links_impl(&conn, 999).is_err());
+    }
+
+    #[test]
+    fn test_link_relapse_urge() {
+        let conn = setup_test_db();
+        let relapse = save_relapse_entry_impl(&conn, make_relapse_input()).unwrap();
+        let urge = save_urge_at(&conn, "22:00", "");
+        let later = save_urge_at(&conn, "23:50", "");
+
+        let linked = link_relapse_urge_impl(&conn, relapse.id, Some(urge.id)).unwrap();
+        assert_eq!(linked.urge_entry_id, Some(urge.id));
+        assert!(suggest_urge_links_impl(&conn, relapse.id).unwrap()[0].is_current);
+
+        let err = link_relapse_urge_impl(&conn, relapse.id, Some(later.id)).unwrap_err();
+        assert!(err.to_string().contains("after the relapse"), "{}", err);
+        assert!(link_relapse_urge_impl(&conn, relapse.id, Some(999)).is_err());
+
+        // An urge can precede only one relapse
+        let second = save_relapse_entry_impl(&conn, make_relapse_input()).unwrap();
+        let err = link_relapse_urge_impl(&conn, second.id, Some(urge.id)).unwrap_err();
+        assert!(err.to_string().contains("already linked"), "{}", err);
+        assert!(suggest_urge_links_impl(&conn, second.id).unwrap().is_empty());
+
+        let cleared = link_relapse_urge_impl(&conn, relapse.id, None).unwrap();
+        assert_eq!(cleared.urge_entry_id, None);
+    }
+
+    #[test]
+    fn test_link_relapse_urge_respects_correction_window() {
+        let conn = setup_test_db();
+        let old = (chrono::Utc::now() - chrono::Duration::hours(25)).to_rfc3339();
+        let relapse_id = insert_relapse_with_created_at(&conn, &old);
+        let urge = save_urge_at(&conn, "22:00", "");
+
+        let err = link_relapse_urge_impl(&conn, relapse_id, Some(urge.id)).unwrap_err();
+        assert!(err.to_string().contains("locked"), "{}", err);
+        // Suggestions stay available for a locked relapse
+        assert_eq!(suggest_urge_links_impl(&conn, relapse_id).unwrap().len(), 1);
+    }
+
+    // -----------------------------------------------------------------------
+    // H. Amendment Tests
+    // -----------------------------------------------------------------------
+
+    fn locked_ts() -> String {
+        (chrono::Utc::now() - chrono::Duration::hours(25)).to_rfc3339()
+    }
+
+    #[test]
+    fn test_amend_locked_relapse_keeps_original() {
+        let conn = setup_test_db();
+        let id = insert_relapse_with_created_at(&conn, &locked_ts());
+
+        let first =
+            add_recovery_amendment_impl(&conn, "relapse_entry", id, "date", "2026-2-20", "Typo")
+                .unwrap();
+        assert_eq!(first.old_value, "2026-02-18");
+        assert_eq!(first.new_value, "2026-02-20", "stored zero-padded");
+        let second = add_recovery_amendment_impl(
+            &conn,
+            "relapse_entry",
+            id,
+            "date",
+            "2026-02-21",
+            "Still wrong",
+        )
+        .unwrap();
+        assert_eq!(second.old_value, "2026-02-20", "chains from the last amendment");
+        add_recovery_amendment_impl(&conn, "relapse_entry", id, "urge_intensity", " 9 ", "x")
+            .unwrap();
+
+        let record = get_amended_relapse_entry_impl(&conn, id).unwrap().unwrap();
+        assert_eq!(record.original.date, "2026-02-18");
+        assert_eq!(record.original.urge_intensity, 7);
+        assert_eq!(record.amendments.len(), 3);
+        assert_eq!(record.effective.date, "2026-02-21");
+        assert_eq!(record.effective.urge_intensity, 9);
+        assert_eq!(record.effective.created_at, record.original.created_at);
+
+        // The row itself is untouched.
+        let row = query_relapse_entry_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(row.date, "2026-02-18");
+        assert_eq!(row.last_modified, record.original.last_modified);
+
+        assert!(get_amended_relapse_entry_impl(&conn, 9999).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_amendment_validation() {
+        let conn = setup_test_db();
+        let fresh = save_urge_entry_impl(&conn, make_urge_input()).unwrap();
+        let err = add_recovery_amendment_impl(&conn, "urge_entry", fresh.id, "notes", "x", "y")
+            .unwrap_err();
+        assert!(err.to_string().contains("edit it directly"), "{}", err);
+
+        let id = insert_urge_with_created_at(&conn, &locked_ts());
+        let add = |entity: &str, field: &str, value: &str, reason: &str| {
+            add_recovery_amendment_impl(&conn, entity, id, field, value, reason)
+        };
+        assert!(add("journal", "notes", "x", "y").is_err());
+        assert!(add("urge_entry", "created_at", "x", "y").is_err());
+        assert!(add("urge_entry", "urge_intensity", "5", "y").is_err(), "relapse field");
+        assert!(add("urge_entry", "date", "18/02/2026", "y").is_err());
+        assert!(add("urge_entry", "time", "25:00", "y").is_err());
+        assert!(add("urge_entry", "intensity", "11", "y").is_err());
+        assert!(add("urge_entry", "effectiveness", "high", "y").is_err());
+        assert!(add("urge_entry", "notes", "x", "  ").is_err(), "reason required");
+        assert!(add("urge_entry", "intensity", "6", "y").is_err(), "unchanged value");
+        assert!(
+            add_recovery_amendment_impl(&conn, "urge_entry", 9999, "notes", "x", "y").is_err()
+        );
+
+        add("urge_entry", "intensity", "8", "Misread scale").unwrap();
+        let record = get_amended_urge_entry_impl(&conn, id).unwrap().unwrap();
+        assert_eq!((record.original.intensity, record.effective.intensity), (6, 8));
+        assert_eq!(record.amendments[0].reason, "Misread scale");
+    }
+
+    #[test]
+    fn test_entry_lists_by_view() {
+        let conn = setup_test_db();
+        let id = insert_relapse_with_created_at(&conn, &locked_ts());
+        add_recovery_amendment_impl(&conn, "relapse_entry", id, "date", "2026-03-02", "Wrong day")
+            .unwrap();
+
+        let feb = |view| get_relapse_entries_impl(&conn, "2026-02-01", "2026-02-28", view);
+        let mar = |view| get_relapse_entries_impl(&conn, "2026-03-01", "2026-03-31", view);
+        assert_eq!(feb(RecoveryView::Original).unwrap().len(), 1);
+        assert!(mar(RecoveryView::Original).unwrap().is_empty());
+        assert!(feb(RecoveryView::Amended).unwrap().is_empty());
+        let moved = mar(RecoveryView::Amended).unwrap();
+        assert_eq!((moved[0].id, moved[0].date.as_str()), (id, "2026-03-02"));
+
+        let urge_id = insert_urge_with_created_at(&conn, &locked_ts());
+        add_recovery_amendment_impl(&conn, "urge_entry", urge_id, "did_pass", "No", "Gave in")
+            .unwrap();
+        let urges =
+            get_urge_entries_impl(&conn, "2026-02-01", "2026-02-28", RecoveryView::Amended)
+                .unwrap();
+        assert_eq!(urges[0].did_pass, "No");
+    }
 }
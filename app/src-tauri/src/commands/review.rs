@@ -59,10 +59,11 @@ pub struct WeeklyReviewInput {
     pub reflection: String,
 }
 
-/// Live-computed weekly stats (not stored in DB).
-/// Returned by `compute_weekly_stats` for the review UI preview.
+/// Live-computed stats for a date range (not stored in DB).
+/// Returned by `compute_weekly_stats` for the review UI preview, and frozen
+/// into weekly and periodic reviews when they are saved.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WeeklyStats {
+pub struct PeriodStats {
     pub avg_score: Option<f64>,
     pub days_tracked: i64,
     pub best_day_score: Option<f64>,
@@ -118,7 +119,7 @@ fn row_to_weekly_review(row: &rusqlite::Row) -> rusqlite::Result<WeeklyReview> {
 // Query Helpers
 // ---------------------------------------------------------------------------
 
-fn query_weekly_review_by_week_start(
+pub(crate) fn query_weekly_review_by_week_start(
     conn: &Connection,
     week_start: &str,
 ) -> CommandResult<Option<WeeklyReview>> {
@@ -133,7 +134,7 @@ fn query_weekly_review_by_week_start(
 
 /// Compute the week_end date (Sunday) from a week_start date (Monday).
 /// Uses SQLite date arithmetic: week_start + 6 days.
-fn compute_week_end(conn: &Connection, week_start: &str) -> CommandResult<String> {
+pub(crate) fn compute_week_end(conn: &Connection, week_start: &str) -> CommandResult<String> {
     conn.query_row(
         "SELECT date(?1, '+6 days')",
         [week_start],
@@ -142,12 +143,12 @@ fn compute_week_end(conn: &Connection, week_start: &str) -> CommandResult<String
     .map_err(CommandError::from)
 }
 
-/// Compute live weekly stats from the database (not saved).
-fn compute_weekly_stats_impl(
+/// Compute live stats for `[start, end]` from the database (not saved).
+pub(crate) fn compute_period_stats_impl(
     conn: &Connection,
-    week_start: &str,
-    week_end: &str,
-) -> CommandResult<WeeklyStats> {
+    start: &str,
+    end: &str,
+) -> CommandResult<PeriodStats> {
     // 1. Score stats + days tracked
     let (avg_score, min_score, max_score, days_tracked): (
         Option<f64>,
@@ -159,7 +160,7 @@ fn compute_weekly_stats_impl(
             "SELECT AVG(final_score) AS avg_score, MIN(final_score) AS min_score, \
              MAX(final_score) AS max_score, COUNT(*) AS day_count \
              FROM daily_log WHERE date >= ?1 AND date <= ?2 AND final_score IS NOT NULL",
-            params![week_start, week_end],
+            params![start, end],
             |row| Ok((row.get("avg_score")?, row.get("min_score")?, row.get("max_score")?, row.get("day_count")?)),
         )
         .map_err(CommandError::from)?;
@@ -169,7 +170,7 @@ fn compute_weekly_stats_impl(
         .query_row(
             "SELECT COALESCE(SUM(duration_minutes), 0) / 60.0 \
              FROM study_session WHERE date >= ?1 AND date <= ?2",
-            params![week_start, week_end],
+            params![start, end],
             |row| row.get(0),
         )
         .map_err(CommandError::from)?;
@@ -179,7 +180,7 @@ fn compute_weekly_stats_impl(
         .query_row(
             "SELECT COUNT(*) FROM application \
              WHERE date_applied >= ?1 AND date_applied <= ?2",
-            params![week_start, week_end],
+            params![start, end],
             |row| row.get(0),
         )
         .map_err(CommandError::from)?;
@@ -189,7 +190,7 @@ fn compute_weekly_stats_impl(
         .query_row(
             "SELECT COUNT(*) FROM relapse_entry \
              WHERE date >= ?1 AND date <= ?2",
-            params![week_start, week_end],
+            params![start, end],
             |row| row.get(0),
         )
         .map_err(CommandError::from)?;
@@ -199,25 +200,25 @@ fn compute_weekly_stats_impl(
         .query_row(
             "SELECT COUNT(*) FROM urge_entry \
              WHERE date >= ?1 AND date <= ?2 AND did_pass LIKE 'Yes%'",
-            params![week_start, week_end],
+            params![start, end],
             |row| row.get(0),
         )
         .map_err(CommandError::from)?;
 
-    // 6. Current streak: from last tracked day in the week
+    // 6. Current streak: from last tracked day in the range
     let current_streak: Option<i32> = conn
         .query_row(
             "SELECT streak FROM daily_log \
              WHERE date >= ?1 AND date <= ?2 AND streak IS NOT NULL \
              ORDER BY date DESC LIMIT 1",
-            params![week_start, week_end],
+            params![start, end],
             |row| row.get(0),
         )
         .optional()
         .map_err(CommandError::from)?
         .flatten();
 
-    Ok(WeeklyStats {
+    Ok(PeriodStats {
         avg_score,
         days_tracked,
         best_day_score: max_score,
@@ -230,13 +231,13 @@ fn compute_weekly_stats_impl(
     })
 }
 
-/// Count total good habit completions for the week.
+/// Count total good habit completions for `[start, end]`.
 /// A habit is "completed" if its column value > 0 (for checkbox/number)
 /// or is not 'None'/'' (for dropdown).
-fn compute_habits_completed(
+pub(crate) fn compute_habits_completed(
     conn: &Connection,
-    week_start: &str,
-    week_end: &str,
+    start: &str,
+    end: &str,
 ) -> CommandResult<i64> {
     // Query active good habits
     let mut stmt = conn.prepare(
@@ -264,7 +265,7 @@ fn compute_habits_completed(
                      WHERE date >= ?1 AND date <= ?2 AND {} != 'None' AND {} != ''",
                     quoted_col, quoted_col
                 ),
-                params![week_start, week_end],
+                params![start, end],
                 |row| row.get(0),
             )
             .map_err(CommandError::from)?
@@ -275,7 +276,7 @@ fn compute_habits_completed(
                      WHERE date >= ?1 AND date <= ?2 AND {} > 0",
                     quoted_col
                 ),
-                params![week_start, week_end],
+                params![start, end],
                 |row| row.get(0),
             )
             .map_err(CommandError::from)?
@@ -286,20 +287,26 @@ fn compute_habits_completed(
     Ok(total_completions)
 }
 
-/// Build the score snapshot: JSON array of 7 final_score values for Mon–Sun.
-/// Null for missing days.
-fn build_score_snapshot(
+/// Build the score snapshot: JSON array of final_score values, one per day
+/// from `start` to `end` (7 for a week, Mon–Sun). Null for missing days.
+pub(crate) fn build_score_snapshot(
     conn: &Connection,
-    week_start: &str,
-    week_end: &str,
+    start: &str,
+    end: &str,
 ) -> CommandResult<String> {
-    // Query all daily_log entries for the week
+    let parse = |d: &str| {
+        chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|e| CommandError::from(format!("Invalid date '{}': {}", d, e)))
+    };
+    let (start_date, end_date) = (parse(start)?, parse(end)?);
+
+    // Query all daily_log entries for the range
     let mut stmt = conn.prepare(
         "SELECT date, final_score FROM daily_log \
          WHERE date >= ?1 AND date <= ?2 ORDER BY date ASC",
     )?;
     let rows: Vec<(String, Option<f64>)> = stmt
-        .query_map(params![week_start, week_end], |row| {
+        .query_map(params![start, end], |row| {
             Ok((row.get("date")?, row.get("final_score")?))
         })?
         .collect::<Result<Vec<_>, _>>()
@@ -309,21 +316,15 @@ fn build_score_snapshot(
     let score_map: std::collections::HashMap<String, Option<f64>> =
         rows.into_iter().collect();
 
-    // Generate 7 dates Mon(+0) through Sun(+6)
-    let mut snapshot: Vec<serde_json::Value> = Vec::with_capacity(7);
-    for offset in 0..7 {
-        let date: String = conn
-            .query_row(
-                &format!("SELECT date(?1, '+{} days')", offset),
-                [week_start],
-                |row| row.get(0),
-            )
-            .map_err(CommandError::from)?;
-
-        match score_map.get(&date) {
+    // One entry per day, start through end
+    let mut snapshot: Vec<serde_json::Value> = Vec::new();
+    let mut day = start_date;
+    while day <= end_date {
+        match score_map.get(&day.format("%Y-%m-%d").to_string()) {
             Some(Some(score)) => snapshot.push(serde_json::json!(*score)),
             _ => snapshot.push(serde_json::Value::Null),
         }
+        day += chrono::Duration::days(1);
     }
 
     serde_json::to_string(&snapshot).map_err(CommandError::from)
@@ -392,13 +393,13 @@ pub fn get_weekly_review(
 pub fn compute_weekly_stats(
     state: tauri::State<'_, AppState>,
     week_start: String,
-) -> CommandResult<WeeklyStats> {
+) -> CommandResult<PeriodStats> {
     let db = state
         .db
         .lock()
         .map_err(|_| CommandError::from("DB lock poisoned"))?;
     let week_end = compute_week_end(&db, &week_start)?;
-    compute_weekly_stats_impl(&db, &week_start, &week_end)
+    compute_period_stats_impl(&db, &week_start, &week_end)
 }
 
 #[tauri::command]
@@ -413,7 +414,7 @@ pub fn save_weekly_review(
     save_weekly_review_impl(&db, review)
 }
 
-fn save_weekly_review_impl(
+pub(crate) fn save_weekly_review_impl(
     conn: &Connection,
     review: WeeklyReviewInput,
 ) -> CommandResult<WeeklyReview> {
@@ -431,7 +432,7 @@ fn save_weekly_review_impl(
             .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
 
         // 1. Compute stats
-        let stats = compute_weekly_stats_impl(&tx, &review.week_start, &week_end)?;
+        let stats = compute_period_stats_impl(&tx, &review.week_start, &week_end)?;
         let habits_completed =
             compute_habits_completed(&tx, &review.week_start, &week_end)?;
 
@@ -607,7 +608,7 @@ mod tests {
     #[test]
     fn test_compute_weekly_stats_empty_week() {
         let conn = setup_test_db();
-        let stats = compute_weekly_stats_impl(&conn, "2026-02-16", "2026-02-22").unwrap();
+        let stats = compute_period_stats_impl(&conn, "2026-02-16", "2026-02-22").unwrap();
         assert_eq!(stats.days_tracked, 0);
         assert!(stats.avg_score.is_none());
         assert!(stats.best_day_score.is_none());
@@ -676,7 +677,7 @@ mod tests {
         )
         .unwrap();
 
-        let stats = compute_weekly_stats_impl(&conn, "2026-02-16", "2026-02-22").unwrap();
+        let stats = compute_period_stats_impl(&conn, "2026-02-16", "2026-02-22").unwrap();
 
         assert_eq!(stats.days_tracked, 3);
         assert!((stats.avg_score.unwrap() - 75.0).abs() < 0.01);
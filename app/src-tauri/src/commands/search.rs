@@ -0,0 +1,344 @@
+use rusqlite::{types::ToSql, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+use super::validation::validate_text_length;
+use super::{CommandError, CommandResult};
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// Filter parameters for the search command.
+/// All fields are optional — `#[serde(default)]` allows passing `{}` from the frontend.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchFilters {
+    /// Restrict to these entity types (see `SEARCHABLE_ENTITIES`).
+    pub entity_types: Option<Vec<String>>,
+    /// Inclusive YYYY-MM-DD bounds on the entity's date.
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// One ranked search result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub date: String,
+    pub title: String,
+    /// Excerpt around the best match with hits wrapped in `<mark>…</mark>`.
+    pub snippet: String,
+    /// FTS5 bm25 rank — lower is more relevant.
+    pub rank: f64,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Entity types written to `search_index` by the migration 004 and 014
+/// triggers.
+const SEARCHABLE_ENTITIES: &[&str] = &[
+    "journal",
+    "study_session",
+    "application",
+    "status_change",
+    "urge_entry",
+    "relapse_entry",
+    "weekly_review",
+    "periodic_review",
+];
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+
+/// Tokens of context on each side of the match in a snippet.
+const SNIPPET_TOKENS: i64 = 12;
+
+// ---------------------------------------------------------------------------
+// Query Building
+// ---------------------------------------------------------------------------
+
+/// Turn free text into a safe FTS5 MATCH expression.
+///
+/// Each whitespace-separated term is quoted, so FTS5 operators and punctuation
+/// in user input (`-`, `:`, `"`, `AND`) are matched literally rather than
+/// parsed. Terms are ANDed; the last one is a prefix match so results update
+/// while typing.
+fn build_match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", terms.join(" ")))
+}
+
+// ---------------------------------------------------------------------------
+// Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn search(
+    state: tauri::State<'_, AppState>,
+    query: String,
+    filters: SearchFilters,
+) -> CommandResult<Vec<SearchHit>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    search_impl(&db, &query, filters)
+}
+
+pub(crate) fn search_impl(
+    conn: &Connection,
+    query: &str,
+    filters: SearchFilters,
+) -> CommandResult<Vec<SearchHit>> {
+    validate_text_length("Search query", query, 500)?;
+    let match_expr = build_match_expression(query)
+        .ok_or_else(|| CommandError::from("Search query cannot be empty"))?;
+
+    let mut conditions: Vec<String> = vec!["search_index MATCH ?".to_string()];
+    let mut param_values: Vec<Box<dyn ToSql>> = vec![Box::new(match_expr)];
+
+    if let Some(ref types) = filters.entity_types {
+        if let Some(bad) = types.iter().find(|t| !SEARCHABLE_ENTITIES.contains(&t.as_str())) {
+            return Err(CommandError::from(format!("Unknown entity type '{}'", bad)));
+        }
+        if !types.is_empty() {
+            let placeholders: Vec<&str> = types.iter().map(|_| "?").collect();
+            conditions.push(format!("entity_type IN ({})", placeholders.join(", ")));
+            for t in types {
+                param_values.push(Box::new(t.clone()));
+            }
+        }
+    }
+    if let Some(ref start) = filters.start {
+        conditions.push("date >= ?".to_string());
+        param_values.push(Box::new(start.clone()));
+    }
+    if let Some(ref end) = filters.end {
+        conditions.push("date <= ?".to_string());
+        param_values.push(Box::new(end.clone()));
+    }
+
+    let limit = filters.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    param_values.push(Box::new(limit));
+
+    let sql = format!(
+        "SELECT entity_type, entity_id, date, title, \
+           snippet(search_index, -1, '<mark>', '</mark>', '…', {}) AS snippet, \
+           bm25(search_index) AS rank \
+         FROM search_index WHERE {} \
+         ORDER BY rank ASC, date DESC \
+         LIMIT ?",
+        SNIPPET_TOKENS,
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let param_refs: Vec<&dyn ToSql> = param_values.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt.query_map(param_refs.as_slice(), |row| {
+        Ok(SearchHit {
+            entity_type: row.get("entity_type")?,
+            entity_id: row.get("entity_id")?,
+            date: row.get("date")?,
+            title: row.get("title")?,
+            snippet: row.get("snippet")?,
+            rank: row.get("rank")?,
+        })
+    })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+    use rusqlite::params;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    fn insert_journal(conn: &Connection, date: &str, reflection: &str) -> i64 {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO journal (date, mood, energy, reflection, logged_at, last_modified) \
+             VALUES (?1, 3, 3, ?2, ?3, ?4)",
+            params![date, reflection, &now, &now],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn insert_application(conn: &Connection, date: &str, company: &str, notes: &str) -> i64 {
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO application (date_applied, company, role, source, notes, logged_at, last_modified) \
+             VALUES (?1, ?2, 'Engineer', 'LinkedIn', ?3, ?4, ?5)",
+            params![date, company, notes, &now, &now],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn search_all(conn: &Connection, query: &str) -> Vec<SearchHit> {
+        search_impl(conn, query, SearchFilters::default()).unwrap()
+    }
+
+    #[test]
+    fn test_build_match_expression_quotes_terms() {
+        assert_eq!(build_match_expression("  "), None);
+        assert_eq!(build_match_expression("rust"), Some("\"rust\"*".to_string()));
+        assert_eq!(
+            build_match_expression("say \"hi\" -x"),
+            Some("\"say\" \"\"\"hi\"\"\" \"-x\"*".to_string())
+        );
+    }
+
+    #[test]
+    fn test_search_finds_journal_with_snippet() {
+        let conn = setup_test_db();
+        let id = insert_journal(&conn, "2026-03-01", "Went running along the river at dawn");
+
+        let hits = search_all(&conn, "river");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].entity_type, "journal");
+        assert_eq!(hits[0].entity_id, id);
+        assert_eq!(hits[0].date, "2026-03-01");
+        assert!(hits[0].snippet.contains("<mark>river</mark>"));
+
+        // Porter stemming: "runs" matches "running"
+        assert_eq!(search_all(&conn, "runs").len(), 1);
+        // Prefix on the last term
+        assert_eq!(search_all(&conn, "riv").len(), 1);
+    }
+
+    #[test]
+    fn test_search_index_follows_updates_and_deletes() {
+        let conn = setup_test_db();
+        let id = insert_journal(&conn, "2026-03-01", "original words");
+
+        conn.execute(
+            "UPDATE journal SET reflection = 'replacement text' WHERE id = ?1",
+            [id],
+        )
+        .unwrap();
+        assert!(search_all(&conn, "original").is_empty());
+        assert_eq!(search_all(&conn, "replacement").len(), 1);
+
+        // Rows are keyed by id * 16 + type code, so equal ids of different
+        // entities don't collide.
+        let app_id = insert_application(&conn, "2026-03-01", "Initech", "replacement");
+        assert_eq!(app_id, id);
+        let rowids: Vec<(String, i64)> = conn
+            .prepare("SELECT entity_type, rowid FROM search_index ORDER BY rowid")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert!(rowids.contains(&("journal".to_string(), id * 16)));
+        assert!(rowids.contains(&("application".to_string(), id * 16 + 2)));
+        assert_eq!(search_all(&conn, "replacement").len(), 2);
+        conn.execute("DELETE FROM application WHERE id = ?1", [app_id]).unwrap();
+
+        conn.execute("DELETE FROM journal WHERE id = ?1", [id]).unwrap();
+        assert!(search_all(&conn, "replacement").is_empty());
+    }
+
+    #[test]
+    fn test_search_titles_and_filters() {
+        let conn = setup_test_db();
+        let app_id = insert_application(&conn, "2026-02-10", "Acme", "Referral from Dana");
+        insert_journal(&conn, "2026-03-05", "Acme interview prep went well");
+
+        let hits = search_all(&conn, "acme");
+        assert_eq!(hits.len(), 2);
+
+        let apps = search_impl(
+            &conn,
+            "acme",
+            SearchFilters {
+                entity_types: Some(vec!["application".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].entity_id, app_id);
+        assert_eq!(apps[0].title, "Acme — Engineer");
+
+        let march = search_impl(
+            &conn,
+            "acme",
+            SearchFilters {
+                start: Some("2026-03-01".to_string()),
+                end: Some("2026-03-31".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(march.len(), 1);
+        assert_eq!(march[0].entity_type, "journal");
+    }
+
+    #[test]
+    fn test_search_status_change_titles_follow_company_rename() {
+        let conn = setup_test_db();
+        let app_id = insert_application(&conn, "2026-02-10", "Acme", "");
+        conn.execute(
+            "INSERT INTO status_change (application_id, status, date, notes, created_at) \
+             VALUES (?1, 'phone_screen', '2026-02-14', 'Recruiter call', ?2)",
+            params![app_id, chrono::Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+        let hits = search_all(&conn, "recruiter");
+        assert_eq!(hits[0].title, "Acme — phone_screen");
+
+        conn.execute("UPDATE application SET company = 'Globex' WHERE id = ?1", [app_id])
+            .unwrap();
+        let hits = search_all(&conn, "recruiter");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].title, "Globex — phone_screen");
+        assert!(search_all(&conn, "acme").is_empty());
+    }
+
+    #[test]
+    fn test_search_operators_are_literal() {
+        let conn = setup_test_db();
+        insert_journal(&conn, "2026-03-01", "fixed the C++ build: NOT fun");
+        // Would be FTS5 syntax errors if passed through unquoted
+        assert_eq!(search_all(&conn, "build: NOT").len(), 1);
+        assert!(search_impl(&conn, "\"unbalanced", SearchFilters::default()).is_ok());
+    }
+
+    #[test]
+    fn test_search_rejects_empty_query_and_unknown_type() {
+        let conn = setup_test_db();
+        assert!(search_impl(&conn, "   ", SearchFilters::default()).is_err());
+        let result = search_impl(
+            &conn,
+            "x",
+            SearchFilters {
+                entity_types: Some(vec!["daily_log".to_string()]),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,8 +1,11 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
 use crate::AppState;
 
+use super::milestone::{emit_milestones_achieved, evaluate_milestones_impl, Milestone};
+use super::study_topic::{prune_study_topics, resolve_study_topic};
 use super::validation::validate_text_length;
 use super::{CommandError, CommandResult};
 
@@ -28,6 +31,8 @@ pub struct StudySession {
     pub notes: String,
     pub logged_at: String,
     pub last_modified: String,
+    /// `study_topic` the `topic` text resolved to; `None` for a blank topic.
+    pub topic_id: Option<i64>,
 }
 
 /// Input received from the frontend when saving/updating a study session.
@@ -54,7 +59,7 @@ pub struct StudySessionInput {
 const STUDY_SESSION_COLUMNS: &str = "\
     id, date, subject, study_type, start_time, end_time, \
     duration_minutes, focus_score, location, topic, \
-    resources, notes, logged_at, last_modified";
+    resources, notes, logged_at, last_modified, topic_id";
 
 fn row_to_study_session(row: &rusqlite::Row) -> rusqlite::Result<StudySession> {
     Ok(StudySession {
@@ -72,6 +77,7 @@ fn row_to_study_session(row: &rusqlite::Row) -> rusqlite::Result<StudySession> {
         notes: row.get("notes")?,
         logged_at: row.get("logged_at")?,
         last_modified: row.get("last_modified")?,
+        topic_id: row.get("topic_id")?,
     })
 }
 
@@ -88,6 +94,114 @@ fn query_study_session_by_id(
         .map_err(CommandError::from)
 }
 
+fn parse_session_time(field: &str, value: &str) -> CommandResult<NaiveTime> {
+    NaiveTime::parse_from_str(value, "%H:%M").map_err(|_| {
+        CommandError::from(format!("Invalid {} '{}'. Expected HH:MM", field, value))
+    })
+}
+
+/// Start and end of a session as date-times. An end time before the start
+/// time means the session crossed midnight: it belongs to `date`, the day it
+/// started, and ends on the following day.
+fn session_bounds(
+    date: &str,
+    start_time: &str,
+    end_time: &str,
+) -> CommandResult<(NaiveDateTime, NaiveDateTime)> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+        CommandError::from(format!("Invalid date '{}'. Expected YYYY-MM-DD", date))
+    })?;
+    let start = day.and_time(parse_session_time("start time", start_time)?);
+    let mut end = day.and_time(parse_session_time("end time", end_time)?);
+    if end == start {
+        return Err(CommandError::from("Start and end time cannot be the same"));
+    }
+    if end < start {
+        end += chrono::Duration::days(1);
+    }
+    Ok((start, end))
+}
+
+/// Validates a session and returns it with `duration_minutes` filled in.
+///
+/// - `focus_score` must be 1-5.
+/// - Times must be `HH:MM`; see `session_bounds` for sessions crossing midnight.
+/// - A `duration_minutes` of 0 is derived from the times. Otherwise it must
+///   be positive and no longer than the span between them; it may be shorter,
+///   since breaks are not counted.
+/// - The session may not overlap another one. `exclude_id` is the session
+///   being updated. Sessions that only touch (one ends as the next starts)
+///   do not overlap.
+fn validate_study_session_input(
+    conn: &Connection,
+    mut session: StudySessionInput,
+    exclude_id: Option<i64>,
+) -> CommandResult<StudySessionInput> {
+    validate_text_length("Subject", &session.subject, 500)?;
+    validate_text_length("Study type", &session.study_type, 500)?;
+    validate_text_length("Location", &session.location, 500)?;
+    validate_text_length("Topic", &session.topic, 500)?;
+    validate_text_length("Resources", &session.resources, 500)?;
+    validate_text_length("Notes", &session.notes, 2000)?;
+
+    if !(1..=5).contains(&session.focus_score) {
+        return Err(CommandError::from(format!(
+            "Focus score must be between 1 and 5 (got {})",
+            session.focus_score
+        )));
+    }
+
+    let (start, end) = session_bounds(&session.date, &session.start_time, &session.end_time)?;
+    let span_minutes = (end - start).num_minutes();
+    match session.duration_minutes {
+        0 => session.duration_minutes = span_minutes,
+        d if d < 0 => {
+            return Err(CommandError::from(format!(
+                "Duration cannot be negative (got {})",
+                d
+            )))
+        }
+        d if d > span_minutes => {
+            return Err(CommandError::from(format!(
+                "Duration of {} minutes is longer than the {} minutes from {} to {}",
+                d, span_minutes, session.start_time, session.end_time
+            )))
+        }
+        _ => {}
+    }
+
+    if let Some(other) = find_overlapping_session(conn, start, end, exclude_id)? {
+        return Err(CommandError::from(format!(
+            "Session overlaps '{}' on {} from {} to {}",
+            other.subject, other.date, other.start_time, other.end_time
+        )));
+    }
+
+    Ok(session)
+}
+
+/// The first stored session overlapping `[start, end)`, if any. Sessions on
+/// the neighbouring dates are checked too, because either side may cross
+/// midnight. Rows with unparseable times are skipped.
+fn find_overlapping_session(
+    conn: &Connection,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    exclude_id: Option<i64>,
+) -> CommandResult<Option<StudySession>> {
+    let day = start.date();
+    let from = (day - chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+    let to = (day + chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+
+    let candidates = get_study_sessions_range_impl(conn, &from, &to)?;
+    Ok(candidates.into_iter().find(|other| {
+        Some(other.id) != exclude_id
+            && session_bounds(&other.date, &other.start_time, &other.end_time)
+                .map(|(other_start, other_end)| start < other_end && other_start < end)
+                .unwrap_or(false)
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // Commands
 // ---------------------------------------------------------------------------
@@ -98,11 +212,18 @@ pub fn get_study_sessions(
     date: String,
 ) -> CommandResult<Vec<StudySession>> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_sessions_impl(&db, &date)
+}
+
+pub(crate) fn get_study_sessions_impl(
+    conn: &Connection,
+    date: &str,
+) -> CommandResult<Vec<StudySession>> {
     let sql = format!(
         "SELECT {} FROM study_session WHERE date = ?1 ORDER BY start_time ASC",
         STUDY_SESSION_COLUMNS
     );
-    let mut stmt = db.prepare(&sql)?;
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params![date], row_to_study_session)?;
     rows.collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)
@@ -115,44 +236,37 @@ pub fn get_study_sessions_range(
     end: String,
 ) -> CommandResult<Vec<StudySession>> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_sessions_range_impl(&db, &start, &end)
+}
+
+pub(crate) fn get_study_sessions_range_impl(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> CommandResult<Vec<StudySession>> {
     let sql = format!(
         "SELECT {} FROM study_session WHERE date >= ?1 AND date <= ?2 \
          ORDER BY date ASC, start_time ASC",
         STUDY_SESSION_COLUMNS
     );
-    let mut stmt = db.prepare(&sql)?;
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params![start, end], row_to_study_session)?;
     rows.collect::<Result<Vec<_>, _>>()
         .map_err(CommandError::from)
 }
 
-fn validate_study_session_input(session: &StudySessionInput) -> CommandResult<()> {
-    validate_text_length("Subject", &session.subject, 500)?;
-    validate_text_length("Study type", &session.study_type, 500)?;
-    validate_text_length("Location", &session.location, 500)?;
-    validate_text_length("Topic", &session.topic, 500)?;
-    validate_text_length("Resources", &session.resources, 500)?;
-    validate_text_length("Notes", &session.notes, 2000)?;
-    Ok(())
-}
-
-#[tauri::command]
-pub fn save_study_session(
-    state: tauri::State<'_, AppState>,
-    session: StudySessionInput,
-) -> CommandResult<StudySession> {
-    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
-
-    validate_study_session_input(&session)?;
-
+/// Inserts a validated session, linked to its topic, and returns its id.
+/// Callers own the transaction.
+fn insert_study_session(conn: &Connection, session: &StudySessionInput) -> CommandResult<i64> {
     let now = chrono::Utc::now().to_rfc3339();
+    let topic_id = resolve_study_topic(conn, &session.subject, &session.topic)?;
 
-    db.execute(
+    conn.execute(
         "INSERT INTO study_session (\
          date, subject, study_type, start_time, end_time, \
          duration_minutes, focus_score, location, topic, \
-         resources, notes, logged_at, last_modified\
-         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+         resources, notes, logged_at, last_modified, topic_id\
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
         params![
             session.date,
             session.subject,
@@ -167,12 +281,52 @@ pub fn save_study_session(
             session.notes,
             &now,
             &now,
+            topic_id,
         ],
     )?;
 
-    let id = db.last_insert_rowid();
-    query_study_session_by_id(&db, id)?
-        .ok_or_else(|| CommandError::from("Failed to read back saved study session"))
+    Ok(conn.last_insert_rowid())
+}
+
+/// Newly achieved milestones are emitted as `milestones-achieved`.
+#[tauri::command]
+pub fn save_study_session(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    session: StudySessionInput,
+) -> CommandResult<StudySession> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    let (saved, achieved) = save_study_session_with_milestones(&db, session)?;
+    emit_milestones_achieved(&app, &achieved);
+    Ok(saved)
+}
+
+pub(crate) fn save_study_session_impl(
+    conn: &Connection,
+    session: StudySessionInput,
+) -> CommandResult<StudySession> {
+    save_study_session_with_milestones(conn, session).map(|(saved, _)| saved)
+}
+
+/// `save_study_session_impl`, also returning the milestones the save newly
+/// achieved. The insert and the milestone check share one transaction.
+pub(crate) fn save_study_session_with_milestones(
+    conn: &Connection,
+    session: StudySessionInput,
+) -> CommandResult<(StudySession, Vec<Milestone>)> {
+    let session = validate_study_session_input(conn, session, None)?;
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let id = insert_study_session(&tx, &session)?;
+    let achieved = evaluate_milestones_impl(&tx)?;
+    tx.commit()?;
+
+    let saved = query_study_session_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved study session"))?;
+    Ok((saved, achieved))
 }
 
 #[tauri::command]
@@ -182,17 +336,34 @@ pub fn update_study_session(
     session: StudySessionInput,
 ) -> CommandResult<StudySession> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_study_session_impl(&db, id, session)
+}
 
-    validate_study_session_input(&session)?;
+pub(crate) fn update_study_session_impl(
+    conn: &Connection,
+    id: i64,
+    session: StudySessionInput,
+) -> CommandResult<StudySession> {
+    if query_study_session_by_id(conn, id)?.is_none() {
+        return Err(CommandError::from(format!(
+            "Study session with id {} not found",
+            id
+        )));
+    }
+    let session = validate_study_session_input(conn, session, Some(id))?;
 
     let now = chrono::Utc::now().to_rfc3339();
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
 
-    let rows_affected = db.execute(
+    let topic_id = resolve_study_topic(&tx, &session.subject, &session.topic)?;
+    tx.execute(
         "UPDATE study_session SET \
          date = ?2, subject = ?3, study_type = ?4, \
          start_time = ?5, end_time = ?6, duration_minutes = ?7, \
          focus_score = ?8, location = ?9, topic = ?10, \
-         resources = ?11, notes = ?12, last_modified = ?13 \
+         resources = ?11, notes = ?12, last_modified = ?13, topic_id = ?14 \
          WHERE id = ?1",
         params![
             id,
@@ -208,17 +379,14 @@ pub fn update_study_session(
             session.resources,
             session.notes,
             &now,
+            topic_id,
         ],
     )?;
+    // The session may have left its old topic behind
+    prune_study_topics(&tx)?;
+    tx.commit()?;
 
-    if rows_affected == 0 {
-        return Err(CommandError::from(format!(
-            "Study session with id {} not found",
-            id
-        )));
-    }
-
-    query_study_session_by_id(&db, id)?
+    query_study_session_by_id(conn, id)?
         .ok_or_else(|| CommandError::from("Failed to read back updated study session"))
 }
 
@@ -228,8 +396,15 @@ pub fn delete_study_session(
     id: i64,
 ) -> CommandResult<()> {
     let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_study_session_impl(&db, id)
+}
 
-    let rows_affected = db.execute(
+pub(crate) fn delete_study_session_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let rows_affected = tx.execute(
         "DELETE FROM study_session WHERE id = ?1",
         [id],
     )?;
@@ -241,6 +416,349 @@ pub fn delete_study_session(
         )));
     }
 
+    prune_study_topics(&tx)?;
+    tx.commit()?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Study Timer
+// ---------------------------------------------------------------------------
+
+/// Local timestamp format used by `study_timer` (`YYYY-MM-DDTHH:MM:SS`).
+const TIMER_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// A running or paused study timer. At most one exists at a time.
+/// Field names and types must match the TypeScript `StudyTimer` interface exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyTimer {
+    pub id: i64,
+    pub subject: String,
+    pub study_type: String,
+    pub location: String,
+    pub started_at: String,
+    pub paused_at: Option<String>,
+    pub paused_seconds: i64,
+    pub pause_count: i64,
+    /// Derived: whether the timer is currently paused.
+    pub is_paused: bool,
+    /// Derived: active (unpaused) seconds as of the request.
+    pub elapsed_seconds: i64,
+}
+
+/// Details the user fills in when stopping the timer. Date, times and
+/// duration come from the timer itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyTimerStopInput {
+    pub focus_score: i64,
+    #[serde(default)]
+    pub topic: String,
+    #[serde(default)]
+    pub resources: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Raw `study_timer` row, before the derived fields are computed.
+struct StudyTimerRow {
+    id: i64,
+    subject: String,
+    study_type: String,
+    location: String,
+    started_at: NaiveDateTime,
+    paused_at: Option<NaiveDateTime>,
+    paused_seconds: i64,
+    pause_count: i64,
+}
+
+impl StudyTimerRow {
+    /// Active seconds up to `now`. A paused timer stops counting at `paused_at`.
+    fn active_seconds(&self, now: NaiveDateTime) -> i64 {
+        let end = self.paused_at.unwrap_or(now);
+        ((end - self.started_at).num_seconds() - self.paused_seconds).max(0)
+    }
+
+    fn into_timer(self, now: NaiveDateTime) -> StudyTimer {
+        let elapsed_seconds = self.active_seconds(now);
+        StudyTimer {
+            id: self.id,
+            subject: self.subject,
+            study_type: self.study_type,
+            location: self.location,
+            started_at: self.started_at.format(TIMER_TIMESTAMP_FORMAT).to_string(),
+            paused_at: self
+                .paused_at
+                .map(|p| p.format(TIMER_TIMESTAMP_FORMAT).to_string()),
+            paused_seconds: self.paused_seconds,
+            pause_count: self.pause_count,
+            is_paused: self.paused_at.is_some(),
+            elapsed_seconds,
+        }
+    }
+}
+
+fn parse_timer_timestamp(value: &str) -> CommandResult<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, TIMER_TIMESTAMP_FORMAT).map_err(|_| {
+        CommandError::from(format!("Invalid study timer timestamp '{}'", value))
+    })
+}
+
+fn query_study_timer(conn: &Connection) -> CommandResult<Option<StudyTimerRow>> {
+    let raw = conn
+        .query_row(
+            "SELECT id, subject, study_type, location, started_at, paused_at, \
+             paused_seconds, pause_count FROM study_timer ORDER BY id LIMIT 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, i64>("id")?,
+                    row.get::<_, String>("subject")?,
+                    row.get::<_, String>("study_type")?,
+                    row.get::<_, String>("location")?,
+                    row.get::<_, String>("started_at")?,
+                    row.get::<_, Option<String>>("paused_at")?,
+                    row.get::<_, i64>("paused_seconds")?,
+                    row.get::<_, i64>("pause_count")?,
+                ))
+            },
+        )
+        .optional()?;
+
+    let Some((
+        id,
+        subject,
+        study_type,
+        location,
+        started_at,
+        paused_at,
+        paused_seconds,
+        pause_count,
+    )) = raw
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(StudyTimerRow {
+        id,
+        subject,
+        study_type,
+        location,
+        started_at: parse_timer_timestamp(&started_at)?,
+        paused_at: paused_at.as_deref().map(parse_timer_timestamp).transpose()?,
+        paused_seconds,
+        pause_count,
+    }))
+}
+
+fn require_study_timer(conn: &Connection) -> CommandResult<StudyTimerRow> {
+    query_study_timer(conn)?.ok_or_else(|| CommandError::from("No study timer is running"))
+}
+
+fn local_now() -> NaiveDateTime {
+    chrono::Local::now().naive_local()
+}
+
+/// Returns the running or paused timer, if any, so the UI can restore it
+/// after a restart.
+#[tauri::command]
+pub fn get_study_timer(state: tauri::State<'_, AppState>) -> CommandResult<Option<StudyTimer>> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_timer_impl(&db, local_now())
+}
+
+pub(crate) fn get_study_timer_impl(
+    conn: &Connection,
+    now: NaiveDateTime,
+) -> CommandResult<Option<StudyTimer>> {
+    Ok(query_study_timer(conn)?.map(|row| row.into_timer(now)))
+}
+
+#[tauri::command]
+pub fn start_study_timer(
+    state: tauri::State<'_, AppState>,
+    subject: String,
+    study_type: String,
+    location: String,
+) -> CommandResult<StudyTimer> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    start_study_timer_impl(&db, &subject, &study_type, &location, local_now())
+}
+
+pub(crate) fn start_study_timer_impl(
+    conn: &Connection,
+    subject: &str,
+    study_type: &str,
+    location: &str,
+    now: NaiveDateTime,
+) -> CommandResult<StudyTimer> {
+    if subject.trim().is_empty() {
+        return Err(CommandError::from("Subject is required"));
+    }
+    validate_text_length("Subject", subject, 500)?;
+    validate_text_length("Study type", study_type, 500)?;
+    validate_text_length("Location", location, 500)?;
+
+    if query_study_timer(conn)?.is_some() {
+        return Err(CommandError::from(
+            "A study timer is already running; stop it before starting another",
+        ));
+    }
+
+    conn.execute(
+        "INSERT INTO study_timer (subject, study_type, location, started_at) \
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            subject,
+            study_type,
+            location,
+            now.format(TIMER_TIMESTAMP_FORMAT).to_string(),
+        ],
+    )?;
+
+    Ok(require_study_timer(conn)?.into_timer(now))
+}
+
+#[tauri::command]
+pub fn pause_study_timer(state: tauri::State<'_, AppState>) -> CommandResult<StudyTimer> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    pause_study_timer_impl(&db, local_now())
+}
+
+pub(crate) fn pause_study_timer_impl(
+    conn: &Connection,
+    now: NaiveDateTime,
+) -> CommandResult<StudyTimer> {
+    let timer = require_study_timer(conn)?;
+    if timer.paused_at.is_some() {
+        return Err(CommandError::from("Study timer is already paused"));
+    }
+
+    conn.execute(
+        "UPDATE study_timer SET paused_at = ?2, pause_count = pause_count + 1 WHERE id = ?1",
+        params![timer.id, now.format(TIMER_TIMESTAMP_FORMAT).to_string()],
+    )?;
+
+    Ok(require_study_timer(conn)?.into_timer(now))
+}
+
+#[tauri::command]
+pub fn resume_study_timer(state: tauri::State<'_, AppState>) -> CommandResult<StudyTimer> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    resume_study_timer_impl(&db, local_now())
+}
+
+pub(crate) fn resume_study_timer_impl(
+    conn: &Connection,
+    now: NaiveDateTime,
+) -> CommandResult<StudyTimer> {
+    let timer = require_study_timer(conn)?;
+    let Some(paused_at) = timer.paused_at else {
+        return Err(CommandError::from("Study timer is not paused"));
+    };
+
+    // A clock that moved backwards must not shrink the accumulated pause.
+    let paused_for = (now - paused_at).num_seconds().max(0);
+    conn.execute(
+        "UPDATE study_timer SET paused_at = NULL, paused_seconds = paused_seconds + ?2 \
+         WHERE id = ?1",
+        params![timer.id, paused_for],
+    )?;
+
+    Ok(require_study_timer(conn)?.into_timer(now))
+}
+
+/// Converts the timer into a study session. Duration is the active time in
+/// whole minutes, rounded down; the HH:MM end time is likewise cut to the
+/// minute, and a timer stopped while paused ends at the moment it was paused.
+/// Newly achieved milestones are emitted as `milestones-achieved`.
+#[tauri::command]
+pub fn stop_study_timer(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    details: StudyTimerStopInput,
+) -> CommandResult<StudySession> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    let (saved, achieved) = stop_study_timer_with_milestones(&db, details, local_now())?;
+    emit_milestones_achieved(&app, &achieved);
+    Ok(saved)
+}
+
+pub(crate) fn stop_study_timer_impl(
+    conn: &Connection,
+    details: StudyTimerStopInput,
+    now: NaiveDateTime,
+) -> CommandResult<StudySession> {
+    stop_study_timer_with_milestones(conn, details, now).map(|(saved, _)| saved)
+}
+
+/// `stop_study_timer_impl`, also returning newly achieved milestones. The
+/// session insert, the timer delete and the milestone check share one
+/// transaction, so a failed stop leaves the timer running.
+pub(crate) fn stop_study_timer_with_milestones(
+    conn: &Connection,
+    details: StudyTimerStopInput,
+    now: NaiveDateTime,
+) -> CommandResult<(StudySession, Vec<Milestone>)> {
+    let timer = require_study_timer(conn)?;
+
+    let active_seconds = timer.active_seconds(now);
+    // Floored, like the HH:MM end time, so the duration always fits the span
+    // between start and end.
+    let duration_minutes = active_seconds / 60;
+    if duration_minutes < 1 {
+        return Err(CommandError::from(
+            "Study timer ran for less than a minute; cancel it instead",
+        ));
+    }
+
+    let ended_at = timer.paused_at.unwrap_or(now);
+    if ended_at - timer.started_at >= chrono::Duration::days(1) {
+        return Err(CommandError::from(
+            "Study timer has run for more than 24 hours; cancel it and log the session manually",
+        ));
+    }
+
+    let session = StudySessionInput {
+        date: timer.started_at.format("%Y-%m-%d").to_string(),
+        subject: timer.subject,
+        study_type: timer.study_type,
+        start_time: timer.started_at.format("%H:%M").to_string(),
+        end_time: ended_at.format("%H:%M").to_string(),
+        duration_minutes,
+        focus_score: details.focus_score,
+        location: timer.location,
+        topic: details.topic,
+        resources: details.resources,
+        notes: details.notes,
+    };
+    let session = validate_study_session_input(conn, session, None)?;
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| CommandError::from(format!("Transaction error: {}", e)))?;
+
+    let id = insert_study_session(&tx, &session)?;
+    tx.execute("DELETE FROM study_timer WHERE id = ?1", [timer.id])?;
+    let achieved = evaluate_milestones_impl(&tx)?;
+    tx.commit()?;
+
+    let saved = query_study_session_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved study session"))?;
+    Ok((saved, achieved))
+}
+
+/// Discards the timer without recording a session.
+#[tauri::command]
+pub fn cancel_study_timer(state: tauri::State<'_, AppState>) -> CommandResult<()> {
+    let db = state.db.lock().map_err(|_| CommandError::from("DB lock poisoned"))?;
+    cancel_study_timer_impl(&db)
+}
+
+pub(crate) fn cancel_study_timer_impl(conn: &Connection) -> CommandResult<()> {
+    let rows_affected = conn.execute("DELETE FROM study_timer", [])?;
+    if rows_affected == 0 {
+        return Err(CommandError::from("No study timer is running"));
+    }
     Ok(())
 }
 
@@ -707,6 +1225,17 @@ mod tests {
         assert!(err.contains("99999"), "error should mention the id");
     }
 
+    #[test]
+    fn test_save_awards_study_milestones() {
+        let conn = setup_test_db();
+        let (saved, achieved) =
+            save_study_session_with_milestones(&conn, make_default_session_input("2026-02-18"))
+                .unwrap();
+        assert!(saved.id > 0);
+        let ids: Vec<&str> = achieved.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["first_session"]);
+    }
+
     #[test]
     fn test_save_validates_focus_score_range() {
         let conn = setup_test_db();
@@ -721,4 +1250,329 @@ mod tests {
         let result = save_study_session_direct(&conn, input);
         assert!(result.is_err(), "focus_score=6 should be rejected by CHECK constraint");
     }
+
+    // -------------------------------------------------------------------
+    // Study Timer Tests
+    // -------------------------------------------------------------------
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, TIMER_TIMESTAMP_FORMAT).unwrap()
+    }
+
+    fn stop_details(focus_score: i64) -> StudyTimerStopInput {
+        StudyTimerStopInput {
+            focus_score,
+            topic: "Shor's algorithm".to_string(),
+            resources: String::new(),
+            notes: String::new(),
+        }
+    }
+
+    fn start_default_timer(conn: &Connection, now: &str) -> StudyTimer {
+        start_study_timer_impl(conn, "Quantum Computing", "Self-Study", "Library", at(now))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_timer_none_by_default() {
+        let conn = setup_test_db();
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T09:00:00")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_timer_start_and_restore() {
+        let conn = setup_test_db();
+        let started = start_default_timer(&conn, "2026-02-18T09:00:00");
+        assert_eq!(started.started_at, "2026-02-18T09:00:00");
+        assert!(!started.is_paused);
+        assert_eq!(started.elapsed_seconds, 0);
+
+        // A fresh read (as after an app restart) sees the same timer.
+        let restored = get_study_timer_impl(&conn, at("2026-02-18T09:10:00"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored.id, started.id);
+        assert_eq!(restored.subject, "Quantum Computing");
+        assert_eq!(restored.elapsed_seconds, 600);
+    }
+
+    #[test]
+    fn test_timer_rejects_second_start() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        let result =
+            start_study_timer_impl(&conn, "Maths", "Lecture", "Home", at("2026-02-18T09:05:00"));
+        assert!(result.unwrap_err().to_string().contains("already running"));
+    }
+
+    #[test]
+    fn test_timer_requires_subject() {
+        let conn = setup_test_db();
+        let now = at("2026-02-18T09:00:00");
+        let result = start_study_timer_impl(&conn, "  ", "Self-Study", "Library", now);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_timer_pause_resume_tracks_paused_time() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+
+        let paused = pause_study_timer_impl(&conn, at("2026-02-18T09:20:00")).unwrap();
+        assert!(paused.is_paused);
+        assert_eq!(paused.pause_count, 1);
+
+        // Elapsed time does not grow while paused.
+        let still = get_study_timer_impl(&conn, at("2026-02-18T09:50:00")).unwrap().unwrap();
+        assert_eq!(still.elapsed_seconds, 20 * 60);
+
+        let resumed = resume_study_timer_impl(&conn, at("2026-02-18T09:30:00")).unwrap();
+        assert!(!resumed.is_paused);
+        assert_eq!(resumed.paused_seconds, 600);
+        assert_eq!(resumed.elapsed_seconds, 20 * 60);
+    }
+
+    #[test]
+    fn test_timer_pause_resume_state_errors() {
+        let conn = setup_test_db();
+        assert!(pause_study_timer_impl(&conn, at("2026-02-18T09:00:00")).is_err());
+
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        assert!(resume_study_timer_impl(&conn, at("2026-02-18T09:05:00")).is_err());
+        pause_study_timer_impl(&conn, at("2026-02-18T09:05:00")).unwrap();
+        assert!(pause_study_timer_impl(&conn, at("2026-02-18T09:06:00")).is_err());
+    }
+
+    #[test]
+    fn test_timer_stop_computes_duration_excluding_pauses() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        pause_study_timer_impl(&conn, at("2026-02-18T09:30:00")).unwrap();
+        resume_study_timer_impl(&conn, at("2026-02-18T09:45:00")).unwrap();
+
+        let (session, achieved) =
+            stop_study_timer_with_milestones(&conn, stop_details(4), at("2026-02-18T10:30:20"))
+                .unwrap();
+        assert_eq!(session.date, "2026-02-18");
+        assert_eq!(session.start_time, "09:00");
+        assert_eq!(session.end_time, "10:30");
+        assert_eq!(session.duration_minutes, 75);
+        assert_eq!(session.focus_score, 4);
+        assert_eq!(session.location, "Library");
+        assert_eq!(session.topic, "Shor's algorithm");
+        let ids: Vec<&str> = achieved.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["first_session"]);
+
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T10:31:00")).unwrap().is_none());
+        assert_eq!(get_sessions_by_date(&conn, "2026-02-18").len(), 1);
+    }
+
+    #[test]
+    fn test_timer_stop_does_not_block_the_next_minute() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        let session = stop_study_timer_impl(&conn, stop_details(4), at("2026-02-18T10:30:50"))
+            .unwrap();
+        assert_eq!(session.end_time, "10:30");
+        assert_eq!(session.duration_minutes, 90);
+
+        save_study_session_impl(&conn, session_at("2026-02-18", "10:31", "11:00", 0)).unwrap();
+    }
+
+    #[test]
+    fn test_timer_stop_while_paused_ends_at_pause() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T14:00:00");
+        pause_study_timer_impl(&conn, at("2026-02-18T14:40:00")).unwrap();
+
+        let session = stop_study_timer_impl(&conn, stop_details(3), at("2026-02-18T16:00:00"))
+            .unwrap();
+        assert_eq!(session.end_time, "14:40");
+        assert_eq!(session.duration_minutes, 40);
+    }
+
+    #[test]
+    fn test_timer_stop_under_a_minute_keeps_timer() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        let result = stop_study_timer_impl(&conn, stop_details(3), at("2026-02-18T09:00:20"));
+        assert!(result.is_err());
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T09:00:30")).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_timer_failed_stop_rolls_back() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        // focus_score outside 1-5 fails the study_session CHECK.
+        let result = stop_study_timer_impl(&conn, stop_details(9), at("2026-02-18T10:00:00"));
+        assert!(result.is_err());
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T10:00:00")).unwrap().is_some());
+        assert!(get_sessions_by_date(&conn, "2026-02-18").is_empty());
+    }
+
+    #[test]
+    fn test_timer_cancel() {
+        let conn = setup_test_db();
+        assert!(cancel_study_timer_impl(&conn).is_err());
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        cancel_study_timer_impl(&conn).unwrap();
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T09:10:00")).unwrap().is_none());
+        assert!(get_sessions_by_date(&conn, "2026-02-18").is_empty());
+    }
+
+    #[test]
+    fn test_timer_stop_across_midnight() {
+        let conn = setup_test_db();
+        start_default_timer(&conn, "2026-02-18T23:30:00");
+        let session = stop_study_timer_impl(&conn, stop_details(4), at("2026-02-19T00:45:00"))
+            .unwrap();
+        assert_eq!(session.date, "2026-02-18");
+        assert_eq!(session.start_time, "23:30");
+        assert_eq!(session.end_time, "00:45");
+        assert_eq!(session.duration_minutes, 75);
+    }
+
+    #[test]
+    fn test_timer_stop_rejects_overlap_with_logged_session() {
+        let conn = setup_test_db();
+        insert_raw_session(&conn, "2026-02-18", "Maths", "09:30", "10:00", 30);
+        start_default_timer(&conn, "2026-02-18T09:00:00");
+        let result = stop_study_timer_impl(&conn, stop_details(4), at("2026-02-18T09:45:00"));
+        assert!(result.unwrap_err().to_string().contains("overlaps 'Maths'"));
+        assert!(get_study_timer_impl(&conn, at("2026-02-18T09:45:00")).unwrap().is_some());
+    }
+
+    // -------------------------------------------------------------------
+    // Validation Tests
+    // -------------------------------------------------------------------
+
+    fn session_at(date: &str, start: &str, end: &str, duration: i64) -> StudySessionInput {
+        StudySessionInput {
+            start_time: start.to_string(),
+            end_time: end.to_string(),
+            duration_minutes: duration,
+            ..make_default_session_input(date)
+        }
+    }
+
+    fn save_err(conn: &Connection, session: StudySessionInput) -> String {
+        save_study_session_impl(conn, session).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_validate_derives_duration_from_times() {
+        let conn = setup_test_db();
+        let saved =
+            save_study_session_impl(&conn, session_at("2026-02-18", "09:15", "11:00", 0)).unwrap();
+        assert_eq!(saved.duration_minutes, 105);
+    }
+
+    #[test]
+    fn test_validate_allows_duration_shorter_than_span() {
+        let conn = setup_test_db();
+        let saved =
+            save_study_session_impl(&conn, session_at("2026-02-18", "09:00", "11:00", 100))
+                .unwrap();
+        assert_eq!(saved.duration_minutes, 100);
+    }
+
+    #[test]
+    fn test_validate_rejects_duration_longer_than_span() {
+        let conn = setup_test_db();
+        let err = save_err(&conn, session_at("2026-02-18", "09:00", "10:00", 90));
+        assert!(err.contains("longer than the 60 minutes"), "{}", err);
+        let err = save_err(&conn, session_at("2026-02-18", "09:00", "10:00", -5));
+        assert!(err.contains("negative"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_times() {
+        let conn = setup_test_db();
+        assert!(save_err(&conn, session_at("2026-02-18", "9am", "10:00", 0)).contains("HH:MM"));
+        assert!(save_err(&conn, session_at("2026-02-18", "09:00", "24:30", 0)).contains("HH:MM"));
+        assert!(save_err(&conn, session_at("2026-02-18", "10:00", "10:00", 0)).contains("same"));
+        assert!(save_err(&conn, session_at("18/02/2026", "09:00", "10:00", 0)).contains("date"));
+    }
+
+    #[test]
+    fn test_validate_cross_midnight_attributed_to_start_date() {
+        let conn = setup_test_db();
+        let saved =
+            save_study_session_impl(&conn, session_at("2026-02-18", "23:00", "01:30", 0)).unwrap();
+        assert_eq!(saved.date, "2026-02-18");
+        assert_eq!(saved.duration_minutes, 150);
+        assert!(get_sessions_by_date(&conn, "2026-02-19").is_empty());
+    }
+
+    #[test]
+    fn test_validate_focus_score_error_message() {
+        let conn = setup_test_db();
+        let mut input = make_default_session_input("2026-02-18");
+        input.focus_score = 6;
+        let err = save_err(&conn, input);
+        assert!(err.contains("Focus score must be between 1 and 5"), "{}", err);
+        assert!(!err.contains("CHECK"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_overlap_on_same_date() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session_at("2026-02-18", "09:00", "10:30", 0)).unwrap();
+        let err = save_err(&conn, session_at("2026-02-18", "10:00", "11:00", 0));
+        assert!(err.contains("overlaps 'Quantum Computing' on 2026-02-18"), "{}", err);
+        // A session containing the existing one overlaps too.
+        let err = save_err(&conn, session_at("2026-02-18", "08:00", "12:00", 0));
+        assert!(err.contains("overlaps"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_allows_back_to_back_sessions() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session_at("2026-02-18", "09:00", "10:30", 0)).unwrap();
+        save_study_session_impl(&conn, session_at("2026-02-18", "10:30", "11:00", 0)).unwrap();
+        save_study_session_impl(&conn, session_at("2026-02-18", "08:00", "09:00", 0)).unwrap();
+        assert_eq!(get_sessions_by_date(&conn, "2026-02-18").len(), 3);
+    }
+
+    #[test]
+    fn test_validate_overlap_across_midnight() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session_at("2026-02-18", "23:00", "01:00", 0)).unwrap();
+        // The next day's early session runs into the previous night's session.
+        let err = save_err(&conn, session_at("2026-02-19", "00:30", "02:00", 0));
+        assert!(err.contains("on 2026-02-18"), "{}", err);
+        // Starting after it ends is fine.
+        save_study_session_impl(&conn, session_at("2026-02-19", "01:00", "02:00", 0)).unwrap();
+    }
+
+    #[test]
+    fn test_update_validates_and_ignores_itself() {
+        let conn = setup_test_db();
+        let first =
+            save_study_session_impl(&conn, session_at("2026-02-18", "09:00", "10:00", 0)).unwrap();
+        let second =
+            save_study_session_impl(&conn, session_at("2026-02-18", "11:00", "12:00", 0)).unwrap();
+
+        // Moving a session within its own slot does not overlap itself.
+        let moved = update_study_session_impl(
+            &conn,
+            first.id,
+            session_at("2026-02-18", "09:15", "10:15", 0),
+        )
+        .unwrap();
+        assert_eq!(moved.duration_minutes, 60);
+
+        // Moving it onto the other session is rejected.
+        let result = update_study_session_impl(
+            &conn,
+            first.id,
+            session_at("2026-02-18", "11:30", "12:30", 0),
+        );
+        assert!(result.unwrap_err().to_string().contains("overlaps"));
+
+        let mut bad_focus = session_at("2026-02-18", "13:00", "14:00", 0);
+        bad_focus.focus_score = 0;
+        assert!(update_study_session_impl(&conn, second.id, bad_focus).is_err());
+    }
 }
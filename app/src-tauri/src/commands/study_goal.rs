@@ -0,0 +1,722 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+use super::validation::validate_text_length;
+use super::{CommandError, CommandResult};
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// Allowed `study_goal.period` values.
+pub(crate) const STUDY_GOAL_PERIODS: &[&str] = &["week", "term"];
+
+/// A target for study hours. Field names match the TypeScript `StudyGoal`
+/// interface.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoal {
+    pub id: i64,
+    /// `None` counts sessions of every subject.
+    pub subject: Option<String>,
+    /// `'week'`: hours in each week. `'term'`: hours over the whole range.
+    pub period: String,
+    pub target_hours: f64,
+    pub start_date: String,
+    /// `None` means open-ended; only weekly goals may omit it.
+    pub end_date: Option<String>,
+    pub created_at: String,
+    pub last_modified: String,
+}
+
+/// Input for save_study_goal / update_study_goal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoalInput {
+    #[serde(default)]
+    pub subject: Option<String>,
+    pub period: String,
+    pub target_hours: f64,
+    pub start_date: String,
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+/// Progress of one goal in the requested week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoalProgress {
+    pub goal: StudyGoal,
+    /// The goal period containing the week: the week itself for weekly
+    /// goals, cut to the goal's start and end dates, and the whole term for
+    /// term goals.
+    pub period_start: String,
+    pub period_end: String,
+    /// Hours to log in the period. For a weekly goal cut short, the share of
+    /// its weekly target for the days it covers.
+    pub target_hours: f64,
+    /// Hours logged in the period, up to the end of the requested week.
+    pub actual_hours: f64,
+    pub remaining_hours: f64,
+    pub percent_complete: f64,
+    pub met: bool,
+    /// Days of the period still ahead, counting today. 0 once it is over.
+    pub days_remaining: i64,
+    /// Hours per day needed over `days_remaining` to meet the target. `None`
+    /// when the target is met or no days remain.
+    pub required_hours_per_day: Option<f64>,
+    /// Hours a week needs to count as met. For term goals, the target
+    /// spread evenly over the term's weeks.
+    pub weekly_target_hours: f64,
+    /// Hours logged in the requested week.
+    pub week_hours: f64,
+    /// Consecutive met weeks ending at the requested week. A week still in
+    /// progress does not break the streak before it is met.
+    pub current_streak_weeks: i64,
+    /// Longest run of met weeks from the goal's start to the requested week.
+    pub best_streak_weeks: i64,
+}
+
+/// Result of get_study_goal_progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoalWeekProgress {
+    pub week_start: String,
+    pub week_end: String,
+    /// Goals active at some point in the week.
+    pub goals: Vec<StudyGoalProgress>,
+}
+
+// ---------------------------------------------------------------------------
+// Column Constants & Row Mapper
+// ---------------------------------------------------------------------------
+
+const STUDY_GOAL_COLUMNS: &str = "\
+    id, subject, period, target_hours, start_date, end_date, created_at, last_modified";
+
+/// All-subject goals first, then by subject and start date.
+const STUDY_GOAL_ORDER: &str = "ORDER BY subject IS NOT NULL, subject, start_date, id";
+
+fn row_to_study_goal(row: &rusqlite::Row) -> rusqlite::Result<StudyGoal> {
+    Ok(StudyGoal {
+        id: row.get("id")?,
+        subject: row.get("subject")?,
+        period: row.get("period")?,
+        target_hours: row.get("target_hours")?,
+        start_date: row.get("start_date")?,
+        end_date: row.get("end_date")?,
+        created_at: row.get("created_at")?,
+        last_modified: row.get("last_modified")?,
+    })
+}
+
+fn query_study_goal_by_id(conn: &Connection, id: i64) -> CommandResult<Option<StudyGoal>> {
+    let sql = format!(
+        "SELECT {} FROM study_goal WHERE id = ?1",
+        STUDY_GOAL_COLUMNS
+    );
+    conn.query_row(&sql, params![id], row_to_study_goal)
+        .optional()
+        .map_err(CommandError::from)
+}
+
+fn parse_date(date: &str) -> CommandResult<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| CommandError::from(format!("Invalid date '{}'. Expected YYYY-MM-DD", date)))
+}
+
+fn fmt_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+/// Validates a goal and returns it with a blank subject normalised to `None`.
+fn validate_study_goal_input(mut input: StudyGoalInput) -> CommandResult<StudyGoalInput> {
+    input.subject = input
+        .subject
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    if let Some(subject) = &input.subject {
+        validate_text_length("Subject", subject, 500)?;
+    }
+
+    if !STUDY_GOAL_PERIODS.contains(&input.period.as_str()) {
+        return Err(CommandError::from(format!(
+            "Invalid goal period '{}'. Expected one of: {}",
+            input.period,
+            STUDY_GOAL_PERIODS.join(", ")
+        )));
+    }
+    if !input.target_hours.is_finite() || input.target_hours <= 0.0 {
+        return Err(CommandError::from("Target hours must be greater than 0"));
+    }
+    if input.period == "week" && input.target_hours > 168.0 {
+        return Err(CommandError::from(
+            "A weekly target cannot exceed 168 hours",
+        ));
+    }
+
+    let start = parse_date(&input.start_date)?;
+    match input.end_date.as_deref().filter(|e| !e.is_empty()) {
+        Some(end) => {
+            if parse_date(end)? < start {
+                return Err(CommandError::from("End date cannot be before start date"));
+            }
+        }
+        None if input.period == "term" => {
+            return Err(CommandError::from("A term goal needs an end date"));
+        }
+        None => input.end_date = None,
+    }
+    Ok(input)
+}
+
+// ---------------------------------------------------------------------------
+// CRUD Implementations
+// ---------------------------------------------------------------------------
+
+pub(crate) fn get_study_goals_impl(conn: &Connection) -> CommandResult<Vec<StudyGoal>> {
+    let sql = format!(
+        "SELECT {} FROM study_goal {}",
+        STUDY_GOAL_COLUMNS, STUDY_GOAL_ORDER
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([], row_to_study_goal)?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(CommandError::from)
+}
+
+pub(crate) fn save_study_goal_impl(
+    conn: &Connection,
+    goal: StudyGoalInput,
+) -> CommandResult<StudyGoal> {
+    let goal = validate_study_goal_input(goal)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    conn.execute(
+        "INSERT INTO study_goal (subject, period, target_hours, start_date, end_date, \
+         created_at, last_modified) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            goal.subject,
+            goal.period,
+            goal.target_hours,
+            goal.start_date,
+            goal.end_date,
+            &now,
+            &now,
+        ],
+    )?;
+
+    let id = conn.last_insert_rowid();
+    query_study_goal_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back saved study goal"))
+}
+
+pub(crate) fn update_study_goal_impl(
+    conn: &Connection,
+    id: i64,
+    goal: StudyGoalInput,
+) -> CommandResult<StudyGoal> {
+    let goal = validate_study_goal_input(goal)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let rows_affected = conn.execute(
+        "UPDATE study_goal SET subject = ?2, period = ?3, target_hours = ?4, \
+         start_date = ?5, end_date = ?6, last_modified = ?7 WHERE id = ?1",
+        params![
+            id,
+            goal.subject,
+            goal.period,
+            goal.target_hours,
+            goal.start_date,
+            goal.end_date,
+            &now,
+        ],
+    )?;
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!(
+            "Study goal with id {} not found",
+            id
+        )));
+    }
+
+    query_study_goal_by_id(conn, id)?
+        .ok_or_else(|| CommandError::from("Failed to read back updated study goal"))
+}
+
+pub(crate) fn delete_study_goal_impl(conn: &Connection, id: i64) -> CommandResult<()> {
+    let rows_affected = conn.execute("DELETE FROM study_goal WHERE id = ?1", params![id])?;
+    if rows_affected == 0 {
+        return Err(CommandError::from(format!(
+            "Study goal with id {} not found",
+            id
+        )));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Progress
+// ---------------------------------------------------------------------------
+
+/// Logged study minutes per date in `from..=to`, for one subject or all.
+fn load_daily_minutes(
+    conn: &Connection,
+    subject: Option<&str>,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> CommandResult<BTreeMap<NaiveDate, i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT date, SUM(duration_minutes) FROM study_session \
+         WHERE date >= ?1 AND date <= ?2 AND (?3 IS NULL OR subject = ?3) \
+         GROUP BY date",
+    )?;
+    let rows = stmt
+        .query_map(params![fmt_date(from), fmt_date(to), subject], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(date, minutes)| parse_date(&date).ok().map(|d| (d, minutes)))
+        .collect())
+}
+
+fn hours_between(minutes: &BTreeMap<NaiveDate, i64>, from: NaiveDate, to: NaiveDate) -> f64 {
+    if from > to {
+        return 0.0;
+    }
+    minutes.range(from..=to).map(|(_, m)| *m).sum::<i64>() as f64 / 60.0
+}
+
+/// Progress of one goal in the week `week_start..=week_end`, as seen on `today`.
+fn goal_progress(
+    conn: &Connection,
+    goal: StudyGoal,
+    week_start: NaiveDate,
+    today: NaiveDate,
+) -> CommandResult<StudyGoalProgress> {
+    let week_end = week_start + chrono::Duration::days(6);
+    let goal_start = parse_date(&goal.start_date)?;
+    let goal_end = goal.end_date.as_deref().map(parse_date).transpose()?;
+
+    let (period_start, period_end, target_hours, weekly_target) = match goal.period.as_str() {
+        "term" => {
+            let (start, end) = (goal_start, goal_end.unwrap_or(week_end));
+            let term_weeks = ((end - start).num_days() + 7) / 7;
+            (
+                start,
+                end,
+                goal.target_hours,
+                goal.target_hours / term_weeks as f64,
+            )
+        }
+        _ => {
+            // A goal starting or ending mid-week only gets its share of the
+            // target for the days it covers.
+            let start = week_start.max(goal_start);
+            let end = goal_end.map_or(week_end, |e| e.min(week_end));
+            let days = (end - start).num_days() + 1;
+            let target = goal.target_hours * days as f64 / 7.0;
+            (start, end, target, goal.target_hours)
+        }
+    };
+
+    // Load from the first week touching the goal's start through this week.
+    let minutes = load_daily_minutes(
+        conn,
+        goal.subject.as_deref(),
+        (goal_start - chrono::Duration::days(6)).min(period_start),
+        week_end,
+    )?;
+
+    let actual_hours = hours_between(&minutes, period_start, period_end.min(week_end));
+    let remaining_hours = (target_hours - actual_hours).max(0.0);
+    let met = remaining_hours <= f64::EPSILON;
+
+    // A past week is over; otherwise count from today (or the period start).
+    let first_open_day = if today > week_end {
+        week_end + chrono::Duration::days(1)
+    } else {
+        today.max(period_start)
+    };
+    let days_remaining = ((period_end - first_open_day).num_days() + 1).max(0);
+    let required_hours_per_day =
+        (!met && days_remaining > 0).then(|| remaining_hours / days_remaining as f64);
+
+    // Weeks of the goal, newest first, on the same Monday-to-Sunday grid.
+    let week_met = |start: NaiveDate| {
+        let hours = hours_between(&minutes, start, start + chrono::Duration::days(6));
+        hours + f64::EPSILON >= weekly_target
+    };
+    let mut weeks = Vec::new();
+    let mut start = week_start;
+    while start + chrono::Duration::days(6) >= goal_start {
+        weeks.push(week_met(start));
+        start -= chrono::Duration::days(7);
+    }
+
+    let in_progress = today <= week_end && !weeks.first().copied().unwrap_or(false);
+    let current_streak_weeks = weeks
+        .iter()
+        .skip(usize::from(in_progress))
+        .take_while(|met| **met)
+        .count() as i64;
+    let best_streak_weeks = weeks
+        .split(|met| !*met)
+        .map(|run| run.len() as i64)
+        .max()
+        .unwrap_or(0);
+
+    Ok(StudyGoalProgress {
+        period_start: fmt_date(period_start),
+        period_end: fmt_date(period_end),
+        target_hours,
+        actual_hours,
+        remaining_hours,
+        percent_complete: actual_hours / target_hours * 100.0,
+        met,
+        days_remaining,
+        required_hours_per_day,
+        weekly_target_hours: weekly_target,
+        week_hours: hours_between(&minutes, week_start, week_end),
+        current_streak_weeks,
+        best_streak_weeks,
+        goal,
+    })
+}
+
+/// Progress of every goal active in the week starting `week_start`.
+/// `today` decides which days still count as remaining.
+pub(crate) fn get_study_goal_progress_impl(
+    conn: &Connection,
+    week_start: &str,
+    today: NaiveDate,
+) -> CommandResult<StudyGoalWeekProgress> {
+    let start = parse_date(week_start)?;
+    if start.weekday() != Weekday::Mon {
+        return Err(CommandError::from(format!(
+            "Week start {} is a {}; weeks start on Monday",
+            week_start,
+            start.format("%A")
+        )));
+    }
+    let end = start + chrono::Duration::days(6);
+
+    let sql = format!(
+        "SELECT {} FROM study_goal \
+         WHERE start_date <= ?2 AND (end_date IS NULL OR end_date >= ?1) {}",
+        STUDY_GOAL_COLUMNS, STUDY_GOAL_ORDER
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let goals = stmt
+        .query_map(params![fmt_date(start), fmt_date(end)], row_to_study_goal)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let goals = goals
+        .into_iter()
+        .map(|goal| goal_progress(conn, goal, start, today))
+        .collect::<CommandResult<Vec<_>>>()?;
+
+    Ok(StudyGoalWeekProgress {
+        week_start: fmt_date(start),
+        week_end: fmt_date(end),
+        goals,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tauri Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn get_study_goals(state: tauri::State<'_, AppState>) -> CommandResult<Vec<StudyGoal>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_goals_impl(&db)
+}
+
+#[tauri::command]
+pub fn save_study_goal(
+    state: tauri::State<'_, AppState>,
+    goal: StudyGoalInput,
+) -> CommandResult<StudyGoal> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    save_study_goal_impl(&db, goal)
+}
+
+#[tauri::command]
+pub fn update_study_goal(
+    state: tauri::State<'_, AppState>,
+    id: i64,
+    goal: StudyGoalInput,
+) -> CommandResult<StudyGoal> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    update_study_goal_impl(&db, id, goal)
+}
+
+#[tauri::command]
+pub fn delete_study_goal(state: tauri::State<'_, AppState>, id: i64) -> CommandResult<()> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    delete_study_goal_impl(&db, id)
+}
+
+#[tauri::command]
+pub fn get_study_goal_progress(
+    state: tauri::State<'_, AppState>,
+    week_start: String,
+) -> CommandResult<StudyGoalWeekProgress> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_goal_progress_impl(&db, &week_start, chrono::Local::now().date_naive())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migration should succeed");
+        conn
+    }
+
+    fn day(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    fn goal_input(subject: Option<&str>, period: &str, hours: f64) -> StudyGoalInput {
+        StudyGoalInput {
+            subject: subject.map(str::to_string),
+            period: period.to_string(),
+            target_hours: hours,
+            start_date: "2026-02-02".to_string(),
+            end_date: None,
+        }
+    }
+
+    fn insert_session(conn: &Connection, date: &str, subject: &str, minutes: i64) {
+        conn.execute(
+            "INSERT INTO study_session (\
+             date, subject, study_type, start_time, end_time, \
+             duration_minutes, focus_score, location, topic, \
+             resources, notes, logged_at, last_modified\
+             ) VALUES (?1, ?2, 'Self-Study', '09:00', '10:00', ?3, 4, 'Library', '', '', '', \
+             '2026-01-20T00:00:00+00:00', '2026-01-20T00:00:00+00:00')",
+            params![date, subject, minutes],
+        )
+        .unwrap();
+    }
+
+    fn progress(conn: &Connection, week_start: &str, today: &str) -> Vec<StudyGoalProgress> {
+        get_study_goal_progress_impl(conn, week_start, day(today))
+            .unwrap()
+            .goals
+    }
+
+    // -------------------------------------------------------------------
+    // CRUD Tests
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn test_save_update_delete_goal() {
+        let conn = setup_test_db();
+        let saved =
+            save_study_goal_impl(&conn, goal_input(Some(" Physics "), "week", 10.0)).unwrap();
+        assert_eq!(saved.subject.as_deref(), Some("Physics"));
+        assert_eq!(saved.end_date, None);
+
+        let mut input = goal_input(None, "term", 120.0);
+        input.end_date = Some("2026-05-31".to_string());
+        let updated = update_study_goal_impl(&conn, saved.id, input).unwrap();
+        assert_eq!(updated.subject, None);
+        assert_eq!(updated.period, "term");
+        assert_eq!(updated.created_at, saved.created_at);
+
+        assert_eq!(get_study_goals_impl(&conn).unwrap().len(), 1);
+        delete_study_goal_impl(&conn, saved.id).unwrap();
+        assert!(get_study_goals_impl(&conn).unwrap().is_empty());
+        assert!(delete_study_goal_impl(&conn, saved.id).is_err());
+        assert!(update_study_goal_impl(&conn, 999, goal_input(None, "week", 5.0)).is_err());
+    }
+
+    #[test]
+    fn test_goal_validation() {
+        let conn = setup_test_db();
+        let err = |input| save_study_goal_impl(&conn, input).unwrap_err().to_string();
+
+        assert!(err(goal_input(None, "month", 5.0)).contains("Invalid goal period"));
+        assert!(err(goal_input(None, "week", 0.0)).contains("greater than 0"));
+        assert!(err(goal_input(None, "week", 200.0)).contains("168"));
+        assert!(err(goal_input(None, "term", 50.0)).contains("needs an end date"));
+
+        let mut input = goal_input(None, "week", 5.0);
+        input.end_date = Some("2026-01-01".to_string());
+        assert!(err(input).contains("before start date"));
+
+        let mut input = goal_input(None, "week", 5.0);
+        input.start_date = "02/02/2026".to_string();
+        assert!(err(input).contains("Invalid date"));
+    }
+
+    // -------------------------------------------------------------------
+    // Progress Tests
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn test_weekly_progress_by_subject_and_overall() {
+        let conn = setup_test_db();
+        save_study_goal_impl(&conn, goal_input(Some("Physics"), "week", 5.0)).unwrap();
+        save_study_goal_impl(&conn, goal_input(None, "week", 6.0)).unwrap();
+        insert_session(&conn, "2026-02-09", "Physics", 120);
+        insert_session(&conn, "2026-02-10", "Maths", 90);
+        insert_session(&conn, "2026-02-16", "Physics", 600); // next week
+
+        // Wednesday of the week: Wed..Sun remain.
+        let goals = progress(&conn, "2026-02-09", "2026-02-11");
+        assert_eq!(goals.len(), 2);
+
+        let overall = &goals[0];
+        assert_eq!(overall.goal.subject, None);
+        assert!((overall.actual_hours - 3.5).abs() < 1e-9);
+        assert_eq!(overall.days_remaining, 5);
+        assert!((overall.required_hours_per_day.unwrap() - 0.5).abs() < 1e-9);
+
+        let physics = &goals[1];
+        assert_eq!(physics.period_start, "2026-02-09");
+        assert_eq!(physics.period_end, "2026-02-15");
+        assert!((physics.actual_hours - 2.0).abs() < 1e-9);
+        assert!((physics.remaining_hours - 3.0).abs() < 1e-9);
+        assert!((physics.percent_complete - 40.0).abs() < 1e-9);
+        assert!(!physics.met);
+    }
+
+    #[test]
+    fn test_past_week_has_no_days_remaining() {
+        let conn = setup_test_db();
+        save_study_goal_impl(&conn, goal_input(None, "week", 5.0)).unwrap();
+        insert_session(&conn, "2026-02-03", "Physics", 60);
+
+        let goal = &progress(&conn, "2026-02-02", "2026-03-01")[0];
+        assert_eq!(goal.days_remaining, 0);
+        assert_eq!(goal.required_hours_per_day, None);
+        assert_eq!(goal.current_streak_weeks, 0);
+    }
+
+    #[test]
+    fn test_weekly_streaks() {
+        let conn = setup_test_db();
+        save_study_goal_impl(&conn, goal_input(None, "week", 2.0)).unwrap();
+        // Weeks of Feb 2, 9 met; Feb 16 missed; Feb 23, Mar 2 met; Mar 9 in progress.
+        for date in ["2026-02-03", "2026-02-10", "2026-02-24", "2026-03-03"] {
+            insert_session(&conn, date, "Physics", 120);
+        }
+        insert_session(&conn, "2026-02-17", "Physics", 60);
+
+        let goal = &progress(&conn, "2026-03-09", "2026-03-10")[0];
+        assert!(!goal.met);
+        assert_eq!(
+            goal.current_streak_weeks, 2,
+            "in-progress week does not break it"
+        );
+        assert_eq!(goal.best_streak_weeks, 2);
+
+        // Once the week is over unmet, the streak is broken.
+        let goal = &progress(&conn, "2026-03-09", "2026-03-20")[0];
+        assert_eq!(goal.current_streak_weeks, 0);
+
+        insert_session(&conn, "2026-03-11", "Physics", 150);
+        let goal = &progress(&conn, "2026-03-09", "2026-03-11")[0];
+        assert!(goal.met);
+        assert_eq!(goal.required_hours_per_day, None);
+        assert_eq!(goal.current_streak_weeks, 3);
+        assert_eq!(goal.best_streak_weeks, 3);
+    }
+
+    #[test]
+    fn test_term_goal_pace_and_weekly_share() {
+        let conn = setup_test_db();
+        let mut input = goal_input(Some("Physics"), "term", 40.0);
+        input.end_date = Some("2026-03-01".to_string()); // 4 weeks
+        save_study_goal_impl(&conn, input).unwrap();
+        insert_session(&conn, "2026-02-03", "Physics", 600);
+        insert_session(&conn, "2026-02-10", "Physics", 300);
+        insert_session(&conn, "2026-02-11", "Maths", 600);
+
+        // Monday of week 2: Feb 9..Mar 1 remain (21 days), 25 hours to go.
+        let goal = &progress(&conn, "2026-02-09", "2026-02-09")[0];
+        assert_eq!(goal.period_start, "2026-02-02");
+        assert_eq!(goal.period_end, "2026-03-01");
+        assert!((goal.weekly_target_hours - 10.0).abs() < 1e-9);
+        assert!((goal.actual_hours - 15.0).abs() < 1e-9);
+        assert!((goal.week_hours - 5.0).abs() < 1e-9);
+        assert_eq!(goal.days_remaining, 21);
+        assert!((goal.required_hours_per_day.unwrap() - 25.0 / 21.0).abs() < 1e-9);
+        assert_eq!(goal.current_streak_weeks, 1);
+    }
+
+    #[test]
+    fn test_only_goals_active_in_week_are_reported() {
+        let conn = setup_test_db();
+        let mut ended = goal_input(None, "week", 3.0);
+        ended.end_date = Some("2026-02-08".to_string());
+        save_study_goal_impl(&conn, ended).unwrap();
+        let mut later = goal_input(None, "week", 3.0);
+        later.start_date = "2026-03-01".to_string();
+        save_study_goal_impl(&conn, later).unwrap();
+
+        assert!(progress(&conn, "2026-02-16", "2026-02-16").is_empty());
+        assert_eq!(progress(&conn, "2026-02-02", "2026-02-02").len(), 1);
+        assert!(get_study_goal_progress_impl(&conn, "bad", day("2026-02-02")).is_err());
+    }
+
+    #[test]
+    fn test_weekly_goal_ending_mid_week_is_cut_short() {
+        let conn = setup_test_db();
+        let mut goal = goal_input(None, "week", 7.0);
+        goal.end_date = Some("2026-02-11".to_string()); // Wednesday
+        save_study_goal_impl(&conn, goal).unwrap();
+        insert_session(&conn, "2026-02-09", "Physics", 60);
+        insert_session(&conn, "2026-02-12", "Physics", 600); // after the end
+
+        let goal = &progress(&conn, "2026-02-09", "2026-02-10")[0];
+        assert_eq!(goal.period_start, "2026-02-09");
+        assert_eq!(goal.period_end, "2026-02-11");
+        assert!((goal.target_hours - 3.0).abs() < 1e-9);
+        assert!((goal.actual_hours - 1.0).abs() < 1e-9);
+        assert_eq!(goal.days_remaining, 2);
+        assert!((goal.required_hours_per_day.unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_progress_rejects_week_not_starting_monday() {
+        let conn = setup_test_db();
+        let err = get_study_goal_progress_impl(&conn, "2026-02-11", day("2026-02-11"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Monday"), "{}", err);
+    }
+}
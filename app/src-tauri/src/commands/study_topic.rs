@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::engine::spaced_repetition::{
+    schedule_reviews, ReviewSchedule, TopicEvent, TopicEventKind,
+};
+use crate::AppState;
+
+use super::{CommandError, CommandResult};
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// `study_session.study_type` of sessions that count as reviews. Any other
+/// type counts as learning the topic.
+pub(crate) const REVIEW_STUDY_TYPE: &str = "Review";
+
+/// A topic within a subject, with its review schedule. Field names match the
+/// TypeScript `StudyTopic` interface.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyTopic {
+    pub id: i64,
+    pub subject: String,
+    pub name: String,
+    pub created_at: String,
+    /// Computed: sessions linked to the topic.
+    pub session_count: i64,
+    /// Computed from the linked sessions. `None` when there are none.
+    pub schedule: Option<ReviewSchedule>,
+}
+
+/// A topic due for review on the requested date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueReview {
+    pub topic: StudyTopic,
+    /// Days past `schedule.due_date`; 0 when due that day.
+    pub days_overdue: i64,
+}
+
+// ---------------------------------------------------------------------------
+// Topic Resolution
+// ---------------------------------------------------------------------------
+
+/// The id of the topic named `topic` in `subject`, created if missing.
+/// Names match ignoring case and surrounding whitespace. A blank topic has
+/// no id.
+pub(crate) fn resolve_study_topic(
+    conn: &Connection,
+    subject: &str,
+    topic: &str,
+) -> CommandResult<Option<i64>> {
+    let name = topic.trim();
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    let existing: Option<i64> = conn
+        .query_row(
+            "SELECT id FROM study_topic WHERE subject = ?1 AND name = ?2",
+            params![subject, name],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(id) = existing {
+        return Ok(Some(id));
+    }
+
+    conn.execute(
+        "INSERT INTO study_topic (subject, name, created_at) VALUES (?1, ?2, ?3)",
+        params![subject, name, chrono::Utc::now().to_rfc3339()],
+    )?;
+    Ok(Some(conn.last_insert_rowid()))
+}
+
+/// Deletes topics no session links to any more, after a session moved to
+/// another topic or was deleted.
+pub(crate) fn prune_study_topics(conn: &Connection) -> CommandResult<()> {
+    conn.execute(
+        "DELETE FROM study_topic WHERE NOT EXISTS \
+         (SELECT 1 FROM study_session WHERE study_session.topic_id = study_topic.id)",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Links sessions that have topic text but no `topic_id`, as after importing
+/// an export from before topics existed.
+pub(crate) fn link_session_topics(conn: &Connection) -> CommandResult<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id, subject, topic FROM study_session \
+         WHERE topic_id IS NULL AND TRIM(topic) != '' ORDER BY logged_at, id",
+    )?;
+    let sessions = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>("id")?,
+                row.get::<_, String>("subject")?,
+                row.get::<_, String>("topic")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, subject, topic) in sessions {
+        let topic_id = resolve_study_topic(conn, &subject, &topic)?;
+        conn.execute(
+            "UPDATE study_session SET topic_id = ?1 WHERE id = ?2",
+            params![topic_id, id],
+        )?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Schedules
+// ---------------------------------------------------------------------------
+
+/// Review events per topic from sessions on or before `as_of` (all sessions
+/// when `None`), oldest first. `focus_score` is the review grade.
+fn load_topic_events(
+    conn: &Connection,
+    as_of: Option<&str>,
+) -> CommandResult<HashMap<i64, Vec<TopicEvent>>> {
+    let mut stmt = conn.prepare(
+        "SELECT topic_id, date, study_type, focus_score FROM study_session \
+         WHERE topic_id IS NOT NULL AND (?1 IS NULL OR date <= ?1) \
+         ORDER BY date ASC, start_time ASC, id ASC",
+    )?;
+    let rows = stmt
+        .query_map(params![as_of], |row| {
+            Ok((
+                row.get::<_, i64>("topic_id")?,
+                row.get::<_, String>("date")?,
+                row.get::<_, String>("study_type")?,
+                row.get::<_, i64>("focus_score")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut events: HashMap<i64, Vec<TopicEvent>> = HashMap::new();
+    for (topic_id, date, study_type, focus_score) in rows {
+        let Ok(date) = NaiveDate::parse_from_str(&date, "%Y-%m-%d") else {
+            continue;
+        };
+        let kind = if study_type.eq_ignore_ascii_case(REVIEW_STUDY_TYPE) {
+            TopicEventKind::Review
+        } else {
+            TopicEventKind::Learn
+        };
+        events.entry(topic_id).or_default().push(TopicEvent {
+            date,
+            kind,
+            quality: focus_score,
+        });
+    }
+    Ok(events)
+}
+
+/// Every topic with its schedule as of `as_of`, ordered by subject and name.
+fn query_study_topics(conn: &Connection, as_of: Option<&str>) -> CommandResult<Vec<StudyTopic>> {
+    let events = load_topic_events(conn, as_of)?;
+
+    let mut stmt = conn
+        .prepare("SELECT id, subject, name, created_at FROM study_topic ORDER BY subject, name")?;
+    let topics = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>("id")?,
+                row.get::<_, String>("subject")?,
+                row.get::<_, String>("name")?,
+                row.get::<_, String>("created_at")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(topics
+        .into_iter()
+        .map(|(id, subject, name, created_at)| {
+            let topic_events = events.get(&id).map(Vec::as_slice).unwrap_or_default();
+            StudyTopic {
+                id,
+                subject,
+                name,
+                created_at,
+                session_count: topic_events.len() as i64,
+                schedule: schedule_reviews(topic_events),
+            }
+        })
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+pub(crate) fn get_study_topics_impl(conn: &Connection) -> CommandResult<Vec<StudyTopic>> {
+    query_study_topics(conn, None)
+}
+
+/// Topics whose next review falls on or before `date`, most overdue first.
+/// Only sessions up to `date` are replayed.
+pub(crate) fn get_due_reviews_impl(conn: &Connection, date: &str) -> CommandResult<Vec<DueReview>> {
+    let as_of = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| CommandError::from(format!("Invalid date '{}'. Expected YYYY-MM-DD", date)))?;
+
+    let mut due: Vec<DueReview> = query_study_topics(conn, Some(date))?
+        .into_iter()
+        .filter_map(|topic| {
+            let due_date = topic
+                .schedule
+                .as_ref()
+                .and_then(|s| NaiveDate::parse_from_str(&s.due_date, "%Y-%m-%d").ok())?;
+            (due_date <= as_of).then(|| DueReview {
+                days_overdue: (as_of - due_date).num_days(),
+                topic,
+            })
+        })
+        .collect();
+    due.sort_by_key(|d| std::cmp::Reverse(d.days_overdue));
+    Ok(due)
+}
+
+// ---------------------------------------------------------------------------
+// Tauri Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn get_study_topics(state: tauri::State<'_, AppState>) -> CommandResult<Vec<StudyTopic>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_study_topics_impl(&db)
+}
+
+#[tauri::command]
+pub fn get_due_reviews(
+    state: tauri::State<'_, AppState>,
+    date: String,
+) -> CommandResult<Vec<DueReview>> {
+    let db = state
+        .db
+        .lock()
+        .map_err(|_| CommandError::from("DB lock poisoned"))?;
+    get_due_reviews_impl(&db, &date)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::study::{
+        delete_study_session_impl, save_study_session_impl, update_study_session_impl,
+        StudySessionInput,
+    };
+    use crate::db::migrations::run_migrations;
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migration should succeed");
+        conn
+    }
+
+    fn session(date: &str, study_type: &str, topic: &str, focus: i64) -> StudySessionInput {
+        StudySessionInput {
+            date: date.to_string(),
+            subject: "Networking".to_string(),
+            study_type: study_type.to_string(),
+            start_time: "09:00".to_string(),
+            end_time: "10:00".to_string(),
+            duration_minutes: 0,
+            focus_score: focus,
+            location: "Library".to_string(),
+            topic: topic.to_string(),
+            resources: String::new(),
+            notes: String::new(),
+        }
+    }
+
+    fn due_names(conn: &Connection, date: &str) -> Vec<String> {
+        get_due_reviews_impl(conn, date)
+            .unwrap()
+            .into_iter()
+            .map(|d| d.topic.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_sessions_link_to_topics_case_insensitively() {
+        let conn = setup_test_db();
+        let a =
+            save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "Subnetting", 4))
+                .unwrap();
+        let b = save_study_session_impl(&conn, session("2026-03-02", "Review", " subnetting ", 4))
+            .unwrap();
+        let none =
+            save_study_session_impl(&conn, session("2026-03-03", "Homework", "", 4)).unwrap();
+
+        assert!(a.topic_id.is_some());
+        assert_eq!(a.topic_id, b.topic_id);
+        assert_eq!(none.topic_id, None);
+
+        let topics = get_study_topics_impl(&conn).unwrap();
+        assert_eq!(topics.len(), 1);
+        assert_eq!(topics[0].name, "Subnetting");
+        assert_eq!(topics[0].session_count, 2);
+    }
+
+    #[test]
+    fn test_same_topic_name_in_other_subject_is_separate() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "Routing", 4)).unwrap();
+        let mut other = session("2026-03-02", "Self-Study", "Routing", 4);
+        other.subject = "Certs".to_string();
+        save_study_session_impl(&conn, other).unwrap();
+        assert_eq!(get_study_topics_impl(&conn).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_review_sessions_update_schedule() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "VLANs", 4)).unwrap();
+
+        // Learned on the 1st: due on the 2nd.
+        assert!(due_names(&conn, "2026-03-01").is_empty());
+        assert_eq!(due_names(&conn, "2026-03-02"), vec!["VLANs"]);
+
+        // Reviewing it pushes the next review out: 1 day, then 6.
+        save_study_session_impl(&conn, session("2026-03-02", "Review", "VLANs", 5)).unwrap();
+        assert!(due_names(&conn, "2026-03-02").is_empty());
+        save_study_session_impl(&conn, session("2026-03-03", "Review", "VLANs", 5)).unwrap();
+        assert!(due_names(&conn, "2026-03-08").is_empty());
+        let due = get_due_reviews_impl(&conn, "2026-03-11").unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].days_overdue, 2);
+        let schedule = due[0].topic.schedule.as_ref().unwrap();
+        assert_eq!(schedule.due_date, "2026-03-09");
+        assert_eq!(schedule.review_count, 2);
+    }
+
+    #[test]
+    fn test_due_reviews_ignore_later_sessions_and_sort_by_overdue() {
+        let conn = setup_test_db();
+        save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "OSPF", 4)).unwrap();
+        save_study_session_impl(&conn, session("2026-03-05", "Self-Study", "BGP", 4)).unwrap();
+        save_study_session_impl(&conn, session("2026-03-20", "Review", "OSPF", 5)).unwrap();
+
+        // As of the 10th the review on the 20th has not happened yet.
+        assert_eq!(due_names(&conn, "2026-03-10"), vec!["OSPF", "BGP"]);
+        assert_eq!(due_names(&conn, "2026-03-20"), vec!["BGP"]);
+        assert!(get_due_reviews_impl(&conn, "10/03/2026").is_err());
+    }
+
+    #[test]
+    fn test_update_relinks_topic() {
+        let conn = setup_test_db();
+        let saved =
+            save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "NAT", 4)).unwrap();
+        let updated = update_study_session_impl(
+            &conn,
+            saved.id,
+            session("2026-03-01", "Self-Study", "DNS", 4),
+        )
+        .unwrap();
+        assert_ne!(updated.topic_id, saved.topic_id);
+
+        let names: Vec<String> = get_study_topics_impl(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["DNS"], "NAT has no sessions left");
+    }
+
+    #[test]
+    fn test_delete_removes_unused_topic() {
+        let conn = setup_test_db();
+        let first =
+            save_study_session_impl(&conn, session("2026-03-01", "Self-Study", "NAT", 4)).unwrap();
+        let second =
+            save_study_session_impl(&conn, session("2026-03-02", "Self-Study", "NAT", 4)).unwrap();
+
+        delete_study_session_impl(&conn, first.id).unwrap();
+        assert_eq!(get_study_topics_impl(&conn).unwrap()[0].session_count, 1);
+
+        delete_study_session_impl(&conn, second.id).unwrap();
+        assert!(get_study_topics_impl(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_update_of_missing_session_creates_no_topic() {
+        let conn = setup_test_db();
+        let result =
+            update_study_session_impl(&conn, 999, session("2026-03-01", "Self-Study", "NAT", 4));
+        assert!(result.is_err());
+        assert!(get_study_topics_impl(&conn).unwrap().is_empty());
+    }
+}
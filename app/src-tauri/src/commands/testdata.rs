@@ -839,6 +839,7 @@ mod tests {
         get_habit_completion_rates_impl, get_recovery_frequency_impl, get_score_trend_impl,
         get_study_summary_impl, get_vice_frequency_impl,
     };
+    use crate::commands::recovery::RecoveryView;
     use crate::db::migrations::run_migrations;
 
     fn setup_test_db() -> Connection {
@@ -1007,7 +1008,7 @@ mod tests {
                 Ok(())
             })),
             ("get_recovery_frequency", Box::new(|| {
-                get_recovery_frequency_impl(&conn, start, end)?;
+                get_recovery_frequency_impl(&conn, start, end, RecoveryView::Original)?;
                 Ok(())
             })),
         ];
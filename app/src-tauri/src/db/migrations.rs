@@ -33,7 +33,7 @@ pub fn run_migrations(conn: &mut Connection) -> Result<(), Box<dyn std::error::E
 
     for (version, name, sql) in migrations {
         if version > current {
-            println!("Applying migration {}: {}", version, name);
+            eprintln!("Applying migration {}: {}", version, name);
             let tx = conn.transaction()?;
             tx.execute_batch(sql)?;
             tx.execute(
@@ -41,7 +41,7 @@ pub fn run_migrations(conn: &mut Connection) -> Result<(), Box<dyn std::error::E
                 [version],
             )?;
             tx.commit()?;
-            println!("Migration {} applied successfully", version);
+            eprintln!("Migration {} applied successfully", version);
         }
     }
 
@@ -67,6 +67,61 @@ fn get_migrations() -> Vec<(i64, &'static str, &'static str)> {
             "reset_start_date",
             include_str!("../../migrations/003_reset_start_date.sql"),
         ),
+        (
+            4,
+            "search_index",
+            include_str!("../../migrations/004_search_index.sql"),
+        ),
+        (
+            5,
+            "milestone_rules",
+            include_str!("../../migrations/005_milestone_rules.sql"),
+        ),
+        (
+            6,
+            "trending_up_rule",
+            include_str!("../../migrations/006_trending_up_rule.sql"),
+        ),
+        (
+            7,
+            "clean_streaks",
+            include_str!("../../migrations/007_clean_streaks.sql"),
+        ),
+        (
+            8,
+            "status_change_audit",
+            include_str!("../../migrations/008_status_change_audit.sql"),
+        ),
+        (
+            9,
+            "contacts_interviews",
+            include_str!("../../migrations/009_contacts_interviews.sql"),
+        ),
+        (
+            10,
+            "recovery_amendment",
+            include_str!("../../migrations/010_recovery_amendment.sql"),
+        ),
+        (
+            11,
+            "study_timer",
+            include_str!("../../migrations/011_study_timer.sql"),
+        ),
+        (
+            12,
+            "study_goal",
+            include_str!("../../migrations/012_study_goal.sql"),
+        ),
+        (
+            13,
+            "study_topic",
+            include_str!("../../migrations/013_study_topic.sql"),
+        ),
+        (
+            14,
+            "periodic_review",
+            include_str!("../../migrations/014_periodic_review.sql"),
+        ),
     ]
 }
 
@@ -84,26 +139,37 @@ mod tests {
     }
 
     #[test]
-    fn all_11_tables_created() {
+    fn all_21_tables_created() {
         let conn = setup_test_db();
 
         let expected = [
             "app_config",
             "application",
+            "clean_streak",
+            "contact",
             "daily_log",
             "habit_config",
+            "interview",
             "journal",
             "milestone",
+            "periodic_review",
+            "recovery_amendment",
             "relapse_entry",
+            "search_index",
             "status_change",
+            "status_change_audit",
+            "study_goal",
             "study_session",
+            "study_timer",
+            "study_topic",
             "urge_entry",
             "weekly_review",
         ];
 
         let mut stmt = conn
             .prepare(
-                "SELECT name FROM sqlite_master WHERE type='table' AND name != 'schema_migrations' ORDER BY name",
+                "SELECT name FROM sqlite_master WHERE type='table' AND name != 'schema_migrations' \
+                 AND name NOT LIKE 'search_index_%' ORDER BY name",
             )
             .unwrap();
         let tables: Vec<String> = stmt
@@ -112,23 +178,29 @@ mod tests {
             .filter_map(|r| r.ok())
             .collect();
 
-        assert_eq!(tables, expected, "All 11 tables should exist");
+        assert_eq!(tables, expected, "All 21 tables should exist");
     }
 
     #[test]
-    fn all_11_indexes_created() {
+    fn all_17_indexes_created() {
         let conn = setup_test_db();
 
         let expected = [
             "idx_application_company",
             "idx_application_date",
             "idx_application_status",
+            "idx_clean_streak_default",
+            "idx_contact_app",
             "idx_daily_log_date",
+            "idx_interview_app",
             "idx_journal_date",
+            "idx_recovery_amendment_entry",
             "idx_relapse_date",
             "idx_status_change_app",
+            "idx_status_change_audit_app",
             "idx_study_session_date",
             "idx_study_session_subject",
+            "idx_study_session_topic",
             "idx_urge_date",
             "idx_weekly_review_week",
         ];
@@ -142,7 +214,7 @@ mod tests {
             .filter_map(|r| r.ok())
             .collect();
 
-        assert_eq!(indexes, expected, "All 11 indexes should exist");
+        assert_eq!(indexes, expected, "All 17 indexes should exist");
     }
 
     #[test]
@@ -318,12 +390,57 @@ mod tests {
 
         let table_count: i64 = conn
             .query_row(
-                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name != 'schema_migrations'",
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name != 'schema_migrations' \
+                 AND name NOT LIKE 'search_index_%'",
                 [],
                 |row| row.get(0),
             )
             .unwrap();
 
-        assert_eq!(table_count, 11, "Should still have exactly 11 tables");
+        assert_eq!(table_count, 21, "Should still have exactly 21 tables");
+    }
+
+    #[test]
+    fn study_topic_migration_backfills_sessions() {
+        let conn = Connection::open_in_memory().unwrap();
+        let (earlier, later): (Vec<_>, Vec<_>) =
+            get_migrations().into_iter().partition(|(version, _, _)| *version < 13);
+        for (_, _, sql) in earlier {
+            conn.execute_batch(sql).unwrap();
+        }
+        conn.execute_batch(
+            "INSERT INTO study_session (date, subject, study_type, start_time, end_time, \
+             duration_minutes, focus_score, location, topic, resources, notes, logged_at, \
+             last_modified) VALUES \
+             ('2026-03-01', 'Certs', 'Self-Study', '09:00', '10:00', 60, 4, 'Home', 'Ports', \
+              '', '', '2026-03-01T10:00:00Z', '2026-03-01T10:00:00Z'), \
+             ('2026-03-02', 'Certs', 'Review', '09:00', '10:00', 60, 4, 'Home', 'ports ', \
+              '', '', '2026-03-02T10:00:00Z', '2026-03-02T10:00:00Z'), \
+             ('2026-03-02', 'Certs', 'Review', '11:00', '12:00', 60, 4, 'Home', '', \
+              '', '', '2026-03-02T12:00:00Z', '2026-03-02T12:00:00Z');",
+        )
+        .unwrap();
+        for (_, _, sql) in later {
+            conn.execute_batch(sql).unwrap();
+        }
+
+        let topics: Vec<(i64, String)> = conn
+            .prepare("SELECT id, name FROM study_topic")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(topics.len(), 1);
+        assert_eq!(topics[0].1, "Ports", "named as first logged");
+
+        let linked: Vec<Option<i64>> = conn
+            .prepare("SELECT topic_id FROM study_session ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(linked, vec![Some(topics[0].0), Some(topics[0].0), None]);
     }
 }
@@ -2,7 +2,7 @@ pub mod backup;
 pub mod migrations;
 
 use rusqlite::Connection;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Get the platform-specific database directory.
 /// Per ADR-001 SD1: app_data_dir()/ltu.db
@@ -21,8 +21,13 @@ pub fn init_db() -> Result<Connection, Box<dyn std::error::Error>> {
     let db_dir = get_db_dir();
     std::fs::create_dir_all(&db_dir)?;
 
-    let db_path = get_db_path();
-    let conn = Connection::open(&db_path)?;
+    open_db(&get_db_path())
+}
+
+/// Open a database file at an explicit path with the same pragmas as the app.
+/// Used directly by the headless CLI, which may point at a fixture database.
+pub fn open_db(db_path: &Path) -> Result<Connection, Box<dyn std::error::Error>> {
+    let conn = Connection::open(db_path)?;
 
     // Enable WAL mode for better concurrent read performance
     conn.execute_batch("PRAGMA journal_mode=WAL;")?;
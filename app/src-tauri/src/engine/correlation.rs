@@ -0,0 +1,402 @@
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Minimum paired observations before a coefficient is reported. Matches the
+/// TypeScript engine (ADR-003 SD2): below 7 pairs a single outlier dominates
+/// and the t-test has too few degrees of freedom to mean anything.
+pub const MIN_DATA_POINTS: usize = 7;
+
+/// Two-sided 95% critical value of the standard normal distribution.
+const Z_95: f64 = 1.959_963_984_540_054;
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// Summary statistics for one pair of series.
+///
+/// `flag` mirrors the TypeScript engine: `"insufficient_data"` (fewer than
+/// `MIN_DATA_POINTS` pairs, all coefficients `None`) or `"zero_variance"`
+/// (one series is constant, coefficients reported as 0 rather than NaN).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorrelationStats {
+    pub n: i64,
+    pub pearson_r: Option<f64>,
+    pub spearman_rho: Option<f64>,
+    /// Two-tailed p-value for H0: ρ = 0 (t-test on Pearson's r, df = n − 2).
+    pub p_value: Option<f64>,
+    /// 95% confidence interval for Pearson's r via the Fisher z-transform.
+    pub ci_low: Option<f64>,
+    pub ci_high: Option<f64>,
+    pub flag: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Helper: Coefficients
+// ---------------------------------------------------------------------------
+
+/// Pearson's r for two equal-length series.
+/// Returns `None` if the lengths differ, n < 2, or either series is constant.
+pub fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let n = x.len();
+    if n != y.len() || n < 2 {
+        return None;
+    }
+
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+
+    let mut cov = 0.0;
+    let mut var_x = 0.0;
+    let mut var_y = 0.0;
+    for (xi, yi) in x.iter().zip(y) {
+        let dx = xi - mean_x;
+        let dy = yi - mean_y;
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+
+    let r = cov / (var_x.sqrt() * var_y.sqrt());
+    if r.is_finite() {
+        // Guard against rounding pushing |r| fractionally above 1
+        Some(r.clamp(-1.0, 1.0))
+    } else {
+        None
+    }
+}
+
+/// 1-based ranks with ties assigned their average rank.
+pub fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        // Positions i..=j share the average of ranks (i+1)..=(j+1)
+        let avg = (i + j) as f64 / 2.0 + 1.0;
+        for &idx in &order[i..=j] {
+            ranks[idx] = avg;
+        }
+        i = j + 1;
+    }
+    ranks
+}
+
+/// Spearman's rho: Pearson's r on average ranks (handles ties correctly).
+pub fn spearman(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() != y.len() {
+        return None;
+    }
+    pearson(&average_ranks(x), &average_ranks(y))
+}
+
+// ---------------------------------------------------------------------------
+// Helper: Significance
+// ---------------------------------------------------------------------------
+
+/// Natural log of the gamma function (Lanczos approximation, g = 7).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let mut sum = COEFFS[0];
+    for (i, c) in COEFFS.iter().enumerate().skip(1) {
+        sum += c / (x + i as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Continued-fraction evaluation for the incomplete beta function
+/// (modified Lentz's method).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITER: usize = 300;
+    const EPS: f64 = 1e-14;
+    const FPMIN: f64 = 1e-300;
+
+    let guard = |v: f64| if v.abs() < FPMIN { FPMIN } else { v };
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 / guard(1.0 - qab * x / qap);
+    let mut h = d;
+
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 / guard(1.0 + aa * d);
+        c = guard(1.0 + aa / c);
+        h *= d * c;
+
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 / guard(1.0 + aa * d);
+        c = guard(1.0 + aa / c);
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+/// Regularized incomplete beta function I_x(a, b).
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    let front = ln_front.exp();
+
+    // The continued fraction converges fastest on this side of the mean
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Two-tailed p-value for a Pearson coefficient `r` over `n` pairs.
+///
+/// Uses t = r·√((n−2)/(1−r²)) with n − 2 degrees of freedom; the Student-t
+/// tail probability is I_{df/(df+t²)}(df/2, 1/2). Returns `None` for n < 3.
+pub fn p_value(r: f64, n: usize) -> Option<f64> {
+    if n < 3 || !r.is_finite() {
+        return None;
+    }
+    let df = (n - 2) as f64;
+    let r2 = r * r;
+    if r2 >= 1.0 {
+        return Some(0.0);
+    }
+    let t2 = r2 * df / (1.0 - r2);
+    let p = regularized_incomplete_beta(df / 2.0, 0.5, df / (df + t2));
+    Some(p.clamp(0.0, 1.0))
+}
+
+/// 95% confidence interval for `r` via the Fisher z-transform.
+/// Returns `None` for n ≤ 3, where the standard error is undefined.
+pub fn confidence_interval_95(r: f64, n: usize) -> Option<(f64, f64)> {
+    if n <= 3 || !r.is_finite() {
+        return None;
+    }
+    // atanh(±1) is infinite; nudge perfect correlations inside the domain
+    let z = r.clamp(-0.999_999_9, 0.999_999_9).atanh();
+    let se = 1.0 / ((n - 3) as f64).sqrt();
+    Some(((z - Z_95 * se).tanh(), (z + Z_95 * se).tanh()))
+}
+
+// ---------------------------------------------------------------------------
+// Helper: Lagged Pairing
+// ---------------------------------------------------------------------------
+
+/// Pair `x[i]` with `y[i + lag]`, keeping only positions where both are
+/// present. Both series must be indexed by consecutive calendar days, so
+/// `lag = 1` means "habit today vs. outcome tomorrow".
+pub fn lagged_pairs(x: &[Option<f64>], y: &[Option<f64>], lag: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+    for (i, xi) in x.iter().enumerate() {
+        if let (Some(xv), Some(Some(yv))) = (xi, y.get(i + lag)) {
+            xs.push(*xv);
+            ys.push(*yv);
+        }
+    }
+    (xs, ys)
+}
+
+// ---------------------------------------------------------------------------
+// Public API: correlate
+// ---------------------------------------------------------------------------
+
+/// Compute Pearson, Spearman, p-value and 95% CI for paired observations.
+pub fn correlate(x: &[f64], y: &[f64]) -> CorrelationStats {
+    let n = x.len().min(y.len());
+    let (x, y) = (&x[..n], &y[..n]);
+
+    let empty = |flag: &str, r: Option<f64>| CorrelationStats {
+        n: n as i64,
+        pearson_r: r,
+        spearman_rho: r,
+        p_value: None,
+        ci_low: None,
+        ci_high: None,
+        flag: Some(flag.to_string()),
+    };
+
+    if n < MIN_DATA_POINTS {
+        return empty("insufficient_data", None);
+    }
+
+    let r = match pearson(x, y) {
+        Some(r) => r,
+        // Only a constant series makes pearson() fail at this n
+        None => return empty("zero_variance", Some(0.0)),
+    };
+    let ci = confidence_interval_95(r, n);
+
+    CorrelationStats {
+        n: n as i64,
+        pearson_r: Some(r),
+        spearman_rho: spearman(x, y),
+        p_value: p_value(r, n),
+        ci_low: ci.map(|(lo, _)| lo),
+        ci_high: ci.map(|(_, hi)| hi),
+        flag: None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, label: &str) {
+        let diff = (actual - expected).abs();
+        assert!(
+            diff < 0.001,
+            "{}: expected {:.5}, got {:.5} (diff {:.6})",
+            label, expected, actual, diff
+        );
+    }
+
+    #[test]
+    fn test_pearson_perfect_positive_and_negative() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_close(pearson(&x, &[2.0, 4.0, 6.0, 8.0, 10.0]).unwrap(), 1.0, "r=+1");
+        assert_close(pearson(&x, &[5.0, 4.0, 3.0, 2.0, 1.0]).unwrap(), -1.0, "r=-1");
+    }
+
+    #[test]
+    fn test_pearson_known_value() {
+        // Hand-computed: Σdxdy = 6, Σdx² = 10, Σdy² = 6 → r = 6/√60 ≈ 0.77460
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [2.0, 4.0, 5.0, 4.0, 5.0];
+        assert_close(pearson(&x, &y).unwrap(), 0.77460, "r");
+    }
+
+    #[test]
+    fn test_pearson_zero_variance_and_mismatch() {
+        assert_eq!(pearson(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]), None);
+        assert_eq!(pearson(&[1.0, 2.0], &[1.0]), None);
+        assert_eq!(pearson(&[1.0], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_average_ranks_with_ties() {
+        let ranks = average_ranks(&[10.0, 20.0, 20.0, 5.0]);
+        assert_eq!(ranks, vec![2.0, 3.5, 3.5, 1.0]);
+    }
+
+    #[test]
+    fn test_spearman_monotonic_nonlinear() {
+        // y = x³ is perfectly monotonic: rho = 1 while Pearson < 1
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y: Vec<f64> = x.iter().map(|v| v * v * v).collect();
+        assert_close(spearman(&x, &y).unwrap(), 1.0, "rho");
+        assert!(pearson(&x, &y).unwrap() < 0.99);
+    }
+
+    #[test]
+    fn test_p_value_reference_points() {
+        // r = 0.5, n = 10 → t = 1.633, df = 8 → p ≈ 0.1411
+        assert_close(p_value(0.5, 10).unwrap(), 0.1411, "p(r=0.5,n=10)");
+        // r = 0.632, n = 10 sits right at the classic 0.05 critical value
+        assert_close(p_value(0.632, 10).unwrap(), 0.050, "p(r=0.632,n=10)");
+        assert_close(p_value(0.0, 30).unwrap(), 1.0, "p(r=0)");
+        assert_eq!(p_value(1.0, 10), Some(0.0));
+        assert_eq!(p_value(0.5, 2), None);
+    }
+
+    #[test]
+    fn test_confidence_interval_brackets_r() {
+        // r = 0.5, n = 30: z = 0.5493, se = 0.19245 → (0.1704, 0.7289)
+        let (lo, hi) = confidence_interval_95(0.5, 30).unwrap();
+        assert_close(lo, 0.1704, "ci_low");
+        assert_close(hi, 0.7289, "ci_high");
+        assert_eq!(confidence_interval_95(0.5, 3), None);
+    }
+
+    #[test]
+    fn test_lagged_pairs_skips_missing_days() {
+        let x = [Some(1.0), Some(0.0), None, Some(1.0)];
+        let y = [Some(10.0), Some(20.0), Some(30.0), Some(40.0)];
+
+        let (xs, ys) = lagged_pairs(&x, &y, 0);
+        assert_eq!(xs, vec![1.0, 0.0, 1.0]);
+        assert_eq!(ys, vec![10.0, 20.0, 40.0]);
+
+        // lag 1: x[0]→y[1], x[1]→y[2], x[3]→(out of range)
+        let (xs, ys) = lagged_pairs(&x, &y, 1);
+        assert_eq!(xs, vec![1.0, 0.0]);
+        assert_eq!(ys, vec![20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_correlate_flags() {
+        let short = correlate(&[1.0, 0.0, 1.0], &[3.0, 1.0, 2.0]);
+        assert_eq!(short.flag.as_deref(), Some("insufficient_data"));
+        assert_eq!(short.pearson_r, None);
+        assert_eq!(short.n, 3);
+
+        let constant = correlate(&[1.0; 8], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(constant.flag.as_deref(), Some("zero_variance"));
+        assert_eq!(constant.pearson_r, Some(0.0));
+    }
+
+    #[test]
+    fn test_correlate_full_stats() {
+        let x = [0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let y = [40.0, 70.0, 45.0, 80.0, 65.0, 50.0, 75.0, 60.0, 35.0, 85.0];
+        let stats = correlate(&x, &y);
+
+        assert_eq!(stats.n, 10);
+        assert_eq!(stats.flag, None);
+        let r = stats.pearson_r.unwrap();
+        assert!(r > 0.8, "strong positive relationship, got {}", r);
+        assert!(stats.spearman_rho.unwrap() > 0.8);
+        assert!(stats.p_value.unwrap() < 0.01);
+        assert!(stats.ci_low.unwrap() < r && r < stats.ci_high.unwrap());
+    }
+}
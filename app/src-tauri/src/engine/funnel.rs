@@ -0,0 +1,512 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::stats::{median, parse_date};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Forward stages of an application, in pipeline order. Matches the
+/// frontend's `PIPELINE_ORDER` up to `offer`.
+pub const PROGRESSION_STAGES: [&str; 5] = [
+    "applied",
+    "phone_screen",
+    "technical_screen",
+    "interview",
+    "offer",
+];
+
+/// Every status in display order: the progression stages, then the outcomes.
+pub const PIPELINE_ORDER: [&str; 8] = [
+    "applied",
+    "phone_screen",
+    "technical_screen",
+    "interview",
+    "offer",
+    "rejected",
+    "withdrawn",
+    "no_response",
+];
+
+/// Statuses that count as a reply from the employer. `withdrawn` is the
+/// applicant's own decision and `no_response` is the absence of a reply.
+const RESPONSE_STATUSES: [&str; 5] = [
+    "phone_screen",
+    "technical_screen",
+    "interview",
+    "offer",
+    "rejected",
+];
+
+/// Label used for an empty source or role.
+const UNKNOWN_LABEL: &str = "Unknown";
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// One application and its status history, as loaded from the database.
+#[derive(Debug, Clone)]
+pub struct ApplicationHistory {
+    pub source: String,
+    pub role: String,
+    pub date_applied: String,
+    pub current_status: String,
+    /// `(status, YYYY-MM-DD)` pairs in the order they were recorded.
+    pub changes: Vec<(String, String)>,
+}
+
+/// Applications reaching one progression stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunnelStage {
+    pub status: String,
+    /// Applications that reached this stage or a later one.
+    pub reached: i64,
+    /// `reached / previous stage's reached`. `None` for `applied` or when the
+    /// previous stage was never reached.
+    pub conversion_rate: Option<f64>,
+    /// Median days from reaching the previous stage to reaching this one.
+    pub median_days_from_previous: Option<f64>,
+}
+
+/// Response rate and speed for one application source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceFunnel {
+    pub source: String,
+    pub applications: i64,
+    pub responses: i64,
+    pub response_rate: f64,
+    /// Median days from applying to the first employer reply.
+    pub median_days_to_response: Option<f64>,
+    pub offers: i64,
+}
+
+/// Current outcome counts for one role.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoleOutcomes {
+    pub role: String,
+    pub applications: i64,
+    /// Still at applied, phone_screen, technical_screen or interview.
+    pub in_progress: i64,
+    pub offers: i64,
+    pub rejected: i64,
+    pub withdrawn: i64,
+    pub no_response: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationFunnel {
+    pub total: i64,
+    pub responses: i64,
+    /// Fraction of applications with an employer reply (0 when empty).
+    pub response_rate: f64,
+    pub median_days_to_response: Option<f64>,
+    pub stages: Vec<FunnelStage>,
+    /// Most applications first.
+    pub by_source: Vec<SourceFunnel>,
+    /// Most applications first.
+    pub by_role: Vec<RoleOutcomes>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Position of `status` in `PROGRESSION_STAGES`; `None` for outcomes.
+pub fn stage_index(status: &str) -> Option<usize> {
+    PROGRESSION_STAGES.iter().position(|s| *s == status)
+}
+
+/// Position of `status` in `PIPELINE_ORDER`; unknown statuses sort last.
+pub fn pipeline_rank(status: &str) -> usize {
+    PIPELINE_ORDER
+        .iter()
+        .position(|s| *s == status)
+        .unwrap_or(PIPELINE_ORDER.len())
+}
+
+fn rate(part: i64, whole: i64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+/// Date the application first reached each progression stage. Skipping a
+/// stage (applied → interview) reaches it on the later stage's date.
+fn stage_dates(app: &ApplicationHistory) -> [Option<NaiveDate>; 5] {
+    let mut reached = [None; 5];
+    reached[0] = parse_date(&app.date_applied);
+    for (status, date) in &app.changes {
+        let (Some(index), Some(date)) = (stage_index(status), parse_date(date)) else {
+            continue;
+        };
+        for slot in reached.iter_mut().take(index + 1) {
+            if !slot.is_some_and(|d| d <= date) {
+                *slot = Some(date);
+            }
+        }
+    }
+    reached
+}
+
+/// Days from applying to the first employer reply, if there was one.
+fn days_to_response(app: &ApplicationHistory) -> Option<i64> {
+    let applied = parse_date(&app.date_applied)?;
+    app.changes
+        .iter()
+        .filter(|(status, _)| RESPONSE_STATUSES.contains(&status.as_str()))
+        .filter_map(|(_, date)| parse_date(date))
+        .min()
+        .map(|d| (d - applied).num_days().max(0))
+}
+
+/// Group key for free-text source/role: case-insensitive, trimmed, with the
+/// first spelling seen used as the label.
+fn group_label(value: &str) -> (String, String) {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        (UNKNOWN_LABEL.to_lowercase(), UNKNOWN_LABEL.to_string())
+    } else {
+        (trimmed.to_lowercase(), trimmed.to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Funnel
+// ---------------------------------------------------------------------------
+
+/// Funnel, response and outcome analytics over `apps`.
+pub fn application_funnel(apps: &[ApplicationHistory]) -> ApplicationFunnel {
+    let mut reached = [0i64; 5];
+    let mut stage_days: [Vec<f64>; 5] = Default::default();
+    let mut response_days = Vec::new();
+
+    // Grouped by case-insensitive key, with response days per source
+    let mut sources: Vec<(String, SourceFunnel, Vec<f64>)> = Vec::new();
+    let mut roles: Vec<(String, RoleOutcomes)> = Vec::new();
+
+    for app in apps {
+        let dates = stage_dates(app);
+        for k in 0..PROGRESSION_STAGES.len() {
+            let Some(date) = dates[k] else { continue };
+            reached[k] += 1;
+            if k > 0 {
+                if let Some(prev) = dates[k - 1] {
+                    stage_days[k].push((date - prev).num_days().max(0) as f64);
+                }
+            }
+        }
+
+        let response = days_to_response(app);
+        if let Some(days) = response {
+            response_days.push(days as f64);
+        }
+        let has_offer = dates[4].is_some();
+
+        let (key, label) = group_label(&app.source);
+        let index = match sources.iter().position(|(k, _, _)| *k == key) {
+            Some(i) => i,
+            None => {
+                sources.push((
+                    key,
+                    SourceFunnel {
+                        source: label,
+                        applications: 0,
+                        responses: 0,
+                        response_rate: 0.0,
+                        median_days_to_response: None,
+                        offers: 0,
+                    },
+                    Vec::new(),
+                ));
+                sources.len() - 1
+            }
+        };
+        let (_, source, days) = &mut sources[index];
+        source.applications += 1;
+        if let Some(d) = response {
+            source.responses += 1;
+            days.push(d as f64);
+        }
+        if has_offer {
+            source.offers += 1;
+        }
+
+        let (key, label) = group_label(&app.role);
+        let index = match roles.iter().position(|(k, _)| *k == key) {
+            Some(i) => i,
+            None => {
+                roles.push((
+                    key,
+                    RoleOutcomes {
+                        role: label,
+                        applications: 0,
+                        in_progress: 0,
+                        offers: 0,
+                        rejected: 0,
+                        withdrawn: 0,
+                        no_response: 0,
+                    },
+                ));
+                roles.len() - 1
+            }
+        };
+        let role = &mut roles[index].1;
+        role.applications += 1;
+        match app.current_status.as_str() {
+            "offer" => role.offers += 1,
+            "rejected" => role.rejected += 1,
+            "withdrawn" => role.withdrawn += 1,
+            "no_response" => role.no_response += 1,
+            _ => role.in_progress += 1,
+        }
+    }
+
+    let stages = PROGRESSION_STAGES
+        .iter()
+        .enumerate()
+        .map(|(k, status)| FunnelStage {
+            status: status.to_string(),
+            reached: reached[k],
+            conversion_rate: (k > 0 && reached[k - 1] > 0)
+                .then(|| rate(reached[k], reached[k - 1])),
+            median_days_from_previous: median(&mut stage_days[k]),
+        })
+        .collect();
+
+    let mut by_source: Vec<SourceFunnel> = sources
+        .into_iter()
+        .map(|(_, mut source, mut days)| {
+            source.response_rate = rate(source.responses, source.applications);
+            source.median_days_to_response = median(&mut days);
+            source
+        })
+        .collect();
+    by_source.sort_by(|a, b| {
+        b.applications
+            .cmp(&a.applications)
+            .then(a.source.cmp(&b.source))
+    });
+
+    let mut by_role: Vec<RoleOutcomes> = roles.into_iter().map(|(_, role)| role).collect();
+    by_role.sort_by(|a, b| {
+        b.applications
+            .cmp(&a.applications)
+            .then(a.role.cmp(&b.role))
+    });
+
+    let total = apps.len() as i64;
+    let responses = response_days.len() as i64;
+    ApplicationFunnel {
+        total,
+        responses,
+        response_rate: rate(responses, total),
+        median_days_to_response: median(&mut response_days),
+        stages,
+        by_source,
+        by_role,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(
+        source: &str,
+        role: &str,
+        applied: &str,
+        changes: &[(&str, &str)],
+    ) -> ApplicationHistory {
+        let mut all = vec![("applied".to_string(), applied.to_string())];
+        all.extend(changes.iter().map(|(s, d)| (s.to_string(), d.to_string())));
+        ApplicationHistory {
+            source: source.to_string(),
+            role: role.to_string(),
+            date_applied: applied.to_string(),
+            current_status: all.last().unwrap().0.clone(),
+            changes: all,
+        }
+    }
+
+    fn stage<'a>(f: &'a ApplicationFunnel, status: &str) -> &'a FunnelStage {
+        f.stages.iter().find(|s| s.status == status).unwrap()
+    }
+
+    #[test]
+    fn test_empty_funnel() {
+        let f = application_funnel(&[]);
+        assert_eq!(f.total, 0);
+        assert_eq!(f.response_rate, 0.0);
+        assert_eq!(f.median_days_to_response, None);
+        assert_eq!(f.stages.len(), 5);
+        assert!(f
+            .stages
+            .iter()
+            .all(|s| s.reached == 0 && s.conversion_rate.is_none()));
+        assert!(f.by_source.is_empty());
+        assert!(f.by_role.is_empty());
+    }
+
+    #[test]
+    fn test_stage_conversion_and_velocity() {
+        let apps = vec![
+            app(
+                "LinkedIn",
+                "Dev",
+                "2026-01-01",
+                &[("phone_screen", "2026-01-05"), ("interview", "2026-01-15")],
+            ),
+            app(
+                "LinkedIn",
+                "Dev",
+                "2026-01-01",
+                &[("phone_screen", "2026-01-11"), ("rejected", "2026-01-12")],
+            ),
+            app("LinkedIn", "Dev", "2026-01-01", &[]),
+            app("LinkedIn", "Dev", "2026-01-01", &[]),
+        ];
+        let f = application_funnel(&apps);
+
+        assert_eq!(stage(&f, "applied").reached, 4);
+        assert_eq!(stage(&f, "applied").conversion_rate, None);
+        assert_eq!(stage(&f, "phone_screen").reached, 2);
+        assert_eq!(stage(&f, "phone_screen").conversion_rate, Some(0.5));
+        // 4 and 10 days
+        assert_eq!(
+            stage(&f, "phone_screen").median_days_from_previous,
+            Some(7.0)
+        );
+        // Interview skipped technical_screen: both reached on 2026-01-15
+        assert_eq!(stage(&f, "technical_screen").reached, 1);
+        assert_eq!(
+            stage(&f, "technical_screen").median_days_from_previous,
+            Some(10.0)
+        );
+        assert_eq!(stage(&f, "interview").reached, 1);
+        assert_eq!(stage(&f, "interview").conversion_rate, Some(1.0));
+        assert_eq!(stage(&f, "interview").median_days_from_previous, Some(0.0));
+        assert_eq!(stage(&f, "offer").reached, 0);
+        assert_eq!(stage(&f, "offer").conversion_rate, Some(0.0));
+    }
+
+    #[test]
+    fn test_response_rate_and_time_by_source() {
+        let apps = vec![
+            app(
+                "LinkedIn",
+                "Dev",
+                "2026-01-01",
+                &[("rejected", "2026-01-03")],
+            ),
+            app(
+                "linkedin ",
+                "Dev",
+                "2026-01-01",
+                &[("phone_screen", "2026-01-09")],
+            ),
+            app(
+                "LinkedIn",
+                "Dev",
+                "2026-01-01",
+                &[("no_response", "2026-02-01")],
+            ),
+            app("Referral", "Dev", "2026-01-01", &[("offer", "2026-01-20")]),
+            app("", "Dev", "2026-01-01", &[("withdrawn", "2026-01-02")]),
+        ];
+        let f = application_funnel(&apps);
+
+        assert_eq!(f.total, 5);
+        assert_eq!(f.responses, 3);
+        assert!((f.response_rate - 0.6).abs() < 1e-9);
+        assert_eq!(f.median_days_to_response, Some(8.0));
+
+        let sources: Vec<&str> = f.by_source.iter().map(|s| s.source.as_str()).collect();
+        assert_eq!(sources, vec!["LinkedIn", "Referral", "Unknown"]);
+        let linkedin = &f.by_source[0];
+        assert_eq!(linkedin.applications, 3);
+        assert_eq!(linkedin.responses, 2);
+        assert!((linkedin.response_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(linkedin.median_days_to_response, Some(5.0));
+        assert_eq!(linkedin.offers, 0);
+        assert_eq!(f.by_source[1].offers, 1);
+        // Withdrawing is not a response
+        assert_eq!(f.by_source[2].responses, 0);
+        assert_eq!(f.by_source[2].median_days_to_response, None);
+    }
+
+    #[test]
+    fn test_outcomes_by_role() {
+        let apps = vec![
+            app("LinkedIn", "Dev", "2026-01-01", &[("offer", "2026-01-20")]),
+            app(
+                "LinkedIn",
+                "Dev",
+                "2026-01-01",
+                &[("rejected", "2026-01-03")],
+            ),
+            app(
+                "LinkedIn",
+                "dev",
+                "2026-01-01",
+                &[("interview", "2026-01-03")],
+            ),
+            app(
+                "LinkedIn",
+                "QA",
+                "2026-01-01",
+                &[("no_response", "2026-02-01")],
+            ),
+            app(
+                "LinkedIn",
+                "QA",
+                "2026-01-01",
+                &[("withdrawn", "2026-01-02")],
+            ),
+        ];
+        let f = application_funnel(&apps);
+
+        assert_eq!(f.by_role.len(), 2);
+        let dev = &f.by_role[0];
+        assert_eq!(dev.role, "Dev");
+        assert_eq!(
+            (dev.applications, dev.in_progress, dev.offers, dev.rejected),
+            (3, 1, 1, 1)
+        );
+        let qa = &f.by_role[1];
+        assert_eq!((qa.applications, qa.no_response, qa.withdrawn), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_pipeline_rank_uses_snake_case_order() {
+        let mut statuses = vec![
+            "no_response",
+            "offer",
+            "applied",
+            "interview",
+            "technical_screen",
+            "Applied",
+        ];
+        statuses.sort_by_key(|s| pipeline_rank(s));
+        assert_eq!(
+            statuses,
+            vec![
+                "applied",
+                "technical_screen",
+                "interview",
+                "offer",
+                "no_response",
+                "Applied"
+            ]
+        );
+        assert_eq!(stage_index("phone_screen"), Some(1));
+        assert_eq!(stage_index("rejected"), None);
+    }
+}
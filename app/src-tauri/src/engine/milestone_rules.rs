@@ -0,0 +1,784 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::stats::parse_date;
+use super::trend::{monthly_trend, MonthlyTrendPoint};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// A study session at or above this focus score counts as high focus.
+pub const HIGH_FOCUS_THRESHOLD: i64 = 4;
+
+/// Longest window a rule may look back over (ten years).
+pub const MAX_WINDOW_DAYS: i64 = 3650;
+
+// ---------------------------------------------------------------------------
+// Rule Types
+// ---------------------------------------------------------------------------
+
+/// What a milestone rule measures. Stored as its snake_case name in
+/// `milestone.metric`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    /// Number of days with a daily log.
+    DaysTracked,
+    /// Streak of the most recent scored day.
+    Streak,
+    /// Consecutive clean days ending at the most recent log.
+    CleanDays,
+    /// Total study hours.
+    StudyHours,
+    /// Study sessions with focus >= `HIGH_FOCUS_THRESHOLD`.
+    HighFocusSessions,
+    /// Number of job applications.
+    Applications,
+    /// Average final score over the last `window_days` scored days.
+    AvgScore,
+    /// Highest final score.
+    HighestScore,
+    /// Days on which `habit` was done (value > 0).
+    HabitCount,
+    /// Consecutive completed months whose rolling 30-day average beat the
+    /// month before (see `engine::trend`).
+    ImprovingMonths,
+}
+
+impl Metric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Metric::DaysTracked => "days_tracked",
+            Metric::Streak => "streak",
+            Metric::CleanDays => "clean_days",
+            Metric::StudyHours => "study_hours",
+            Metric::HighFocusSessions => "high_focus_sessions",
+            Metric::Applications => "applications",
+            Metric::AvgScore => "avg_score",
+            Metric::HighestScore => "highest_score",
+            Metric::HabitCount => "habit_count",
+            Metric::ImprovingMonths => "improving_months",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Metric> {
+        match s {
+            "days_tracked" => Some(Metric::DaysTracked),
+            "streak" => Some(Metric::Streak),
+            "clean_days" => Some(Metric::CleanDays),
+            "study_hours" => Some(Metric::StudyHours),
+            "high_focus_sessions" => Some(Metric::HighFocusSessions),
+            "applications" => Some(Metric::Applications),
+            "avg_score" => Some(Metric::AvgScore),
+            "highest_score" => Some(Metric::HighestScore),
+            "habit_count" => Some(Metric::HabitCount),
+            "improving_months" => Some(Metric::ImprovingMonths),
+            _ => None,
+        }
+    }
+
+    /// Point-in-time metrics describe "now" and cannot take a window.
+    fn is_point_in_time(&self) -> bool {
+        matches!(self, Metric::Streak | Metric::CleanDays | Metric::ImprovingMonths)
+    }
+}
+
+/// How the metric value is compared with the rule's target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparator {
+    #[serde(rename = ">=")]
+    Gte,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "<=")]
+    Lte,
+    #[serde(rename = "<")]
+    Lt,
+}
+
+impl Comparator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Comparator::Gte => ">=",
+            Comparator::Gt => ">",
+            Comparator::Eq => "==",
+            Comparator::Lte => "<=",
+            Comparator::Lt => "<",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Comparator> {
+        match s {
+            ">=" => Some(Comparator::Gte),
+            ">" => Some(Comparator::Gt),
+            "==" => Some(Comparator::Eq),
+            "<=" => Some(Comparator::Lte),
+            "<" => Some(Comparator::Lt),
+            _ => None,
+        }
+    }
+
+    /// `Eq` tolerates float noise from hour and average computations.
+    pub fn holds(&self, value: f64, target: f64) -> bool {
+        match self {
+            Comparator::Gte => value >= target,
+            Comparator::Gt => value > target,
+            Comparator::Eq => (value - target).abs() < 1e-9,
+            Comparator::Lte => value <= target,
+            Comparator::Lt => value < target,
+        }
+    }
+
+    /// Upper-bound comparisons are trivially true on missing data.
+    fn is_upper_bound(&self) -> bool {
+        matches!(self, Comparator::Lte | Comparator::Lt)
+    }
+}
+
+/// A declarative milestone rule: `metric comparator target`, optionally
+/// restricted to the last `window_days` days.
+///
+/// For `AvgScore` the window counts scored days (the last N logged days);
+/// for every other metric it is a calendar window ending at the evaluation
+/// date. `habit` names a `habit_config` row and is required for `HabitCount`;
+/// `clean_streak` names a `clean_streak` row and only applies to `CleanDays`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneRule {
+    pub metric: Metric,
+    pub comparator: Comparator,
+    pub target: f64,
+    #[serde(default)]
+    pub window_days: Option<i64>,
+    #[serde(default)]
+    pub habit: Option<String>,
+    /// Named clean streak measured by `CleanDays`; `None` is the default streak.
+    #[serde(default)]
+    pub clean_streak: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// History Types
+// ---------------------------------------------------------------------------
+
+/// One daily_log row as seen by the rule evaluator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRecord {
+    pub date: String,
+    pub final_score: Option<f64>,
+    pub streak: Option<i64>,
+    pub clean: bool,
+}
+
+/// One study_session row as seen by the rule evaluator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudyRecord {
+    pub date: String,
+    pub minutes: i64,
+    pub focus: i64,
+}
+
+/// Everything rules can measure. All vectors are sorted by date ascending.
+#[derive(Debug, Clone, Default)]
+pub struct MilestoneHistory {
+    pub days: Vec<DayRecord>,
+    pub study: Vec<StudyRecord>,
+    pub applications: Vec<String>,
+    /// Habit name → dates the habit was done. Only habits referenced by the
+    /// rules being evaluated need to be present.
+    pub habit_days: HashMap<String, Vec<String>>,
+    /// Clean streak name → logged dates that were clean under it. Only named
+    /// streaks referenced by the rules need to be present; `DayRecord::clean`
+    /// holds the default streak.
+    pub clean_streak_days: HashMap<String, HashSet<String>>,
+}
+
+impl MilestoneHistory {
+    /// Monthly trend of final scores as of `as_of`.
+    fn score_trend(&self, as_of: NaiveDate) -> Vec<MonthlyTrendPoint> {
+        let scores: Vec<(String, f64)> = self
+            .days
+            .iter()
+            .filter_map(|d| d.final_score.map(|s| (d.date.clone(), s)))
+            .collect();
+        monthly_trend(&scores, as_of)
+    }
+
+    /// Earliest date anything was recorded.
+    fn first_date(&self) -> Option<&str> {
+        [
+            self.days.first().map(|d| d.date.as_str()),
+            self.study.first().map(|s| s.date.as_str()),
+            self.applications.first().map(|a| a.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+/// Check a rule's shape. Habit existence is checked by the caller against
+/// `habit_config`.
+pub fn validate_rule(rule: &MilestoneRule) -> Result<(), String> {
+    if !rule.target.is_finite() || rule.target < 0.0 {
+        return Err(format!("Target must be a non-negative number (got {})", rule.target));
+    }
+
+    match rule.window_days {
+        Some(_) if rule.metric.is_point_in_time() => {
+            return Err(format!("Metric '{}' does not take a window", rule.metric.as_str()));
+        }
+        Some(w) if !(1..=MAX_WINDOW_DAYS).contains(&w) => {
+            return Err(format!(
+                "Window must be between 1 and {} days (got {})",
+                MAX_WINDOW_DAYS, w
+            ));
+        }
+        None if rule.metric == Metric::AvgScore => {
+            return Err("Metric 'avg_score' requires a window".to_string());
+        }
+        _ => {}
+    }
+
+    let has_streak = rule.clean_streak.as_deref().is_some_and(|s| !s.trim().is_empty());
+    if has_streak && rule.metric != Metric::CleanDays {
+        return Err(format!("Metric '{}' does not take a clean streak", rule.metric.as_str()));
+    }
+
+    let has_habit = rule.habit.as_deref().is_some_and(|h| !h.trim().is_empty());
+    match (rule.metric, has_habit) {
+        (Metric::HabitCount, false) => Err("Metric 'habit_count' requires a habit".to_string()),
+        (Metric::HabitCount, true) => Ok(()),
+        (_, true) => Err(format!("Metric '{}' does not take a habit", rule.metric.as_str())),
+        (_, false) => Ok(()),
+    }
+}
+
+/// Human-readable form of a rule, used as the default `threshold` text.
+pub fn describe_rule(rule: &MilestoneRule) -> String {
+    let subject = match (&rule.metric, &rule.habit, &rule.clean_streak) {
+        (Metric::HabitCount, Some(habit), _) => format!("{} days", habit),
+        (Metric::CleanDays, _, Some(streak)) => format!("{} clean days", streak),
+        (metric, _, _) => metric.as_str().replace('_', " "),
+    };
+    let window = match rule.window_days {
+        Some(w) if rule.metric == Metric::AvgScore => format!(" over last {} scored days", w),
+        Some(w) => format!(" in last {} days", w),
+        None => String::new(),
+    };
+    format!("{}{} {} {}", subject, window, rule.comparator.as_str(), rule.target)
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation
+// ---------------------------------------------------------------------------
+
+/// Value of the rule's metric using only data dated on or before `as_of`.
+///
+/// Returns `None` when the metric is undefined: no scored days for
+/// `HighestScore`, or fewer than `window_days` scored days for `AvgScore`.
+pub fn metric_value(rule: &MilestoneRule, history: &MilestoneHistory, as_of: NaiveDate) -> Option<f64> {
+    let end = as_of.format("%Y-%m-%d").to_string();
+    let start = match rule.window_days {
+        Some(w) if rule.metric != Metric::AvgScore => {
+            (as_of - chrono::Duration::days(w - 1)).format("%Y-%m-%d").to_string()
+        }
+        _ => String::new(),
+    };
+    let in_window = |date: &str| date >= start.as_str() && date <= end.as_str();
+    let days_up_to = || history.days.iter().filter(|d| d.date <= end);
+
+    match rule.metric {
+        Metric::DaysTracked => Some(history.days.iter().filter(|d| in_window(&d.date)).count() as f64),
+        Metric::Streak => Some(
+            days_up_to()
+                .rev()
+                .find(|d| d.final_score.is_some())
+                .and_then(|d| d.streak)
+                .unwrap_or(0) as f64,
+        ),
+        Metric::CleanDays => {
+            let named = rule.clean_streak.as_ref().map(|name| history.clean_streak_days.get(name));
+            let is_clean = |day: &DayRecord| match named {
+                Some(dates) => dates.is_some_and(|d| d.contains(&day.date)),
+                None => day.clean,
+            };
+            let mut count = 0i64;
+            let mut expected: Option<NaiveDate> = None;
+            for day in days_up_to().rev() {
+                let date = parse_date(&day.date)?;
+                if expected.is_some_and(|e| e != date) || !is_clean(day) {
+                    break;
+                }
+                count += 1;
+                expected = date.pred_opt();
+            }
+            Some(count as f64)
+        }
+        Metric::StudyHours => Some(
+            history
+                .study
+                .iter()
+                .filter(|s| in_window(&s.date))
+                .map(|s| s.minutes)
+                .sum::<i64>() as f64
+                / 60.0,
+        ),
+        Metric::HighFocusSessions => Some(
+            history
+                .study
+                .iter()
+                .filter(|s| in_window(&s.date) && s.focus >= HIGH_FOCUS_THRESHOLD)
+                .count() as f64,
+        ),
+        Metric::Applications => {
+            Some(history.applications.iter().filter(|a| in_window(a)).count() as f64)
+        }
+        Metric::AvgScore => {
+            let n = rule.window_days.unwrap_or(1).max(1) as usize;
+            let scores: Vec<f64> = days_up_to().filter_map(|d| d.final_score).collect();
+            if scores.len() < n {
+                return None;
+            }
+            Some(scores[scores.len() - n..].iter().sum::<f64>() / n as f64)
+        }
+        Metric::HighestScore => history
+            .days
+            .iter()
+            .filter(|d| in_window(&d.date))
+            .filter_map(|d| d.final_score)
+            .fold(None, |max: Option<f64>, s| Some(max.map_or(s, |m| m.max(s)))),
+        Metric::HabitCount => {
+            let habit = rule.habit.as_deref().unwrap_or_default();
+            Some(
+                history
+                    .habit_days
+                    .get(habit)
+                    .map(|dates| dates.iter().filter(|d| in_window(d)).count())
+                    .unwrap_or(0) as f64,
+            )
+        }
+        Metric::ImprovingMonths => Some(
+            history
+                .score_trend(as_of)
+                .iter()
+                .rev()
+                .find(|p| p.complete)
+                .map_or(0, |p| p.improving_months) as f64,
+        ),
+    }
+}
+
+/// Whether `rule` is met on `as_of`.
+///
+/// An upper-bound rule over a window (e.g. "at most 2 gaming days in the last
+/// 30") is only met once tracking covers the whole window — otherwise every
+/// such rule would be earned on the first day of use.
+pub fn is_rule_met(rule: &MilestoneRule, history: &MilestoneHistory, as_of: NaiveDate) -> bool {
+    if let (Some(w), true) = (rule.window_days, rule.comparator.is_upper_bound()) {
+        if rule.metric != Metric::AvgScore {
+            let start = (as_of - chrono::Duration::days(w - 1)).format("%Y-%m-%d").to_string();
+            if history.first_date().is_none_or(|first| first > start.as_str()) {
+                return false;
+            }
+        }
+    }
+    metric_value(rule, history, as_of).is_some_and(|v| rule.comparator.holds(v, rule.target))
+}
+
+/// End of the first completed month whose improving-month run met `rule`.
+fn first_trend_date(
+    rule: &MilestoneRule,
+    history: &MilestoneHistory,
+    as_of: NaiveDate,
+) -> Option<NaiveDate> {
+    history
+        .score_trend(as_of)
+        .iter()
+        .filter(|p| p.complete)
+        .find(|p| rule.comparator.holds(p.improving_months as f64, rule.target))
+        .and_then(|p| parse_date(&p.period_end))
+}
+
+/// Date to record when `rule` is found met on `as_of`, or `None` if it isn't.
+///
+/// `ImprovingMonths` rules are dated from the end of the first month that met
+/// them, so history logged or imported late still earns the milestone on the
+/// day it was reached — even if a later month broke the run. Every other rule
+/// is dated `as_of`; use `first_met_date` for the true date.
+pub fn achievement_date(
+    rule: &MilestoneRule,
+    history: &MilestoneHistory,
+    as_of: NaiveDate,
+) -> Option<NaiveDate> {
+    if rule.metric == Metric::ImprovingMonths {
+        return first_trend_date(rule, history, as_of);
+    }
+    is_rule_met(rule, history, as_of).then_some(as_of)
+}
+
+/// First date on or before `as_of` on which `rule` was met.
+///
+/// Replays history one calendar day at a time from the earliest record, so
+/// windowed and upper-bound rules — whose value can change on days with no
+/// records as old entries leave the window — are dated correctly too.
+/// Returns `None` if the rule was never met.
+pub fn first_met_date(
+    rule: &MilestoneRule,
+    history: &MilestoneHistory,
+    as_of: NaiveDate,
+) -> Option<NaiveDate> {
+    if rule.metric == Metric::ImprovingMonths {
+        return first_trend_date(rule, history, as_of);
+    }
+    let mut day = parse_date(history.first_date()?)?;
+    while day <= as_of {
+        if is_rule_met(rule, history, day) {
+            return Some(day);
+        }
+        day = day.succ_opt()?;
+    }
+    None
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn rule(metric: Metric, comparator: Comparator, target: f64) -> MilestoneRule {
+        MilestoneRule {
+            metric,
+            comparator,
+            target,
+            window_days: None,
+            habit: None,
+            clean_streak: None,
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn day(date: &str, score: f64, streak: i64, clean: bool) -> DayRecord {
+        DayRecord {
+            date: date.to_string(),
+            final_score: Some(score),
+            streak: Some(streak),
+            clean,
+        }
+    }
+
+    /// Ten consecutive days from 2026-01-01; day 4 is not clean; scores 0.50..0.95.
+    fn sample_history() -> MilestoneHistory {
+        let days = (1..=10)
+            .map(|i| day(&format!("2026-01-{:02}", i), 0.45 + 0.05 * i as f64, i, i != 4))
+            .collect();
+        let study = vec![
+            StudyRecord { date: "2026-01-02".into(), minutes: 90, focus: 4 },
+            StudyRecord { date: "2026-01-08".into(), minutes: 30, focus: 2 },
+            StudyRecord { date: "2026-01-09".into(), minutes: 60, focus: 5 },
+        ];
+        let mut habit_days = HashMap::new();
+        habit_days.insert(
+            "gym".to_string(),
+            vec!["2026-01-01".into(), "2026-01-05".into(), "2026-01-09".into()],
+        );
+        MilestoneHistory {
+            days,
+            study,
+            applications: vec!["2026-01-03".into(), "2026-01-10".into()],
+            habit_days,
+            ..Default::default()
+        }
+    }
+
+    fn value(rule: &MilestoneRule, as_of: &str) -> Option<f64> {
+        metric_value(rule, &sample_history(), date(as_of))
+    }
+
+    // -----------------------------------------------------------------------
+    // A. Comparators & parsing
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_comparators() {
+        assert!(Comparator::Gte.holds(5.0, 5.0));
+        assert!(!Comparator::Gt.holds(5.0, 5.0));
+        assert!(Comparator::Eq.holds(0.1 + 0.2, 0.3));
+        assert!(Comparator::Lte.holds(5.0, 5.0));
+        assert!(!Comparator::Lt.holds(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_parse_round_trips() {
+        for c in [Comparator::Gte, Comparator::Gt, Comparator::Eq, Comparator::Lte, Comparator::Lt] {
+            assert_eq!(Comparator::parse(c.as_str()), Some(c));
+        }
+        for m in [
+            Metric::Streak,
+            Metric::AvgScore,
+            Metric::HabitCount,
+            Metric::HighFocusSessions,
+            Metric::ImprovingMonths,
+        ] {
+            assert_eq!(Metric::parse(m.as_str()), Some(m));
+        }
+        assert_eq!(Metric::parse("karma"), None);
+    }
+
+    #[test]
+    fn test_rule_deserializes_from_json() {
+        let r: MilestoneRule = serde_json::from_str(
+            r#"{"metric": "habit_count", "comparator": ">=", "target": 20, "window_days": 30, "habit": "gym"}"#,
+        )
+        .unwrap();
+        assert_eq!(r.metric, Metric::HabitCount);
+        assert_eq!(r.comparator, Comparator::Gte);
+        assert_eq!(r.window_days, Some(30));
+        assert_eq!(describe_rule(&r), "gym days in last 30 days >= 20");
+    }
+
+    // -----------------------------------------------------------------------
+    // B. Validation
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_validate_rule() {
+        assert!(validate_rule(&rule(Metric::Streak, Comparator::Gte, 5.0)).is_ok());
+        assert!(validate_rule(&rule(Metric::Streak, Comparator::Gte, -1.0)).is_err());
+        assert!(validate_rule(&rule(Metric::Streak, Comparator::Gte, f64::NAN)).is_err());
+
+        let mut r = rule(Metric::CleanDays, Comparator::Gte, 7.0);
+        r.window_days = Some(30);
+        assert!(validate_rule(&r).is_err(), "point-in-time metric with window");
+
+        assert!(validate_rule(&rule(Metric::AvgScore, Comparator::Gte, 0.8)).is_err());
+        let mut r = rule(Metric::AvgScore, Comparator::Gte, 0.8);
+        r.window_days = Some(0);
+        assert!(validate_rule(&r).is_err());
+
+        assert!(validate_rule(&rule(Metric::HabitCount, Comparator::Gte, 5.0)).is_err());
+        let mut r = rule(Metric::StudyHours, Comparator::Gte, 5.0);
+        r.habit = Some("gym".into());
+        assert!(validate_rule(&r).is_err(), "habit on non-habit metric");
+        let mut r = rule(Metric::Streak, Comparator::Gte, 5.0);
+        r.clean_streak = Some("Weed".into());
+        assert!(validate_rule(&r).is_err(), "clean streak on non-clean metric");
+    }
+
+    // -----------------------------------------------------------------------
+    // C. Metric values
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_counts_all_time_and_windowed() {
+        assert_eq!(value(&rule(Metric::DaysTracked, Comparator::Gte, 0.0), "2026-01-10"), Some(10.0));
+        assert_eq!(value(&rule(Metric::DaysTracked, Comparator::Gte, 0.0), "2026-01-06"), Some(6.0));
+        assert_eq!(value(&rule(Metric::Applications, Comparator::Gte, 0.0), "2026-01-10"), Some(2.0));
+        assert_eq!(value(&rule(Metric::StudyHours, Comparator::Gte, 0.0), "2026-01-10"), Some(3.0));
+        assert_eq!(
+            value(&rule(Metric::HighFocusSessions, Comparator::Gte, 0.0), "2026-01-10"),
+            Some(2.0)
+        );
+
+        let mut r = rule(Metric::StudyHours, Comparator::Gte, 0.0);
+        r.window_days = Some(3);
+        assert_eq!(value(&r, "2026-01-10"), Some(1.5)); // 01-08 .. 01-10
+
+        let mut r = rule(Metric::HabitCount, Comparator::Gte, 0.0);
+        r.habit = Some("gym".into());
+        assert_eq!(value(&r, "2026-01-10"), Some(3.0));
+        r.window_days = Some(6);
+        assert_eq!(value(&r, "2026-01-10"), Some(2.0)); // 01-05 and 01-09
+        r.habit = Some("read".into());
+        assert_eq!(value(&r, "2026-01-10"), Some(0.0));
+    }
+
+    #[test]
+    fn test_streak_and_clean_days_respect_as_of() {
+        assert_eq!(value(&rule(Metric::Streak, Comparator::Gte, 0.0), "2026-01-10"), Some(10.0));
+        assert_eq!(value(&rule(Metric::Streak, Comparator::Gte, 0.0), "2026-01-05"), Some(5.0));
+        assert_eq!(value(&rule(Metric::Streak, Comparator::Gte, 0.0), "2025-12-31"), Some(0.0));
+
+        // Day 4 is not clean
+        assert_eq!(value(&rule(Metric::CleanDays, Comparator::Gte, 0.0), "2026-01-10"), Some(6.0));
+        assert_eq!(value(&rule(Metric::CleanDays, Comparator::Gte, 0.0), "2026-01-03"), Some(3.0));
+        assert_eq!(value(&rule(Metric::CleanDays, Comparator::Gte, 0.0), "2026-01-04"), Some(0.0));
+    }
+
+    #[test]
+    fn test_clean_days_break_on_gap() {
+        let history = MilestoneHistory {
+            days: vec![
+                day("2026-01-01", 0.5, 1, true),
+                day("2026-01-03", 0.5, 1, true),
+                day("2026-01-04", 0.5, 2, true),
+            ],
+            ..Default::default()
+        };
+        let r = rule(Metric::CleanDays, Comparator::Gte, 0.0);
+        assert_eq!(metric_value(&r, &history, date("2026-01-04")), Some(2.0));
+    }
+
+    #[test]
+    fn test_clean_days_for_named_streak() {
+        let mut history = sample_history();
+        let weed_clean: HashSet<String> =
+            ["2026-01-08", "2026-01-09", "2026-01-10"].iter().map(|d| d.to_string()).collect();
+        history.clean_streak_days.insert("Weed".to_string(), weed_clean);
+
+        let mut r = rule(Metric::CleanDays, Comparator::Gte, 3.0);
+        r.clean_streak = Some("Weed".into());
+        assert_eq!(metric_value(&r, &history, date("2026-01-10")), Some(3.0));
+        assert_eq!(metric_value(&r, &history, date("2026-01-07")), Some(0.0));
+        // A streak missing from the history counts nothing
+        r.clean_streak = Some("Gaming".into());
+        assert_eq!(metric_value(&r, &history, date("2026-01-10")), Some(0.0));
+        assert_eq!(describe_rule(&r), "Gaming clean days >= 3");
+    }
+
+    #[test]
+    fn test_avg_score_needs_full_window_of_scored_days() {
+        let mut r = rule(Metric::AvgScore, Comparator::Gte, 0.0);
+        r.window_days = Some(3);
+        // Last three: 0.85, 0.90, 0.95
+        let v = value(&r, "2026-01-10").unwrap();
+        assert!((v - 0.90).abs() < 1e-9);
+        assert_eq!(value(&r, "2026-01-02"), None);
+    }
+
+    #[test]
+    fn test_highest_score_undefined_without_scores() {
+        let r = rule(Metric::HighestScore, Comparator::Gte, 0.0);
+        assert!((value(&r, "2026-01-10").unwrap() - 0.95).abs() < 1e-9);
+        assert_eq!(metric_value(&r, &MilestoneHistory::default(), date("2026-01-10")), None);
+    }
+
+    // -----------------------------------------------------------------------
+    // D. is_rule_met
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_is_rule_met() {
+        let history = sample_history();
+        assert!(is_rule_met(&rule(Metric::Streak, Comparator::Gte, 7.0), &history, date("2026-01-10")));
+        assert!(!is_rule_met(&rule(Metric::Streak, Comparator::Gte, 7.0), &history, date("2026-01-06")));
+        assert!(is_rule_met(&rule(Metric::StudyHours, Comparator::Gt, 0.0), &history, date("2026-01-02")));
+        assert!(!is_rule_met(&rule(Metric::StudyHours, Comparator::Gt, 0.0), &history, date("2026-01-01")));
+    }
+
+    #[test]
+    fn test_upper_bound_window_requires_coverage() {
+        let history = sample_history();
+        let mut r = rule(Metric::HabitCount, Comparator::Lte, 1.0);
+        r.habit = Some("gym".into());
+        r.window_days = Some(5);
+        // 01-06..01-10 contains one gym day and is fully tracked
+        assert!(is_rule_met(&r, &history, date("2026-01-10")));
+        // 01-01..01-05 would be 2 gym days — not met
+        assert!(!is_rule_met(&r, &history, date("2026-01-05")));
+        // Window reaching back before the first record is never met for "<="
+        r.window_days = Some(30);
+        r.target = 10.0;
+        assert!(!is_rule_met(&r, &history, date("2026-01-10")));
+    }
+
+    // -----------------------------------------------------------------------
+    // E. Monthly trend & achievement dates
+    // -----------------------------------------------------------------------
+
+    /// Months of 2026 from January, every day scoring that month's entry in
+    /// `steps`.
+    fn monthly_history(steps: &[f64]) -> MilestoneHistory {
+        let mut days = Vec::new();
+        let mut d = date("2026-01-01");
+        while d.year() == 2026 && (d.month0() as usize) < steps.len() {
+            let score = steps[d.month0() as usize];
+            days.push(day(&d.format("%Y-%m-%d").to_string(), score, 1, true));
+            d = d.succ_opt().unwrap();
+        }
+        MilestoneHistory { days, ..Default::default() }
+    }
+
+    #[test]
+    fn test_improving_months_uses_completed_months() {
+        let history = monthly_history(&[0.5, 0.55, 0.6, 0.65]);
+        let r = rule(Metric::ImprovingMonths, Comparator::Gte, 3.0);
+        assert_eq!(metric_value(&r, &history, date("2026-03-31")), Some(2.0));
+        // April in progress doesn't count yet
+        assert_eq!(metric_value(&r, &history, date("2026-04-29")), Some(2.0));
+        assert_eq!(metric_value(&r, &history, date("2026-04-30")), Some(3.0));
+        assert!(is_rule_met(&r, &history, date("2026-04-30")));
+    }
+
+    #[test]
+    fn test_achievement_date_backfills_trend() {
+        // Three improvements end in April, then May drops
+        let history = monthly_history(&[0.5, 0.55, 0.6, 0.65, 0.4]);
+        let r = rule(Metric::ImprovingMonths, Comparator::Gte, 3.0);
+        let as_of = date("2026-06-15");
+        assert_eq!(metric_value(&r, &history, as_of), Some(0.0));
+        assert_eq!(achievement_date(&r, &history, as_of), Some(date("2026-04-30")));
+        // Not reached yet
+        assert_eq!(achievement_date(&r, &history, date("2026-04-29")), None);
+
+        // Other metrics are dated as_of
+        let days = rule(Metric::DaysTracked, Comparator::Gte, 10.0);
+        assert_eq!(achievement_date(&days, &history, as_of), Some(as_of));
+        let never = rule(Metric::DaysTracked, Comparator::Gte, 1000.0);
+        assert_eq!(achievement_date(&never, &history, as_of), None);
+    }
+
+    // -----------------------------------------------------------------------
+    // F. Historical replay
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_first_met_date_replays_history() {
+        let history = sample_history();
+        let as_of = date("2026-03-01");
+        let first = |r: &MilestoneRule| first_met_date(r, &history, as_of);
+
+        assert_eq!(first(&rule(Metric::DaysTracked, Comparator::Gte, 7.0)), Some(date("2026-01-07")));
+        assert_eq!(first(&rule(Metric::Streak, Comparator::Gte, 5.0)), Some(date("2026-01-05")));
+        // Day 4 breaks the clean run; 5 clean days in a row first on 01-09
+        assert_eq!(first(&rule(Metric::CleanDays, Comparator::Gte, 5.0)), Some(date("2026-01-09")));
+        // 90 min on 01-02, +30 on 01-08
+        assert_eq!(first(&rule(Metric::StudyHours, Comparator::Gte, 2.0)), Some(date("2026-01-08")));
+        assert_eq!(first(&rule(Metric::Applications, Comparator::Gte, 2.0)), Some(date("2026-01-10")));
+        assert_eq!(first(&rule(Metric::HighestScore, Comparator::Gte, 0.88)), Some(date("2026-01-09")));
+
+        let mut avg = rule(Metric::AvgScore, Comparator::Gte, 0.79);
+        avg.window_days = Some(3);
+        // Last three scores average 0.75 on 01-07, 0.80 on 01-08
+        assert_eq!(first(&avg), Some(date("2026-01-08")));
+
+        assert_eq!(first(&rule(Metric::DaysTracked, Comparator::Gte, 11.0)), None);
+    }
+
+    #[test]
+    fn test_first_met_date_upper_bound_window_met_after_records_stop() {
+        let history = sample_history();
+        let mut r = rule(Metric::HabitCount, Comparator::Eq, 0.0);
+        r.habit = Some("gym".into());
+        r.window_days = Some(5);
+        // Gym on 01-09 is the last; a 5-day window free of it starts 01-14,
+        // days after the final record
+        assert_eq!(first_met_date(&r, &history, date("2026-03-01")), Some(date("2026-01-14")));
+        assert_eq!(first_met_date(&r, &history, date("2026-01-13")), None);
+    }
+}
@@ -1,2 +1,11 @@
 pub mod scoring;
 pub mod cascade;
+pub mod correlation;
+pub mod funnel;
+pub mod milestone_rules;
+pub mod recovery_patterns;
+pub mod risk;
+pub mod spaced_repetition;
+pub mod stats;
+pub mod study_patterns;
+pub mod trend;
@@ -0,0 +1,773 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use super::stats::median;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Time-of-day buckets as `(label, start hour, end hour)`, end exclusive.
+/// Labels match the seeded `relapse_time_options` dropdown.
+pub const TIME_BUCKETS: [(&str, u32, u32); 7] = [
+    ("Late Night (12-3am)", 0, 3),
+    ("Early Morning (3-6am)", 3, 6),
+    ("Morning (6-9am)", 6, 9),
+    ("Late Morning (9am-12pm)", 9, 12),
+    ("Afternoon (12-5pm)", 12, 17),
+    ("Evening (5-9pm)", 17, 21),
+    ("Night (9pm-12am)", 21, 24),
+];
+
+/// Relative risk at or above which an hour or weekday is flagged.
+pub const ELEVATED_RISK_THRESHOLD: f64 = 1.5;
+
+/// Relapses an hour or weekday needs before it can be flagged, so a single
+/// incident doesn't make its hour look dangerous.
+pub const MIN_ELEVATED_RELAPSES: i64 = 2;
+
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Urge intensity bands as `(label, min, max)`, inclusive.
+pub const INTENSITY_BANDS: [(&str, i64, i64); 4] = [
+    ("low", 1, 3),
+    ("moderate", 4, 6),
+    ("high", 7, 8),
+    ("severe", 9, 10),
+];
+
+/// Added to a link suggestion's score when the urge's free-text trigger
+/// matches the relapse trigger. Worth half a day of time proximity.
+pub const TRIGGER_MATCH_BONUS: f64 = 0.5;
+
+/// Label used for an empty category value.
+const UNKNOWN_LABEL: &str = "Unknown";
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// The fields of one relapse_entry that patterns are computed over.
+#[derive(Debug, Clone)]
+pub struct RelapseObservation {
+    pub date: String,
+    /// `HH:MM`.
+    pub time: String,
+    pub trigger: String,
+    pub device: String,
+    pub emotional_state: String,
+}
+
+/// The fields of one urge_entry that patterns are computed over.
+#[derive(Debug, Clone)]
+pub struct UrgeObservation {
+    pub date: String,
+    /// `HH:MM`.
+    pub time: String,
+    pub technique: String,
+    pub intensity: i64,
+    pub effectiveness: i64,
+    /// The urge passed (`did_pass` starts with "Yes").
+    pub passed: bool,
+}
+
+/// Configured dropdown values. Options never chosen still appear, with zero
+/// relapses, and count towards the baseline.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryOptions {
+    pub triggers: Vec<String>,
+    pub devices: Vec<String>,
+    pub emotional_states: Vec<String>,
+}
+
+/// Relapses for one value of a category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryRisk {
+    pub value: String,
+    pub relapses: i64,
+    /// Fraction of the relapses with this value.
+    pub share: f64,
+    /// `share` over the share expected if relapses were spread evenly; 1.0 is
+    /// average. `None` when there are no relapses.
+    pub relative_risk: Option<f64>,
+}
+
+/// Outcome of the urges handled with one technique.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TechniqueStats {
+    pub technique: String,
+    pub uses: i64,
+    pub passed: i64,
+    pub pass_rate: f64,
+    /// Mean of the 1–5 effectiveness ratings.
+    pub avg_effectiveness: f64,
+    /// Mean of the 1–10 intensities the technique was used against.
+    pub avg_intensity: f64,
+}
+
+/// Relapses and urges in one hour of the day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HourRisk {
+    pub hour: u32,
+    pub relapses: i64,
+    pub urges: i64,
+    /// Relapse share over 1/24.
+    pub relative_risk: Option<f64>,
+}
+
+/// Relapses and urges on one day of the week.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeekdayRisk {
+    pub weekday: String,
+    pub relapses: i64,
+    pub urges: i64,
+    /// Relapse share over the share of days in the range on this weekday.
+    pub relative_risk: Option<f64>,
+}
+
+/// Result of `get_recovery_patterns`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryPatterns {
+    pub start: String,
+    pub end: String,
+    pub relapse_count: i64,
+    pub urge_count: i64,
+    /// Most relapses first.
+    pub triggers: Vec<CategoryRisk>,
+    /// In clock order from midnight. The expected share is the bucket's width.
+    pub time_buckets: Vec<CategoryRisk>,
+    pub devices: Vec<CategoryRisk>,
+    pub emotional_states: Vec<CategoryRisk>,
+    /// Highest pass rate first, then highest average effectiveness.
+    pub techniques: Vec<TechniqueStats>,
+    /// All 24 hours, from 0.
+    pub hours: Vec<HourRisk>,
+    /// Monday to Sunday.
+    pub weekdays: Vec<WeekdayRisk>,
+    /// Hours at or above `ELEVATED_RISK_THRESHOLD`, riskiest first.
+    pub elevated_hours: Vec<u32>,
+    /// Weekdays at or above `ELEVATED_RISK_THRESHOLD`, riskiest first.
+    pub elevated_weekdays: Vec<String>,
+}
+
+/// An urge and, when a relapse links to it, that relapse's date and time.
+#[derive(Debug, Clone)]
+pub struct LinkedUrge {
+    pub date: String,
+    pub time: String,
+    pub intensity: i64,
+    /// `(date, time)` of the relapse whose `urge_entry_id` is this urge.
+    pub relapse: Option<(String, String)>,
+}
+
+/// How often urges of one intensity band ended in a linked relapse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntensityBandConversion {
+    pub band: String,
+    pub min_intensity: i64,
+    pub max_intensity: i64,
+    pub urges: i64,
+    pub relapses: i64,
+    /// `relapses / urges`; 0.0 when the band has no urges.
+    pub conversion_rate: f64,
+}
+
+/// Result of `get_urge_linkage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UrgeLinkage {
+    pub start: String,
+    pub end: String,
+    pub urges: i64,
+    /// Urges a relapse links to.
+    pub linked_urges: i64,
+    pub conversion_rate: f64,
+    /// Relapses in the range without a linked urge.
+    pub unlinked_relapses: i64,
+    /// Median minutes from a linked urge to its relapse.
+    pub median_minutes_to_relapse: Option<f64>,
+    pub bands: Vec<IntensityBandConversion>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn hour_of(time: &str) -> Option<u32> {
+    NaiveTime::parse_from_str(time, "%H:%M")
+        .ok()
+        .map(|t| t.hour())
+}
+
+/// Monday = 0.
+fn weekday_index(date: &str) -> Option<usize> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .map(|d| d.weekday().num_days_from_monday() as usize)
+}
+
+/// Time-of-day bucket label for an `HH:MM` time.
+pub fn time_bucket(time: &str) -> Option<&'static str> {
+    let hour = hour_of(time)?;
+    TIME_BUCKETS
+        .iter()
+        .find(|(_, from, to)| hour >= *from && hour < *to)
+        .map(|(label, _, _)| *label)
+}
+
+fn relative_risk(count: i64, total: i64, expected_share: f64) -> Option<f64> {
+    if total == 0 || expected_share <= 0.0 {
+        return None;
+    }
+    Some((count as f64 / total as f64) / expected_share)
+}
+
+fn share(count: i64, total: i64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Distribution of `values` against a uniform baseline over every configured
+/// or observed value.
+fn category_risks<'a>(
+    values: impl Iterator<Item = &'a str>,
+    options: &[String],
+) -> Vec<CategoryRisk> {
+    let mut counts: BTreeMap<String, i64> = options.iter().map(|o| (o.clone(), 0)).collect();
+    let mut total = 0;
+    for value in values {
+        let label = if value.trim().is_empty() {
+            UNKNOWN_LABEL
+        } else {
+            value
+        };
+        *counts.entry(label.to_string()).or_insert(0) += 1;
+        total += 1;
+    }
+
+    let expected = 1.0 / counts.len().max(1) as f64;
+    let mut risks: Vec<CategoryRisk> = counts
+        .into_iter()
+        .map(|(value, relapses)| CategoryRisk {
+            value,
+            relapses,
+            share: share(relapses, total),
+            relative_risk: relative_risk(relapses, total, expected),
+        })
+        .collect();
+    risks.sort_by(|a, b| {
+        b.relapses
+            .cmp(&a.relapses)
+            .then_with(|| a.value.cmp(&b.value))
+    });
+    risks
+}
+
+fn technique_stats(urges: &[UrgeObservation]) -> Vec<TechniqueStats> {
+    // technique -> (uses, passed, effectiveness sum, intensity sum)
+    let mut groups: BTreeMap<String, (i64, i64, i64, i64)> = BTreeMap::new();
+    for urge in urges {
+        let label = if urge.technique.trim().is_empty() {
+            UNKNOWN_LABEL
+        } else {
+            &urge.technique
+        };
+        let g = groups.entry(label.to_string()).or_default();
+        g.0 += 1;
+        g.1 += i64::from(urge.passed);
+        g.2 += urge.effectiveness;
+        g.3 += urge.intensity;
+    }
+
+    let mut stats: Vec<TechniqueStats> = groups
+        .into_iter()
+        .map(
+            |(technique, (uses, passed, effectiveness, intensity))| TechniqueStats {
+                technique,
+                uses,
+                passed,
+                pass_rate: share(passed, uses),
+                avg_effectiveness: effectiveness as f64 / uses as f64,
+                avg_intensity: intensity as f64 / uses as f64,
+            },
+        )
+        .collect();
+    stats.sort_by(|a, b| {
+        b.pass_rate
+            .total_cmp(&a.pass_rate)
+            .then_with(|| b.avg_effectiveness.total_cmp(&a.avg_effectiveness))
+            .then_with(|| b.uses.cmp(&a.uses))
+            .then_with(|| a.technique.cmp(&b.technique))
+    });
+    stats
+}
+
+fn is_elevated(relapses: i64, risk: Option<f64>) -> bool {
+    relapses >= MIN_ELEVATED_RELAPSES && risk.is_some_and(|r| r >= ELEVATED_RISK_THRESHOLD)
+}
+
+/// Order flagged keys by relative risk, highest first.
+fn riskiest_first<T>(mut flagged: Vec<(T, f64)>) -> Vec<T> {
+    flagged.sort_by(|a, b| b.1.total_cmp(&a.1));
+    flagged.into_iter().map(|(key, _)| key).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Patterns
+// ---------------------------------------------------------------------------
+
+/// Relapse distributions, technique outcomes and risky times for entries
+/// dated `[start, end]`. Callers pass only entries in that range.
+///
+/// Relative risk compares a value's share of relapses with the share it
+/// would get if relapses were spread evenly: over the configured options for
+/// categories, over bucket width for time of day, and over how often each
+/// weekday occurs in the range. Entries with an unparseable time or date are
+/// left out of the time-based breakdowns only.
+pub fn recovery_patterns(
+    start: NaiveDate,
+    end: NaiveDate,
+    relapses: &[RelapseObservation],
+    urges: &[UrgeObservation],
+    options: &CategoryOptions,
+) -> RecoveryPatterns {
+    // Time of day
+    let mut bucket_counts = [0i64; TIME_BUCKETS.len()];
+    let mut hour_relapses = [0i64; 24];
+    let mut hour_urges = [0i64; 24];
+    for relapse in relapses {
+        if let Some(hour) = hour_of(&relapse.time) {
+            hour_relapses[hour as usize] += 1;
+            if let Some(i) = TIME_BUCKETS
+                .iter()
+                .position(|(_, f, t)| hour >= *f && hour < *t)
+            {
+                bucket_counts[i] += 1;
+            }
+        }
+    }
+    for urge in urges {
+        if let Some(hour) = hour_of(&urge.time) {
+            hour_urges[hour as usize] += 1;
+        }
+    }
+    let timed: i64 = hour_relapses.iter().sum();
+
+    let time_buckets = TIME_BUCKETS
+        .iter()
+        .zip(bucket_counts)
+        .map(|((label, from, to), count)| CategoryRisk {
+            value: label.to_string(),
+            relapses: count,
+            share: share(count, timed),
+            relative_risk: relative_risk(count, timed, f64::from(to - from) / 24.0),
+        })
+        .collect();
+
+    let hours: Vec<HourRisk> = (0..24u32)
+        .map(|hour| {
+            let count = hour_relapses[hour as usize];
+            HourRisk {
+                hour,
+                relapses: count,
+                urges: hour_urges[hour as usize],
+                relative_risk: relative_risk(count, timed, 1.0 / 24.0),
+            }
+        })
+        .collect();
+
+    // Day of week, against how often each weekday occurs in the range
+    let mut days_in_range = [0i64; 7];
+    let mut day = start;
+    while day <= end {
+        days_in_range[day.weekday().num_days_from_monday() as usize] += 1;
+        match day.succ_opt() {
+            Some(next) => day = next,
+            None => break,
+        }
+    }
+    let range_days: i64 = days_in_range.iter().sum();
+
+    let mut weekday_relapses = [0i64; 7];
+    let mut weekday_urges = [0i64; 7];
+    for i in relapses.iter().filter_map(|r| weekday_index(&r.date)) {
+        weekday_relapses[i] += 1;
+    }
+    for i in urges.iter().filter_map(|u| weekday_index(&u.date)) {
+        weekday_urges[i] += 1;
+    }
+    let dated: i64 = weekday_relapses.iter().sum();
+
+    let weekdays: Vec<WeekdayRisk> = WEEKDAYS
+        .iter()
+        .enumerate()
+        .map(|(i, name)| WeekdayRisk {
+            weekday: name.to_string(),
+            relapses: weekday_relapses[i],
+            urges: weekday_urges[i],
+            relative_risk: relative_risk(
+                weekday_relapses[i],
+                dated,
+                share(days_in_range[i], range_days),
+            ),
+        })
+        .collect();
+
+    let elevated_hours = riskiest_first(
+        hours
+            .iter()
+            .filter(|h| is_elevated(h.relapses, h.relative_risk))
+            .map(|h| (h.hour, h.relative_risk.unwrap_or(0.0)))
+            .collect(),
+    );
+    let elevated_weekdays = riskiest_first(
+        weekdays
+            .iter()
+            .filter(|d| is_elevated(d.relapses, d.relative_risk))
+            .map(|d| (d.weekday.clone(), d.relative_risk.unwrap_or(0.0)))
+            .collect(),
+    );
+
+    RecoveryPatterns {
+        start: start.format("%Y-%m-%d").to_string(),
+        end: end.format("%Y-%m-%d").to_string(),
+        relapse_count: relapses.len() as i64,
+        urge_count: urges.len() as i64,
+        triggers: category_risks(
+            relapses.iter().map(|r| r.trigger.as_str()),
+            &options.triggers,
+        ),
+        time_buckets,
+        devices: category_risks(relapses.iter().map(|r| r.device.as_str()), &options.devices),
+        emotional_states: category_risks(
+            relapses.iter().map(|r| r.emotional_state.as_str()),
+            &options.emotional_states,
+        ),
+        techniques: technique_stats(urges),
+        hours,
+        weekdays,
+        elevated_hours,
+        elevated_weekdays,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Urge Linkage
+// ---------------------------------------------------------------------------
+
+/// Minutes from `(from_date, from_time)` to `(to_date, to_time)`; negative
+/// when `to` is earlier. `None` if either side doesn't parse.
+pub fn minutes_between(
+    from_date: &str,
+    from_time: &str,
+    to_date: &str,
+    to_time: &str,
+) -> Option<i64> {
+    let parse = |d: &str, t: &str| {
+        NaiveDateTime::parse_from_str(&format!("{} {}", d, t), "%Y-%m-%d %H:%M").ok()
+    };
+    Some((parse(to_date, to_time)? - parse(from_date, from_time)?).num_minutes())
+}
+
+/// Whether an urge's free-text trigger names the relapse's dropdown trigger,
+/// compared case-insensitively in either direction ("stress" matches
+/// "Stress", "work stress" matches "Stress").
+pub fn trigger_matches(urge_trigger: &str, relapse_trigger: &str) -> bool {
+    let urge = urge_trigger.trim().to_lowercase();
+    let relapse = relapse_trigger.trim().to_lowercase();
+    !urge.is_empty() && !relapse.is_empty() && (urge.contains(&relapse) || relapse.contains(&urge))
+}
+
+/// Score of an urge as the one preceding a relapse `minutes_before` later:
+/// 1.0 for the same minute, falling linearly to 0 a day earlier, plus
+/// `TRIGGER_MATCH_BONUS` on a trigger match.
+pub fn link_score(minutes_before: i64, trigger_match: bool) -> f64 {
+    let proximity = (1.0 - minutes_before as f64 / 1440.0).clamp(0.0, 1.0);
+    if trigger_match {
+        proximity + TRIGGER_MATCH_BONUS
+    } else {
+        proximity
+    }
+}
+
+/// Conversion of urges to relapses by intensity band, and the median time
+/// from an urge to the relapse linked to it.
+pub fn urge_linkage(
+    start: &str,
+    end: &str,
+    urges: &[LinkedUrge],
+    unlinked_relapses: i64,
+) -> UrgeLinkage {
+    let mut bands: Vec<IntensityBandConversion> = INTENSITY_BANDS
+        .iter()
+        .map(|(band, min, max)| IntensityBandConversion {
+            band: band.to_string(),
+            min_intensity: *min,
+            max_intensity: *max,
+            urges: 0,
+            relapses: 0,
+            conversion_rate: 0.0,
+        })
+        .collect();
+
+    let mut minutes = Vec::new();
+    let mut linked = 0;
+    for urge in urges {
+        let band = bands
+            .iter_mut()
+            .find(|b| urge.intensity >= b.min_intensity && urge.intensity <= b.max_intensity);
+        if let Some(band) = band {
+            band.urges += 1;
+            band.relapses += i64::from(urge.relapse.is_some());
+        }
+        if let Some((date, time)) = &urge.relapse {
+            linked += 1;
+            if let Some(m) = minutes_between(&urge.date, &urge.time, date, time) {
+                minutes.push(m as f64);
+            }
+        }
+    }
+    for band in &mut bands {
+        band.conversion_rate = share(band.relapses, band.urges);
+    }
+
+    UrgeLinkage {
+        start: start.to_string(),
+        end: end.to_string(),
+        urges: urges.len() as i64,
+        linked_urges: linked,
+        conversion_rate: share(linked, urges.len() as i64),
+        unlinked_relapses,
+        median_minutes_to_relapse: median(&mut minutes),
+        bands,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn relapse(date: &str, time: &str, trigger: &str) -> RelapseObservation {
+        RelapseObservation {
+            date: date.to_string(),
+            time: time.to_string(),
+            trigger: trigger.to_string(),
+            device: "Phone".to_string(),
+            emotional_state: "Bored".to_string(),
+        }
+    }
+
+    fn urge(time: &str, technique: &str, effectiveness: i64, passed: bool) -> UrgeObservation {
+        UrgeObservation {
+            date: "2026-02-02".to_string(),
+            time: time.to_string(),
+            technique: technique.to_string(),
+            intensity: 6,
+            effectiveness,
+            passed,
+        }
+    }
+
+    fn options(triggers: &[&str]) -> CategoryOptions {
+        CategoryOptions {
+            triggers: triggers.iter().map(|s| s.to_string()).collect(),
+            devices: vec!["Phone".to_string(), "Laptop".to_string()],
+            emotional_states: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_time_bucket() {
+        assert_eq!(time_bucket("00:30"), Some("Late Night (12-3am)"));
+        assert_eq!(time_bucket("12:00"), Some("Afternoon (12-5pm)"));
+        assert_eq!(time_bucket("23:59"), Some("Night (9pm-12am)"));
+        assert_eq!(time_bucket("late"), None);
+        // Buckets cover the whole day without overlap
+        let hours: u32 = TIME_BUCKETS.iter().map(|(_, f, t)| t - f).sum();
+        assert_eq!(hours, 24);
+    }
+
+    #[test]
+    fn test_empty_patterns() {
+        let p = recovery_patterns(
+            date("2026-02-02"),
+            date("2026-02-08"),
+            &[],
+            &[],
+            &options(&["Stress"]),
+        );
+        assert_eq!(p.relapse_count, 0);
+        assert_eq!(p.triggers[0].value, "Stress");
+        assert_eq!(p.triggers[0].relative_risk, None);
+        assert_eq!(p.hours.len(), 24);
+        assert_eq!(p.weekdays.len(), 7);
+        assert!(p.elevated_hours.is_empty());
+        assert!(p.techniques.is_empty());
+    }
+
+    #[test]
+    fn test_category_relative_risk_uses_configured_options() {
+        let relapses = [
+            relapse("2026-02-02", "22:00", "Stress"),
+            relapse("2026-02-03", "22:30", "Stress"),
+            relapse("2026-02-04", "10:00", "Boredom"),
+            relapse("2026-02-05", "23:00", ""),
+        ];
+        let p = recovery_patterns(
+            date("2026-02-02"),
+            date("2026-02-08"),
+            &relapses,
+            &[],
+            &options(&["Stress", "Boredom", "Loneliness"]),
+        );
+
+        // Stress, Boredom, Loneliness and Unknown: baseline share 1/4
+        let stress = &p.triggers[0];
+        assert_eq!(stress.value, "Stress");
+        assert_eq!(stress.relapses, 2);
+        assert!((stress.share - 0.5).abs() < 1e-9);
+        assert!((stress.relative_risk.unwrap() - 2.0).abs() < 1e-9);
+        let lonely = p.triggers.iter().find(|t| t.value == "Loneliness").unwrap();
+        assert_eq!(lonely.relapses, 0);
+        assert!(p.triggers.iter().any(|t| t.value == "Unknown"));
+
+        // Three of four relapses at night, a bucket 3h wide
+        let night = p
+            .time_buckets
+            .iter()
+            .find(|b| b.value == "Night (9pm-12am)")
+            .unwrap();
+        assert_eq!(night.relapses, 3);
+        assert!((night.relative_risk.unwrap() - 0.75 / (3.0 / 24.0)).abs() < 1e-9);
+
+        let phone = &p.devices[0];
+        assert_eq!((phone.value.as_str(), phone.relapses), ("Phone", 4));
+    }
+
+    #[test]
+    fn test_elevated_hours_and_weekdays() {
+        // Two Mondays in range; every relapse on a Monday, mostly at 22h
+        let relapses = [
+            relapse("2026-02-02", "22:10", "Stress"),
+            relapse("2026-02-09", "22:40", "Stress"),
+            relapse("2026-02-09", "14:00", "Stress"),
+        ];
+        let p = recovery_patterns(
+            date("2026-02-02"),
+            date("2026-02-15"),
+            &relapses,
+            &[urge("22:05", "Left Room", 4, true)],
+            &options(&[]),
+        );
+
+        assert_eq!(p.elevated_hours, vec![22]);
+        let h22 = &p.hours[22];
+        assert_eq!((h22.relapses, h22.urges), (2, 1));
+        // A single relapse never flags its hour
+        assert!(!p.elevated_hours.contains(&14));
+
+        assert_eq!(p.elevated_weekdays, vec!["Monday".to_string()]);
+        let monday = &p.weekdays[0];
+        assert!((monday.relative_risk.unwrap() - 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_link_helpers() {
+        assert_eq!(
+            minutes_between("2026-02-02", "22:00", "2026-02-02", "23:30"),
+            Some(90)
+        );
+        assert_eq!(
+            minutes_between("2026-02-01", "23:30", "2026-02-02", "00:15"),
+            Some(45)
+        );
+        assert_eq!(
+            minutes_between("2026-02-02", "late", "2026-02-02", "23:30"),
+            None
+        );
+
+        assert!(trigger_matches("Stress", "stress"));
+        assert!(trigger_matches("work stress again", "Stress"));
+        assert!(!trigger_matches("", "Stress"));
+        assert!(!trigger_matches("Boredom", "Stress"));
+
+        assert!((link_score(0, false) - 1.0).abs() < 1e-9);
+        assert!((link_score(720, true) - 1.0).abs() < 1e-9);
+        assert_eq!(link_score(2000, false), 0.0);
+    }
+
+    #[test]
+    fn test_urge_linkage_bands_and_median() {
+        let linked =
+            |date: &str, time: &str, intensity: i64, relapse: Option<(&str, &str)>| LinkedUrge {
+                date: date.to_string(),
+                time: time.to_string(),
+                intensity,
+                relapse: relapse.map(|(d, t)| (d.to_string(), t.to_string())),
+            };
+        let urges = [
+            linked("2026-02-02", "22:00", 9, Some(("2026-02-02", "22:30"))),
+            linked("2026-02-03", "23:00", 10, Some(("2026-02-04", "00:30"))),
+            linked("2026-02-04", "21:00", 9, None),
+            linked("2026-02-05", "10:00", 2, None),
+            linked("2026-02-06", "11:00", 5, Some(("2026-02-06", "12:00"))),
+        ];
+        let l = urge_linkage("2026-02-01", "2026-02-28", &urges, 2);
+
+        assert_eq!((l.urges, l.linked_urges, l.unlinked_relapses), (5, 3, 2));
+        assert!((l.conversion_rate - 0.6).abs() < 1e-9);
+        // 30, 90 and 60 minutes
+        assert_eq!(l.median_minutes_to_relapse, Some(60.0));
+
+        let severe = l.bands.iter().find(|b| b.band == "severe").unwrap();
+        assert_eq!((severe.urges, severe.relapses), (3, 2));
+        assert!((severe.conversion_rate - 2.0 / 3.0).abs() < 1e-9);
+        let high = l.bands.iter().find(|b| b.band == "high").unwrap();
+        assert_eq!((high.urges, high.conversion_rate), (0, 0.0));
+    }
+
+    #[test]
+    fn test_techniques_ranked_by_pass_rate_then_effectiveness() {
+        let urges = [
+            urge("10:00", "Exercise", 5, true),
+            urge("11:00", "Exercise", 4, true),
+            urge("12:00", "Cold Water", 3, true),
+            urge("13:00", "Meditation", 2, true),
+            urge("14:00", "Meditation", 4, false),
+        ];
+        let p = recovery_patterns(
+            date("2026-02-02"),
+            date("2026-02-08"),
+            &[],
+            &urges,
+            &options(&[]),
+        );
+
+        let names: Vec<&str> = p.techniques.iter().map(|t| t.technique.as_str()).collect();
+        assert_eq!(names, ["Exercise", "Cold Water", "Meditation"]);
+        let meditation = &p.techniques[2];
+        assert_eq!((meditation.uses, meditation.passed), (2, 1));
+        assert!((meditation.pass_rate - 0.5).abs() < 1e-9);
+        assert!((meditation.avg_effectiveness - 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,471 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::stats::{mean, parse_date};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Days before the assessed date whose habits, mood and urges are read.
+pub const RISK_LOOKBACK_DAYS: i64 = 7;
+
+/// Most recent days averaged for the score-decline signal. They are compared
+/// with the `SCORE_BASELINE_DAYS` before them.
+pub const SCORE_RECENT_DAYS: i64 = 3;
+pub const SCORE_BASELINE_DAYS: i64 = 7;
+
+/// Scores each side of the score-decline comparison needs.
+const MIN_SCORES_PER_SIDE: usize = 2;
+
+/// Drop in average `final_score` at which the decline signal saturates.
+pub const SCORE_DECLINE_CEILING: f64 = 0.3;
+
+/// Prior relapses needed before weekday history counts as a signal.
+pub const MIN_WEEKDAY_HISTORY: usize = 3;
+
+/// Weekday relative risk, over an even spread, at which that signal saturates.
+pub const WEEKDAY_RISK_CEILING: f64 = 2.0;
+
+/// Scores at or above these are "elevated" and "high"; below is "low".
+pub const ELEVATED_RISK_SCORE: f64 = 0.35;
+pub const HIGH_RISK_SCORE: f64 = 0.6;
+
+/// Factors in output order as `(key, label, weight)`. Weights sum to 1.
+pub const RISK_FACTORS: [(&str, &str, f64); 6] = [
+    ("score_decline", "Falling daily score", 0.25),
+    ("late_nights", "Late nights", 0.15),
+    ("phone_use", "Phone use", 0.15),
+    ("low_mood", "Low mood and energy", 0.15),
+    ("urge_intensity", "Recent urge intensity", 0.20),
+    ("weekday_history", "Day-of-week history", 0.10),
+];
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// The fields of one daily_log that risk is computed over.
+#[derive(Debug, Clone)]
+pub struct DaySignal {
+    pub date: String,
+    pub final_score: Option<f64>,
+    pub past_12am: bool,
+    pub phone_use: i64,
+}
+
+/// Mood and energy (1–5) from one journal entry.
+#[derive(Debug, Clone)]
+pub struct MoodSignal {
+    pub date: String,
+    pub mood: i64,
+    pub energy: i64,
+}
+
+/// Intensity (1–10) of one urge_entry.
+#[derive(Debug, Clone)]
+pub struct UrgeSignal {
+    pub date: String,
+    pub intensity: i64,
+}
+
+/// Everything an assessment reads. Entries on or after the assessed date are
+/// ignored, so the same inputs can be replayed over a history.
+#[derive(Debug, Clone, Default)]
+pub struct RiskInputs {
+    pub days: Vec<DaySignal>,
+    pub moods: Vec<MoodSignal>,
+    pub urges: Vec<UrgeSignal>,
+    /// Dates of relapse entries.
+    pub relapse_dates: Vec<String>,
+    /// Daily phone minutes at which the phone signal saturates; the top
+    /// phone penalty tier.
+    pub phone_ceiling_minutes: i64,
+}
+
+/// One weighted signal of a risk assessment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskFactor {
+    pub key: String,
+    pub label: String,
+    pub weight: f64,
+    /// 0 (no concern) to 1 (strongest concern). `None` when there is too
+    /// little data; the factor is then left out of the score.
+    pub value: Option<f64>,
+    /// Share of the score from this factor: `weight × value / coverage`.
+    pub contribution: f64,
+    /// What the value was computed from, for display.
+    pub detail: String,
+}
+
+/// Early-warning relapse risk for one day, from the days before it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskAssessment {
+    pub date: String,
+    /// First and last day of the lookback window.
+    pub window_start: String,
+    pub window_end: String,
+    /// Weighted mean of the available factor values, 0–1. `None` when no
+    /// factor has data.
+    pub score: Option<f64>,
+    /// "low", "elevated" or "high"; `None` with the score.
+    pub level: Option<String>,
+    /// Total weight of the factors with data, 0–1.
+    pub coverage: f64,
+    pub factors: Vec<RiskFactor>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn in_range(date: &str, first: NaiveDate, last: NaiveDate) -> bool {
+    parse_date(date).is_some_and(|d| d >= first && d <= last)
+}
+
+/// Level name for a 0–1 risk score.
+pub fn risk_level(score: f64) -> &'static str {
+    if score >= HIGH_RISK_SCORE {
+        "high"
+    } else if score >= ELEVATED_RISK_SCORE {
+        "elevated"
+    } else {
+        "low"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Signals
+// ---------------------------------------------------------------------------
+
+/// Average score of the last `SCORE_RECENT_DAYS` against the
+/// `SCORE_BASELINE_DAYS` before them.
+fn score_decline(days: &[DaySignal], date: NaiveDate) -> (Option<f64>, String) {
+    let recent_start = date - Duration::days(SCORE_RECENT_DAYS);
+    let baseline_start = recent_start - Duration::days(SCORE_BASELINE_DAYS);
+    let scores = |first: NaiveDate, last: NaiveDate| -> Vec<f64> {
+        days.iter()
+            .filter(|d| in_range(&d.date, first, last))
+            .filter_map(|d| d.final_score)
+            .collect()
+    };
+    let recent = scores(recent_start, date - Duration::days(1));
+    let baseline = scores(baseline_start, recent_start - Duration::days(1));
+    if recent.len() < MIN_SCORES_PER_SIDE || baseline.len() < MIN_SCORES_PER_SIDE {
+        return (
+            None,
+            format!(
+                "Needs {} scored days in each of the last {} and the {} before",
+                MIN_SCORES_PER_SIDE, SCORE_RECENT_DAYS, SCORE_BASELINE_DAYS
+            ),
+        );
+    }
+    let (recent_avg, baseline_avg) = (mean(&recent).unwrap(), mean(&baseline).unwrap());
+    let value = ((baseline_avg - recent_avg) / SCORE_DECLINE_CEILING).clamp(0.0, 1.0);
+    (
+        Some(value),
+        format!(
+            "Average score {:.2} over the last {} days vs {:.2} before",
+            recent_avg, SCORE_RECENT_DAYS, baseline_avg
+        ),
+    )
+}
+
+fn late_nights(window: &[&DaySignal]) -> (Option<f64>, String) {
+    if window.is_empty() {
+        return (None, "No daily logs in the window".to_string());
+    }
+    let late = window.iter().filter(|d| d.past_12am).count();
+    (
+        Some(late as f64 / window.len() as f64),
+        format!("Up past 12am on {} of {} logged days", late, window.len()),
+    )
+}
+
+fn phone_use(window: &[&DaySignal], ceiling: i64) -> (Option<f64>, String) {
+    if window.is_empty() || ceiling <= 0 {
+        return (None, "No daily logs in the window".to_string());
+    }
+    let minutes: Vec<f64> = window.iter().map(|d| d.phone_use as f64).collect();
+    let avg = mean(&minutes).unwrap();
+    (
+        Some((avg / ceiling as f64).clamp(0.0, 1.0)),
+        format!(
+            "{:.0} phone minutes a day on average (ceiling {})",
+            avg, ceiling
+        ),
+    )
+}
+
+/// Mood and energy mapped so 5 is 0 and 1 is 1, then averaged.
+fn low_mood(moods: &[&MoodSignal]) -> (Option<f64>, String) {
+    if moods.is_empty() {
+        return (None, "No journal entries in the window".to_string());
+    }
+    let mood = mean(&moods.iter().map(|m| m.mood as f64).collect::<Vec<_>>()).unwrap();
+    let energy = mean(&moods.iter().map(|m| m.energy as f64).collect::<Vec<_>>()).unwrap();
+    let value = (((5.0 - mood) + (5.0 - energy)) / 8.0).clamp(0.0, 1.0);
+    (
+        Some(value),
+        format!(
+            "Average mood {:.1} and energy {:.1} over {} journal entries",
+            mood,
+            energy,
+            moods.len()
+        ),
+    )
+}
+
+/// Mean urge intensity over the window. `None` when no urges were logged,
+/// since an empty log says nothing about how strong urges have been.
+fn urge_intensity(urges: &[&UrgeSignal]) -> (Option<f64>, String) {
+    if urges.is_empty() {
+        return (None, "No urges logged".to_string());
+    }
+    let avg = mean(&urges.iter().map(|u| u.intensity as f64).collect::<Vec<_>>()).unwrap();
+    (
+        Some((avg / 10.0).clamp(0.0, 1.0)),
+        format!("{} urges, average intensity {:.1}", urges.len(), avg),
+    )
+}
+
+/// How over-represented the assessed weekday is among prior relapses.
+fn weekday_history(relapse_dates: &[String], date: NaiveDate) -> (Option<f64>, String) {
+    let prior: Vec<NaiveDate> = relapse_dates
+        .iter()
+        .filter_map(|d| parse_date(d))
+        .filter(|d| *d < date)
+        .collect();
+    if prior.len() < MIN_WEEKDAY_HISTORY {
+        return (
+            None,
+            format!("Needs {} prior relapses", MIN_WEEKDAY_HISTORY),
+        );
+    }
+    let same = prior
+        .iter()
+        .filter(|d| d.weekday() == date.weekday())
+        .count();
+    let relative_risk = same as f64 / prior.len() as f64 * 7.0;
+    let value = ((relative_risk - 1.0) / (WEEKDAY_RISK_CEILING - 1.0)).clamp(0.0, 1.0);
+    (
+        Some(value),
+        format!(
+            "{} of {} prior relapses on a {}",
+            same,
+            prior.len(),
+            date.format("%A")
+        ),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Assessment
+// ---------------------------------------------------------------------------
+
+/// Relapse risk for `date` from the `RISK_LOOKBACK_DAYS` before it (the
+/// score decline looks back further). Nothing logged on or after `date` is
+/// read, so the day's own log and relapses can't leak into its score.
+pub fn assess_risk(date: NaiveDate, inputs: &RiskInputs) -> RiskAssessment {
+    let window_start = date - Duration::days(RISK_LOOKBACK_DAYS);
+    let window_end = date - Duration::days(1);
+
+    let days: Vec<&DaySignal> = inputs
+        .days
+        .iter()
+        .filter(|d| in_range(&d.date, window_start, window_end))
+        .collect();
+    let moods: Vec<&MoodSignal> = inputs
+        .moods
+        .iter()
+        .filter(|m| in_range(&m.date, window_start, window_end))
+        .collect();
+    let urges: Vec<&UrgeSignal> = inputs
+        .urges
+        .iter()
+        .filter(|u| in_range(&u.date, window_start, window_end))
+        .collect();
+
+    let signals = [
+        score_decline(&inputs.days, date),
+        late_nights(&days),
+        phone_use(&days, inputs.phone_ceiling_minutes),
+        low_mood(&moods),
+        urge_intensity(&urges),
+        weekday_history(&inputs.relapse_dates, date),
+    ];
+
+    let coverage: f64 = RISK_FACTORS
+        .iter()
+        .zip(&signals)
+        .filter(|(_, (value, _))| value.is_some())
+        .map(|((_, _, weight), _)| weight)
+        .sum();
+
+    let factors: Vec<RiskFactor> = RISK_FACTORS
+        .iter()
+        .zip(signals)
+        .map(|((key, label, weight), (value, detail))| RiskFactor {
+            key: key.to_string(),
+            label: label.to_string(),
+            weight: *weight,
+            value,
+            contribution: match value {
+                Some(v) if coverage > 0.0 => weight * v / coverage,
+                _ => 0.0,
+            },
+            detail,
+        })
+        .collect();
+
+    let score = if coverage > 0.0 {
+        Some(factors.iter().map(|f| f.contribution).sum::<f64>())
+    } else {
+        None
+    };
+
+    RiskAssessment {
+        date: date.format("%Y-%m-%d").to_string(),
+        window_start: window_start.format("%Y-%m-%d").to_string(),
+        window_end: window_end.format("%Y-%m-%d").to_string(),
+        score,
+        level: score.map(|s| risk_level(s).to_string()),
+        coverage,
+        factors,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn day(date: NaiveDate, score: f64, late: bool, phone: i64) -> DaySignal {
+        DaySignal {
+            date: date.format("%Y-%m-%d").to_string(),
+            final_score: Some(score),
+            past_12am: late,
+            phone_use: phone,
+        }
+    }
+
+    fn factor<'a>(assessment: &'a RiskAssessment, key: &str) -> &'a RiskFactor {
+        assessment.factors.iter().find(|f| f.key == key).unwrap()
+    }
+
+    #[test]
+    fn test_weights_sum_to_one() {
+        let total: f64 = RISK_FACTORS.iter().map(|(_, _, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_data_has_no_score() {
+        let inputs = RiskInputs {
+            phone_ceiling_minutes: 301,
+            ..Default::default()
+        };
+        let a = assess_risk(d("2026-03-10"), &inputs);
+        assert_eq!(a.window_start, "2026-03-03");
+        assert_eq!(a.window_end, "2026-03-09");
+        // An empty urge log is missing data, not low risk.
+        assert_eq!(a.coverage, 0.0);
+        assert_eq!(a.score, None);
+        assert_eq!(a.level, None);
+        assert_eq!(factor(&a, "urge_intensity").value, None);
+        assert_eq!(factor(&a, "score_decline").value, None);
+        assert_eq!(factor(&a, "weekday_history").value, None);
+    }
+
+    #[test]
+    fn test_factor_values() {
+        let date = d("2026-03-16"); // Monday
+        let mut days = Vec::new();
+        // Baseline 0.9 for 7 days, then 0.6 for the last 3: a 0.3 drop.
+        for offset in 4..=10 {
+            days.push(day(date - Duration::days(offset), 0.9, false, 0));
+        }
+        for offset in 1..=3 {
+            days.push(day(date - Duration::days(offset), 0.6, true, 301));
+        }
+        // Logged on the assessed day itself: must be ignored.
+        days.push(day(date, 0.0, true, 1440));
+
+        let inputs = RiskInputs {
+            days,
+            moods: vec![MoodSignal {
+                date: "2026-03-15".into(),
+                mood: 1,
+                energy: 3,
+            }],
+            urges: vec![
+                UrgeSignal {
+                    date: "2026-03-14".into(),
+                    intensity: 6,
+                },
+                UrgeSignal {
+                    date: "2026-03-16".into(),
+                    intensity: 10,
+                },
+            ],
+            relapse_dates: vec![
+                "2026-03-02".into(), // Monday
+                "2026-03-09".into(), // Monday
+                "2026-03-04".into(),
+                "2026-03-16".into(), // the assessed day: ignored
+            ],
+            phone_ceiling_minutes: 301,
+        };
+        let a = assess_risk(date, &inputs);
+
+        assert_eq!(factor(&a, "score_decline").value, Some(1.0));
+        // 3 late nights of 7 logged days in the window.
+        let late = factor(&a, "late_nights").value.unwrap();
+        assert!((late - 3.0 / 7.0).abs() < 1e-9);
+        let phone = factor(&a, "phone_use").value.unwrap();
+        assert!((phone - 3.0 / 7.0).abs() < 1e-9);
+        assert_eq!(factor(&a, "low_mood").value, Some(0.75));
+        assert_eq!(factor(&a, "urge_intensity").value, Some(0.6));
+        // 2 of 3 prior relapses on a Monday: relative risk 4.67, saturated.
+        assert_eq!(factor(&a, "weekday_history").value, Some(1.0));
+
+        assert!((a.coverage - 1.0).abs() < 1e-9);
+        let contributions: f64 = a.factors.iter().map(|f| f.contribution).sum();
+        assert!((a.score.unwrap() - contributions).abs() < 1e-9);
+        assert_eq!(a.level.as_deref(), Some("high"));
+    }
+
+    #[test]
+    fn test_score_rising_is_no_decline() {
+        let date = d("2026-03-16");
+        let mut days = Vec::new();
+        for offset in 4..=10 {
+            days.push(day(date - Duration::days(offset), 0.5, false, 0));
+        }
+        for offset in 1..=3 {
+            days.push(day(date - Duration::days(offset), 0.8, false, 0));
+        }
+        let inputs = RiskInputs {
+            days,
+            phone_ceiling_minutes: 301,
+            ..Default::default()
+        };
+        let a = assess_risk(date, &inputs);
+        assert_eq!(factor(&a, "score_decline").value, Some(0.0));
+        assert_eq!(a.level.as_deref(), Some("low"));
+    }
+
+    #[test]
+    fn test_risk_level_thresholds() {
+        assert_eq!(risk_level(0.0), "low");
+        assert_eq!(risk_level(ELEVATED_RISK_SCORE), "elevated");
+        assert_eq!(risk_level(HIGH_RISK_SCORE), "high");
+    }
+}
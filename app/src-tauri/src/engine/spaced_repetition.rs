@@ -0,0 +1,228 @@
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Ease factor of a topic that has not been reviewed yet (SM-2).
+pub const INITIAL_EASE_FACTOR: f64 = 2.5;
+
+/// SM-2 floor for the ease factor.
+pub const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// Lowest quality grade (0–5) that counts as a successful review.
+pub const PASSING_QUALITY: i64 = 3;
+
+/// Intervals after the first and second successful reviews. Later intervals
+/// grow by the ease factor.
+pub const FIRST_INTERVAL_DAYS: i64 = 1;
+pub const SECOND_INTERVAL_DAYS: i64 = 6;
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// How a session touched its topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicEventKind {
+    /// Studied as new material. Restarts the repetition count.
+    Learn,
+    /// A review session, graded by `quality`.
+    Review,
+}
+
+/// One study session of a topic.
+#[derive(Debug, Clone)]
+pub struct TopicEvent {
+    pub date: NaiveDate,
+    pub kind: TopicEventKind,
+    /// SM-2 grade 0–5. Only read for reviews.
+    pub quality: i64,
+}
+
+/// A topic's review state after replaying its sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewSchedule {
+    /// Successful reviews in a row since the topic was last learned or lapsed.
+    pub repetitions: i64,
+    pub interval_days: i64,
+    pub ease_factor: f64,
+    pub review_count: i64,
+    /// Reviews graded below `PASSING_QUALITY`.
+    pub lapses: i64,
+    pub last_studied: String,
+    pub last_reviewed: Option<String>,
+    /// `last_studied` plus `interval_days`.
+    pub due_date: String,
+}
+
+// ---------------------------------------------------------------------------
+// Scheduling
+// ---------------------------------------------------------------------------
+
+/// SM-2 ease factor after a review of grade `quality`.
+pub fn next_ease_factor(ease_factor: f64, quality: i64) -> f64 {
+    let miss = (5 - quality) as f64;
+    (ease_factor + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE_FACTOR)
+}
+
+/// Replays a topic's sessions, oldest first, with SM-2.
+///
+/// A learn session sets the next review one day later and restarts the
+/// repetition count. A passing review moves the interval to 1 day, then 6,
+/// then the previous interval times the ease factor, and adjusts the ease
+/// factor. A failed review restarts the count at a 1-day interval and keeps
+/// the ease factor. Returns `None` for a topic with no sessions.
+pub fn schedule_reviews(events: &[TopicEvent]) -> Option<ReviewSchedule> {
+    let last = events.last()?;
+
+    let mut repetitions = 0;
+    let mut interval_days = FIRST_INTERVAL_DAYS;
+    let mut ease_factor = INITIAL_EASE_FACTOR;
+    let mut review_count = 0;
+    let mut lapses = 0;
+    let mut last_reviewed = None;
+
+    for event in events {
+        match event.kind {
+            TopicEventKind::Learn => {
+                repetitions = 0;
+                interval_days = FIRST_INTERVAL_DAYS;
+            }
+            TopicEventKind::Review => {
+                let quality = event.quality.clamp(0, 5);
+                review_count += 1;
+                last_reviewed = Some(event.date);
+                if quality >= PASSING_QUALITY {
+                    interval_days = match repetitions {
+                        0 => FIRST_INTERVAL_DAYS,
+                        1 => SECOND_INTERVAL_DAYS,
+                        _ => (interval_days as f64 * ease_factor).round() as i64,
+                    };
+                    repetitions += 1;
+                    ease_factor = next_ease_factor(ease_factor, quality);
+                } else {
+                    repetitions = 0;
+                    interval_days = FIRST_INTERVAL_DAYS;
+                    lapses += 1;
+                }
+            }
+        }
+    }
+
+    let fmt = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    Some(ReviewSchedule {
+        repetitions,
+        interval_days,
+        ease_factor,
+        review_count,
+        lapses,
+        last_studied: fmt(last.date),
+        last_reviewed: last_reviewed.map(fmt),
+        due_date: fmt(last.date + Duration::days(interval_days)),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn learn(date: &str) -> TopicEvent {
+        TopicEvent {
+            date: d(date),
+            kind: TopicEventKind::Learn,
+            quality: 4,
+        }
+    }
+
+    fn review(date: &str, quality: i64) -> TopicEvent {
+        TopicEvent {
+            date: d(date),
+            kind: TopicEventKind::Review,
+            quality,
+        }
+    }
+
+    #[test]
+    fn test_no_events_no_schedule() {
+        assert_eq!(schedule_reviews(&[]), None);
+    }
+
+    #[test]
+    fn test_learning_schedules_first_review_next_day() {
+        let s = schedule_reviews(&[learn("2026-03-01")]).unwrap();
+        assert_eq!(s.repetitions, 0);
+        assert_eq!(s.interval_days, 1);
+        assert_eq!(s.due_date, "2026-03-02");
+        assert_eq!(s.last_reviewed, None);
+        assert!((s.ease_factor - INITIAL_EASE_FACTOR).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_passing_reviews_follow_sm2_intervals() {
+        let events = [
+            learn("2026-03-01"),
+            review("2026-03-02", 5),
+            review("2026-03-03", 5),
+            review("2026-03-09", 5),
+        ];
+        let s = schedule_reviews(&events).unwrap();
+        // 1, 6, then 6 * EF. EF after three perfect reviews: 2.5 + 3 * 0.1.
+        assert_eq!(s.repetitions, 3);
+        assert!((s.ease_factor - 2.8).abs() < 1e-9);
+        assert_eq!(s.interval_days, 16); // round(6 * 2.7)
+        assert_eq!(s.due_date, "2026-03-25");
+        assert_eq!(s.review_count, 3);
+        assert_eq!(s.last_reviewed.as_deref(), Some("2026-03-09"));
+    }
+
+    #[test]
+    fn test_failed_review_restarts_without_changing_ease() {
+        let events = [
+            learn("2026-03-01"),
+            review("2026-03-02", 4),
+            review("2026-03-08", 2),
+        ];
+        let s = schedule_reviews(&events).unwrap();
+        assert_eq!(s.repetitions, 0);
+        assert_eq!(s.interval_days, 1);
+        assert_eq!(s.lapses, 1);
+        assert!(
+            (s.ease_factor - 2.5).abs() < 1e-9,
+            "grade 4 leaves EF at 2.5"
+        );
+        assert_eq!(s.due_date, "2026-03-09");
+    }
+
+    #[test]
+    fn test_relearning_restarts_repetitions() {
+        let events = [
+            learn("2026-03-01"),
+            review("2026-03-02", 5),
+            review("2026-03-08", 5),
+            learn("2026-03-10"),
+        ];
+        let s = schedule_reviews(&events).unwrap();
+        assert_eq!(s.repetitions, 0);
+        assert_eq!(s.due_date, "2026-03-11");
+        assert!(s.ease_factor > INITIAL_EASE_FACTOR, "ease is kept");
+    }
+
+    #[test]
+    fn test_ease_factor_has_a_floor() {
+        let mut ef = INITIAL_EASE_FACTOR;
+        for _ in 0..10 {
+            ef = next_ease_factor(ef, PASSING_QUALITY);
+        }
+        assert!((ef - MIN_EASE_FACTOR).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,583 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use super::correlation::{correlate, CorrelationStats};
+use super::stats::{mean, median};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Start-time buckets as `(label, start hour, end hour)`, end exclusive.
+pub const STUDY_TIME_BUCKETS: [(&str, u32, u32); 6] = [
+    ("Night (12-6am)", 0, 6),
+    ("Early Morning (6-9am)", 6, 9),
+    ("Morning (9am-12pm)", 9, 12),
+    ("Afternoon (12-5pm)", 12, 17),
+    ("Evening (5-9pm)", 17, 21),
+    ("Late Evening (9pm-12am)", 21, 24),
+];
+
+/// Session length bands as `(label, min minutes, max minutes)`, inclusive.
+pub const SESSION_LENGTH_BANDS: [(&str, i64, i64); 5] = [
+    ("Under 30 min", 0, 29),
+    ("30-59 min", 30, 59),
+    ("60-89 min", 60, 89),
+    ("90-119 min", 90, 119),
+    ("2h+", 120, i64::MAX),
+];
+
+/// Label used for an empty location or study type.
+const UNKNOWN_LABEL: &str = "Unknown";
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// The fields of one study_session that patterns are computed over.
+#[derive(Debug, Clone)]
+pub struct StudyObservation {
+    pub date: String,
+    /// `HH:MM`.
+    pub start_time: String,
+    pub subject: String,
+    pub study_type: String,
+    pub location: String,
+    pub duration_minutes: i64,
+    pub focus_score: i64,
+}
+
+/// One daily_log row: whether `schoolwork` was ticked and the day's score.
+#[derive(Debug, Clone)]
+pub struct LoggedDay {
+    pub date: String,
+    pub schoolwork: bool,
+    pub final_score: Option<f64>,
+}
+
+/// Sessions, hours and focus for one value of a breakdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudyBreakdown {
+    pub value: String,
+    pub sessions: i64,
+    pub hours: f64,
+    /// `None` when there are no sessions.
+    pub avg_focus: Option<f64>,
+}
+
+/// Hours in one Monday-based week.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeekHours {
+    pub week_start: String,
+    pub hours: f64,
+    /// Days of the week inside the range; under 7 for a partial first or
+    /// last week.
+    pub days_in_range: i64,
+}
+
+/// Weekly hours of one subject across the whole range.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubjectWeeklyTrend {
+    pub subject: String,
+    pub total_hours: f64,
+    /// Every week overlapping the range, including weeks with no sessions.
+    pub weeks: Vec<WeekHours>,
+    /// Least-squares slope of the whole weeks in `weeks`, in hours per week
+    /// per week. Positive means the subject is getting more time. Partial
+    /// weeks are left out. `None` with fewer than 2 whole weeks.
+    pub trend_hours_per_week: Option<f64>,
+}
+
+/// How days with study relate to the `schoolwork` habit and the day's score,
+/// over days with a daily_log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudyDayCorrelation {
+    pub logged_days: i64,
+    /// Logged days with at least one session.
+    pub study_days: i64,
+    pub schoolwork_days: i64,
+    pub study_and_schoolwork_days: i64,
+    pub avg_score_study_days: Option<f64>,
+    pub avg_score_other_days: Option<f64>,
+    /// Hours studied that day against `schoolwork` (0/1).
+    pub schoolwork: CorrelationStats,
+    /// Hours studied that day against `final_score`.
+    pub final_score: CorrelationStats,
+}
+
+/// Result of `get_study_analytics`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudyPatterns {
+    pub start: String,
+    pub end: String,
+    pub session_count: i64,
+    /// By start time, in clock order from midnight. Same buckets as relapse
+    /// times.
+    pub time_of_day: Vec<StudyBreakdown>,
+    /// Most hours first.
+    pub locations: Vec<StudyBreakdown>,
+    /// Most hours first.
+    pub study_types: Vec<StudyBreakdown>,
+    /// Shortest band first.
+    pub session_lengths: Vec<StudyBreakdown>,
+    pub median_session_minutes: Option<f64>,
+    /// Session length against focus score, over sessions.
+    pub length_focus: CorrelationStats,
+    /// Most hours first.
+    pub subject_trends: Vec<SubjectWeeklyTrend>,
+    pub study_days: StudyDayCorrelation,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn hours(minutes: i64) -> f64 {
+    minutes as f64 / 60.0
+}
+
+/// `STUDY_TIME_BUCKETS` label for an `HH:MM` start time.
+fn time_bucket(time: &str) -> Option<&'static str> {
+    let hour = NaiveTime::parse_from_str(time, "%H:%M").ok()?.hour();
+    STUDY_TIME_BUCKETS
+        .iter()
+        .find(|(_, from, to)| hour >= *from && hour < *to)
+        .map(|(label, _, _)| *label)
+}
+
+fn monday_of(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+/// Least-squares slope of `ys` against 0, 1, 2, ….
+pub fn linear_slope(ys: &[f64]) -> Option<f64> {
+    if ys.len() < 2 {
+        return None;
+    }
+    let n = ys.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let (mut cov, mut var) = (0.0, 0.0);
+    for (i, y) in ys.iter().enumerate() {
+        let dx = i as f64 - mean_x;
+        cov += dx * (y - mean_y);
+        var += dx * dx;
+    }
+    Some(cov / var)
+}
+
+/// Sessions grouped under `labels`, in that order; groups with no sessions
+/// are kept.
+fn ordered_breakdown<'a>(
+    labels: impl Iterator<Item = &'a str>,
+    sessions: impl Iterator<Item = (Option<&'a str>, &'a StudyObservation)>,
+) -> Vec<StudyBreakdown> {
+    // label -> (sessions, minutes, focus sum)
+    let mut groups: Vec<(&str, i64, i64, i64)> = labels.map(|l| (l, 0, 0, 0)).collect();
+    for (label, session) in sessions {
+        if let Some(g) = label.and_then(|l| groups.iter_mut().find(|g| g.0 == l)) {
+            g.1 += 1;
+            g.2 += session.duration_minutes;
+            g.3 += session.focus_score;
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(value, count, minutes, focus)| StudyBreakdown {
+            value: value.to_string(),
+            sessions: count,
+            hours: hours(minutes),
+            avg_focus: (count > 0).then(|| focus as f64 / count as f64),
+        })
+        .collect()
+}
+
+/// Sessions grouped by a free-text field, most hours first.
+fn category_breakdown<'a>(
+    values: impl Iterator<Item = (&'a str, i64, i64)>,
+) -> Vec<StudyBreakdown> {
+    // value -> (sessions, minutes, focus sum)
+    let mut groups: BTreeMap<&str, (i64, i64, i64)> = BTreeMap::new();
+    for (value, minutes, focus) in values {
+        let label = if value.trim().is_empty() {
+            UNKNOWN_LABEL
+        } else {
+            value
+        };
+        let g = groups.entry(label).or_default();
+        g.0 += 1;
+        g.1 += minutes;
+        g.2 += focus;
+    }
+
+    let mut breakdown: Vec<StudyBreakdown> = groups
+        .into_iter()
+        .map(|(value, (count, minutes, focus))| StudyBreakdown {
+            value: value.to_string(),
+            sessions: count,
+            hours: hours(minutes),
+            avg_focus: Some(focus as f64 / count as f64),
+        })
+        .collect();
+    breakdown.sort_by(|a, b| {
+        b.hours
+            .total_cmp(&a.hours)
+            .then_with(|| a.value.cmp(&b.value))
+    });
+    breakdown
+}
+
+fn length_band(minutes: i64) -> Option<&'static str> {
+    SESSION_LENGTH_BANDS
+        .iter()
+        .find(|(_, min, max)| minutes >= *min && minutes <= *max)
+        .map(|(label, _, _)| *label)
+}
+
+fn subject_trends(
+    start: NaiveDate,
+    end: NaiveDate,
+    sessions: &[StudyObservation],
+) -> Vec<SubjectWeeklyTrend> {
+    let first_week = monday_of(start);
+    let week_count = ((monday_of(end) - first_week).num_days() / 7 + 1).max(0) as usize;
+
+    let mut minutes: BTreeMap<&str, Vec<i64>> = BTreeMap::new();
+    for session in sessions {
+        let Ok(date) = NaiveDate::parse_from_str(&session.date, "%Y-%m-%d") else {
+            continue;
+        };
+        let week = ((monday_of(date) - first_week).num_days() / 7) as usize;
+        if date < start || week >= week_count {
+            continue;
+        }
+        minutes
+            .entry(&session.subject)
+            .or_insert_with(|| vec![0; week_count])[week] += session.duration_minutes;
+    }
+
+    let days_in_range: Vec<i64> = (0..week_count)
+        .map(|i| {
+            let monday = first_week + Duration::weeks(i as i64);
+            let sunday = monday + Duration::days(6);
+            (sunday.min(end) - monday.max(start)).num_days() + 1
+        })
+        .collect();
+
+    let mut trends: Vec<SubjectWeeklyTrend> = minutes
+        .into_iter()
+        .map(|(subject, weekly)| {
+            let weeks: Vec<WeekHours> = weekly
+                .iter()
+                .zip(&days_in_range)
+                .enumerate()
+                .map(|(i, (m, days))| WeekHours {
+                    week_start: (first_week + Duration::weeks(i as i64))
+                        .format("%Y-%m-%d")
+                        .to_string(),
+                    hours: hours(*m),
+                    days_in_range: *days,
+                })
+                .collect();
+            let whole_weeks: Vec<f64> = weeks
+                .iter()
+                .filter(|w| w.days_in_range == 7)
+                .map(|w| w.hours)
+                .collect();
+            SubjectWeeklyTrend {
+                subject: subject.to_string(),
+                total_hours: weeks.iter().map(|w| w.hours).sum(),
+                trend_hours_per_week: linear_slope(&whole_weeks),
+                weeks,
+            }
+        })
+        .collect();
+    trends.sort_by(|a, b| {
+        b.total_hours
+            .total_cmp(&a.total_hours)
+            .then_with(|| a.subject.cmp(&b.subject))
+    });
+    trends
+}
+
+fn study_day_correlation(sessions: &[StudyObservation], days: &[LoggedDay]) -> StudyDayCorrelation {
+    let mut minutes_by_date: BTreeMap<&str, i64> = BTreeMap::new();
+    for session in sessions {
+        *minutes_by_date.entry(&session.date).or_default() += session.duration_minutes;
+    }
+
+    let mut study_hours = Vec::with_capacity(days.len());
+    let mut schoolwork = Vec::with_capacity(days.len());
+    let (mut scored_hours, mut scores) = (Vec::new(), Vec::new());
+    let (mut study_scores, mut other_scores) = (Vec::new(), Vec::new());
+    let (mut study_days, mut schoolwork_days, mut both) = (0, 0, 0);
+
+    for day in days {
+        let minutes = minutes_by_date.get(day.date.as_str()).copied();
+        let studied = minutes.is_some();
+        study_days += i64::from(studied);
+        schoolwork_days += i64::from(day.schoolwork);
+        both += i64::from(studied && day.schoolwork);
+
+        let h = hours(minutes.unwrap_or(0));
+        study_hours.push(h);
+        schoolwork.push(if day.schoolwork { 1.0 } else { 0.0 });
+        if let Some(score) = day.final_score {
+            scored_hours.push(h);
+            scores.push(score);
+            if studied {
+                study_scores.push(score);
+            } else {
+                other_scores.push(score);
+            }
+        }
+    }
+
+    StudyDayCorrelation {
+        logged_days: days.len() as i64,
+        study_days,
+        schoolwork_days,
+        study_and_schoolwork_days: both,
+        avg_score_study_days: mean(&study_scores),
+        avg_score_other_days: mean(&other_scores),
+        schoolwork: correlate(&study_hours, &schoolwork),
+        final_score: correlate(&scored_hours, &scores),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Patterns
+// ---------------------------------------------------------------------------
+
+/// Study breakdowns, per-subject weekly trends and study-day correlations for
+/// `[start, end]`. Callers pass only sessions and logged days in that range.
+///
+/// Sessions with an unparseable start time are left out of the time-of-day
+/// breakdown only. Weeks cut short by the range are listed with the days they
+/// cover but left out of the subject trends.
+pub fn study_patterns(
+    start: NaiveDate,
+    end: NaiveDate,
+    sessions: &[StudyObservation],
+    days: &[LoggedDay],
+) -> StudyPatterns {
+    let durations: Vec<f64> = sessions.iter().map(|s| s.duration_minutes as f64).collect();
+    let focus: Vec<f64> = sessions.iter().map(|s| s.focus_score as f64).collect();
+
+    StudyPatterns {
+        start: start.format("%Y-%m-%d").to_string(),
+        end: end.format("%Y-%m-%d").to_string(),
+        session_count: sessions.len() as i64,
+        time_of_day: ordered_breakdown(
+            STUDY_TIME_BUCKETS.iter().map(|(label, _, _)| *label),
+            sessions.iter().map(|s| (time_bucket(&s.start_time), s)),
+        ),
+        locations: category_breakdown(
+            sessions
+                .iter()
+                .map(|s| (s.location.as_str(), s.duration_minutes, s.focus_score)),
+        ),
+        study_types: category_breakdown(
+            sessions
+                .iter()
+                .map(|s| (s.study_type.as_str(), s.duration_minutes, s.focus_score)),
+        ),
+        session_lengths: ordered_breakdown(
+            SESSION_LENGTH_BANDS.iter().map(|(label, _, _)| *label),
+            sessions
+                .iter()
+                .map(|s| (length_band(s.duration_minutes), s)),
+        ),
+        median_session_minutes: median(&mut durations.clone()),
+        length_focus: correlate(&durations, &focus),
+        subject_trends: subject_trends(start, end, sessions),
+        study_days: study_day_correlation(sessions, days),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn session(date: &str, start_time: &str, minutes: i64, focus: i64) -> StudyObservation {
+        StudyObservation {
+            date: date.to_string(),
+            start_time: start_time.to_string(),
+            subject: "Math".to_string(),
+            study_type: "Self-Study".to_string(),
+            location: "Library".to_string(),
+            duration_minutes: minutes,
+            focus_score: focus,
+        }
+    }
+
+    fn day(date: &str, schoolwork: bool, final_score: f64) -> LoggedDay {
+        LoggedDay {
+            date: date.to_string(),
+            schoolwork,
+            final_score: Some(final_score),
+        }
+    }
+
+    #[test]
+    fn test_empty_patterns() {
+        let p = study_patterns(date("2026-03-02"), date("2026-03-08"), &[], &[]);
+        assert_eq!(p.session_count, 0);
+        assert_eq!(p.time_of_day.len(), STUDY_TIME_BUCKETS.len());
+        assert!(p.time_of_day.iter().all(|b| b.avg_focus.is_none()));
+        assert_eq!(p.session_lengths.len(), SESSION_LENGTH_BANDS.len());
+        assert!(p.locations.is_empty());
+        assert!(p.subject_trends.is_empty());
+        assert_eq!(p.median_session_minutes, None);
+        assert_eq!(p.study_days.logged_days, 0);
+        assert_eq!(p.study_days.avg_score_study_days, None);
+    }
+
+    #[test]
+    fn test_breakdowns_by_time_location_type_and_length() {
+        let mut evening = session("2026-03-03", "19:30", 120, 2);
+        evening.location = "Home".to_string();
+        evening.study_type = "Review".to_string();
+        let sessions = [
+            session("2026-03-02", "09:15", 45, 5),
+            session("2026-03-03", "10:00", 60, 3),
+            evening,
+        ];
+        let p = study_patterns(date("2026-03-02"), date("2026-03-08"), &sessions, &[]);
+
+        let late_morning = p
+            .time_of_day
+            .iter()
+            .find(|b| b.value == "Morning (9am-12pm)")
+            .unwrap();
+        assert_eq!(late_morning.sessions, 2);
+        assert!((late_morning.hours - 1.75).abs() < 1e-9);
+        assert_eq!(late_morning.avg_focus, Some(4.0));
+        let evening = p
+            .time_of_day
+            .iter()
+            .find(|b| b.value == "Evening (5-9pm)")
+            .unwrap();
+        assert_eq!(evening.avg_focus, Some(2.0));
+
+        // Most hours first
+        assert_eq!(p.locations[0].value, "Home");
+        assert_eq!(p.locations[1].value, "Library");
+        assert_eq!(p.study_types[0].value, "Review");
+
+        let counts: Vec<i64> = p.session_lengths.iter().map(|b| b.sessions).collect();
+        assert_eq!(counts, vec![0, 1, 1, 0, 1]);
+        assert_eq!(p.median_session_minutes, Some(60.0));
+    }
+
+    #[test]
+    fn test_length_focus_correlation() {
+        // Longer sessions, lower focus
+        let sessions: Vec<StudyObservation> = (0..8)
+            .map(|i| session("2026-03-02", "09:00", 30 + i * 15, 5 - i / 2))
+            .collect();
+        let p = study_patterns(date("2026-03-02"), date("2026-03-08"), &sessions, &[]);
+        assert_eq!(p.length_focus.n, 8);
+        assert!(p.length_focus.pearson_r.unwrap() < -0.9);
+    }
+
+    #[test]
+    fn test_subject_weekly_trend() {
+        let mut physics = session("2026-03-03", "09:00", 600, 4);
+        physics.subject = "Physics".to_string();
+        let sessions = [
+            session("2026-03-02", "09:00", 60, 4),
+            session("2026-03-10", "09:00", 120, 4),
+            session("2026-03-19", "09:00", 180, 4),
+            physics,
+        ];
+        // Wednesday to Thursday: four Monday-based weeks
+        let p = study_patterns(date("2026-02-25"), date("2026-03-19"), &sessions, &[]);
+
+        assert_eq!(p.subject_trends[0].subject, "Physics");
+        let math = &p.subject_trends[1];
+        let weeks: Vec<(&str, f64)> = math
+            .weeks
+            .iter()
+            .map(|w| (w.week_start.as_str(), w.hours))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![
+                ("2026-02-23", 0.0),
+                ("2026-03-02", 1.0),
+                ("2026-03-09", 2.0),
+                ("2026-03-16", 3.0),
+            ]
+        );
+        assert!((math.total_hours - 6.0).abs() < 1e-9);
+        let days: Vec<i64> = math.weeks.iter().map(|w| w.days_in_range).collect();
+        assert_eq!(days, vec![5, 7, 7, 4]);
+        // Fitted over the two whole weeks only
+        assert!((math.trend_hours_per_week.unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_partial_weeks_do_not_skew_trend() {
+        let sessions = [
+            session("2026-03-02", "09:00", 120, 4),
+            session("2026-03-09", "09:00", 120, 4),
+            session("2026-03-16", "09:00", 120, 4),
+        ];
+        // Starts on a Sunday: the first week holds a single day with no study
+        let p = study_patterns(date("2026-03-01"), date("2026-03-22"), &sessions, &[]);
+        let math = &p.subject_trends[0];
+        assert_eq!(math.weeks[0].week_start, "2026-02-23");
+        assert_eq!(math.weeks[0].days_in_range, 1);
+        assert_eq!(math.trend_hours_per_week, Some(0.0));
+
+        // A single whole week has no trend
+        let p = study_patterns(date("2026-03-01"), date("2026-03-10"), &sessions, &[]);
+        assert_eq!(p.subject_trends[0].trend_hours_per_week, None);
+    }
+
+    #[test]
+    fn test_study_days_against_schoolwork_and_score() {
+        let mut sessions = Vec::new();
+        let mut days = Vec::new();
+        for d in 1..=10 {
+            let date = format!("2026-03-{:02}", d);
+            let studied = d % 2 == 0;
+            if studied {
+                sessions.push(session(&date, "09:00", 90, 4));
+            }
+            days.push(day(&date, studied, if studied { 80.0 } else { 60.0 }));
+        }
+        // A session on a day with no log is not counted as a study day
+        sessions.push(session("2026-03-11", "09:00", 60, 4));
+
+        let p = study_patterns(date("2026-03-01"), date("2026-03-11"), &sessions, &days);
+        let s = &p.study_days;
+        assert_eq!(s.logged_days, 10);
+        assert_eq!(s.study_days, 5);
+        assert_eq!(s.schoolwork_days, 5);
+        assert_eq!(s.study_and_schoolwork_days, 5);
+        assert_eq!(s.avg_score_study_days, Some(80.0));
+        assert_eq!(s.avg_score_other_days, Some(60.0));
+        assert!((s.schoolwork.pearson_r.unwrap() - 1.0).abs() < 1e-9);
+        assert!((s.final_score.pearson_r.unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_linear_slope() {
+        assert_eq!(linear_slope(&[]), None);
+        assert_eq!(linear_slope(&[3.0]), None);
+        assert!((linear_slope(&[4.0, 3.0, 2.0]).unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(linear_slope(&[2.0, 2.0, 2.0]), Some(0.0));
+    }
+}
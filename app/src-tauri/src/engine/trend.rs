@@ -0,0 +1,229 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Length of the rolling average taken at the end of each month.
+pub const TREND_WINDOW_DAYS: i64 = 30;
+
+/// Scored days a window needs before its average is reported. With fewer,
+/// a handful of days would decide whether a whole month "improved".
+pub const MIN_TREND_SCORED_DAYS: usize = 10;
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// Rolling 30-day average score at the end of one calendar month, compared
+/// with the same measure for the month before.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonthlyTrendPoint {
+    /// `YYYY-MM`.
+    pub month: String,
+    /// Last day of the month, or the as-of date for the current month.
+    pub period_end: String,
+    /// False for the month still in progress.
+    pub complete: bool,
+    /// Scored days inside the 30-day window ending at `period_end`.
+    pub scored_days: i64,
+    /// `None` when the window has fewer than `MIN_TREND_SCORED_DAYS` scores.
+    pub rolling_avg: Option<f64>,
+    pub previous_avg: Option<f64>,
+    /// `rolling_avg − previous_avg` when both are defined.
+    pub change: Option<f64>,
+    /// Consecutive months, ending with this one, whose average beat the month
+    /// before. 0 when this month did not improve or can't be compared.
+    pub improving_months: i64,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn first_of_next_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).expect("first of month is always valid")
+}
+
+fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("day 1 is always valid")
+}
+
+// ---------------------------------------------------------------------------
+// Series
+// ---------------------------------------------------------------------------
+
+/// Monthly trend series from the month of the first score through the month
+/// containing `as_of`.
+///
+/// `scores` are `(YYYY-MM-DD, final_score)` pairs sorted by date; entries
+/// after `as_of` or with unparseable dates are ignored. Each month is measured
+/// by the average of the scores in the 30 days ending on its last day (or on
+/// `as_of` for the current month).
+pub fn monthly_trend(scores: &[(String, f64)], as_of: NaiveDate) -> Vec<MonthlyTrendPoint> {
+    let dated: Vec<(NaiveDate, f64)> = scores
+        .iter()
+        .filter_map(|(d, s)| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok().map(|d| (d, *s)))
+        .filter(|(d, _)| *d <= as_of)
+        .collect();
+    let first = match dated.iter().map(|(d, _)| *d).min() {
+        Some(d) => d,
+        None => return Vec::new(),
+    };
+
+    let mut points: Vec<MonthlyTrendPoint> = Vec::new();
+    let mut month = month_start(first);
+    while month <= as_of {
+        let next = first_of_next_month(month);
+        let last_day = next.pred_opt().expect("month has a last day");
+        let complete = last_day <= as_of;
+        let period_end = if complete { last_day } else { as_of };
+        let window_start = period_end - chrono::Duration::days(TREND_WINDOW_DAYS - 1);
+
+        let window: Vec<f64> = dated
+            .iter()
+            .filter(|(d, _)| *d >= window_start && *d <= period_end)
+            .map(|(_, s)| *s)
+            .collect();
+        let rolling_avg = if window.len() >= MIN_TREND_SCORED_DAYS {
+            Some(window.iter().sum::<f64>() / window.len() as f64)
+        } else {
+            None
+        };
+
+        let previous = points.last();
+        let previous_avg = previous.and_then(|p| p.rolling_avg);
+        let change = rolling_avg.zip(previous_avg).map(|(cur, prev)| cur - prev);
+        let improving_months = match change {
+            Some(c) if c > 0.0 => previous.map_or(0, |p| p.improving_months) + 1,
+            _ => 0,
+        };
+
+        points.push(MonthlyTrendPoint {
+            month: month.format("%Y-%m").to_string(),
+            period_end: period_end.format("%Y-%m-%d").to_string(),
+            complete,
+            scored_days: window.len() as i64,
+            rolling_avg,
+            previous_avg,
+            change,
+            improving_months,
+        });
+        month = next;
+    }
+    points
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    /// One score per day over `[start, end]`, the same score every day.
+    fn daily(start: &str, end: &str, score: f64) -> Vec<(String, f64)> {
+        let mut out = Vec::new();
+        let mut d = date(start);
+        while d <= date(end) {
+            out.push((d.format("%Y-%m-%d").to_string(), score));
+            d = d.succ_opt().unwrap();
+        }
+        out
+    }
+
+    /// Months of 2026 from January, every day scoring that month's entry in
+    /// `steps`.
+    fn stepped(steps: &[f64]) -> Vec<(String, f64)> {
+        let mut out = Vec::new();
+        for (i, score) in steps.iter().enumerate() {
+            let start = NaiveDate::from_ymd_opt(2026, i as u32 + 1, 1).unwrap();
+            let end = first_of_next_month(start).pred_opt().unwrap();
+            out.extend(daily(
+                &start.format("%Y-%m-%d").to_string(),
+                &end.format("%Y-%m-%d").to_string(),
+                *score,
+            ));
+        }
+        out
+    }
+
+    #[test]
+    fn test_empty_scores_give_empty_series() {
+        assert!(monthly_trend(&[], date("2026-05-01")).is_empty());
+        // Scores only after as_of are ignored
+        let later = daily("2026-06-01", "2026-06-30", 0.7);
+        assert!(monthly_trend(&later, date("2026-05-01")).is_empty());
+    }
+
+    #[test]
+    fn test_months_span_first_score_to_as_of() {
+        let scores = daily("2026-01-15", "2026-03-10", 0.6);
+        let points = monthly_trend(&scores, date("2026-03-20"));
+        let months: Vec<&str> = points.iter().map(|p| p.month.as_str()).collect();
+        assert_eq!(months, vec!["2026-01", "2026-02", "2026-03"]);
+
+        assert!(points[0].complete);
+        assert_eq!(points[0].period_end, "2026-01-31");
+        assert_eq!(points[0].scored_days, 17);
+        // Current month ends at as_of and is flagged incomplete
+        assert!(!points[2].complete);
+        assert_eq!(points[2].period_end, "2026-03-20");
+        assert_eq!(points[2].scored_days, 20);
+    }
+
+    #[test]
+    fn test_window_needs_minimum_scored_days() {
+        let scores = daily("2026-01-25", "2026-02-28", 0.6);
+        let points = monthly_trend(&scores, date("2026-02-28"));
+        // January window holds only 7 scores
+        assert_eq!(points[0].scored_days, 7);
+        assert_eq!(points[0].rolling_avg, None);
+        assert!(points[1].rolling_avg.is_some());
+        // Can't compare with an undefined previous month
+        assert_eq!(points[1].change, None);
+        assert_eq!(points[1].improving_months, 0);
+    }
+
+    #[test]
+    fn test_improving_months_count_and_reset() {
+        let scores = stepped(&[0.5, 0.55, 0.6, 0.65, 0.6, 0.7]);
+        let points = monthly_trend(&scores, date("2026-06-30"));
+        let runs: Vec<i64> = points.iter().map(|p| p.improving_months).collect();
+        assert_eq!(runs, vec![0, 1, 2, 3, 0, 1]);
+
+        let april = &points[3];
+        assert!((april.rolling_avg.unwrap() - 0.65).abs() < 1e-9);
+        assert!((april.previous_avg.unwrap() - 0.6).abs() < 1e-9);
+        assert!((april.change.unwrap() - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flat_month_is_not_improvement() {
+        // March and April windows both lie inside a 0.6 month
+        let scores = stepped(&[0.5, 0.6, 0.6, 0.6]);
+        let points = monthly_trend(&scores, date("2026-04-30"));
+        assert_eq!(points[3].change, Some(0.0));
+        assert_eq!(points[3].improving_months, 0);
+    }
+
+    #[test]
+    fn test_year_boundary() {
+        let scores = daily("2025-12-01", "2026-01-31", 0.6);
+        let points = monthly_trend(&scores, date("2026-01-31"));
+        let months: Vec<&str> = points.iter().map(|p| p.month.as_str()).collect();
+        assert_eq!(months, vec!["2025-12", "2026-01"]);
+        assert_eq!(points[0].period_end, "2025-12-31");
+    }
+}
@@ -2,9 +2,11 @@ use std::sync::Mutex;
 
 use rusqlite::Connection;
 
+pub mod cli;
 mod commands;
 mod db;
 pub mod engine;
+mod server;
 
 pub struct AppState {
     pub db: Mutex<Connection>,
@@ -46,6 +48,19 @@ pub fn run() {
             commands::study::save_study_session,
             commands::study::update_study_session,
             commands::study::delete_study_session,
+            commands::study::get_study_timer,
+            commands::study::start_study_timer,
+            commands::study::pause_study_timer,
+            commands::study::resume_study_timer,
+            commands::study::stop_study_timer,
+            commands::study::cancel_study_timer,
+            commands::study_goal::get_study_goals,
+            commands::study_goal::save_study_goal,
+            commands::study_goal::update_study_goal,
+            commands::study_goal::delete_study_goal,
+            commands::study_goal::get_study_goal_progress,
+            commands::study_topic::get_study_topics,
+            commands::study_topic::get_due_reviews,
             // Phase 6.4: Application commands
             commands::application::get_applications,
             commands::application::get_application,
@@ -53,7 +68,21 @@ pub fn run() {
             commands::application::update_application,
             commands::application::archive_application,
             commands::application::add_status_change,
+            commands::application::reopen_application,
             commands::application::get_status_history,
+            commands::application::update_status_change,
+            commands::application::delete_status_change,
+            commands::application::get_status_change_audit,
+            commands::application::get_contacts,
+            commands::application::save_contact,
+            commands::application::update_contact,
+            commands::application::delete_contact,
+            commands::application::get_interviews,
+            commands::application::save_interview,
+            commands::application::update_interview,
+            commands::application::delete_interview,
+            commands::application::get_follow_ups,
+            commands::application::mark_stale_applications,
             // Phase 6.5: Recovery commands (relapse + urge)
             commands::recovery::get_relapse_entries,
             commands::recovery::save_relapse_entry,
@@ -61,6 +90,11 @@ pub fn run() {
             commands::recovery::get_urge_entries,
             commands::recovery::save_urge_entry,
             commands::recovery::update_urge_entry,
+            commands::recovery::suggest_urge_links,
+            commands::recovery::link_relapse_urge,
+            commands::recovery::add_recovery_amendment,
+            commands::recovery::get_amended_relapse_entry,
+            commands::recovery::get_amended_urge_entry,
             // Phase 6.6: Config & settings commands
             commands::config::get_config,
             commands::config::save_config,
@@ -72,6 +106,11 @@ pub fn run() {
             commands::review::get_weekly_review,
             commands::review::compute_weekly_stats,
             commands::review::save_weekly_review,
+            // Monthly, quarterly and yearly reviews
+            commands::periodic_review::get_review_prompts,
+            commands::periodic_review::get_periodic_review,
+            commands::periodic_review::compute_periodic_stats,
+            commands::periodic_review::save_periodic_review,
             // Phase 6.8: Analytics commands
             commands::analytics::get_score_trend,
             commands::analytics::get_habit_completion_rates,
@@ -79,12 +118,29 @@ pub fn run() {
             commands::analytics::get_day_of_week_averages,
             commands::analytics::get_correlation_data,
             commands::analytics::get_study_summary,
+            commands::analytics::get_study_analytics,
             commands::analytics::get_application_pipeline,
+            commands::analytics::get_application_funnel,
             commands::analytics::get_recovery_frequency,
+            commands::analytics::get_recovery_patterns,
+            commands::analytics::get_urge_linkage,
+            commands::analytics::get_risk_assessment,
+            commands::analytics::get_correlations,
+            commands::analytics::get_mood_analytics,
+            commands::analytics::get_monthly_trend,
+            // Full-text search
+            commands::search::search,
             // Phase 6.9: Milestone commands
             commands::milestone::get_milestones,
             commands::milestone::check_milestones,
             commands::milestone::get_milestone_context,
+            commands::milestone::create_milestone,
+            commands::milestone::update_milestone,
+            commands::milestone::delete_milestone,
+            commands::milestone::recompute_milestones,
+            commands::clean_streak::get_clean_streaks,
+            commands::clean_streak::save_clean_streak,
+            commands::clean_streak::delete_clean_streak,
             // Phase 6.10: Data management commands
             commands::data::export_data,
             commands::data::import_data,
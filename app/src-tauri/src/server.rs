@@ -0,0 +1,677 @@
+//! Opt-in local HTTP/JSON API (`ltu-cli serve`).
+//!
+//! Binds to 127.0.0.1 only and mirrors the Tauri command surface: every
+//! request is `POST /api/<command_name>` with the same JSON argument object
+//! the frontend passes to `invoke()` (e.g. `{"start": "...", "end": "..."}`,
+//! `{"appId": 3, "change": {...}}`). Handlers call the same `*_impl`
+//! functions as the IPC commands, so validation and `CommandResult` errors are
+//! identical.
+//!
+//! Every request must carry `Authorization: Bearer <token>`. The token is
+//! generated on first start and stored next to the database file in
+//! `api_token`; anything that can read that file already has the database.
+//!
+//! Responses: `200` with the command's JSON result, `400` with
+//! `{"error": "..."}` for a `CommandError` or malformed arguments, `401` for a
+//! missing/wrong token, `404` for an unknown command, `405` for non-POST.
+//!
+//! Saves award milestones exactly as in the app, but the HTTP API has no
+//! event channel — call `get_milestones` to see what was earned.
+//!
+//! Settings, import/export and file I/O commands are deliberately not exposed.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::commands::analytics::{
+    get_application_funnel_impl, get_application_pipeline_impl, get_correlation_data_impl,
+    get_correlations_impl, get_day_of_week_averages_impl, get_habit_completion_rates_impl,
+    get_monthly_trend_impl, get_mood_analytics_impl, get_recovery_frequency_impl,
+    get_recovery_patterns_impl, get_risk_assessment_impl, get_score_trend_impl,
+    get_study_analytics_impl, get_study_summary_impl, get_urge_linkage_impl,
+    get_vice_frequency_impl,
+};
+use crate::commands::application::{
+    add_status_change_impl, archive_application_impl, delete_contact_impl,
+    delete_interview_impl, delete_status_change_impl, get_application_impl,
+    get_applications_impl, get_contacts_impl, get_follow_ups_impl, get_interviews_impl,
+    get_status_change_audit_impl, get_status_history_impl, mark_stale_applications_impl,
+    reopen_application_impl, save_application_impl, save_contact_impl, save_interview_impl,
+    update_application_impl, update_contact_impl, update_interview_impl,
+    update_status_change_impl,
+};
+use crate::commands::daily_log::{
+    get_daily_logs_impl, get_streak_at_date_impl, query_daily_log_by_date, save_daily_log_impl,
+};
+use crate::commands::journal::{query_journal_by_date, save_journal_impl};
+use crate::commands::clean_streak::{
+    delete_clean_streak_impl, query_clean_streaks, save_clean_streak_impl,
+};
+use crate::commands::milestone::{
+    check_milestones_impl, create_milestone_impl, delete_milestone_impl,
+    get_milestone_context_impl, query_all_milestones, recompute_milestones_impl,
+    update_milestone_impl,
+};
+use crate::commands::periodic_review::{
+    compute_periodic_stats_impl, get_periodic_review_impl, get_review_prompts_impl,
+    save_periodic_review_impl,
+};
+use crate::commands::recovery::{
+    add_recovery_amendment_impl, get_amended_relapse_entry_impl, get_amended_urge_entry_impl,
+    get_relapse_entries_impl, get_urge_entries_impl, link_relapse_urge_impl,
+    save_relapse_entry_impl, save_urge_entry_impl, suggest_urge_links_impl,
+    update_relapse_entry_impl, update_urge_entry_impl, RecoveryView,
+};
+use crate::commands::review::{
+    compute_period_stats_impl, compute_week_end, query_weekly_review_by_week_start,
+    save_weekly_review_impl,
+};
+use crate::commands::search::{search_impl, SearchFilters};
+use crate::commands::study::{
+    cancel_study_timer_impl, delete_study_session_impl, get_study_sessions_impl,
+    get_study_sessions_range_impl, get_study_timer_impl, pause_study_timer_impl,
+    resume_study_timer_impl, save_study_session_impl, start_study_timer_impl,
+    stop_study_timer_impl, update_study_session_impl,
+};
+use crate::commands::study_goal::{
+    delete_study_goal_impl, get_study_goal_progress_impl, get_study_goals_impl,
+    save_study_goal_impl, update_study_goal_impl,
+};
+use crate::commands::study_topic::{get_due_reviews_impl, get_study_topics_impl};
+use crate::commands::{CommandError, CommandResult};
+
+/// Default port for `ltu-cli serve`.
+pub const DEFAULT_PORT: u16 = 8765;
+
+/// Largest request body accepted. No mirrored command takes more than a
+/// single entry, so anything bigger is a mistake or abuse.
+const MAX_BODY_BYTES: u64 = 1024 * 1024;
+
+/// File name of the API token, stored beside the database file.
+const TOKEN_FILE_NAME: &str = "api_token";
+
+// ---------------------------------------------------------------------------
+// Token
+// ---------------------------------------------------------------------------
+
+/// Path of the token file for a given database path.
+pub fn token_path(db_path: &Path) -> PathBuf {
+    db_path.with_file_name(TOKEN_FILE_NAME)
+}
+
+/// Read the API token from `path`, generating and writing a new one if the
+/// file is missing, empty, or `rotate` is set.
+pub fn load_or_create_token(path: &Path, rotate: bool) -> Result<String, String> {
+    if !rotate {
+        if let Ok(existing) = std::fs::read_to_string(path) {
+            let existing = existing.trim();
+            if !existing.is_empty() {
+                return Ok(existing.to_string());
+            }
+        }
+    }
+
+    let bytes: [u8; 32] = rand::random();
+    let token: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    write_private_file(path, &token)
+        .map_err(|e| format!("Failed to write token file '{}': {}", path.display(), e))?;
+    Ok(token)
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    std::fs::write(path, contents)
+}
+
+/// Compare the `Authorization` header against the token without an early
+/// exit on the first mismatching byte.
+fn is_authorized(auth_header: Option<&str>, token: &str) -> bool {
+    let presented = match auth_header.and_then(|h| h.strip_prefix("Bearer ")) {
+        Some(p) => p.trim().as_bytes(),
+        None => return false,
+    };
+    let expected = token.as_bytes();
+    if presented.len() != expected.len() {
+        return false;
+    }
+    presented
+        .iter()
+        .zip(expected)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+// ---------------------------------------------------------------------------
+// Server Loop
+// ---------------------------------------------------------------------------
+
+/// Serve requests on `127.0.0.1:<port>` until the process is killed.
+///
+/// Requests are handled one at a time on the calling thread, which matches the
+/// app's single `Mutex<Connection>` and keeps each command's transaction
+/// semantics unchanged.
+pub fn serve(conn: &Connection, port: u16, token: &str) -> Result<(), String> {
+    let server = tiny_http::Server::http(("127.0.0.1", port))
+        .map_err(|e| format!("Failed to bind 127.0.0.1:{}: {}", port, e))?;
+    eprintln!("Listening on http://127.0.0.1:{}/api/", port);
+
+    for mut request in server.incoming_requests() {
+        let method = request.method().as_str().to_string();
+        let url = request.url().to_string();
+        let auth = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("Authorization"))
+            .map(|h| h.value.as_str().to_string());
+
+        let mut body = String::new();
+        let read = request
+            .as_reader()
+            .take(MAX_BODY_BYTES + 1)
+            .read_to_string(&mut body);
+
+        let (status, payload) = match read {
+            Err(_) => error_response(400, "Request body must be UTF-8 JSON"),
+            Ok(n) if n as u64 > MAX_BODY_BYTES => error_response(413, "Request body too large"),
+            Ok(_) => handle_request(conn, token, &method, &url, auth.as_deref(), &body),
+        };
+        eprintln!("{} {} -> {}", method, url, status);
+
+        let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            .expect("static header is valid");
+        let response = tiny_http::Response::from_string(payload.to_string())
+            .with_status_code(status)
+            .with_header(header);
+        if let Err(e) = request.respond(response) {
+            eprintln!("Failed to send response: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Request Handling
+// ---------------------------------------------------------------------------
+
+fn error_response(status: u16, msg: impl std::fmt::Display) -> (u16, Value) {
+    (status, serde_json::json!({ "error": msg.to_string() }))
+}
+
+/// Route one request. Kept free of any HTTP types so it can be unit tested.
+pub(crate) fn handle_request(
+    conn: &Connection,
+    token: &str,
+    method: &str,
+    url: &str,
+    auth_header: Option<&str>,
+    body: &str,
+) -> (u16, Value) {
+    if !is_authorized(auth_header, token) {
+        return error_response(401, "Missing or invalid bearer token");
+    }
+
+    let path = url.split('?').next().unwrap_or("");
+    let command = match path.strip_prefix("/api/") {
+        Some(c) if !c.is_empty() && !c.contains('/') => c,
+        _ => return error_response(404, format!("Unknown path '{}'", path)),
+    };
+    if method != "POST" {
+        return error_response(405, "Use POST with a JSON body");
+    }
+
+    let args = if body.trim().is_empty() {
+        Map::new()
+    } else {
+        match serde_json::from_str::<Value>(body) {
+            Ok(Value::Object(map)) => map,
+            Ok(_) => return error_response(400, "Request body must be a JSON object"),
+            Err(e) => return error_response(400, format!("Invalid JSON: {}", e)),
+        }
+    };
+
+    match dispatch(conn, command, &args) {
+        Some(Ok(value)) => (200, value),
+        Some(Err(e)) => error_response(400, e),
+        None => error_response(404, format!("Unknown command '{}'", command)),
+    }
+}
+
+/// Deserialize one named argument, as Tauri does for command parameters.
+fn arg<T: DeserializeOwned>(args: &Map<String, Value>, name: &str) -> CommandResult<T> {
+    let value = args
+        .get(name)
+        .cloned()
+        .ok_or_else(|| CommandError::from(format!("Missing argument '{}'", name)))?;
+    serde_json::from_value(value)
+        .map_err(|e| CommandError::from(format!("Invalid argument '{}': {}", name, e)))
+}
+
+/// Like `arg`, but a missing or null argument is `None` (Tauri's handling of
+/// `Option<T>` command parameters).
+fn opt_arg<T: DeserializeOwned>(args: &Map<String, Value>, name: &str) -> CommandResult<Option<T>> {
+    match args.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(_) => arg(args, name).map(Some),
+    }
+}
+
+/// Today's local date, for commands whose Tauri wrapper supplies it.
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// The current local time, for the study timer and goal commands.
+fn now() -> chrono::NaiveDateTime {
+    chrono::Local::now().naive_local()
+}
+
+fn json<T: Serialize>(result: CommandResult<T>) -> CommandResult<Value> {
+    Ok(serde_json::to_value(result?)?)
+}
+
+/// Run a mirrored command by its Tauri name. Argument names follow the
+/// frontend's `invoke()` payloads (camelCase). Returns `None` for commands
+/// that are not exposed.
+fn dispatch(
+    conn: &Connection,
+    command: &str,
+    args: &Map<String, Value>,
+) -> Option<CommandResult<Value>> {
+    let s = |name: &str| arg::<String>(args, name);
+    let id = || arg::<i64>(args, "id");
+    let view = || opt_arg::<RecoveryView>(args, "view").map(Option::unwrap_or_default);
+
+    let result = match command {
+        // Daily log
+        "get_daily_log" => s("date").and_then(|d| json(query_daily_log_by_date(conn, &d))),
+        "get_daily_logs" => s("start")
+            .and_then(|st| s("end").and_then(|en| json(get_daily_logs_impl(conn, &st, &en)))),
+        "get_streak_at_date" => s("date").and_then(|d| json(get_streak_at_date_impl(conn, &d))),
+        "save_daily_log" => arg(args, "entry").and_then(|e| json(save_daily_log_impl(conn, e))),
+
+        // Journal
+        "get_journal" => s("date").and_then(|d| json(query_journal_by_date(conn, &d))),
+        "save_journal" => arg(args, "entry").and_then(|e| json(save_journal_impl(conn, e))),
+
+        // Study sessions
+        "get_study_sessions" => s("date").and_then(|d| json(get_study_sessions_impl(conn, &d))),
+        "get_study_sessions_range" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_study_sessions_range_impl(conn, &st, &en)))
+        }),
+        "save_study_session" => {
+            arg(args, "session").and_then(|x| json(save_study_session_impl(conn, x)))
+        }
+        "update_study_session" => id().and_then(|i| {
+            arg(args, "session").and_then(|x| json(update_study_session_impl(conn, i, x)))
+        }),
+        "delete_study_session" => id().and_then(|i| json(delete_study_session_impl(conn, i))),
+        "get_study_timer" => json(get_study_timer_impl(conn, now())),
+        "start_study_timer" => s("subject").and_then(|su| {
+            s("studyType").and_then(|ty| {
+                s("location")
+                    .and_then(|lo| json(start_study_timer_impl(conn, &su, &ty, &lo, now())))
+            })
+        }),
+        "pause_study_timer" => json(pause_study_timer_impl(conn, now())),
+        "resume_study_timer" => json(resume_study_timer_impl(conn, now())),
+        "stop_study_timer" => {
+            arg(args, "details").and_then(|d| json(stop_study_timer_impl(conn, d, now())))
+        }
+        "cancel_study_timer" => json(cancel_study_timer_impl(conn)),
+
+        // Study goals
+        "get_study_goals" => json(get_study_goals_impl(conn)),
+        "save_study_goal" => arg(args, "goal").and_then(|g| json(save_study_goal_impl(conn, g))),
+        "update_study_goal" => id().and_then(|i| {
+            arg(args, "goal").and_then(|g| json(update_study_goal_impl(conn, i, g)))
+        }),
+        "delete_study_goal" => id().and_then(|i| json(delete_study_goal_impl(conn, i))),
+        "get_study_goal_progress" => s("weekStart")
+            .and_then(|w| json(get_study_goal_progress_impl(conn, &w, now().date()))),
+
+        // Study topics
+        "get_study_topics" => json(get_study_topics_impl(conn)),
+        "get_due_reviews" => s("date").and_then(|d| json(get_due_reviews_impl(conn, &d))),
+
+        // Applications
+        "get_applications" => {
+            arg(args, "filters").and_then(|f| json(get_applications_impl(conn, f)))
+        }
+        "get_application" => id().and_then(|i| json(get_application_impl(conn, i))),
+        "save_application" => arg(args, "app").and_then(|a| json(save_application_impl(conn, a))),
+        "update_application" => id().and_then(|i| {
+            arg(args, "app").and_then(|a| json(update_application_impl(conn, i, a)))
+        }),
+        "archive_application" => id().and_then(|i| json(archive_application_impl(conn, i))),
+        "add_status_change" => arg::<i64>(args, "appId").and_then(|a| {
+            arg(args, "change").and_then(|c| json(add_status_change_impl(conn, a, c)))
+        }),
+        "reopen_application" => id().and_then(|i| {
+            s("date").and_then(|d| {
+                opt_arg(args, "notes").and_then(|n| json(reopen_application_impl(conn, i, &d, n)))
+            })
+        }),
+        "get_status_history" => {
+            arg::<i64>(args, "appId").and_then(|a| json(get_status_history_impl(conn, a)))
+        }
+        "update_status_change" => id().and_then(|i| {
+            arg(args, "change").and_then(|c| json(update_status_change_impl(conn, i, c)))
+        }),
+        "delete_status_change" => id().and_then(|i| json(delete_status_change_impl(conn, i))),
+        "get_status_change_audit" => {
+            arg::<i64>(args, "appId").and_then(|a| json(get_status_change_audit_impl(conn, a)))
+        }
+        "get_contacts" => arg::<i64>(args, "appId").and_then(|a| json(get_contacts_impl(conn, a))),
+        "save_contact" => arg::<i64>(args, "appId").and_then(|a| {
+            arg(args, "contact").and_then(|c| json(save_contact_impl(conn, a, c)))
+        }),
+        "update_contact" => id().and_then(|i| {
+            arg(args, "contact").and_then(|c| json(update_contact_impl(conn, i, c)))
+        }),
+        "delete_contact" => id().and_then(|i| json(delete_contact_impl(conn, i))),
+        "get_interviews" => {
+            arg::<i64>(args, "appId").and_then(|a| json(get_interviews_impl(conn, a)))
+        }
+        "save_interview" => arg::<i64>(args, "appId").and_then(|a| {
+            arg(args, "interview").and_then(|v| json(save_interview_impl(conn, a, v)))
+        }),
+        "update_interview" => id().and_then(|i| {
+            arg(args, "interview").and_then(|v| json(update_interview_impl(conn, i, v)))
+        }),
+        "delete_interview" => id().and_then(|i| json(delete_interview_impl(conn, i))),
+        "get_follow_ups" => s("asOf").and_then(|d| json(get_follow_ups_impl(conn, &d))),
+        "mark_stale_applications" => s("asOf").and_then(|d| {
+            opt_arg(args, "days").and_then(|n| json(mark_stale_applications_impl(conn, &d, n)))
+        }),
+
+        // Recovery
+        "get_relapse_entries" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_relapse_entries_impl(conn, &st, &en, v)))
+            })
+        }),
+        "save_relapse_entry" => {
+            arg(args, "entry").and_then(|e| json(save_relapse_entry_impl(conn, e)))
+        }
+        "update_relapse_entry" => id().and_then(|i| {
+            arg(args, "entry").and_then(|e| json(update_relapse_entry_impl(conn, i, e)))
+        }),
+        "get_urge_entries" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_urge_entries_impl(conn, &st, &en, v)))
+            })
+        }),
+        "save_urge_entry" => arg(args, "entry").and_then(|e| json(save_urge_entry_impl(conn, e))),
+        "update_urge_entry" => id().and_then(|i| {
+            arg(args, "entry").and_then(|e| json(update_urge_entry_impl(conn, i, e)))
+        }),
+        "suggest_urge_links" => arg::<i64>(args, "relapseId")
+            .and_then(|r| json(suggest_urge_links_impl(conn, r))),
+        "link_relapse_urge" => arg::<i64>(args, "relapseId").and_then(|r| {
+            opt_arg(args, "urgeId").and_then(|u| json(link_relapse_urge_impl(conn, r, u)))
+        }),
+        "add_recovery_amendment" => s("entity").and_then(|e| {
+            id().and_then(|i| {
+                s("field").and_then(|f| {
+                    s("newValue").and_then(|v| {
+                        s("reason").and_then(|r| {
+                            json(add_recovery_amendment_impl(conn, &e, i, &f, &v, &r))
+                        })
+                    })
+                })
+            })
+        }),
+        "get_amended_relapse_entry" => {
+            id().and_then(|i| json(get_amended_relapse_entry_impl(conn, i)))
+        }
+        "get_amended_urge_entry" => id().and_then(|i| json(get_amended_urge_entry_impl(conn, i))),
+
+        // Weekly review
+        "get_weekly_review" => {
+            s("weekStart").and_then(|w| json(query_weekly_review_by_week_start(conn, &w)))
+        }
+        "compute_weekly_stats" => s("weekStart").and_then(|w| {
+            compute_week_end(conn, &w)
+                .and_then(|end| json(compute_period_stats_impl(conn, &w, &end)))
+        }),
+        "save_weekly_review" => {
+            arg(args, "review").and_then(|r| json(save_weekly_review_impl(conn, r)))
+        }
+
+        // Periodic review
+        "get_review_prompts" => {
+            arg(args, "period").and_then(|p| json(Ok(get_review_prompts_impl(p))))
+        }
+        "get_periodic_review" => arg(args, "period").and_then(|p| {
+            s("periodStart").and_then(|st| json(get_periodic_review_impl(conn, p, &st)))
+        }),
+        "compute_periodic_stats" => arg(args, "period").and_then(|p| {
+            s("periodStart").and_then(|st| json(compute_periodic_stats_impl(conn, p, &st)))
+        }),
+        "save_periodic_review" => {
+            arg(args, "review").and_then(|r| json(save_periodic_review_impl(conn, r)))
+        }
+
+        // Analytics
+        "get_score_trend" => s("start")
+            .and_then(|st| s("end").and_then(|en| json(get_score_trend_impl(conn, &st, &en)))),
+        "get_habit_completion_rates" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_habit_completion_rates_impl(conn, &st, &en)))
+        }),
+        "get_vice_frequency" => s("start")
+            .and_then(|st| s("end").and_then(|en| json(get_vice_frequency_impl(conn, &st, &en)))),
+        "get_day_of_week_averages" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_day_of_week_averages_impl(conn, &st, &en)))
+        }),
+        "get_correlation_data" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_correlation_data_impl(conn, &st, &en)))
+        }),
+        "get_study_summary" => s("start")
+            .and_then(|st| s("end").and_then(|en| json(get_study_summary_impl(conn, &st, &en)))),
+        "get_study_analytics" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_study_analytics_impl(conn, &st, &en)))
+        }),
+        "get_application_pipeline" => json(get_application_pipeline_impl(conn)),
+        "get_application_funnel" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_application_funnel_impl(conn, &st, &en)))
+        }),
+        "get_recovery_frequency" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_recovery_frequency_impl(conn, &st, &en, v)))
+            })
+        }),
+        "get_recovery_patterns" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_recovery_patterns_impl(conn, &st, &en, v)))
+            })
+        }),
+        "get_urge_linkage" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_urge_linkage_impl(conn, &st, &en, v)))
+            })
+        }),
+        "get_risk_assessment" => s("date").and_then(|d| {
+            view().and_then(|v| json(get_risk_assessment_impl(conn, &d, v)))
+        }),
+        "get_correlations" => opt_arg(args, "window")
+            .and_then(|w| json(get_correlations_impl(conn, w, &today()))),
+        "get_mood_analytics" => s("start").and_then(|st| {
+            s("end").and_then(|en| {
+                view().and_then(|v| json(get_mood_analytics_impl(conn, &st, &en, v)))
+            })
+        }),
+        "get_monthly_trend" => s("start").and_then(|st| {
+            s("end").and_then(|en| json(get_monthly_trend_impl(conn, &st, &en)))
+        }),
+
+        // Search
+        "search" => s("query").and_then(|q| {
+            opt_arg::<SearchFilters>(args, "filters")
+                .and_then(|f| json(search_impl(conn, &q, f.unwrap_or_default())))
+        }),
+
+        // Milestones
+        "get_milestones" => json(query_all_milestones(conn)),
+        "get_milestone_context" => json(get_milestone_context_impl(conn)),
+        "check_milestones" => {
+            arg(args, "context").and_then(|c| json(check_milestones_impl(conn, &c)))
+        }
+        "recompute_milestones" => json(recompute_milestones_impl(conn)),
+        "create_milestone" => {
+            arg(args, "milestone").and_then(|m| json(create_milestone_impl(conn, m)))
+        }
+        "update_milestone" => s("id").and_then(|i| {
+            arg(args, "milestone").and_then(|m| json(update_milestone_impl(conn, &i, m)))
+        }),
+        "delete_milestone" => s("id").and_then(|i| json(delete_milestone_impl(conn, &i))),
+
+        // Clean streaks
+        "get_clean_streaks" => json(query_clean_streaks(conn)),
+        "save_clean_streak" => {
+            arg(args, "streak").and_then(|st| json(save_clean_streak_impl(conn, st)))
+        }
+        "delete_clean_streak" => id().and_then(|i| json(delete_clean_streak_impl(conn, i))),
+
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::migrations::run_migrations;
+
+    const TOKEN: &str = "test-token";
+    const AUTH: Option<&str> = Some("Bearer test-token");
+
+    fn setup_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys=ON;").unwrap();
+        run_migrations(&mut conn).expect("Migrations should succeed");
+        conn
+    }
+
+    fn post(conn: &Connection, command: &str, body: &str) -> (u16, Value) {
+        handle_request(conn, TOKEN, "POST", &format!("/api/{}", command), AUTH, body)
+    }
+
+    #[test]
+    fn test_rejects_missing_or_wrong_token() {
+        let conn = setup_test_db();
+        let (status, _) = handle_request(&conn, TOKEN, "POST", "/api/get_milestones", None, "");
+        assert_eq!(status, 401);
+        let (status, _) = handle_request(
+            &conn,
+            TOKEN,
+            "POST",
+            "/api/get_milestones",
+            Some("Bearer test-tokeN"),
+            "",
+        );
+        assert_eq!(status, 401);
+    }
+
+    #[test]
+    fn test_unknown_command_and_method() {
+        let conn = setup_test_db();
+        assert_eq!(post(&conn, "import_data", "{}").0, 404, "data commands are not exposed");
+        let (status, _) =
+            handle_request(&conn, TOKEN, "GET", "/api/get_milestones", AUTH, "");
+        assert_eq!(status, 405);
+    }
+
+    #[test]
+    fn test_save_and_get_journal() {
+        let conn = setup_test_db();
+        let body = r#"{"entry": {"date": "2026-03-01", "mood": 4, "energy": 3,
+            "highlight": "", "gratitude": "", "reflection": "", "tomorrow_goal": ""}}"#;
+        let (status, saved) = post(&conn, "save_journal", body);
+        assert_eq!(status, 200);
+        assert_eq!(saved["mood"], 4);
+
+        let (status, fetched) = post(&conn, "get_journal", r#"{"date": "2026-03-01"}"#);
+        assert_eq!(status, 200);
+        assert_eq!(fetched["id"], saved["id"]);
+    }
+
+    #[test]
+    fn test_command_error_is_400() {
+        let conn = setup_test_db();
+        let body = r#"{"entry": {"date": "2026-03-01", "mood": 9, "energy": 3,
+            "highlight": "", "gratitude": "", "reflection": "", "tomorrow_goal": ""}}"#;
+        let (status, payload) = post(&conn, "save_journal", body);
+        assert_eq!(status, 400);
+        assert!(payload["error"].is_string());
+    }
+
+    #[test]
+    fn test_missing_argument_and_bad_json() {
+        let conn = setup_test_db();
+        let (status, payload) = post(&conn, "get_daily_logs", r#"{"start": "2026-03-01"}"#);
+        assert_eq!(status, 400);
+        assert_eq!(payload["error"], "Missing argument 'end'");
+        assert_eq!(post(&conn, "get_daily_logs", "{not json").0, 400);
+    }
+
+    #[test]
+    fn test_camel_case_arguments() {
+        let conn = setup_test_db();
+        let (status, history) = post(&conn, "get_status_history", r#"{"appId": 1}"#);
+        assert_eq!(status, 200);
+        assert!(history.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_with_and_without_filters() {
+        let conn = setup_test_db();
+        let entry = r#"{"entry": {"date": "2026-03-01", "mood": 4, "energy": 3,
+            "highlight": "Finished the parser", "gratitude": "", "reflection": "", "tomorrow_goal": ""}}"#;
+        assert_eq!(post(&conn, "save_journal", entry).0, 200);
+
+        let (status, hits) = post(&conn, "search", r#"{"query": "parser"}"#);
+        assert_eq!(status, 200);
+        assert_eq!(hits.as_array().unwrap().len(), 1);
+        assert_eq!(hits[0]["entity_type"], "journal");
+
+        let body = r#"{"query": "parser", "filters": {"entity_types": ["study_session"]}}"#;
+        let (status, hits) = post(&conn, "search", body);
+        assert_eq!(status, 200);
+        assert!(hits.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_token_is_persisted_and_rotated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = token_path(&dir.path().join("ltu.db"));
+        let first = load_or_create_token(&path, false).unwrap();
+        assert_eq!(first.len(), 64);
+        assert_eq!(load_or_create_token(&path, false).unwrap(), first);
+        assert_ne!(load_or_create_token(&path, true).unwrap(), first);
+    }
+}
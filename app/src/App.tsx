@@ -8,6 +8,7 @@ import Sidebar from './components/shared/Sidebar';
 import { useUIStore } from './stores/ui-store';
 import { useNarrowWindow } from './hooks/use-narrow-window';
 import { useDateChange } from './hooks/use-date-change';
+import { useMilestoneEvents } from './hooks/use-milestones';
 import DailyLogPage from './pages/DailyLogPage';
 import JournalPage from './pages/JournalPage';
 import AnalyticsPage from './pages/AnalyticsPage';
@@ -25,6 +26,7 @@ function AppLayout() {
   const isNarrow = useNarrowWindow();
 
   useDateChange();
+  useMilestoneEvents();
 
   // Auto-collapse when window goes narrow
   useEffect(() => {
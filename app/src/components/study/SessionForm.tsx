@@ -114,8 +114,9 @@ export default function SessionForm({
       show(isEditing ? 'Session updated' : 'Session saved', 'success');
       onClose();
     };
-    const onError = () => {
-      show('Failed to save session', 'error');
+    // Validation errors (overlaps, durations) come back as plain strings.
+    const onError = (err: unknown) => {
+      show(typeof err === 'string' ? err : 'Failed to save session', 'error');
     };
 
     if (isEditing) {
@@ -8,6 +8,7 @@ import {
   useSaveApplication,
   useArchiveApplication,
   useAddStatusChange,
+  useSaveInterview,
 } from '../use-applications';
 import { createWrapper, createTestQueryClient } from './test-utils';
 
@@ -146,3 +147,23 @@ describe('useAddStatusChange', () => {
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['status-history'] });
   });
 });
+
+describe('useSaveInterview', () => {
+  it('invalidates applications so the detail refetches', async () => {
+    const interview = { date: '2026-02-20', round: 'Phone screen' };
+    mockInvoke.mockResolvedValueOnce({ id: 1, application_id: 1, ...interview });
+
+    const queryClient = createTestQueryClient();
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useSaveInterview(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate({ appId: 1, interview });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('save_interview', { appId: 1, interview });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['applications'] });
+  });
+});
@@ -8,6 +8,9 @@ import {
   useUpdateRelapseEntry,
   useSaveUrgeEntry,
   useUpdateUrgeEntry,
+  useUrgeLinkSuggestions,
+  useLinkRelapseUrge,
+  useAddRecoveryAmendment,
 } from '../use-recovery';
 import { createWrapper, createTestQueryClient } from './test-utils';
 
@@ -32,6 +35,7 @@ describe('useRelapseEntries', () => {
     expect(mockInvoke).toHaveBeenCalledWith('get_relapse_entries', {
       start: '2026-02-01',
       end: '2026-02-28',
+      view: 'original',
     });
   });
 
@@ -55,6 +59,21 @@ describe('useUrgeEntries', () => {
     expect(mockInvoke).toHaveBeenCalledWith('get_urge_entries', {
       start: '2026-02-01',
       end: '2026-02-28',
+      view: 'original',
+    });
+  });
+
+  it('passes the amended view through', async () => {
+    mockInvoke.mockResolvedValueOnce([]);
+    const { result } = renderHook(
+      () => useUrgeEntries('2026-02-01', '2026-02-28', 'amended'),
+      { wrapper: createWrapper() },
+    );
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_urge_entries', {
+      start: '2026-02-01',
+      end: '2026-02-28',
+      view: 'amended',
     });
   });
 });
@@ -176,3 +195,69 @@ describe('useUpdateUrgeEntry', () => {
     expect(mockInvoke).toHaveBeenCalledWith('update_urge_entry', { id: 1, entry });
   });
 });
+
+describe('useUrgeLinkSuggestions', () => {
+  it('does not fetch without a relapse id', () => {
+    renderHook(() => useUrgeLinkSuggestions(0), { wrapper: createWrapper() });
+    expect(mockInvoke).not.toHaveBeenCalled();
+  });
+
+  it('calls invoke with the relapse id', async () => {
+    mockInvoke.mockResolvedValueOnce([]);
+
+    const { result } = renderHook(() => useUrgeLinkSuggestions(3), {
+      wrapper: createWrapper(),
+    });
+
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('suggest_urge_links', { relapseId: 3 });
+  });
+});
+
+describe('useLinkRelapseUrge', () => {
+  it('links the urge and invalidates relapses and suggestions', async () => {
+    mockInvoke.mockResolvedValueOnce({ id: 3, urge_entry_id: 7 });
+
+    const queryClient = createTestQueryClient();
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useLinkRelapseUrge(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate({ relapseId: 3, urgeId: 7 });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('link_relapse_urge', { relapseId: 3, urgeId: 7 });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['relapse-entries'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['urge-link-suggestions'] });
+  });
+});
+
+describe('useAddRecoveryAmendment', () => {
+  it('sends the amendment and invalidates entries and frequency', async () => {
+    const amendment = {
+      entity: 'relapse_entry' as const,
+      id: 4,
+      field: 'date',
+      newValue: '2026-02-20',
+      reason: 'Logged on the wrong day',
+    };
+    mockInvoke.mockResolvedValueOnce({ id: 1 });
+
+    const queryClient = createTestQueryClient();
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useAddRecoveryAmendment(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate(amendment);
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('add_recovery_amendment', amendment);
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['relapse-entries'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['urge-entries'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['recovery-frequency'] });
+  });
+});
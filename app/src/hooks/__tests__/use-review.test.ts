@@ -1,7 +1,15 @@
 import { renderHook, waitFor } from '@testing-library/react';
 import { describe, it, expect, vi, beforeEach } from 'vitest';
 import { invoke } from '@tauri-apps/api/core';
-import { useWeeklyReview, useWeeklyStats, useSaveWeeklyReview } from '../use-review';
+import {
+  useWeeklyReview,
+  useWeeklyStats,
+  useSaveWeeklyReview,
+  usePeriodicReview,
+  usePeriodicStats,
+  useReviewPrompts,
+  useSavePeriodicReview,
+} from '../use-review';
 import { createWrapper, createTestQueryClient } from './test-utils';
 
 vi.mock('@tauri-apps/api/core', () => ({
@@ -80,3 +88,93 @@ describe('useSaveWeeklyReview', () => {
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['weekly-review'] });
   });
 });
+
+describe('usePeriodicReview', () => {
+  it('calls invoke with period and periodStart', async () => {
+    mockInvoke.mockResolvedValueOnce(null);
+    const { result } = renderHook(() => usePeriodicReview('quarter', '2026-04-01'), {
+      wrapper: createWrapper(),
+    });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_periodic_review', {
+      period: 'quarter',
+      periodStart: '2026-04-01',
+    });
+  });
+
+  it('does not fetch with empty periodStart', () => {
+    const { result } = renderHook(() => usePeriodicReview('month', ''), {
+      wrapper: createWrapper(),
+    });
+    expect(result.current.fetchStatus).toBe('idle');
+  });
+});
+
+describe('usePeriodicStats', () => {
+  it('calls compute_periodic_stats', async () => {
+    mockInvoke.mockResolvedValueOnce({
+      period: 'month',
+      period_start: '2026-03-01',
+      period_end: '2026-03-31',
+      avg_score: 0.7,
+      days_tracked: 28,
+      best_day_score: 0.95,
+      worst_day_score: 0.3,
+      total_study_hours: 40,
+      applications_sent: 6,
+      relapses: 1,
+      urges_resisted: 5,
+      current_streak: 4,
+      weeks_reviewed: 2,
+      best_week_start: '2026-03-09',
+      best_week_score: 0.8,
+      worst_week_start: '2026-03-02',
+      worst_week_score: 0.6,
+      avg_weekly_score: 0.7,
+      weeks: [],
+    });
+    const { result } = renderHook(() => usePeriodicStats('month', '2026-03-01'), {
+      wrapper: createWrapper(),
+    });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('compute_periodic_stats', {
+      period: 'month',
+      periodStart: '2026-03-01',
+    });
+  });
+});
+
+describe('useReviewPrompts', () => {
+  it('calls get_review_prompts with period', async () => {
+    mockInvoke.mockResolvedValueOnce([{ key: 'proudest', question: 'Proudest?' }]);
+    const { result } = renderHook(() => useReviewPrompts('year'), {
+      wrapper: createWrapper(),
+    });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_review_prompts', { period: 'year' });
+  });
+});
+
+describe('useSavePeriodicReview', () => {
+  it('invalidates periodic-review queries', async () => {
+    const review = {
+      period: 'month' as const,
+      period_start: '2026-03-01',
+      reflections: { biggest_win: 'Shipped the project' },
+    };
+    mockInvoke.mockResolvedValueOnce({ id: 1, ...review, period_end: '2026-03-31' });
+
+    const queryClient = createTestQueryClient();
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useSavePeriodicReview(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate(review);
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('save_periodic_review', { review });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['periodic-review'] });
+  });
+});
@@ -7,6 +7,14 @@ import {
   useSaveStudySession,
   useUpdateStudySession,
   useDeleteStudySession,
+  useStudyTimer,
+  useStartStudyTimer,
+  usePauseStudyTimer,
+  useStopStudyTimer,
+  useStudyGoalProgress,
+  useSaveStudyGoal,
+  useDeleteStudyGoal,
+  useDueReviews,
 } from '../use-study';
 import { createWrapper, createTestQueryClient } from './test-utils';
 
@@ -76,6 +84,8 @@ describe('useSaveStudySession', () => {
     expect(mockInvoke).toHaveBeenCalledWith('save_study_session', { session });
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-sessions'] });
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-summary'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-goals'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-topics'] });
   });
 });
 
@@ -125,3 +135,161 @@ describe('useDeleteStudySession', () => {
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-summary'] });
   });
 });
+
+const runningTimer = {
+  id: 1,
+  subject: 'Math',
+  study_type: 'Self-Study',
+  location: 'Library',
+  started_at: '2026-02-18T09:00:00',
+  paused_at: null,
+  paused_seconds: 0,
+  pause_count: 0,
+  is_paused: false,
+  elapsed_seconds: 0,
+};
+
+describe('useStudyTimer', () => {
+  it('calls get_study_timer', async () => {
+    mockInvoke.mockResolvedValueOnce(null);
+    const { result } = renderHook(() => useStudyTimer(), { wrapper: createWrapper() });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_study_timer');
+    expect(result.current.data).toBeNull();
+  });
+});
+
+describe('useStartStudyTimer', () => {
+  it('sends camelCase args and caches the returned timer', async () => {
+    mockInvoke.mockResolvedValueOnce(runningTimer);
+    const queryClient = createTestQueryClient();
+
+    const { result } = renderHook(() => useStartStudyTimer(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate({ subject: 'Math', studyType: 'Self-Study', location: 'Library' });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('start_study_timer', {
+      subject: 'Math',
+      studyType: 'Self-Study',
+      location: 'Library',
+    });
+    expect(queryClient.getQueryData(['study-timer'])).toEqual(runningTimer);
+  });
+});
+
+describe('usePauseStudyTimer', () => {
+  it('calls pause_study_timer and caches the paused timer', async () => {
+    const paused = { ...runningTimer, paused_at: '2026-02-18T09:30:00', is_paused: true };
+    mockInvoke.mockResolvedValueOnce(paused);
+    const queryClient = createTestQueryClient();
+
+    const { result } = renderHook(() => usePauseStudyTimer(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate();
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('pause_study_timer');
+    expect(queryClient.getQueryData(['study-timer'])).toEqual(paused);
+  });
+});
+
+describe('useStopStudyTimer', () => {
+  it('clears the timer and invalidates study queries', async () => {
+    const details = { focus_score: 4, topic: 'Calculus', resources: '', notes: '' };
+    mockInvoke.mockResolvedValueOnce({ id: 7 });
+    const queryClient = createTestQueryClient();
+    queryClient.setQueryData(['study-timer'], runningTimer);
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useStopStudyTimer(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate(details);
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('stop_study_timer', { details });
+    expect(queryClient.getQueryData(['study-timer'])).toBeNull();
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-sessions'] });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-summary'] });
+  });
+});
+
+describe('useStudyGoalProgress', () => {
+  it('sends weekStart in camelCase', async () => {
+    mockInvoke.mockResolvedValueOnce({
+      week_start: '2026-02-09',
+      week_end: '2026-02-15',
+      goals: [],
+    });
+    const { result } = renderHook(() => useStudyGoalProgress('2026-02-09'), {
+      wrapper: createWrapper(),
+    });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_study_goal_progress', {
+      weekStart: '2026-02-09',
+    });
+  });
+
+  it('is disabled without a week', () => {
+    renderHook(() => useStudyGoalProgress(''), { wrapper: createWrapper() });
+    expect(mockInvoke).not.toHaveBeenCalled();
+  });
+});
+
+describe('useSaveStudyGoal', () => {
+  it('saves the goal and invalidates goal queries', async () => {
+    const goal = {
+      subject: 'Math',
+      period: 'week' as const,
+      target_hours: 10,
+      start_date: '2026-02-02',
+      end_date: null,
+    };
+    mockInvoke.mockResolvedValueOnce({ id: 1, ...goal, created_at: '', last_modified: '' });
+    const queryClient = createTestQueryClient();
+    const invalidateSpy = vi.spyOn(queryClient, 'invalidateQueries');
+
+    const { result } = renderHook(() => useSaveStudyGoal(), {
+      wrapper: createWrapper(queryClient),
+    });
+
+    result.current.mutate(goal);
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+
+    expect(mockInvoke).toHaveBeenCalledWith('save_study_goal', { goal });
+    expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: ['study-goals'] });
+  });
+});
+
+describe('useDeleteStudyGoal', () => {
+  it('calls invoke with id', async () => {
+    mockInvoke.mockResolvedValueOnce(undefined);
+    const { result } = renderHook(() => useDeleteStudyGoal(), { wrapper: createWrapper() });
+
+    result.current.mutate(3);
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('delete_study_goal', { id: 3 });
+  });
+});
+
+describe('useDueReviews', () => {
+  it('calls get_due_reviews with the date', async () => {
+    mockInvoke.mockResolvedValueOnce([]);
+    const { result } = renderHook(() => useDueReviews('2026-03-10'), {
+      wrapper: createWrapper(),
+    });
+    await waitFor(() => expect(result.current.isSuccess).toBe(true));
+    expect(mockInvoke).toHaveBeenCalledWith('get_due_reviews', { date: '2026-03-10' });
+  });
+
+  it('is disabled without a date', () => {
+    renderHook(() => useDueReviews(''), { wrapper: createWrapper() });
+    expect(mockInvoke).not.toHaveBeenCalled();
+  });
+});
@@ -1,7 +1,21 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
-import type { Application, StatusChange } from '../types/models';
-import type { ApplicationInput, AppFilters, StatusChangeInput } from '../types/commands';
+import type {
+  Application,
+  ApplicationDetail,
+  Contact,
+  Interview,
+  StatusChange,
+  StatusChangeAudit,
+} from '../types/models';
+import type {
+  ApplicationInput,
+  AppFilters,
+  ContactInput,
+  FollowUps,
+  InterviewInput,
+  StatusChangeInput,
+} from '../types/commands';
 import { QUERY_KEYS, INVALIDATION_PREFIXES } from '../lib/query-keys';
 import { useMilestoneChecker } from './use-milestones';
 
@@ -21,7 +35,7 @@ export function useApplications(filters?: AppFilters) {
 export function useApplication(id: number) {
   return useQuery({
     queryKey: QUERY_KEYS.application(id),
-    queryFn: () => invoke<Application | null>('get_application', { id }),
+    queryFn: () => invoke<ApplicationDetail | null>('get_application', { id }),
     enabled: id > 0,
   });
 }
@@ -34,6 +48,21 @@ export function useStatusHistory(appId: number) {
   });
 }
 
+export function useFollowUps(asOf: string) {
+  return useQuery({
+    queryKey: QUERY_KEYS.followUps(asOf),
+    queryFn: () => invoke<FollowUps>('get_follow_ups', { asOf }),
+  });
+}
+
+export function useStatusChangeAudit(appId: number) {
+  return useQuery({
+    queryKey: QUERY_KEYS.statusChangeAudit(appId),
+    queryFn: () => invoke<StatusChangeAudit[]>('get_status_change_audit', { appId }),
+    enabled: appId > 0,
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Mutations
 // ---------------------------------------------------------------------------
@@ -44,6 +73,7 @@ function useInvalidateApplications() {
   return () => {
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applicationPipeline });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.followUps });
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
     // Phase 16: check milestones after save (RD7 post-save side effect)
     void checkMilestones();
@@ -90,7 +120,149 @@ export function useAddStatusChange() {
       void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
       void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applicationPipeline });
       void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.statusHistory });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.followUps });
       void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
     },
   });
 }
+
+function useInvalidateStatusHistory() {
+  const queryClient = useQueryClient();
+  return () => {
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applicationPipeline });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.statusHistory });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.statusChangeAudit });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.followUps });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
+  };
+}
+
+/** Correct a recorded status change. current_status is resynced server-side. */
+export function useUpdateStatusChange() {
+  const invalidate = useInvalidateStatusHistory();
+
+  return useMutation({
+    mutationFn: ({ id, change }: { id: number; change: StatusChangeInput }) =>
+      invoke<StatusChange>('update_status_change', { id, change }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useDeleteStatusChange() {
+  const invalidate = useInvalidateStatusHistory();
+
+  return useMutation({
+    mutationFn: (id: number) =>
+      invoke<void>('delete_status_change', { id }),
+    onSuccess: invalidate,
+  });
+}
+
+/**
+ * Reopen a rejected or withdrawn application. It returns to the last
+ * pipeline stage it reached.
+ */
+export function useReopenApplication() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, date, notes }: { id: number; date: string; notes?: string }) =>
+      invoke<StatusChange>('reopen_application', { id, date, notes: notes ?? null }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applicationPipeline });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.statusHistory });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.followUps });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
+    },
+  });
+}
+
+/** Move applications with no reply for `days` (default 30) to no_response. */
+export function useMarkStaleApplications() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ asOf, days }: { asOf: string; days?: number }) =>
+      invoke<Application[]>('mark_stale_applications', { asOf, days: days ?? null }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applicationPipeline });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.statusHistory });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.followUps });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
+    },
+  });
+}
+
+// ---------------------------------------------------------------------------
+// Contacts & Interviews — returned with the application by get_application
+// ---------------------------------------------------------------------------
+
+function useInvalidateApplicationDetail() {
+  const queryClient = useQueryClient();
+  return () => {
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.applications });
+  };
+}
+
+export function useSaveContact() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: ({ appId, contact }: { appId: number; contact: ContactInput }) =>
+      invoke<Contact>('save_contact', { appId, contact }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useUpdateContact() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: ({ id, contact }: { id: number; contact: ContactInput }) =>
+      invoke<Contact>('update_contact', { id, contact }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useDeleteContact() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: (id: number) =>
+      invoke<void>('delete_contact', { id }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useSaveInterview() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: ({ appId, interview }: { appId: number; interview: InterviewInput }) =>
+      invoke<Interview>('save_interview', { appId, interview }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useUpdateInterview() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: ({ id, interview }: { id: number; interview: InterviewInput }) =>
+      invoke<Interview>('update_interview', { id, interview }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useDeleteInterview() {
+  const invalidate = useInvalidateApplicationDetail();
+
+  return useMutation({
+    mutationFn: (id: number) =>
+      invoke<void>('delete_interview', { id }),
+    onSuccess: invalidate,
+  });
+}
@@ -1,8 +1,8 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
-import type { AppConfig, HabitConfig } from '../types/models';
-import type { AppConfigInput, HabitConfigSaveInput } from '../types/commands';
-import { QUERY_KEYS } from '../lib/query-keys';
+import type { AppConfig, CleanStreak, HabitConfig } from '../types/models';
+import type { AppConfigInput, CleanStreakInput, HabitConfigSaveInput } from '../types/commands';
+import { INVALIDATION_PREFIXES, QUERY_KEYS } from '../lib/query-keys';
 
 // ---------------------------------------------------------------------------
 // Queries
@@ -22,6 +22,13 @@ export function useHabitConfigs() {
   });
 }
 
+export function useCleanStreaks() {
+  return useQuery({
+    queryKey: QUERY_KEYS.cleanStreaks,
+    queryFn: () => invoke<CleanStreak[]>('get_clean_streaks'),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Mutations
 // ---------------------------------------------------------------------------
@@ -73,3 +80,29 @@ export function useReorderHabits() {
     },
   });
 }
+
+export function useSaveCleanStreak() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (streak: CleanStreakInput) =>
+      invoke<CleanStreak>('save_clean_streak', { streak }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: QUERY_KEYS.cleanStreaks });
+      // A rename is carried into milestone rules
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.milestones });
+    },
+  });
+}
+
+export function useDeleteCleanStreak() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (id: number) =>
+      invoke<void>('delete_clean_streak', { id }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: QUERY_KEYS.cleanStreaks });
+    },
+  });
+}
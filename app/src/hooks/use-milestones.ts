@@ -1,8 +1,9 @@
-import { useCallback } from 'react';
+import { useCallback, useEffect } from 'react';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import type { Milestone } from '../types/models';
-import type { MilestoneContext } from '../types/commands';
+import type { MilestoneContext, MilestoneInput, MilestoneRecompute } from '../types/commands';
 import { QUERY_KEYS, INVALIDATION_PREFIXES } from '../lib/query-keys';
 import { useToast } from '../components/shared/Toast';
 
@@ -33,10 +34,127 @@ export function useCheckMilestones() {
   });
 }
 
+export function useCreateMilestone() {
+  const queryClient = useQueryClient();
+  const checkMilestones = useMilestoneChecker();
+
+  return useMutation({
+    mutationFn: (milestone: MilestoneInput) =>
+      invoke<Milestone>('create_milestone', { milestone }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.milestones });
+      // A new rule may already be met by existing history
+      void checkMilestones();
+    },
+  });
+}
+
+export function useUpdateMilestone() {
+  const queryClient = useQueryClient();
+  const checkMilestones = useMilestoneChecker();
+
+  return useMutation({
+    mutationFn: ({ id, milestone }: { id: string; milestone: MilestoneInput }) =>
+      invoke<Milestone>('update_milestone', { id, milestone }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.milestones });
+      void checkMilestones();
+    },
+  });
+}
+
+export function useDeleteMilestone() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (id: string) => invoke<void>('delete_milestone', { id }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.milestones });
+    },
+  });
+}
+
+/**
+ * Replay history to give milestones their true achievement dates. Newly
+ * earned milestones arrive through the milestones-achieved event.
+ */
+export function useRecomputeMilestones() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: () => invoke<MilestoneRecompute>('recompute_milestones'),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.milestones });
+    },
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Post-Save Milestone Checker (RD7: post-save side effect)
 // ---------------------------------------------------------------------------
 
+/**
+ * Emitted by save_daily_log, save_study_session, save_application and
+ * import_data with the milestones they newly achieved.
+ * Matches Rust MILESTONES_ACHIEVED_EVENT.
+ */
+const MILESTONES_ACHIEVED_EVENT = 'milestones-achieved';
+
+/**
+ * Returns a stable function that toasts each newly achieved milestone and
+ * removes the milestones query cache.
+ */
+function useAnnounceMilestones(): (newlyAchieved: Milestone[]) => void {
+  const { show } = useToast();
+  const queryClient = useQueryClient();
+
+  return useCallback(
+    (newlyAchieved: Milestone[]) => {
+      if (newlyAchieved.length === 0) return;
+
+      // removeQueries (not invalidateQueries) because refetchOnMount: false
+      // in query-client.ts would prevent stale data from refetching on page mount.
+      // Removing the cache forces a fresh fetch when the Milestones page opens.
+      queryClient.removeQueries({
+        queryKey: INVALIDATION_PREFIXES.milestones,
+      });
+
+      for (const milestone of newlyAchieved) {
+        show(`Milestone unlocked: ${milestone.name}`, 'milestone', milestone.emoji);
+      }
+    },
+    [show, queryClient],
+  );
+}
+
+/**
+ * Subscribes to the backend's `milestones-achieved` event for the lifetime of
+ * the component. Saves award milestones inside their own transaction, so this
+ * is where those achievements surface in the UI. Mount once, in the layout.
+ */
+export function useMilestoneEvents(): void {
+  const announce = useAnnounceMilestones();
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+
+    listen<Milestone[]>(MILESTONES_ACHIEVED_EVENT, (event) => announce(event.payload))
+      .then((fn) => {
+        if (disposed) fn();
+        else unlisten = fn;
+      })
+      .catch(() => {
+        // Non-fatal — no event bus outside the Tauri webview (e.g. tests)
+      });
+
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, [announce]);
+}
+
 /**
  * Returns a stable `checkAndToast` function that:
  * 1. Fetches the current MilestoneContext from the DB
@@ -46,10 +164,13 @@ export function useCheckMilestones() {
  *
  * Milestone check failure is non-fatal and silently caught
  * so it never disrupts the primary save flow.
+ *
+ * Daily log, study session and application saves already award milestones
+ * in the backend (see useMilestoneEvents); for those the check is a cheap
+ * no-op safety net.
  */
 export function useMilestoneChecker(): () => Promise<void> {
-  const { show } = useToast();
-  const queryClient = useQueryClient();
+  const announce = useAnnounceMilestones();
 
   return useCallback(async () => {
     try {
@@ -57,21 +178,9 @@ export function useMilestoneChecker(): () => Promise<void> {
       const newlyAchieved = await invoke<Milestone[]>('check_milestones', {
         context,
       });
-
-      if (newlyAchieved.length > 0) {
-        // removeQueries (not invalidateQueries) because refetchOnMount: false
-        // in query-client.ts would prevent stale data from refetching on page mount.
-        // Removing the cache forces a fresh fetch when the Milestones page opens.
-        queryClient.removeQueries({
-          queryKey: INVALIDATION_PREFIXES.milestones,
-        });
-
-        for (const milestone of newlyAchieved) {
-          show(`Milestone unlocked: ${milestone.name}`, 'milestone', milestone.emoji);
-        }
-      }
+      announce(newlyAchieved);
     } catch {
       // Non-fatal — milestone check failure must not disrupt the save flow
     }
-  }, [show, queryClient]);
+  }, [announce]);
 }
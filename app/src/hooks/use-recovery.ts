@@ -1,29 +1,65 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
-import type { RelapseEntry, UrgeEntry } from '../types/models';
-import type { RelapseEntryInput, UrgeEntryInput } from '../types/commands';
+import type { RecoveryView } from '../types/enums';
+import type {
+  AmendedEntry,
+  RecoveryAmendment,
+  RecoveryEntity,
+  RelapseEntry,
+  UrgeEntry,
+} from '../types/models';
+import type {
+  RelapseEntryInput,
+  UrgeEntryInput,
+  UrgeLinkSuggestion,
+} from '../types/commands';
 import { QUERY_KEYS, INVALIDATION_PREFIXES } from '../lib/query-keys';
 
 // ---------------------------------------------------------------------------
 // Queries
 // ---------------------------------------------------------------------------
 
-export function useRelapseEntries(start: string, end: string) {
+export function useRelapseEntries(start: string, end: string, view: RecoveryView = 'original') {
   return useQuery({
-    queryKey: QUERY_KEYS.relapseEntries(start, end),
-    queryFn: () => invoke<RelapseEntry[]>('get_relapse_entries', { start, end }),
+    queryKey: QUERY_KEYS.relapseEntries(start, end, view),
+    queryFn: () => invoke<RelapseEntry[]>('get_relapse_entries', { start, end, view }),
     enabled: start.length > 0 && end.length > 0,
   });
 }
 
-export function useUrgeEntries(start: string, end: string) {
+export function useUrgeEntries(start: string, end: string, view: RecoveryView = 'original') {
   return useQuery({
-    queryKey: QUERY_KEYS.urgeEntries(start, end),
-    queryFn: () => invoke<UrgeEntry[]>('get_urge_entries', { start, end }),
+    queryKey: QUERY_KEYS.urgeEntries(start, end, view),
+    queryFn: () => invoke<UrgeEntry[]>('get_urge_entries', { start, end, view }),
     enabled: start.length > 0 && end.length > 0,
   });
 }
 
+export function useAmendedRelapseEntry(id: number) {
+  return useQuery({
+    queryKey: QUERY_KEYS.amendedRelapseEntry(id),
+    queryFn: () =>
+      invoke<AmendedEntry<RelapseEntry> | null>('get_amended_relapse_entry', { id }),
+    enabled: id > 0,
+  });
+}
+
+export function useAmendedUrgeEntry(id: number) {
+  return useQuery({
+    queryKey: QUERY_KEYS.amendedUrgeEntry(id),
+    queryFn: () => invoke<AmendedEntry<UrgeEntry> | null>('get_amended_urge_entry', { id }),
+    enabled: id > 0,
+  });
+}
+
+export function useUrgeLinkSuggestions(relapseId: number) {
+  return useQuery({
+    queryKey: QUERY_KEYS.urgeLinkSuggestions(relapseId),
+    queryFn: () => invoke<UrgeLinkSuggestion[]>('suggest_urge_links', { relapseId }),
+    enabled: relapseId > 0,
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Mutations
 // ---------------------------------------------------------------------------
@@ -83,3 +119,35 @@ export function useUpdateUrgeEntry() {
     },
   });
 }
+
+export function useLinkRelapseUrge() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ relapseId, urgeId }: { relapseId: number; urgeId: number | null }) =>
+      invoke<RelapseEntry>('link_relapse_urge', { relapseId, urgeId }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.relapseEntries });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.urgeLinkSuggestions });
+    },
+  });
+}
+
+export function useAddRecoveryAmendment() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (amendment: {
+      entity: RecoveryEntity;
+      id: number;
+      field: string;
+      newValue: string;
+      reason: string;
+    }) => invoke<RecoveryAmendment>('add_recovery_amendment', amendment),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.relapseEntries });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.urgeEntries });
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.recoveryFrequency });
+    },
+  });
+}
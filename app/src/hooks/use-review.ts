@@ -1,7 +1,13 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
-import type { WeeklyReview } from '../types/models';
-import type { WeeklyReviewInput, WeeklyStats } from '../types/commands';
+import type { PeriodicReview, ReviewPeriod, WeeklyReview } from '../types/models';
+import type {
+  PeriodicReviewInput,
+  PeriodicStats,
+  ReviewPrompt,
+  WeeklyReviewInput,
+  WeeklyStats,
+} from '../types/commands';
 import { QUERY_KEYS, INVALIDATION_PREFIXES } from '../lib/query-keys';
 import { useMilestoneChecker } from './use-milestones';
 
@@ -25,6 +31,30 @@ export function useWeeklyStats(weekStart: string) {
   });
 }
 
+export function usePeriodicReview(period: ReviewPeriod, periodStart: string) {
+  return useQuery({
+    queryKey: QUERY_KEYS.periodicReview(period, periodStart),
+    queryFn: () =>
+      invoke<PeriodicReview | null>('get_periodic_review', { period, periodStart }),
+    enabled: periodStart.length > 0,
+  });
+}
+
+export function usePeriodicStats(period: ReviewPeriod, periodStart: string) {
+  return useQuery({
+    queryKey: QUERY_KEYS.periodicStats(period, periodStart),
+    queryFn: () => invoke<PeriodicStats>('compute_periodic_stats', { period, periodStart }),
+    enabled: periodStart.length > 0,
+  });
+}
+
+export function useReviewPrompts(period: ReviewPeriod) {
+  return useQuery({
+    queryKey: QUERY_KEYS.reviewPrompts(period),
+    queryFn: () => invoke<ReviewPrompt[]>('get_review_prompts', { period }),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Mutations
 // ---------------------------------------------------------------------------
@@ -43,3 +73,18 @@ export function useSaveWeeklyReview() {
     },
   });
 }
+
+export function useSavePeriodicReview() {
+  const queryClient = useQueryClient();
+  const checkMilestones = useMilestoneChecker();
+
+  return useMutation({
+    mutationFn: (review: PeriodicReviewInput) =>
+      invoke<PeriodicReview>('save_periodic_review', { review }),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.periodicReview });
+      // Phase 16: check milestones after save (RD7 post-save side effect)
+      void checkMilestones();
+    },
+  });
+}
@@ -1,7 +1,13 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/core';
-import type { StudySession } from '../types/models';
-import type { StudySessionInput } from '../types/commands';
+import type { StudyGoal, StudySession, StudyTimer, StudyTopic } from '../types/models';
+import type {
+  DueReview,
+  StudyGoalInput,
+  StudyGoalWeekProgress,
+  StudySessionInput,
+  StudyTimerStopInput,
+} from '../types/commands';
 import { QUERY_KEYS, INVALIDATION_PREFIXES } from '../lib/query-keys';
 import { useMilestoneChecker } from './use-milestones';
 
@@ -25,6 +31,14 @@ export function useStudySessionsRange(start: string, end: string) {
   });
 }
 
+/** The running or paused timer, or null. Restores the timer after a restart. */
+export function useStudyTimer() {
+  return useQuery({
+    queryKey: QUERY_KEYS.studyTimer,
+    queryFn: () => invoke<StudyTimer | null>('get_study_timer'),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Mutations
 // ---------------------------------------------------------------------------
@@ -36,6 +50,8 @@ function useInvalidateStudy() {
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.studySessions });
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.studySummary });
     void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.weeklyStats });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.studyGoals });
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.studyTopics });
     // Phase 16: check milestones after save (RD7 post-save side effect)
     void checkMilestones();
   };
@@ -70,3 +86,140 @@ export function useDeleteStudySession() {
     onSuccess: invalidate,
   });
 }
+
+// ---------------------------------------------------------------------------
+// Timer
+// ---------------------------------------------------------------------------
+
+function useSetStudyTimer() {
+  const queryClient = useQueryClient();
+  return (timer: StudyTimer | null) => {
+    queryClient.setQueryData(QUERY_KEYS.studyTimer, timer);
+  };
+}
+
+export function useStartStudyTimer() {
+  const setTimer = useSetStudyTimer();
+
+  return useMutation({
+    mutationFn: (args: { subject: string; studyType: string; location: string }) =>
+      invoke<StudyTimer>('start_study_timer', args),
+    onSuccess: setTimer,
+  });
+}
+
+export function usePauseStudyTimer() {
+  const setTimer = useSetStudyTimer();
+
+  return useMutation({
+    mutationFn: () => invoke<StudyTimer>('pause_study_timer'),
+    onSuccess: setTimer,
+  });
+}
+
+export function useResumeStudyTimer() {
+  const setTimer = useSetStudyTimer();
+
+  return useMutation({
+    mutationFn: () => invoke<StudyTimer>('resume_study_timer'),
+    onSuccess: setTimer,
+  });
+}
+
+/** Stops the timer and saves it as a study session. */
+export function useStopStudyTimer() {
+  const setTimer = useSetStudyTimer();
+  const invalidate = useInvalidateStudy();
+
+  return useMutation({
+    mutationFn: (details: StudyTimerStopInput) =>
+      invoke<StudySession>('stop_study_timer', { details }),
+    onSuccess: () => {
+      setTimer(null);
+      invalidate();
+    },
+  });
+}
+
+export function useCancelStudyTimer() {
+  const setTimer = useSetStudyTimer();
+
+  return useMutation({
+    mutationFn: () => invoke<void>('cancel_study_timer'),
+    onSuccess: () => setTimer(null),
+  });
+}
+
+// ---------------------------------------------------------------------------
+// Goals
+// ---------------------------------------------------------------------------
+
+export function useStudyGoals() {
+  return useQuery({
+    queryKey: QUERY_KEYS.studyGoals,
+    queryFn: () => invoke<StudyGoal[]>('get_study_goals'),
+  });
+}
+
+export function useStudyGoalProgress(weekStart: string) {
+  return useQuery({
+    queryKey: QUERY_KEYS.studyGoalProgress(weekStart),
+    queryFn: () => invoke<StudyGoalWeekProgress>('get_study_goal_progress', { weekStart }),
+    enabled: weekStart.length > 0,
+  });
+}
+
+function useInvalidateStudyGoals() {
+  const queryClient = useQueryClient();
+  return () => {
+    void queryClient.invalidateQueries({ queryKey: INVALIDATION_PREFIXES.studyGoals });
+  };
+}
+
+export function useSaveStudyGoal() {
+  const invalidate = useInvalidateStudyGoals();
+
+  return useMutation({
+    mutationFn: (goal: StudyGoalInput) => invoke<StudyGoal>('save_study_goal', { goal }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useUpdateStudyGoal() {
+  const invalidate = useInvalidateStudyGoals();
+
+  return useMutation({
+    mutationFn: ({ id, goal }: { id: number; goal: StudyGoalInput }) =>
+      invoke<StudyGoal>('update_study_goal', { id, goal }),
+    onSuccess: invalidate,
+  });
+}
+
+export function useDeleteStudyGoal() {
+  const invalidate = useInvalidateStudyGoals();
+
+  return useMutation({
+    mutationFn: (id: number) => invoke<void>('delete_study_goal', { id }),
+    onSuccess: invalidate,
+  });
+}
+
+// ---------------------------------------------------------------------------
+// Topics
+// ---------------------------------------------------------------------------
+
+export function useStudyTopics() {
+  return useQuery({
+    queryKey: QUERY_KEYS.studyTopics,
+    queryFn: () => invoke<StudyTopic[]>('get_study_topics'),
+  });
+}
+
+/** Topics due for review on `date`, most overdue first. */
+export function useDueReviews(date: string) {
+  return useQuery({
+    queryKey: QUERY_KEYS.dueReviews(date),
+    queryFn: () => invoke<DueReview[]>('get_due_reviews', { date }),
+    enabled: date.length > 0,
+  });
+}
@@ -1,3 +1,5 @@
+import type { RecoveryView } from "../types/enums";
+
 export const QUERY_KEYS = {
   // Daily Log
   dailyLog: (date: string) => ["daily-log", date] as const,
@@ -29,18 +31,30 @@ export const QUERY_KEYS = {
     ["study-sessions", "range", start, end] as const,
   studySummary: (start: string, end: string) =>
     ["study-summary", start, end] as const,
+  studyTimer: ["study-timer"] as const,
+  studyGoals: ["study-goals"] as const,
+  studyGoalProgress: (weekStart: string) =>
+    ["study-goals", "progress", weekStart] as const,
+  studyTopics: ["study-topics"] as const,
+  dueReviews: (date: string) => ["study-topics", "due", date] as const,
 
   // Applications
   applications: ["applications"] as const,
   application: (id: number) => ["applications", id] as const,
   statusHistory: (appId: number) => ["status-history", appId] as const,
+  statusChangeAudit: (appId: number) => ["status-change-audit", appId] as const,
   applicationPipeline: ["application-pipeline"] as const,
+  followUps: (asOf: string) => ["follow-ups", asOf] as const,
 
   // Recovery
-  relapseEntries: (start: string, end: string) =>
-    ["relapse-entries", start, end] as const,
-  urgeEntries: (start: string, end: string) =>
-    ["urge-entries", start, end] as const,
+  relapseEntries: (start: string, end: string, view: RecoveryView = "original") =>
+    ["relapse-entries", start, end, view] as const,
+  urgeEntries: (start: string, end: string, view: RecoveryView = "original") =>
+    ["urge-entries", start, end, view] as const,
+  amendedRelapseEntry: (id: number) => ["relapse-entries", "amended", id] as const,
+  amendedUrgeEntry: (id: number) => ["urge-entries", "amended", id] as const,
+  urgeLinkSuggestions: (relapseId: number) =>
+    ["urge-link-suggestions", relapseId] as const,
   recoveryFrequency: (start: string, end: string) =>
     ["recovery-frequency", start, end] as const,
 
@@ -48,9 +62,17 @@ export const QUERY_KEYS = {
   weeklyReview: (weekStart: string) => ["weekly-review", weekStart] as const,
   weeklyStats: (weekStart: string) => ["weekly-stats", weekStart] as const,
 
+  // Periodic Review
+  periodicReview: (period: string, periodStart: string) =>
+    ["periodic-review", period, periodStart] as const,
+  periodicStats: (period: string, periodStart: string) =>
+    ["periodic-stats", period, periodStart] as const,
+  reviewPrompts: (period: string) => ["review-prompts", period] as const,
+
   // Config & Settings
   config: ["config"] as const,
   habitConfigs: ["habit-configs"] as const,
+  cleanStreaks: ["clean-streaks"] as const,
 
   // Milestones
   milestones: ["milestones"] as const,
@@ -77,13 +99,21 @@ export const INVALIDATION_PREFIXES = {
   correlationData: ["correlation-data"] as const,
   studySessions: ["study-sessions"] as const,
   studySummary: ["study-summary"] as const,
+  studyTimer: ["study-timer"] as const,
+  studyGoals: ["study-goals"] as const,
+  studyTopics: ["study-topics"] as const,
   applications: ["applications"] as const,
   statusHistory: ["status-history"] as const,
+  statusChangeAudit: ["status-change-audit"] as const,
   applicationPipeline: ["application-pipeline"] as const,
+  followUps: ["follow-ups"] as const,
   relapseEntries: ["relapse-entries"] as const,
   urgeEntries: ["urge-entries"] as const,
+  urgeLinkSuggestions: ["urge-link-suggestions"] as const,
   recoveryFrequency: ["recovery-frequency"] as const,
   weeklyReview: ["weekly-review"] as const,
   weeklyStats: ["weekly-stats"] as const,
+  periodicReview: ["periodic-review"] as const,
+  periodicStats: ["periodic-stats"] as const,
   milestones: ["milestones"] as const,
 } as const;
@@ -3,6 +3,18 @@
 // ---------------------------------------------------------------------------
 
 import type { CorrelationWindow } from './enums';
+import type {
+  ContactRole,
+  InterviewOutcome,
+  Milestone,
+  MilestoneCategory,
+  MilestoneRule,
+  ReviewPeriod,
+  StudyGoal,
+  StudyGoalPeriod,
+  StudyTopic,
+  UrgeEntry,
+} from './models';
 
 // ---------------------------------------------------------------------------
 // Daily Log
@@ -77,6 +89,62 @@ export interface StudySessionInput {
   notes: string;
 }
 
+/** Input for stop_study_timer. Matches Rust StudyTimerStopInput. */
+export interface StudyTimerStopInput {
+  focus_score: number;
+  topic: string;
+  resources: string;
+  notes: string;
+}
+
+/** Input for save_study_goal / update_study_goal. Matches Rust StudyGoalInput. */
+export interface StudyGoalInput {
+  /** null or blank targets every subject. */
+  subject: string | null;
+  period: StudyGoalPeriod;
+  target_hours: number;
+  start_date: string;
+  /** Required for term goals. */
+  end_date: string | null;
+}
+
+/** One goal's progress in a week. Matches Rust StudyGoalProgress. */
+export interface StudyGoalProgress {
+  goal: StudyGoal;
+  /** The week itself for weekly goals, the whole term for term goals. */
+  period_start: string;
+  period_end: string;
+  target_hours: number;
+  /** Hours logged in the period up to the end of the requested week. */
+  actual_hours: number;
+  remaining_hours: number;
+  percent_complete: number;
+  met: boolean;
+  /** Days of the period still ahead, counting today. */
+  days_remaining: number;
+  /** null when met or no days remain. */
+  required_hours_per_day: number | null;
+  /** Term goals: the target spread evenly over the term's weeks. */
+  weekly_target_hours: number;
+  week_hours: number;
+  current_streak_weeks: number;
+  best_streak_weeks: number;
+}
+
+/** Returned by get_study_goal_progress. Matches Rust StudyGoalWeekProgress. */
+export interface StudyGoalWeekProgress {
+  week_start: string;
+  week_end: string;
+  goals: StudyGoalProgress[];
+}
+
+/** Returned by get_due_reviews. Matches Rust DueReview. */
+export interface DueReview {
+  topic: StudyTopic;
+  /** 0 when due on the requested date. */
+  days_overdue: number;
+}
+
 // ---------------------------------------------------------------------------
 // Applications
 // ---------------------------------------------------------------------------
@@ -111,6 +179,52 @@ export interface StatusChangeInput {
   notes?: string;
 }
 
+/** Input for save_contact / update_contact. Matches Rust ContactInput. */
+export interface ContactInput {
+  name: string;
+  role: ContactRole;
+  email?: string;
+  phone?: string;
+  notes?: string;
+}
+
+/** Input for save_interview / update_interview. Matches Rust InterviewInput. */
+export interface InterviewInput {
+  date: string;
+  /** HH:MM, or '' when not known. */
+  time?: string;
+  round: string;
+  interviewers?: string;
+  prep_notes?: string;
+  /** Defaults to 'pending'. */
+  outcome?: InterviewOutcome;
+  notes?: string;
+}
+
+/** One application due for a follow-up. Matches Rust FollowUp. */
+export interface FollowUp {
+  application_id: number;
+  company: string;
+  role: string;
+  current_status: string;
+  last_status_date: string;
+  /** User-set follow_up_date, if any. */
+  follow_up_date: string | null;
+  /** Derived from the status and the age of the last status change. */
+  suggested_date: string | null;
+  /** follow_up_date when set, otherwise suggested_date. */
+  due_date: string;
+  /** Negative when overdue. */
+  days_until_due: number;
+}
+
+/** Result of get_follow_ups. Matches Rust FollowUps. */
+export interface FollowUps {
+  as_of: string;
+  overdue: FollowUp[];
+  upcoming: FollowUp[];
+}
+
 // ---------------------------------------------------------------------------
 // Recovery
 // ---------------------------------------------------------------------------
@@ -144,6 +258,15 @@ export interface UrgeEntryInput {
   notes: string;
 }
 
+/** Candidate urge for a relapse, from suggest_urge_links. Matches Rust UrgeLinkSuggestion. */
+export interface UrgeLinkSuggestion {
+  urge: UrgeEntry;
+  minutes_before: number;
+  trigger_match: boolean;
+  score: number;
+  is_current: boolean;
+}
+
 // ---------------------------------------------------------------------------
 // Config & Habits
 // ---------------------------------------------------------------------------
@@ -189,6 +312,14 @@ export interface HabitConfigSaveInput {
   column_name: string;
 }
 
+/** Input for save_clean_streak. Matches Rust CleanStreakInput. id null = create. */
+export interface CleanStreakInput {
+  id: number | null;
+  name: string;
+  vices: string[];
+  use_relapse_entries: boolean;
+}
+
 // ---------------------------------------------------------------------------
 // Weekly Review
 // ---------------------------------------------------------------------------
@@ -204,8 +335,8 @@ export interface WeeklyReviewInput {
   reflection: string;
 }
 
-/** Live-computed weekly stats returned by compute_weekly_stats. Matches Rust WeeklyStats. */
-export interface WeeklyStats {
+/** Live-computed stats for a date range. Matches Rust PeriodStats. */
+export interface PeriodStats {
   avg_score: number | null;
   days_tracked: number;
   best_day_score: number | null;
@@ -217,10 +348,75 @@ export interface WeeklyStats {
   current_streak: number | null;
 }
 
+/** Live-computed weekly stats returned by compute_weekly_stats. */
+export type WeeklyStats = PeriodStats;
+
+// ---------------------------------------------------------------------------
+// Periodic Review
+// ---------------------------------------------------------------------------
+
+/** Input for save_periodic_review. Matches Rust PeriodicReviewInput. */
+export interface PeriodicReviewInput {
+  period: ReviewPeriod;
+  /** First day of the month, quarter or year. */
+  period_start: string;
+  /** Answers keyed by ReviewPrompt.key. */
+  reflections: Record<string, string>;
+}
+
+/** Reflection prompt returned by get_review_prompts. Matches Rust ReviewPrompt. */
+export interface ReviewPrompt {
+  key: string;
+  question: string;
+}
+
+/** A saved weekly review inside the period. Matches Rust ReviewedWeek. */
+export interface ReviewedWeek {
+  week_start: string;
+  /** The weekly review's frozen avg_score. */
+  avg_score: number | null;
+}
+
+/**
+ * Live stats and weekly roll-up returned by compute_periodic_stats.
+ * Matches Rust PeriodicStats (PeriodStats and WeekRollup flattened).
+ */
+export interface PeriodicStats extends PeriodStats {
+  period: ReviewPeriod;
+  period_start: string;
+  period_end: string;
+  weeks_reviewed: number;
+  best_week_start: string | null;
+  best_week_score: number | null;
+  worst_week_start: string | null;
+  worst_week_score: number | null;
+  /** Mean of the weekly avg_scores, not of the days. */
+  avg_weekly_score: number | null;
+  weeks: ReviewedWeek[];
+}
+
 // ---------------------------------------------------------------------------
 // Milestones
 // ---------------------------------------------------------------------------
 
+/**
+ * Input for create_milestone / update_milestone. Matches Rust MilestoneInput.
+ * An empty threshold is replaced by a description generated from the rule.
+ */
+export interface MilestoneInput {
+  name: string;
+  emoji: string;
+  category: MilestoneCategory;
+  threshold: string;
+  rule: MilestoneRule;
+}
+
+/** Result of recompute_milestones. Matches Rust MilestoneRecompute. */
+export interface MilestoneRecompute {
+  newly_achieved: Milestone[];
+  redated: Milestone[];
+}
+
 /** Context for check_milestones. Matches Rust MilestoneContext. */
 export interface MilestoneContext {
   current_streak: number;
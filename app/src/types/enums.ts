@@ -32,6 +32,9 @@ export enum ApplicationStatus {
   NoResponse = 'no_response',
 }
 
+/** Which values reads of relapse/urge entries return. Matches Rust RecoveryView. */
+export type RecoveryView = 'original' | 'amended';
+
 export type CorrelationWindow = 0 | 30 | 60 | 90 | 180 | 365;
 
 export const VALID_CORRELATION_WINDOWS: readonly CorrelationWindow[] = [
@@ -13,6 +13,36 @@ import {
 
 export type MilestoneCategory = 'score' | 'clean' | 'study' | 'tracking';
 
+/** What a milestone rule measures. Matches Rust engine::milestone_rules::Metric. */
+export type MilestoneMetric =
+  | 'days_tracked'
+  | 'streak'
+  | 'clean_days'
+  | 'study_hours'
+  | 'high_focus_sessions'
+  | 'applications'
+  | 'avg_score'
+  | 'highest_score'
+  | 'habit_count'
+  | 'improving_months';
+
+export type MilestoneComparator = '>=' | '>' | '==' | '<=' | '<';
+
+/**
+ * Declarative award rule. Matches Rust MilestoneRule.
+ * `window_days` is required for avg_score (last N scored days) and not allowed
+ * for streak/clean_days; `habit` is required for habit_count.
+ */
+export interface MilestoneRule {
+  metric: MilestoneMetric;
+  comparator: MilestoneComparator;
+  target: number;
+  window_days: number | null;
+  habit: string | null;
+  /** clean_days only: named clean streak to count. null = the default streak. */
+  clean_streak?: string | null;
+}
+
 // ---------------------------------------------------------------------------
 // 1. HabitConfig (DATA_MODEL.md Section 3.1)
 // ---------------------------------------------------------------------------
@@ -121,6 +151,8 @@ export interface StudySession {
   notes: string;
   logged_at: string;
   last_modified: string;
+  /** StudyTopic the topic text resolved to; null for a blank topic. */
+  topic_id: number | null;
 }
 
 // ---------------------------------------------------------------------------
@@ -148,7 +180,7 @@ export interface Application {
 }
 
 // ---------------------------------------------------------------------------
-// 6. StatusChange (DATA_MODEL.md Section 3.6) — append-only
+// 6. StatusChange (DATA_MODEL.md Section 3.6) — corrections are audited
 // ---------------------------------------------------------------------------
 
 export interface StatusChange {
@@ -277,4 +309,238 @@ export interface Milestone {
   achieved: boolean;
   achieved_date: string | null;
   created_at: string;
+  rule: MilestoneRule | null;
+  is_custom: boolean;
+}
+
+// ---------------------------------------------------------------------------
+// 13. CleanStreak (DATA_MODEL.md Section 3.13)
+// ---------------------------------------------------------------------------
+
+/**
+ * A named clean-streak definition. A logged day is clean when none of
+ * `vices` was done and, if `use_relapse_entries`, no relapse was recorded.
+ */
+export interface CleanStreak {
+  id: number;
+  name: string;
+  vices: string[];
+  use_relapse_entries: boolean;
+  is_default: boolean;
+  created_at: string;
+  last_modified: string;
+  /** Consecutive clean days ending at the latest logged day. */
+  current_days: number;
+}
+
+// ---------------------------------------------------------------------------
+// 14. StatusChangeAudit (DATA_MODEL.md Section 3.14)
+// ---------------------------------------------------------------------------
+
+/** One correction to the status history. new_* are null for deletions. */
+export interface StatusChangeAudit {
+  id: number;
+  status_change_id: number;
+  application_id: number;
+  action: 'update' | 'delete';
+  old_status: ApplicationStatus;
+  old_date: string;
+  old_notes: string;
+  new_status: ApplicationStatus | null;
+  new_date: string | null;
+  new_notes: string | null;
+  created_at: string;
+}
+
+// ---------------------------------------------------------------------------
+// 15. Contact (DATA_MODEL.md Section 3.15)
+// ---------------------------------------------------------------------------
+
+export type ContactRole = 'recruiter' | 'hiring_manager' | 'referrer' | 'interviewer' | 'other';
+
+export interface Contact {
+  id: number;
+  application_id: number;
+  name: string;
+  role: ContactRole;
+  email: string;
+  phone: string;
+  notes: string;
+  created_at: string;
+  last_modified: string;
+}
+
+// ---------------------------------------------------------------------------
+// 16. Interview (DATA_MODEL.md Section 3.16)
+// ---------------------------------------------------------------------------
+
+export type InterviewOutcome = 'pending' | 'passed' | 'failed' | 'cancelled';
+
+export interface Interview {
+  id: number;
+  application_id: number;
+  date: string;
+  /** HH:MM, or '' when not known. */
+  time: string;
+  round: string;
+  interviewers: string;
+  prep_notes: string;
+  outcome: InterviewOutcome;
+  notes: string;
+  created_at: string;
+  last_modified: string;
+}
+
+/** Returned by get_application: the application with its contacts and interviews. */
+export interface ApplicationDetail extends Application {
+  contacts: Contact[];
+  interviews: Interview[];
+}
+
+// ---------------------------------------------------------------------------
+// 17. RecoveryAmendment (DATA_MODEL.md Section 3.17) — append only
+// ---------------------------------------------------------------------------
+
+export type RecoveryEntity = 'relapse_entry' | 'urge_entry';
+
+/** A correction to one field of a locked relapse or urge entry. */
+export interface RecoveryAmendment {
+  id: number;
+  entity: RecoveryEntity;
+  entry_id: number;
+  field: string;
+  /** Effective value before this amendment. */
+  old_value: string;
+  new_value: string;
+  reason: string;
+  created_at: string;
+}
+
+/** Returned by get_amended_relapse_entry / get_amended_urge_entry. */
+export interface AmendedEntry<T> {
+  original: T;
+  /** Oldest first. */
+  amendments: RecoveryAmendment[];
+  /** `original` with the latest amendment of each field applied. */
+  effective: T;
+}
+
+// ---------------------------------------------------------------------------
+// 18. StudyTimer (DATA_MODEL.md Section 3.18) — at most one row
+// ---------------------------------------------------------------------------
+
+/** The running or paused study timer. Stopping it creates a StudySession. */
+export interface StudyTimer {
+  id: number;
+  subject: string;
+  study_type: string;
+  location: string;
+  /** Local 'YYYY-MM-DDTHH:MM:SS'. */
+  started_at: string;
+  /** Set while paused. */
+  paused_at: string | null;
+  /** Total seconds of pauses already resumed. */
+  paused_seconds: number;
+  pause_count: number;
+  /** Derived. */
+  is_paused: boolean;
+  /** Derived: active seconds as of the request. */
+  elapsed_seconds: number;
+}
+
+// ---------------------------------------------------------------------------
+// 19. StudyGoal (DATA_MODEL.md Section 3.19)
+// ---------------------------------------------------------------------------
+
+/** 'week': hours in each week. 'term': hours over start_date..end_date. */
+export type StudyGoalPeriod = 'week' | 'term';
+
+export interface StudyGoal {
+  id: number;
+  /** null counts sessions of every subject. */
+  subject: string | null;
+  period: StudyGoalPeriod;
+  target_hours: number;
+  start_date: string;
+  /** null means open-ended (weekly goals only). */
+  end_date: string | null;
+  created_at: string;
+  last_modified: string;
+}
+
+// ---------------------------------------------------------------------------
+// 20. StudyTopic (DATA_MODEL.md Section 3.20)
+// ---------------------------------------------------------------------------
+
+/** SM-2 review state, replayed from the topic's sessions. */
+export interface ReviewSchedule {
+  /** Successful reviews in a row since the topic was last learned or lapsed. */
+  repetitions: number;
+  interval_days: number;
+  ease_factor: number;
+  review_count: number;
+  /** Reviews with a focus score below 3. */
+  lapses: number;
+  last_studied: string;
+  last_reviewed: string | null;
+  /** last_studied + interval_days. */
+  due_date: string;
+}
+
+/** Created when a session names a new topic; names match ignoring case. */
+export interface StudyTopic {
+  id: number;
+  subject: string;
+  name: string;
+  created_at: string;
+  /** Derived. */
+  session_count: number;
+  /** Derived; null when no session links to the topic. */
+  schedule: ReviewSchedule | null;
+}
+
+// ---------------------------------------------------------------------------
+// 21. PeriodicReview (DATA_MODEL.md Section 3.21)
+// ---------------------------------------------------------------------------
+
+export type ReviewPeriod = 'month' | 'quarter' | 'year';
+
+export interface PeriodicReview {
+  id: number;
+  period: ReviewPeriod;
+  period_start: string;
+  period_end: string;
+
+  // Auto-computed stats (frozen at save)
+  avg_score: number | null;
+  days_tracked: number | null;
+  best_day_score: number | null;
+  worst_day_score: number | null;
+  habits_completed: number | null;
+  study_hours: number | null;
+  applications_sent: number | null;
+  relapses: number | null;
+  urges_resisted: number | null;
+  streak_at_end: number | null;
+
+  // Roll-up of the saved weekly reviews (frozen at save)
+  weeks_reviewed: number | null;
+  best_week_start: string | null;
+  best_week_score: number | null;
+  worst_week_start: string | null;
+  worst_week_score: number | null;
+  avg_weekly_score: number | null;
+
+  /** Prompt key -> answer. */
+  reflections: Record<string, string>;
+
+  // Snapshot data
+  snapshot_date: string | null;
+  /** JSON array of final_score, one per day of the period. */
+  score_snapshot: string | null;
+  /** JSON array of ReviewedWeek. */
+  week_snapshot: string | null;
+
+  logged_at: string;
+  last_modified: string;
 }